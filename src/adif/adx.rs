@@ -0,0 +1,269 @@
+//! ADX (XML ADIF) writing.
+//!
+//! ADX carries the same fields as ADIF: the writer uses the field lists from
+//! [`writer`](super::writer), so an exported log holds everything the `.adif`
+//! file does. Application-defined fields such as `APP_DUKLOG_LOG_ID` become
+//! `<APP PROGRAMID="DUKLOG" FIELDNAME="LOG_ID" TYPE="S">` elements.
+//!
+//! duklog writes ADX but does not read it; log files stay ADIF. An export is
+//! lossless because it carries, in order, exactly the fields that
+//! [`format_adif`](super::format_adif) writes and [`read_log`](super::read_log)
+//! reads back.
+
+use difa::Field;
+
+use super::writer::{header_fields, qso_fields};
+use crate::model::Log;
+
+/// Formats a complete ADX document (header + all QSO records).
+pub fn format_adx(log: &Log) -> String {
+    let mut out = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<ADX>\n  <HEADER>\n");
+    for field in header_fields(log) {
+        push_field(&mut out, "    ", &field);
+    }
+    out.push_str("  </HEADER>\n  <RECORDS>\n");
+    for qso in log.qsos() {
+        out.push_str("    <RECORD>\n");
+        for field in qso_fields(log, qso) {
+            push_field(&mut out, "      ", &field);
+        }
+        out.push_str("    </RECORD>\n");
+    }
+    out.push_str("  </RECORDS>\n</ADX>\n");
+    out
+}
+
+/// Appends one field as an indented element on its own line.
+fn push_field(out: &mut String, indent: &str, field: &Field) {
+    let value = escape(&field.value().as_str());
+    let name = field.name();
+    out.push_str(indent);
+    match app_parts(name) {
+        Some((program_id, field_name)) => out.push_str(&format!(
+            "<APP PROGRAMID=\"{program_id}\" FIELDNAME=\"{field_name}\" TYPE=\"S\">{value}</APP>"
+        )),
+        None => out.push_str(&format!("<{name}>{value}</{name}>")),
+    }
+    out.push('\n');
+}
+
+/// Splits an application-defined field name (`APP_{PROGRAMID}_{FIELDNAME}`)
+/// into its program ID and field name.
+fn app_parts(name: &str) -> Option<(&str, &str)> {
+    name.strip_prefix("APP_")?.split_once('_')
+}
+
+/// Escapes text for use in element content or a quoted attribute.
+fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&apos;"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+
+    use super::*;
+    use crate::model::{
+        Band, FdClass, FdPowerCategory, FieldDayLog, GeneralLog, Mode, PotaLog, Qso, WfdClass,
+        WfdLog,
+    };
+
+    fn make_qso(call: &str) -> Qso {
+        Qso::new(
+            call.to_string(),
+            "59".to_string(),
+            "57".to_string(),
+            Band::M20,
+            Mode::Ft4,
+            Utc.with_ymd_and_hms(2026, 2, 16, 14, 30, 5).unwrap(),
+            "<loud> & \"clear\"".to_string(),
+            None,
+            None,
+            Some(14_080_000),
+            Some("N1OP".to_string()),
+        )
+        .unwrap()
+    }
+
+    fn with_qsos(mut log: Log) -> Log {
+        let mut qso = make_qso("KD9XYZ");
+        qso.id = Some("qso-1".to_string());
+        log.add_qso(qso);
+        let mut qso = make_qso("N0CALL");
+        qso.comments = String::new();
+        qso.frequency = None;
+        qso.operator = None;
+        log.add_qso(qso);
+        log
+    }
+
+    fn make_pota_log() -> Log {
+        let mut log = PotaLog::new(
+            "W1AW".to_string(),
+            Some("KB1ABC".to_string()),
+            "K-0001".to_string(),
+            "FN31".to_string(),
+        )
+        .unwrap();
+        log.header.created_at = Utc.with_ymd_and_hms(2026, 2, 16, 12, 0, 0).unwrap();
+        log.header.log_id = "K-0001-20260216-120000".to_string();
+        Log::Pota(log)
+    }
+
+    mod same_fields_as_adif {
+        use super::*;
+
+        /// Asserts that the ADX document for `log` holds each field the ADIF
+        /// writer writes, in order, and nothing else.
+        fn assert_carries_adif_fields(log: Log) {
+            let log = with_qsos(log);
+            let mut expected = String::new();
+            for field in header_fields(&log) {
+                push_field(&mut expected, "", &field);
+            }
+            for qso in log.qsos() {
+                expected.push_str("<RECORD>\n");
+                for field in qso_fields(&log, qso) {
+                    push_field(&mut expected, "", &field);
+                }
+                expected.push_str("</RECORD>\n");
+            }
+            let xml = format_adx(&log);
+            let elements: String = xml
+                .lines()
+                .map(str::trim)
+                .filter(|line| !["<HEADER>", "</HEADER>", "<RECORDS>", "</RECORDS>"].contains(line))
+                .skip(2)
+                .take_while(|line| *line != "</ADX>")
+                .flat_map(|line| [line, "\n"])
+                .collect();
+            assert_eq!(elements, expected, "{xml}");
+        }
+
+        #[test]
+        fn pota_log() {
+            let mut log = make_pota_log();
+            log.add_qso(Qso {
+                their_park: Some("K-0002".to_string()),
+                ..make_qso("W2P2P")
+            });
+            assert_carries_adif_fields(log);
+        }
+
+        #[test]
+        fn general_log() {
+            let mut log =
+                GeneralLog::new("W1AW/P".to_string(), None, "FN31pr".to_string()).unwrap();
+            log.header.created_at = Utc.with_ymd_and_hms(2026, 2, 16, 12, 0, 0).unwrap();
+            assert_carries_adif_fields(Log::General(log));
+        }
+
+        #[test]
+        fn field_day_log() {
+            let mut log = FieldDayLog::new(
+                "W1AW".to_string(),
+                None,
+                2,
+                FdClass::B,
+                "EPA".to_string(),
+                FdPowerCategory::High,
+                String::new(),
+            )
+            .unwrap();
+            log.header.created_at = Utc.with_ymd_and_hms(2026, 6, 27, 18, 0, 0).unwrap();
+            let mut log = Log::FieldDay(log);
+            log.add_qso(Qso {
+                exchange_rcvd: Some("3A CT".to_string()),
+                ..make_qso("K1ABC")
+            });
+            assert_carries_adif_fields(log);
+        }
+
+        #[test]
+        fn winter_field_day_log() {
+            let mut log = WfdLog::new(
+                "W1AW".to_string(),
+                None,
+                1,
+                WfdClass::H,
+                "EPA".to_string(),
+                "FN31".to_string(),
+            )
+            .unwrap();
+            log.header.created_at = Utc.with_ymd_and_hms(2026, 1, 24, 18, 0, 0).unwrap();
+            assert_carries_adif_fields(Log::WinterFieldDay(log));
+        }
+
+        #[test]
+        fn extra_fields() {
+            let mut log = make_pota_log();
+            log.header_mut().extra_fields.insert("MY_RIG", "KX2");
+            log.add_qso(Qso {
+                extra_fields: [("NAME", "Hiram"), ("APP_OTHER_RATING", "5")]
+                    .into_iter()
+                    .collect(),
+                ..make_qso("W2XTR")
+            });
+            let xml = format_adx(&log);
+            assert!(
+                xml.contains("<APP PROGRAMID=\"OTHER\" FIELDNAME=\"RATING\" TYPE=\"S\">5</APP>"),
+                "{xml}"
+            );
+            assert_carries_adif_fields(log);
+        }
+    }
+
+    mod writing {
+        use super::*;
+
+        #[test]
+        fn app_fields_use_app_elements() {
+            let xml = format_adx(&make_pota_log());
+            assert!(
+                xml.contains(
+                    "<APP PROGRAMID=\"DUKLOG\" FIELDNAME=\"LOG_ID\" TYPE=\"S\">\
+                     K-0001-20260216-120000</APP>"
+                ),
+                "{xml}"
+            );
+            assert!(xml.contains("<STATION_CALLSIGN>W1AW</STATION_CALLSIGN>"));
+        }
+
+        #[test]
+        fn qso_fields_match_adif() {
+            let xml = format_adx(&with_qsos(make_pota_log()));
+            assert!(xml.contains("<QSO_DATE>20260216</QSO_DATE>"), "{xml}");
+            assert!(xml.contains("<TIME_ON>143005</TIME_ON>"), "{xml}");
+            assert!(xml.contains("<MODE>MFSK</MODE>"), "{xml}");
+            assert!(xml.contains("<SUBMODE>FT4</SUBMODE>"), "{xml}");
+            assert!(xml.contains("<FREQ>14.080</FREQ>"), "{xml}");
+        }
+
+        #[test]
+        fn escapes_text() {
+            let xml = format_adx(&with_qsos(make_pota_log()));
+            assert!(
+                xml.contains("<COMMENT>&lt;loud&gt; &amp; &quot;clear&quot;</COMMENT>"),
+                "{xml}"
+            );
+        }
+
+        #[test]
+        fn empty_log_has_empty_records() {
+            let xml = format_adx(&make_pota_log());
+            assert!(xml.starts_with("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<ADX>"));
+            assert!(xml.contains("<RECORDS>\n  </RECORDS>"));
+        }
+    }
+}
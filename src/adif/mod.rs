@@ -3,13 +3,23 @@
 //! Formatting functions convert [`Log`](crate::model::Log) and
 //! [`Qso`](crate::model::Qso) types into ADIF v3.1.6 text. No I/O — the
 //! storage layer handles writing to disk. The reader reconstructs a `Log`
-//! from an `.adif` file previously written by the formatter.
+//! from an `.adif` file previously written by the formatter. The same data
+//! can be exported as ADX, the XML form of ADIF. [`validate`] checks
+//! an ADI document against the specification without an external tool.
+//! [`salvage_log`] reads a log file with damaged records, such as one cut off
+//! by a crash, keeping every readable record.
 
+mod adx;
 mod error;
 mod reader;
+mod salvage;
 // High-level ADIF document formatting.
+mod validate;
 mod writer;
 
+pub use adx::format_adx;
 pub use error::AdifError;
 pub use reader::read_log;
+pub use salvage::{Damage, DamagedRecord, SalvagedLog, salvage_log};
+pub use validate::{Finding, Location, Severity, validate};
 pub use writer::{format_adif, format_header, format_qso};
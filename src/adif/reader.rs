@@ -2,7 +2,9 @@
 //!
 //! Parses internal `.adif` files written by the storage layer and reconstructs
 //! [`Log`](crate::model::Log) values. The `APP_DUKLOG_*` header fields encode
-//! the log type and all type-specific metadata.
+//! the log type and all type-specific metadata. Fields duklog does not
+//! interpret are kept as [`ExtraFields`] so that rewriting the file does not
+//! lose them.
 
 use std::path::Path;
 
@@ -13,10 +15,59 @@ use tokio::io::BufReader;
 
 use super::error::AdifError;
 use crate::model::{
-    Band, FdPowerCategory, FieldDayLog, GeneralLog, Log, LogHeader, Mode, PotaLog, Qso, WfdLog,
-    parse_fd_class, parse_wfd_class, validate_tx_count,
+    Band, ExtraFields, FdPowerCategory, FieldDayLog, GeneralLog, Log, LogHeader, Mode, PotaLog,
+    Qso, WfdLog, parse_fd_class, parse_wfd_class, validate_tx_count,
 };
 
+/// Header fields duklog reads or regenerates on write; any others in the
+/// header are kept as the log's extra fields.
+const HEADER_FIELDS: &[&str] = &[
+    "ADIF_VER",
+    "PROGRAMID",
+    "PROGRAMVERSION",
+    "CREATED_TIMESTAMP",
+    "STATION_CALLSIGN",
+    "OPERATOR",
+    "MY_GRIDSQUARE",
+    "APP_DUKLOG_LOG_ID",
+    "APP_DUKLOG_LOG_TYPE",
+    "APP_DUKLOG_PARK_REF",
+    "APP_DUKLOG_TX_COUNT",
+    "APP_DUKLOG_FD_CLASS",
+    "APP_DUKLOG_WFD_CLASS",
+    "APP_DUKLOG_SECTION",
+    "APP_DUKLOG_POWER",
+];
+
+/// QSO fields duklog reads or derives from the log on write in every log
+/// type; any others in a record, beyond the log type's own (see
+/// [`known_qso_fields`]), are kept as the QSO's extra fields.
+const QSO_FIELDS: &[&str] = &[
+    "STATION_CALLSIGN",
+    "OPERATOR",
+    "CALL",
+    "QSO_DATE",
+    "TIME_ON",
+    "BAND",
+    "MODE",
+    "SUBMODE",
+    "RST_SENT",
+    "RST_RCVD",
+    "MY_GRIDSQUARE",
+    "FREQ",
+    "COMMENT",
+    "APP_DUKLOG_QSO_ID",
+];
+
+/// QSO fields a POTA log writes from its own park reference.
+const POTA_QSO_FIELDS: &[&str] = &["MY_SIG", "MY_SIG_INFO"];
+
+/// QSO fields a POTA log writes for a park-to-park contact.
+const POTA_P2P_FIELDS: &[&str] = &["SIG", "SIG_INFO"];
+
+/// QSO fields Field Day and Winter Field Day logs write for the exchange.
+const CONTEST_QSO_FIELDS: &[&str] = &["CONTEST_ID", "STX_STRING", "SRX_STRING"];
+
 /// Reads an ADIF file and reconstructs the [`Log`] it encodes.
 ///
 /// The file must have been written by [`format_adif`](super::writer::format_adif).
@@ -38,7 +89,22 @@ pub async fn read_log(path: &Path) -> Result<Log, AdifError> {
         }
     };
 
-    let station_callsign = get_str(&header_record, "station_callsign")?;
+    let mut records = Vec::new();
+    while let Some(result) = stream.next().await {
+        records.push(result?);
+    }
+    log_from_records(&header_record, &records)
+}
+
+/// Reconstructs a [`Log`] from a header record and its QSO records.
+///
+/// Shared by [`read_log`] and [`salvage_log`](super::salvage_log); field
+/// lookups are case-insensitive.
+pub(super) fn log_from_records(
+    header_record: &Record,
+    records: &[Record],
+) -> Result<Log, AdifError> {
+    let station_callsign = get_str(header_record, "station_callsign")?;
     let operator = header_record
         .get("operator")
         .map(|d| d.as_str().into_owned());
@@ -46,27 +112,26 @@ pub async fn read_log(path: &Path) -> Result<Log, AdifError> {
         .get("my_gridsquare")
         .map(|d| d.as_str().into_owned())
         .unwrap_or_default();
-    let created_at = parse_created_timestamp(&header_record)?;
-    let log_id = get_str(&header_record, "app_duklog_log_id")?;
-    let log_type = get_str(&header_record, "app_duklog_log_type")?;
+    let created_at = parse_created_timestamp(header_record)?;
+    let log_id = get_str(header_record, "app_duklog_log_id")?;
+    let log_type = get_str(header_record, "app_duklog_log_type")?;
 
-    // Extract type-specific fields from the header record before consuming the stream.
+    // Extract type-specific fields from the header record.
     let park_ref = header_record
         .get("app_duklog_park_ref")
         .map(|d| d.as_str().into_owned());
-    let tx_count = parse_opt_tx_count(&header_record)?;
-    let fd_class = parse_opt_fd_class(&header_record)?;
-    let wfd_class = parse_opt_wfd_class(&header_record)?;
+    let tx_count = parse_opt_tx_count(header_record)?;
+    let fd_class = parse_opt_fd_class(header_record)?;
+    let wfd_class = parse_opt_wfd_class(header_record)?;
     let section = header_record
         .get("app_duklog_section")
         .map(|d| d.as_str().into_owned());
-    let power = parse_opt_power(&header_record)?;
+    let power = parse_opt_power(header_record)?;
 
-    let mut qsos = Vec::new();
-    while let Some(result) = stream.next().await {
-        let record = result?;
-        qsos.push(parse_qso(&record)?);
-    }
+    let qsos = records
+        .iter()
+        .map(|record| parse_qso(record, &log_type, operator.as_deref()))
+        .collect::<Result<Vec<_>, _>>()?;
 
     let header = LogHeader {
         station_callsign,
@@ -75,6 +140,7 @@ pub async fn read_log(path: &Path) -> Result<Log, AdifError> {
         qsos,
         created_at,
         log_id,
+        extra_fields: extra_fields(header_record, HEADER_FIELDS),
     };
 
     match log_type.as_str() {
@@ -129,6 +195,18 @@ pub async fn read_log(path: &Path) -> Result<Log, AdifError> {
     }
 }
 
+/// Collects the fields of `record` not named in `known`, in record order.
+///
+/// Values are kept as ADIF text; typed values (dates, numbers) are written
+/// back in their ADIF form.
+fn extra_fields(record: &Record, known: &[&str]) -> ExtraFields {
+    record
+        .fields()
+        .filter(|(name, _)| !known.iter().any(|k| k.eq_ignore_ascii_case(name)))
+        .map(|(name, value)| (name, value.as_str().into_owned()))
+        .collect()
+}
+
 fn get_str(record: &Record, field: &str) -> Result<String, AdifError> {
     record
         .get(field)
@@ -188,7 +266,45 @@ fn parse_opt_power(record: &Record) -> Result<Option<FdPowerCategory>, AdifError
         .transpose()
 }
 
-fn parse_qso(record: &Record) -> Result<Qso, AdifError> {
+/// Returns `true` if `record` is a contact with another POTA park.
+///
+/// Only a `SIG_INFO` whose `SIG` is `POTA` is a park reference; other
+/// programs (WWFF, SOTA, ...) are kept as extra fields.
+fn is_pota_p2p(record: &Record) -> bool {
+    record
+        .get("sig")
+        .is_some_and(|d| d.as_str().eq_ignore_ascii_case("POTA"))
+        && record.get("sig_info").is_some()
+}
+
+/// Returns the QSO fields the writer regenerates for a record in a log of
+/// `log_type` (its `APP_DUKLOG_LOG_TYPE`).
+fn known_qso_fields(record: &Record, log_type: &str) -> Vec<&'static str> {
+    let mut known = QSO_FIELDS.to_vec();
+    match log_type {
+        "pota" => {
+            known.extend(POTA_QSO_FIELDS);
+            if is_pota_p2p(record) {
+                known.extend(POTA_P2P_FIELDS);
+            }
+        }
+        "field_day" | "wfd" => known.extend(CONTEST_QSO_FIELDS),
+        _ => {}
+    }
+    known
+}
+
+/// Parses a QSO record from a log of `log_type`. A per-record `OPERATOR`
+/// equal to the log's operator is the log default, so it is stored as `None`.
+///
+/// Type-specific fields are only read where the log type writes them back
+/// (park references in POTA logs, the received exchange in contest logs);
+/// elsewhere they are kept as extra fields.
+pub(super) fn parse_qso(
+    record: &Record,
+    log_type: &str,
+    log_operator: Option<&str>,
+) -> Result<Qso, AdifError> {
     let their_call = get_str(record, "call")?;
 
     let date = record
@@ -206,8 +322,8 @@ fn parse_qso(record: &Record) -> Result<Qso, AdifError> {
         .ok_or_else(|| AdifError::InvalidLog(format!("unknown BAND: {band_str}")))?;
 
     let mode_str = get_str(record, "mode")?;
-    let mode = Mode::from_adif_str(&mode_str)
-        .ok_or_else(|| AdifError::InvalidLog(format!("unknown MODE: {mode_str}")))?;
+    let submode_str = record.get("submode").map(|d| d.as_str().into_owned());
+    let mode = Mode::from_adif_str(&mode_str, submode_str.as_deref()).unwrap_or(Mode::Other);
 
     let rst_sent = get_str(record, "rst_sent")?;
     let rst_rcvd = get_str(record, "rst_rcvd")?;
@@ -217,14 +333,23 @@ fn parse_qso(record: &Record) -> Result<Qso, AdifError> {
         .map(|d| d.as_str().into_owned())
         .unwrap_or_default();
 
-    let their_park = record.get("sig_info").map(|d| d.as_str().into_owned());
-    let exchange_rcvd = record.get("srx_string").map(|d| d.as_str().into_owned());
+    let their_park = (log_type == "pota" && is_pota_p2p(record))
+        .then(|| record.get("sig_info").map(|d| d.as_str().into_owned()))
+        .flatten();
+    let exchange_rcvd = matches!(log_type, "field_day" | "wfd")
+        .then(|| record.get("srx_string").map(|d| d.as_str().into_owned()))
+        .flatten();
     let frequency = record
         .get("freq")
         .and_then(|d| d.as_str().parse::<f64>().ok())
-        .map(|mhz| (mhz * 1000.0).round() as u32);
+        .map(|mhz| (mhz * 1_000_000.0).round() as u64)
+        .filter(|&hz| hz > 0);
+    let operator = record
+        .get("operator")
+        .map(|d| d.as_str().into_owned())
+        .filter(|op| Some(op.as_str()) != log_operator);
 
-    Qso::new(
+    let mut qso = Qso::new(
         their_call,
         rst_sent,
         rst_rcvd,
@@ -235,8 +360,32 @@ fn parse_qso(record: &Record) -> Result<Qso, AdifError> {
         their_park,
         exchange_rcvd,
         frequency,
+        operator,
     )
-    .map_err(|e| AdifError::InvalidLog(e.to_string()))
+    .map_err(|e| AdifError::InvalidLog(e.to_string()))?;
+    qso.id = record
+        .get("app_duklog_qso_id")
+        .map(|d| d.as_str().into_owned());
+    qso.extra_fields = extra_fields(record, &known_qso_fields(record, log_type));
+    // A submode with no exact variant (FST4W, PSK63, ...) is read as its mode
+    // family, and a mode duklog does not model (HELL, JT9, ...) as
+    // `Mode::Other`; keep what was read so the writer can put it back.
+    let submode = submode_str.as_deref().filter(|s| !s.is_empty());
+    if mode == Mode::Other {
+        qso.extra_fields.insert("MODE", mode_str.as_str());
+        if let Some(submode) = submode {
+            qso.extra_fields.insert("SUBMODE", submode);
+        }
+    } else if let Some(submode) = submode
+        && !(mode.adif_mode().eq_ignore_ascii_case(&mode_str)
+            && mode
+                .adif_submode()
+                .is_some_and(|s| s.eq_ignore_ascii_case(submode)))
+    {
+        qso.extra_fields.insert("MODE", mode_str.as_str());
+        qso.extra_fields.insert("SUBMODE", submode);
+    }
+    Ok(qso)
 }
 
 #[cfg(test)]
@@ -314,6 +463,7 @@ mod tests {
             None,
             None,
             None,
+            None,
         )
         .unwrap()
     }
@@ -330,6 +480,7 @@ mod tests {
             Some("K-1234".to_string()),
             None,
             None,
+            None,
         )
         .unwrap()
     }
@@ -392,12 +543,266 @@ mod tests {
             String::new(),
             None,
             None,
-            Some(14_225),
+            Some(14_225_000),
+            None,
         )
         .unwrap();
         log.add_qso(qso);
         let loaded = round_trip(&log).await;
-        assert_eq!(loaded.header().qsos[0].frequency, Some(14_225));
+        assert_eq!(loaded.header().qsos[0].frequency, Some(14_225_000));
+    }
+
+    #[tokio::test]
+    async fn sub_khz_frequencies_round_trip() {
+        for (band, hz) in [
+            (Band::M2190, 137_500),
+            (Band::M630, 475_750),
+            (Band::M20, 14_074_125),
+        ] {
+            let mut log = make_general_log();
+            let qso = Qso::new(
+                "KD9XYZ".to_string(),
+                "599".to_string(),
+                "599".to_string(),
+                band,
+                Mode::Cw,
+                Utc.with_ymd_and_hms(2026, 2, 16, 14, 30, 0).unwrap(),
+                String::new(),
+                None,
+                None,
+                Some(hz),
+                None,
+            )
+            .unwrap();
+            log.add_qso(qso);
+            let loaded = round_trip(&log).await;
+            assert_eq!(loaded.header().qsos[0].frequency, Some(hz));
+            assert_eq!(loaded.header().qsos[0].band, band);
+        }
+    }
+
+    #[tokio::test]
+    async fn per_qso_operators_round_trip() {
+        let mut log = make_general_log();
+        log.header_mut().operator = Some("N0CALL".to_string());
+        for op in [None, Some("K1ABC"), Some("W1AW"), Some("N0CALL")] {
+            let mut qso = make_qso();
+            qso.operator = op.map(str::to_string);
+            log.add_qso(qso);
+        }
+        let loaded = round_trip(&log).await;
+        let operators: Vec<Option<&str>> = loaded
+            .header()
+            .qsos
+            .iter()
+            .map(|q| q.operator.as_deref())
+            .collect();
+        // A per-record OPERATOR matching the log operator reads back as the default.
+        assert_eq!(operators, [None, Some("K1ABC"), Some("W1AW"), None]);
+    }
+
+    #[tokio::test]
+    async fn qso_id_round_trips() {
+        let mut log = make_general_log();
+        let mut qso = make_qso();
+        qso.id = Some("20260216T143000-KD9XYZ".to_string());
+        log.add_qso(qso);
+        log.add_qso(make_qso());
+        let loaded = round_trip(&log).await;
+        assert_eq!(log, loaded);
+    }
+
+    #[tokio::test]
+    async fn extra_fields_round_trip() {
+        let mut log = make_fd_log();
+        log.header_mut().extra_fields.insert("MY_RIG", "KX2");
+        let mut qso = make_qso();
+        qso.extra_fields = [("NAME", "Hiram"), ("STATE", "CT"), ("APP_OTHER_X", "1")]
+            .into_iter()
+            .collect();
+        log.add_qso(qso);
+        let loaded = round_trip(&log).await;
+        assert_eq!(log, loaded);
+    }
+
+    #[tokio::test]
+    async fn unknown_fields_survive_rewrite() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("hand-edited.adif");
+        let header = crate::adif::format_header(&make_general_log())
+            .unwrap()
+            .replace("<eoh>", "<MY_RIG:3>KX2\n<eoh>");
+        let record = "<CALL:6>KD9XYZ<NAME:5>Hiram<QSO_DATE:8>20260216<TIME_ON:6>143000\
+                      <BAND:3>20M<MODE:3>SSB<RST_SENT:2>59<RST_RCVD:2>57<state:2>CT<eor>\n";
+        tokio::fs::write(&path, header + record).await.unwrap();
+
+        let log = read_log(&path).await.unwrap();
+        assert_eq!(log.header().extra_fields.get("MY_RIG"), Some("KX2"));
+        let extra: Vec<_> = log.qsos()[0].extra_fields.iter().collect();
+        assert_eq!(extra, vec![("NAME", "Hiram"), ("STATE", "CT")]);
+        assert_eq!(round_trip(&log).await, log);
+    }
+
+    /// Writes `log`'s header followed by `record` and reads the file back.
+    async fn read_with_record(log: &Log, record: &str) -> Log {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("imported.adif");
+        let header = crate::adif::format_header(log).unwrap();
+        tokio::fs::write(&path, header + record).await.unwrap();
+        read_log(&path).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn general_log_keeps_contest_and_sig_fields() {
+        let record = "<CALL:6>KD9XYZ<QSO_DATE:8>20260216<TIME_ON:6>143000<BAND:3>20M\
+                      <MODE:3>SSB<RST_SENT:2>59<RST_RCVD:2>57<CONTEST_ID:6>CQ-WPX\
+                      <STX_STRING:3>001<SRX_STRING:3>042<SIG:4>WWFF<SIG_INFO:8>KFF-1234<eor>\n";
+        let log = read_with_record(&make_general_log(), record).await;
+        let qso = &log.qsos()[0];
+        assert_eq!(qso.exchange_rcvd, None);
+        assert_eq!(qso.their_park, None);
+
+        let written = format_adif(&log).unwrap();
+        for field in [
+            "<CONTEST_ID:6>CQ-WPX",
+            "<STX_STRING:3>001",
+            "<SRX_STRING:3>042",
+            "<SIG:4>WWFF",
+            "<SIG_INFO:8>KFF-1234",
+        ] {
+            assert!(written.contains(field), "{field} missing from: {written}");
+        }
+        assert_eq!(round_trip(&log).await, log);
+    }
+
+    #[tokio::test]
+    async fn pota_log_reads_only_pota_sig_as_park() {
+        let wwff = "<CALL:6>KD9XYZ<QSO_DATE:8>20260216<TIME_ON:6>143000<BAND:3>20M\
+                    <MODE:3>SSB<RST_SENT:2>59<RST_RCVD:2>57<SIG:4>WWFF<SIG_INFO:8>KFF-1234<eor>\n";
+        let pota = "<CALL:6>N0CALL<QSO_DATE:8>20260216<TIME_ON:6>143500<BAND:3>20M\
+                    <MODE:3>SSB<RST_SENT:2>59<RST_RCVD:2>57<SIG:4>POTA<SIG_INFO:6>K-0002<eor>\n";
+        let log = read_with_record(&make_pota_log(), &format!("{wwff}{pota}")).await;
+        assert_eq!(log.qsos()[0].their_park, None);
+        assert_eq!(log.qsos()[0].extra_fields.get("SIG_INFO"), Some("KFF-1234"));
+        assert_eq!(log.qsos()[1].their_park.as_deref(), Some("K-0002"));
+        assert!(log.qsos()[1].extra_fields.is_empty());
+
+        let written = format_adif(&log).unwrap();
+        assert!(
+            written.contains("<SIG:4>WWFF<SIG_INFO:8>KFF-1234"),
+            "{written}"
+        );
+        assert!(
+            !written.contains("<SIG:4>POTA<SIG_INFO:8>KFF-1234"),
+            "{written}"
+        );
+        assert_eq!(round_trip(&log).await, log);
+    }
+
+    #[tokio::test]
+    async fn every_mode_round_trips() {
+        let mut log = make_general_log();
+        for mode in Mode::all() {
+            let mut qso = make_qso();
+            qso.mode = *mode;
+            log.add_qso(qso);
+        }
+        let loaded = round_trip(&log).await;
+        let modes: Vec<Mode> = loaded.header().qsos.iter().map(|q| q.mode).collect();
+        assert_eq!(modes, Mode::all());
+    }
+
+    async fn read_single_qso_mode(mode_fields: &str) -> Result<Mode, AdifError> {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("modes.adif");
+        let header = crate::adif::format_header(&make_general_log()).unwrap();
+        let record = format!(
+            "<CALL:6>KD9XYZ<QSO_DATE:8>20260216<TIME_ON:6>143000<BAND:3>20M{mode_fields}\
+             <RST_SENT:3>-10<RST_RCVD:3>-10<eor>\n"
+        );
+        tokio::fs::write(&path, header + &record).await.unwrap();
+        read_log(&path).await.map(|log| log.header().qsos[0].mode)
+    }
+
+    #[tokio::test]
+    async fn mode_submode_pair_is_parsed() {
+        let mode = read_single_qso_mode("<MODE:4>MFSK<SUBMODE:3>FT4").await;
+        assert_eq!(mode.unwrap(), Mode::Ft4);
+    }
+
+    #[tokio::test]
+    async fn legacy_submode_in_mode_field_is_parsed() {
+        let mode = read_single_qso_mode("<MODE:3>JS8").await;
+        assert_eq!(mode.unwrap(), Mode::Js8);
+    }
+
+    #[tokio::test]
+    async fn unmodelled_submode_falls_back_to_mode_family() {
+        let mode = read_single_qso_mode("<MODE:4>MFSK<SUBMODE:5>FST4W").await;
+        assert_eq!(mode.unwrap(), Mode::Mfsk);
+    }
+
+    #[tokio::test]
+    async fn unmodelled_submode_is_written_back_unchanged() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("fst4w.adif");
+        let header = crate::adif::format_header(&make_general_log()).unwrap();
+        let record = "<CALL:6>KD9XYZ<QSO_DATE:8>20260216<TIME_ON:6>143000<BAND:3>20M\
+                      <MODE:4>MFSK<SUBMODE:5>FST4W<RST_SENT:3>-10<RST_RCVD:3>-10<eor>\n";
+        tokio::fs::write(&path, header + record).await.unwrap();
+
+        let log = read_log(&path).await.unwrap();
+        let qso = &log.qsos()[0];
+        assert_eq!(qso.mode, Mode::Mfsk);
+        assert_eq!(qso.extra_fields.get("SUBMODE"), Some("FST4W"));
+
+        let written = crate::adif::format_qso(&log, qso).unwrap();
+        assert!(
+            written.contains("<MODE:4>MFSK<SUBMODE:5>FST4W"),
+            "{written}"
+        );
+        assert_eq!(written.matches("MODE:").count(), 2, "{written}");
+        assert_eq!(round_trip(&log).await, log);
+    }
+
+    #[tokio::test]
+    async fn modelled_submode_is_not_kept_as_extra_field() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("ft4.adif");
+        let header = crate::adif::format_header(&make_general_log()).unwrap();
+        let record = "<CALL:6>KD9XYZ<QSO_DATE:8>20260216<TIME_ON:6>143000<BAND:3>20M\
+                      <MODE:4>mfsk<SUBMODE:3>ft4<RST_SENT:3>-10<RST_RCVD:3>-10<eor>\n";
+        tokio::fs::write(&path, header + record).await.unwrap();
+        let log = read_log(&path).await.unwrap();
+        assert!(log.qsos()[0].extra_fields.is_empty());
+    }
+
+    #[tokio::test]
+    async fn unmodelled_mode_is_read_as_other() {
+        for mode in ["HELL", "JT9", "MT63", "PKT", "DOMINO"] {
+            let fields = format!("<MODE:{}>{mode}", mode.len());
+            assert_eq!(read_single_qso_mode(&fields).await.unwrap(), Mode::Other);
+        }
+    }
+
+    #[tokio::test]
+    async fn unmodelled_mode_is_written_back_unchanged() {
+        let plain = "<CALL:6>KD9XYZ<QSO_DATE:8>20260216<TIME_ON:6>143000<BAND:3>20M\
+                     <MODE:3>JT9<RST_SENT:3>-10<RST_RCVD:3>-10<eor>\n";
+        let paired = "<CALL:6>N0CALL<QSO_DATE:8>20260216<TIME_ON:6>143500<BAND:3>20M\
+                      <MODE:4>HELL<SUBMODE:6>FMHELL<RST_SENT:3>599<RST_RCVD:3>599<eor>\n";
+        let log = read_with_record(&make_general_log(), &format!("{plain}{paired}")).await;
+        assert_eq!(log.qsos()[0].mode_name(), "JT9");
+        assert_eq!(log.qsos()[1].mode_name(), "FMHELL");
+
+        let written = format_adif(&log).unwrap();
+        assert!(written.contains("<MODE:3>JT9<RST_SENT"), "{written}");
+        assert!(
+            written.contains("<MODE:4>HELL<SUBMODE:6>FMHELL<RST_SENT"),
+            "{written}"
+        );
+        assert!(!written.contains("OTHER"), "{written}");
+        assert_eq!(round_trip(&log).await, log);
     }
 
     #[tokio::test]
@@ -0,0 +1,528 @@
+//! Tolerant reading of log files with damaged records.
+//!
+//! A power cut or crash while appending a QSO can leave a half-written record
+//! at the end of a log file, and a hand edit can garble one in the middle;
+//! either makes [`read_log`](super::read_log) fail. [`salvage_log`] instead
+//! keeps the header and every readable record, skipping each bad record up to
+//! the next `<EOR>`, and reports what it skipped as [`Damage`] so the storage
+//! layer can flag or repair it.
+//!
+//! Only records that are not well-formed ADIF are damage. A well-formed record
+//! that is not a valid QSO (a missing `CALL`, an unknown `BAND`) holds data
+//! someone may want, so it fails the whole read instead of being cut out.
+
+use std::fmt;
+use std::path::Path;
+
+use difa::{Record, RecordStream};
+use futures::StreamExt;
+
+use super::error::AdifError;
+use super::reader::{log_from_records, parse_qso};
+use crate::model::Log;
+
+/// A record that could not be read, or the incomplete data at the end of the
+/// file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DamagedRecord {
+    /// Byte offset where the damaged data starts, just past the previous
+    /// `<EOH>` or `<EOR>`.
+    pub offset: usize,
+    /// Length of the damaged data in bytes, up to and including its `<EOR>`
+    /// if it has one.
+    pub len: usize,
+    /// Why the data at `offset` could not be read.
+    pub reason: String,
+}
+
+impl fmt::Display for DamagedRecord {
+    /// Formats as e.g. `37 damaged bytes at byte 1024: record not terminated by <EOR>`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} damaged bytes at byte {}: {}",
+            self.len, self.offset, self.reason
+        )
+    }
+}
+
+/// The unreadable parts of a log file, in file order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Damage {
+    /// Each record that could not be read. Only the last can be an incomplete
+    /// record running to the end of the file.
+    pub records: Vec<DamagedRecord>,
+}
+
+impl fmt::Display for Damage {
+    /// Formats the only damaged record, or e.g.
+    /// `3 damaged records, first 37 damaged bytes at byte 1024: invalid field length`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.records.as_slice() {
+            [] => f.write_str("no damage"),
+            [record] => record.fmt(f),
+            [first, ..] => write!(f, "{} damaged records, first {first}", self.records.len()),
+        }
+    }
+}
+
+/// A log read by [`salvage_log`].
+#[derive(Debug, Clone)]
+pub struct SalvagedLog {
+    /// The log with every readable record.
+    pub log: Log,
+    /// The records that could not be read, or `None` if the whole file was
+    /// read.
+    pub damage: Option<Damage>,
+}
+
+/// Reads a log file, keeping every readable record.
+///
+/// A record that has an unreadable tag or field lengths that do not match its
+/// `<EOR>` is skipped up to that `<EOR>`, and reading resumes with the next
+/// record; a record cut off at the end of the file ends it. Both are reported
+/// in [`SalvagedLog::damage`].
+///
+/// Fails when the header cannot be read, since the log type and ID come from
+/// it, or when a well-formed record is not a valid QSO; the error names the
+/// record's byte offset so it can be fixed by hand.
+pub async fn salvage_log(path: &Path) -> Result<SalvagedLog, AdifError> {
+    let bytes = tokio::fs::read(path).await.map_err(difa::Error::Io)?;
+    salvage(&bytes).await
+}
+
+/// Salvages a log from the raw contents of a log file.
+async fn salvage(bytes: &[u8]) -> Result<SalvagedLog, AdifError> {
+    let scan = scan(bytes);
+    let header_end = scan.header_end.ok_or_else(|| {
+        AdifError::InvalidLog(scan.error.map_or_else(
+            || "file has no complete header".into(),
+            |e| format!("unreadable header: {e}"),
+        ))
+    })?;
+
+    let header = match read_record(&bytes[..header_end]).await {
+        Ok(rec) if rec.is_header() => rec,
+        Err(AdifError::Encode(e)) => return Err(AdifError::Encode(e)),
+        _ => return Err(AdifError::InvalidLog("first record is not a header".into())),
+    };
+    let operator = header.get("operator").map(|d| d.as_str().into_owned());
+    let log_type = header
+        .get("app_duklog_log_type")
+        .map(|d| d.as_str().into_owned())
+        .unwrap_or_default();
+
+    let mut records = Vec::new();
+    let mut damaged = Vec::new();
+    for scanned in &scan.records {
+        let read = match &scanned.error {
+            Some(e) => Err(e.clone()),
+            None => read_record(&bytes[scanned.start..scanned.end])
+                .await
+                .map_err(|e| e.to_string()),
+        };
+        match read {
+            Ok(record) => {
+                if let Err(e) = parse_qso(&record, &log_type, operator.as_deref()) {
+                    let reason = match e {
+                        AdifError::InvalidLog(msg) => msg,
+                        e => e.to_string(),
+                    };
+                    return Err(AdifError::InvalidLog(format!(
+                        "record at byte {}: {reason}",
+                        scanned.start
+                    )));
+                }
+                records.push(record);
+            }
+            Err(reason) => damaged.push(DamagedRecord {
+                offset: scanned.start,
+                len: scanned.end - scanned.start,
+                reason,
+            }),
+        }
+    }
+    if let Some((offset, reason)) = scan.tail {
+        damaged.push(DamagedRecord {
+            offset,
+            len: bytes.len() - offset,
+            reason,
+        });
+    }
+    let log = log_from_records(&header, &records)?;
+    let damage = (!damaged.is_empty()).then_some(Damage { records: damaged });
+    Ok(SalvagedLog { log, damage })
+}
+
+/// Reads the single record in `bytes`.
+async fn read_record(bytes: &[u8]) -> Result<Record, AdifError> {
+    let mut stream = RecordStream::new(bytes, false);
+    let record = stream
+        .next()
+        .await
+        .ok_or_else(|| AdifError::InvalidLog("empty record".into()))??;
+    if stream.next().await.is_some() {
+        return Err(AdifError::InvalidLog(
+            "field lengths do not match the record's <EOR>".into(),
+        ));
+    }
+    Ok(record)
+}
+
+/// Positions of the parts of an ADI document.
+#[derive(Debug, Default, PartialEq, Eq)]
+struct Scan {
+    /// Offset just past `<EOH>`.
+    header_end: Option<usize>,
+    /// Each record after the header that ends in `<EOR>`.
+    records: Vec<ScannedRecord>,
+    /// Where data that is not a complete record starts at the end of the
+    /// document, and why it is incomplete.
+    tail: Option<(usize, String)>,
+    /// Why the header could not be walked, if it could not.
+    error: Option<String>,
+}
+
+/// The span of one record, from just past the previous `<EOH>` or `<EOR>` to
+/// just past its own `<EOR>`.
+#[derive(Debug, PartialEq, Eq)]
+struct ScannedRecord {
+    start: usize,
+    end: usize,
+    /// Why the record's tags could not be walked, if they could not.
+    error: Option<String>,
+}
+
+/// Walks the tags of an ADI document, skipping field values by their length
+/// prefix, and records where the header and each record end.
+///
+/// When a record's tags cannot be walked (a bad length prefix, say), the
+/// record runs to the next `<EOR>` in the text and walking resumes there.
+fn scan(bytes: &[u8]) -> Scan {
+    let mut scan = Scan::default();
+    let mut start = 0;
+    let mut pos = 0;
+    while let Some(lt) = find(bytes, pos, b'<') {
+        let error = match walk_tag(bytes, lt) {
+            Ok((name, next)) => {
+                pos = next;
+                let end_of = |tag: &[u8]| name.is_some_and(|n| n.eq_ignore_ascii_case(tag));
+                if scan.header_end.is_none() && end_of(b"eoh") {
+                    scan.header_end = Some(pos);
+                    start = pos;
+                } else if scan.header_end.is_some() && end_of(b"eor") {
+                    scan.records.push(ScannedRecord {
+                        start,
+                        end: pos,
+                        error: None,
+                    });
+                    start = pos;
+                }
+                continue;
+            }
+            Err(error) => error,
+        };
+        if scan.header_end.is_none() {
+            scan.error = Some(error);
+            return scan;
+        }
+        match find_eor(bytes, lt) {
+            Some(end) => {
+                scan.records.push(ScannedRecord {
+                    start,
+                    end,
+                    error: Some(error),
+                });
+                start = end;
+                pos = end;
+            }
+            None => {
+                scan.tail = Some((start, error));
+                return scan;
+            }
+        }
+    }
+    if scan.header_end.is_some() && !bytes[start..].iter().all(u8::is_ascii_whitespace) {
+        scan.tail = Some((start, "record not terminated by <EOR>".into()));
+    }
+    scan
+}
+
+/// Walks the tag starting at `lt`, returning its name if it has no value
+/// (`<EOR>`, `<EOH>`) and the offset just past it and its value.
+fn walk_tag(bytes: &[u8], lt: usize) -> Result<(Option<&[u8]>, usize), String> {
+    let gt = find(bytes, lt, b'>').ok_or("unterminated tag")?;
+    let mut parts = bytes[lt + 1..gt].split(|&b| b == b':');
+    let name = parts.next().unwrap_or_default();
+    let Some(len) = parts.next() else {
+        return Ok((Some(name), gt + 1));
+    };
+    let len = std::str::from_utf8(len)
+        .ok()
+        .and_then(|s| s.trim().parse::<usize>().ok())
+        .ok_or("invalid field length")?;
+    if gt + 1 + len > bytes.len() {
+        return Err("field value runs past the end of the file".into());
+    }
+    Ok((None, gt + 1 + len))
+}
+
+/// Returns the offset just past the first `<EOR>` (any case) at or after
+/// `from`.
+fn find_eor(bytes: &[u8], from: usize) -> Option<usize> {
+    bytes[from..]
+        .windows(5)
+        .position(|w| w.eq_ignore_ascii_case(b"<eor>"))
+        .map(|i| from + i + 5)
+}
+
+/// Returns the index of the first `byte` at or after `from`.
+fn find(bytes: &[u8], from: usize, byte: u8) -> Option<usize> {
+    bytes[from..]
+        .iter()
+        .position(|&b| b == byte)
+        .map(|i| from + i)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+
+    use super::*;
+    use crate::adif::{format_adif, format_header};
+    use crate::model::{Band, GeneralLog, Mode, Qso};
+
+    fn make_log(qsos: usize) -> Log {
+        let mut log = GeneralLog::new("W1AW".to_string(), None, "FN31".to_string()).unwrap();
+        log.header.log_id = "general-test".to_string();
+        log.header.created_at = Utc.with_ymd_and_hms(2026, 2, 16, 12, 0, 0).unwrap();
+        let mut log = Log::General(log);
+        for i in 0..qsos {
+            log.add_qso(
+                Qso::new(
+                    format!("W{i}AW"),
+                    "59".to_string(),
+                    "59".to_string(),
+                    Band::M20,
+                    Mode::Ssb,
+                    Utc.with_ymd_and_hms(2026, 2, 16, 14, 30, 0).unwrap(),
+                    String::new(),
+                    None,
+                    None,
+                    None,
+                    None,
+                )
+                .unwrap(),
+            );
+        }
+        log
+    }
+
+    /// Returns the only damaged record.
+    fn only_damage(salvaged: SalvagedLog) -> DamagedRecord {
+        let records = salvaged.damage.unwrap().records;
+        assert_eq!(records.len(), 1, "{records:?}");
+        records.into_iter().next().unwrap()
+    }
+
+    fn calls(salvaged: &SalvagedLog) -> Vec<&str> {
+        salvaged
+            .log
+            .header()
+            .qsos
+            .iter()
+            .map(|q| q.their_call.as_str())
+            .collect()
+    }
+
+    mod salvage {
+        use super::*;
+
+        #[tokio::test]
+        async fn intact_file_has_no_damage() {
+            let content = format_adif(&make_log(2)).unwrap();
+            let salvaged = salvage(content.as_bytes()).await.unwrap();
+            assert_eq!(salvaged.damage, None);
+            assert_eq!(calls(&salvaged), vec!["W0AW", "W1AW"]);
+        }
+
+        #[tokio::test]
+        async fn truncated_record_is_reported_with_offset() {
+            let content = format_adif(&make_log(2)).unwrap();
+            // Cut the second record just before its <eor>.
+            let cut = content.rfind("<eor>").unwrap();
+            let salvaged = salvage(&content.as_bytes()[..cut]).await.unwrap();
+            assert_eq!(calls(&salvaged), vec!["W0AW"]);
+            let damage = only_damage(salvaged);
+            let first_end = content.find("<eor>").unwrap() + "<eor>".len();
+            assert_eq!(damage.offset, first_end);
+            assert_eq!(damage.len, cut - first_end);
+            assert_eq!(damage.reason, "record not terminated by <EOR>");
+        }
+
+        #[tokio::test]
+        async fn value_cut_off_mid_field_is_explained() {
+            let content = format_adif(&make_log(1)).unwrap();
+            let cut = content.find("<CALL:4>").unwrap() + "<CALL:4>W0".len();
+            let salvaged = salvage(&content.as_bytes()[..cut]).await.unwrap();
+            assert!(salvaged.log.header().qsos.is_empty());
+            let damage = only_damage(salvaged);
+            assert_eq!(
+                damage.offset,
+                content.find("<eoh>").unwrap() + "<eoh>".len()
+            );
+            assert_eq!(damage.reason, "field value runs past the end of the file");
+        }
+
+        const GOOD: &str = "<CALL:4>W0AW<QSO_DATE:8>20260216<TIME_ON:6>143000<BAND:3>20M<MODE:3>SSB<RST_SENT:2>59<RST_RCVD:2>59<eor>\n";
+
+        #[tokio::test]
+        async fn garbled_record_in_the_middle_is_skipped() {
+            let header = format_header(&make_log(0)).unwrap();
+            let bad = "<CALL:4>W1AW<QSO_DATE:8>20260216<TIME_ON:6>143000<BAND:x>20M<MODE:3>SSB<RST_SENT:2>59<RST_RCVD:2>59<eor>\n";
+            let content = format!("{header}{GOOD}{bad}{GOOD}");
+            let salvaged = salvage(content.as_bytes()).await.unwrap();
+            assert_eq!(calls(&salvaged), vec!["W0AW", "W0AW"]);
+            let damage = only_damage(salvaged);
+            assert_eq!(damage.offset, header.len() + GOOD.trim_end().len());
+            assert_eq!(damage.len, "\n".len() + bad.trim_end().len());
+            assert_eq!(damage.reason, "invalid field length");
+        }
+
+        #[tokio::test]
+        async fn well_formed_invalid_qso_is_an_error_not_damage() {
+            let header = format_header(&make_log(0)).unwrap();
+            let invalid = "<CALL:4>W1AW<QSO_DATE:8>20260216<TIME_ON:6>143000<BAND:4>NOPE<MODE:3>SSB<RST_SENT:2>59<RST_RCVD:2>59<eor>\n";
+            let content = format!("{header}{GOOD}{invalid}{GOOD}");
+            let Err(AdifError::InvalidLog(msg)) = salvage(content.as_bytes()).await else {
+                panic!("expected InvalidLog");
+            };
+            let offset = header.len() + GOOD.trim_end().len();
+            assert_eq!(msg, format!("record at byte {offset}: unknown BAND: NOPE"));
+        }
+
+        #[tokio::test]
+        async fn bad_length_prefix_resyncs_at_next_eor() {
+            let header = format_header(&make_log(0)).unwrap();
+            let bad = "<CALL:x>W1AW<BAND:3>20M<eor>\n";
+            let cut = &GOOD[..40];
+            let content = format!("{header}{GOOD}{bad}{GOOD}{bad}{GOOD}{cut}");
+            let salvaged = salvage(content.as_bytes()).await.unwrap();
+            assert_eq!(calls(&salvaged), vec!["W0AW"; 3]);
+            let records = salvaged.damage.unwrap().records;
+            let reasons: Vec<_> = records.iter().map(|r| r.reason.as_str()).collect();
+            assert_eq!(
+                reasons,
+                vec![
+                    "invalid field length",
+                    "invalid field length",
+                    "unterminated tag"
+                ]
+            );
+            let second_bad = header.len() + 2 * GOOD.len() + bad.len() - 1;
+            assert_eq!(records[1].offset, second_bad);
+            assert_eq!(records[2].offset + records[2].len, content.len());
+        }
+
+        #[tokio::test]
+        async fn trailing_whitespace_is_not_damage() {
+            let content = format_adif(&make_log(1)).unwrap() + "\n\n  ";
+            let salvaged = salvage(content.as_bytes()).await.unwrap();
+            assert_eq!(salvaged.damage, None);
+        }
+
+        #[tokio::test]
+        async fn unterminated_tag_is_explained() {
+            let intact = format_adif(&make_log(1)).unwrap();
+            let content = intact.clone() + "<CALL:4";
+            let damage = only_damage(salvage(content.as_bytes()).await.unwrap());
+            assert_eq!(damage.reason, "unterminated tag");
+            assert_eq!(damage.offset, intact.trim_end().len());
+            assert_eq!(damage.offset + damage.len, content.len());
+        }
+
+        #[tokio::test]
+        async fn missing_header_is_an_error() {
+            let content = format_adif(&make_log(1)).unwrap();
+            let cut = content.find("<eoh>").unwrap();
+            let result = salvage(&content.as_bytes()[..cut]).await;
+            assert!(matches!(result, Err(AdifError::InvalidLog(_))));
+        }
+
+        #[test]
+        fn damage_display() {
+            let record = DamagedRecord {
+                offset: 1024,
+                len: 37,
+                reason: "record not terminated by <EOR>".into(),
+            };
+            let one = "37 damaged bytes at byte 1024: record not terminated by <EOR>";
+            assert_eq!(record.to_string(), one);
+            let damage = Damage {
+                records: vec![record.clone()],
+            };
+            assert_eq!(damage.to_string(), one);
+            let damage = Damage {
+                records: vec![record.clone(), record],
+            };
+            assert_eq!(
+                damage.to_string(),
+                format!("2 damaged records, first {one}")
+            );
+        }
+    }
+
+    mod scan {
+        use super::*;
+
+        #[test]
+        fn skips_values_by_length() {
+            let doc = b"<ADIF_VER:5>3.1.6<eoh><COMMENT:5><eor><eor>";
+            let scan = scan(doc);
+            assert_eq!(scan.header_end, Some(22));
+            assert_eq!(
+                scan.records,
+                vec![ScannedRecord {
+                    start: 22,
+                    end: doc.len(),
+                    error: None
+                }]
+            );
+            assert_eq!(scan.tail, None);
+        }
+
+        #[test]
+        fn eor_before_header_is_ignored() {
+            let scan = scan(b"<CALL:4>W1AW<eor>");
+            assert_eq!(scan.header_end, None);
+            assert!(scan.records.is_empty());
+        }
+
+        #[test]
+        fn invalid_length_skips_to_next_eor() {
+            let scan = scan(b"<eoh><CALL:x>W1AW<EOR><CALL:4>W1AW<eor>");
+            assert_eq!(
+                scan.records,
+                vec![
+                    ScannedRecord {
+                        start: 5,
+                        end: 22,
+                        error: Some("invalid field length".into())
+                    },
+                    ScannedRecord {
+                        start: 22,
+                        end: 39,
+                        error: None
+                    },
+                ]
+            );
+        }
+
+        #[test]
+        fn invalid_length_in_header_stops_scan() {
+            let scan = scan(b"<ADIF_VER:x>3<eoh><CALL:4>W1AW<eor>");
+            assert_eq!(scan.header_end, None);
+            assert_eq!(scan.error.as_deref(), Some("invalid field length"));
+        }
+    }
+}
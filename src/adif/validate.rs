@@ -0,0 +1,1171 @@
+//! ADIF 3.1.6 conformance checks for ADI documents.
+//!
+//! [`validate`] scans the raw text instead of going through [`difa`], so it
+//! can report malformed tags and wrong length prefixes that a parser would
+//! reject outright or silently misread. It checks:
+//!
+//! - tag syntax, length prefixes and data type indicators;
+//! - header structure (`<EOH>` placement, `ADIF_VER`, `CREATED_TIMESTAMP`);
+//! - unterminated and duplicate fields in records;
+//! - field values against their ADIF data type (dates, times, numbers,
+//!   booleans, grid squares, locations) and the `BAND`, `MODE` and `SUBMODE`
+//!   enumerations.
+//!
+//! Fields the spec does not define (including `APP_*` and user-defined
+//! fields) are only checked for syntax. A header that begins with a field
+//! rather than free text, as duklog's own files do, is accepted.
+
+use std::fmt;
+use std::sync::LazyLock;
+
+use chrono::{NaiveDate, NaiveTime};
+use regex::Regex;
+
+use crate::model::Band;
+
+/// How serious a [`Finding`] is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    /// Legal but likely to be misread or rejected by some programs.
+    Warning,
+    /// Violates the ADIF specification.
+    Error,
+}
+
+/// Where in the document a [`Finding`] was made.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Location {
+    /// The document as a whole.
+    Document,
+    /// The header, before `<EOH>`.
+    Header,
+    /// A QSO record, numbered from 1 in document order.
+    Record(usize),
+}
+
+/// A single problem found by [`validate`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Finding {
+    pub severity: Severity,
+    pub location: Location,
+    /// The field the finding is about, upper-cased, if any.
+    pub field: Option<String>,
+    pub message: String,
+}
+
+impl Finding {
+    fn new(severity: Severity, location: Location, field: Option<&str>, message: String) -> Self {
+        Self {
+            severity,
+            location,
+            field: field.map(str::to_ascii_uppercase),
+            message,
+        }
+    }
+
+    /// Returns `true` if this finding is an [`Severity::Error`].
+    pub fn is_error(&self) -> bool {
+        self.severity == Severity::Error
+    }
+}
+
+impl fmt::Display for Finding {
+    /// Formats as e.g. `QSO 3 BAND: unknown band "21M"`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.location {
+            Location::Document => write!(f, "document")?,
+            Location::Header => write!(f, "header")?,
+            Location::Record(n) => write!(f, "QSO {n}")?,
+        }
+        if let Some(ref field) = self.field {
+            write!(f, " {field}")?;
+        }
+        write!(f, ": {}", self.message)
+    }
+}
+
+/// Checks an ADI document against the ADIF 3.1.6 specification.
+///
+/// Returns the findings in document order; an empty list means the document
+/// conforms. Scanning stops at the first tag that cannot be delimited, since
+/// nothing after it can be located reliably.
+pub fn validate(adi: &str) -> Vec<Finding> {
+    let mut v = Validator::default();
+    v.scan(adi);
+    v.findings
+}
+
+/// Data types of the ADIF fields whose values are checked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DataType {
+    Date,
+    Time,
+    Number,
+    Integer,
+    PositiveInteger,
+    Boolean,
+    GridSquare,
+    Location,
+    Band,
+    Mode,
+    Submode,
+}
+
+/// Returns the data type to check `name` against, for fields defined by the
+/// spec that duklog checks.
+fn data_type(name: &str) -> Option<DataType> {
+    let ty = match name {
+        "QSO_DATE"
+        | "QSO_DATE_OFF"
+        | "QSLRDATE"
+        | "QSLSDATE"
+        | "LOTW_QSLRDATE"
+        | "LOTW_QSLSDATE"
+        | "EQSL_QSLRDATE"
+        | "EQSL_QSLSDATE"
+        | "DCL_QSLRDATE"
+        | "DCL_QSLSDATE"
+        | "CLUBLOG_QSO_UPLOAD_DATE"
+        | "HRDLOG_QSO_UPLOAD_DATE"
+        | "QRZCOM_QSO_UPLOAD_DATE"
+        | "QRZCOM_QSO_DOWNLOAD_DATE"
+        | "HAMLOGEU_QSO_UPLOAD_DATE"
+        | "HAMQTH_QSO_UPLOAD_DATE" => DataType::Date,
+        "TIME_ON" | "TIME_OFF" => DataType::Time,
+        "FREQ" | "FREQ_RX" | "AGE" | "ALTITUDE" | "MY_ALTITUDE" | "ANT_AZ" | "ANT_EL"
+        | "A_INDEX" | "DISTANCE" | "RX_PWR" | "TX_PWR" | "SFI" => DataType::Number,
+        "K_INDEX" | "SRX" | "STX" | "DXCC" | "MY_DXCC" | "MAX_BURSTS" | "NR_BURSTS"
+        | "NR_PINGS" => DataType::Integer,
+        "CQZ" | "ITUZ" | "MY_CQ_ZONE" | "MY_ITU_ZONE" => DataType::PositiveInteger,
+        "FORCE_INIT" | "QSO_RANDOM" | "SILENT_KEY" | "SWL" => DataType::Boolean,
+        "GRIDSQUARE" | "MY_GRIDSQUARE" => DataType::GridSquare,
+        "LAT" | "LON" | "MY_LAT" | "MY_LON" => DataType::Location,
+        "BAND" | "BAND_RX" => DataType::Band,
+        "MODE" => DataType::Mode,
+        "SUBMODE" => DataType::Submode,
+        _ => return None,
+    };
+    Some(ty)
+}
+
+/// The ADIF 3.1.6 `MODE` enumeration with each mode's `SUBMODE` values.
+static MODES: &[(&str, &[&str])] = &[
+    ("AM", &[]),
+    ("ARDOP", &[]),
+    ("ATV", &[]),
+    ("CHIP", &["CHIP64", "CHIP128"]),
+    ("CLO", &[]),
+    ("CONTESTI", &[]),
+    ("CW", &["PCW"]),
+    ("DIGITALVOICE", &["C4FM", "DMR", "DSTAR", "FREEDV", "M17"]),
+    (
+        "DOMINO",
+        &[
+            "DOM-M", "DOM4", "DOM5", "DOM8", "DOM11", "DOM16", "DOM22", "DOM44", "DOM88",
+            "DOMINOEX", "DOMINOF",
+        ],
+    ),
+    (
+        "DYNAMIC",
+        &["VARA HF", "VARA SATELLITE", "VARA FM 1200", "VARA FM 9600"],
+    ),
+    ("FAX", &[]),
+    ("FM", &[]),
+    ("FSK", &["SCAMP_FAST", "SCAMP_SLOW", "SCAMP_VSLOW"]),
+    ("FSK441", &[]),
+    ("FT8", &[]),
+    (
+        "HELL",
+        &[
+            "FMHELL", "FSKHELL", "HELL80", "HELLX5", "HELLX9", "HFSK", "PSKHELL", "SLOWHELL",
+        ],
+    ),
+    ("ISCAT", &["ISCAT-A", "ISCAT-B"]),
+    (
+        "JT4",
+        &["JT4A", "JT4B", "JT4C", "JT4D", "JT4E", "JT4F", "JT4G"],
+    ),
+    ("JT6M", &[]),
+    (
+        "JT9",
+        &[
+            "JT9-1",
+            "JT9-2",
+            "JT9-5",
+            "JT9-10",
+            "JT9-30",
+            "JT9A",
+            "JT9B",
+            "JT9C",
+            "JT9D",
+            "JT9E",
+            "JT9E FAST",
+            "JT9F",
+            "JT9F FAST",
+            "JT9G",
+            "JT9G FAST",
+            "JT9H",
+            "JT9H FAST",
+        ],
+    ),
+    ("JT44", &[]),
+    ("JT65", &["JT65A", "JT65B", "JT65B2", "JT65C", "JT65C2"]),
+    (
+        "MFSK",
+        &[
+            "FSQCALL", "FST4", "FST4W", "FT4", "JS8", "JTMS", "MFSK4", "MFSK8", "MFSK11", "MFSK16",
+            "MFSK22", "MFSK31", "MFSK32", "MFSK64", "MFSK64L", "MFSK128", "MFSK128L", "Q65",
+        ],
+    ),
+    ("MSK144", &[]),
+    ("MTONE", &["SCAMP_OO", "SCAMP_OO_SLW"]),
+    ("MT63", &[]),
+    ("OFDM", &["RIBBIT_PIX", "RIBBIT_SMS"]),
+    (
+        "OLIVIA",
+        &[
+            "OLIVIA 4/125",
+            "OLIVIA 4/250",
+            "OLIVIA 8/250",
+            "OLIVIA 8/500",
+            "OLIVIA 16/500",
+            "OLIVIA 16/1000",
+            "OLIVIA 32/1000",
+        ],
+    ),
+    ("OPERA", &["OPERA-BEACON", "OPERA-QSO"]),
+    ("PAC", &["PAC2", "PAC3", "PAC4"]),
+    ("PAX", &["PAX2"]),
+    ("PKT", &[]),
+    (
+        "PSK",
+        &[
+            "8PSK125",
+            "8PSK125F",
+            "8PSK125FL",
+            "8PSK250",
+            "8PSK250F",
+            "8PSK250FL",
+            "8PSK500",
+            "8PSK500F",
+            "8PSK1000",
+            "8PSK1000F",
+            "8PSK1200F",
+            "FSK31",
+            "PSK10",
+            "PSK31",
+            "PSK63",
+            "PSK63F",
+            "PSK63RC4",
+            "PSK63RC5",
+            "PSK63RC10",
+            "PSK63RC20",
+            "PSK63RC32",
+            "PSK125",
+            "PSK125C12",
+            "PSK125R",
+            "PSK125RC10",
+            "PSK125RC12",
+            "PSK125RC16",
+            "PSK125RC4",
+            "PSK125RC5",
+            "PSK250",
+            "PSK250C6",
+            "PSK250R",
+            "PSK250RC2",
+            "PSK250RC3",
+            "PSK250RC5",
+            "PSK250RC6",
+            "PSK250RC7",
+            "PSK500",
+            "PSK500C2",
+            "PSK500C4",
+            "PSK500R",
+            "PSK500RC2",
+            "PSK500RC3",
+            "PSK500RC4",
+            "PSK800C2",
+            "PSK800RC2",
+            "PSK1000",
+            "PSK1000C2",
+            "PSK1000R",
+            "PSK1000RC2",
+            "PSKAM10",
+            "PSKAM31",
+            "PSKAM50",
+            "PSKFEC31",
+            "QPSK31",
+            "QPSK63",
+            "QPSK125",
+            "QPSK250",
+            "QPSK500",
+            "SIM31",
+        ],
+    ),
+    ("PSK2K", &[]),
+    ("Q15", &[]),
+    ("QRA64", &["QRA64A", "QRA64B", "QRA64C", "QRA64D", "QRA64E"]),
+    ("ROS", &["ROS-EME", "ROS-HF", "ROS-MF"]),
+    ("RTTY", &["ASCI"]),
+    ("RTTYM", &[]),
+    ("SSB", &["LSB", "USB"]),
+    ("SSTV", &[]),
+    ("T10", &[]),
+    (
+        "THOR",
+        &[
+            "THOR-M", "THOR4", "THOR5", "THOR8", "THOR11", "THOR16", "THOR22", "THOR25X4",
+            "THOR50X1", "THOR50X2", "THOR100",
+        ],
+    ),
+    (
+        "THRB",
+        &[
+            "THRBX", "THRBX1", "THRBX2", "THRBX4", "THROB1", "THROB2", "THROB4",
+        ],
+    ),
+    ("TOR", &["AMTORFEC", "GTOR", "NAVTEX", "SITORB"]),
+    ("V4", &[]),
+    ("VOI", &[]),
+    ("WINMOR", &[]),
+    ("WSPR", &[]),
+];
+
+/// Returns the submodes of `mode`, or `None` if it is not an ADIF mode.
+fn submodes_of(mode: &str) -> Option<&'static [&'static str]> {
+    MODES
+        .iter()
+        .find(|(m, _)| m.eq_ignore_ascii_case(mode))
+        .map(|(_, subs)| *subs)
+}
+
+/// Returns the mode a submode belongs to, or `None` if it is not an ADIF submode.
+fn parent_mode(submode: &str) -> Option<&'static str> {
+    MODES
+        .iter()
+        .find(|(_, subs)| subs.iter().any(|s| s.eq_ignore_ascii_case(submode)))
+        .map(|(m, _)| *m)
+}
+
+/// A value that looks like an ADI field tag, suggesting the preceding
+/// length prefix is too long.
+static TAG_LIKE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)<(eor|eoh|[a-z][a-z0-9_]*:\d+(:[a-z])?)>").expect("valid regex")
+});
+
+static GRID_SQUARE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^(?i)[A-R]{2}([0-9]{2}([A-X]{2}([0-9]{2})?)?)?$").expect("valid regex")
+});
+
+static LOCATION: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^(?i)[NSEW][0-9]{3} [0-9]{2}\.[0-9]{3}$").expect("valid regex"));
+
+static ADIF_VER: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^[0-9]+\.[0-9]+\.[0-9]+$").expect("valid regex"));
+
+/// A field as written in the document.
+struct RawField {
+    name: String,
+    value: String,
+    type_indicator: Option<char>,
+}
+
+/// Accumulates findings while scanning a document.
+#[derive(Default)]
+struct Validator {
+    findings: Vec<Finding>,
+    /// Fields since the last `<EOH>` or `<EOR>`.
+    pending: Vec<RawField>,
+    seen_header: bool,
+    records: usize,
+}
+
+impl Validator {
+    /// Where fields currently being read will end up, assuming they are a record.
+    fn next_record(&self) -> Location {
+        Location::Record(self.records + 1)
+    }
+
+    fn push(&mut self, severity: Severity, location: Location, field: Option<&str>, msg: String) {
+        self.findings
+            .push(Finding::new(severity, location, field, msg));
+    }
+
+    fn scan(&mut self, adi: &str) {
+        let mut pos = 0;
+        while let Some(offset) = adi[pos..].find('<') {
+            let start = pos + offset;
+            let Some(len) = adi[start..].find('>') else {
+                let loc = self.next_record();
+                self.push(Severity::Error, loc, None, "unterminated tag".into());
+                return;
+            };
+            let tag = &adi[start + 1..start + len];
+            pos = start + len + 1;
+            match self.read_tag(adi, tag, pos) {
+                Some(next) => pos = next,
+                None => return,
+            }
+            self.check_trailing_text(adi, &mut pos);
+        }
+        if !self.pending.is_empty() {
+            let loc = self.next_record();
+            self.push(
+                Severity::Error,
+                loc,
+                None,
+                "record is not terminated by <EOR>".into(),
+            );
+        }
+        if !self.seen_header {
+            self.push(
+                Severity::Warning,
+                Location::Document,
+                None,
+                "no header; ADIF_VER is not declared".into(),
+            );
+        }
+    }
+
+    /// Handles the tag `tag` whose data starts at `data_start`. Returns the
+    /// position after the data, or `None` if scanning cannot continue.
+    fn read_tag(&mut self, adi: &str, tag: &str, data_start: usize) -> Option<usize> {
+        if tag.eq_ignore_ascii_case("eoh") {
+            self.end_header();
+            return Some(data_start);
+        }
+        if tag.eq_ignore_ascii_case("eor") {
+            self.end_record();
+            return Some(data_start);
+        }
+
+        let loc = self.next_record();
+        let mut parts = tag.splitn(3, ':');
+        let name = parts.next().unwrap_or_default();
+        let Some(length) = parts.next() else {
+            self.push(
+                Severity::Error,
+                loc,
+                Some(name),
+                "field has no length prefix".into(),
+            );
+            return Some(data_start);
+        };
+        if !is_valid_name(name) {
+            self.push(
+                Severity::Error,
+                loc,
+                None,
+                format!("invalid field name {name:?}"),
+            );
+        }
+        let Ok(length) = length.parse::<usize>() else {
+            self.push(
+                Severity::Error,
+                loc,
+                Some(name),
+                format!("invalid length prefix {length:?}"),
+            );
+            return None;
+        };
+        let type_indicator = match parts.next() {
+            None => None,
+            Some(t) => {
+                let mut chars = t.chars();
+                match (chars.next().map(|c| c.to_ascii_uppercase()), chars.next()) {
+                    (Some(c @ ('B' | 'N' | 'D' | 'T' | 'S' | 'M' | 'E' | 'L')), None) => Some(c),
+                    (Some(c @ ('I' | 'G')), None) => {
+                        self.push(
+                            Severity::Error,
+                            loc,
+                            Some(name),
+                            format!("data type {c} is only allowed in ADX"),
+                        );
+                        Some(c)
+                    }
+                    _ => {
+                        self.push(
+                            Severity::Error,
+                            loc,
+                            Some(name),
+                            format!("unknown data type indicator {t:?}"),
+                        );
+                        None
+                    }
+                }
+            }
+        };
+
+        let end = data_start + length;
+        if end > adi.len() {
+            self.push(
+                Severity::Error,
+                loc,
+                Some(name),
+                format!("length {length} runs past the end of the document"),
+            );
+            return None;
+        }
+        let Some(value) = adi.get(data_start..end) else {
+            self.push(
+                Severity::Error,
+                loc,
+                Some(name),
+                format!("length {length} splits a multi-byte character"),
+            );
+            return None;
+        };
+        if !value.is_ascii() {
+            self.push(
+                Severity::Warning,
+                loc,
+                Some(name),
+                "non-ASCII text is only allowed in ADX".into(),
+            );
+        }
+        if TAG_LIKE.is_match(value) {
+            self.push(
+                Severity::Warning,
+                loc,
+                Some(name),
+                format!("value {value:?} contains a tag; the length prefix may be too long"),
+            );
+        }
+        self.pending.push(RawField {
+            name: name.to_ascii_uppercase(),
+            value: value.to_string(),
+            type_indicator,
+        });
+        Some(end)
+    }
+
+    /// Warns about non-whitespace text between a value and the next tag,
+    /// which usually means the length prefix was too short. Text before the
+    /// first tag is the header preamble and is skipped.
+    fn check_trailing_text(&mut self, adi: &str, pos: &mut usize) {
+        let next = adi[*pos..].find('<').map_or(adi.len(), |i| *pos + i);
+        let text = adi[*pos..next].trim();
+        if !text.is_empty()
+            && let Some(field) = self.pending.last()
+        {
+            let name = field.name.clone();
+            let loc = self.next_record();
+            self.push(
+                Severity::Warning,
+                loc,
+                Some(&name),
+                format!("unexpected text {text:?} after value; the length prefix may be too short"),
+            );
+        }
+        *pos = next;
+    }
+
+    fn end_header(&mut self) {
+        if self.seen_header {
+            self.push(
+                Severity::Error,
+                Location::Header,
+                None,
+                "more than one <EOH>".into(),
+            );
+        } else if self.records > 0 {
+            self.push(
+                Severity::Error,
+                Location::Header,
+                None,
+                "<EOH> after the first record".into(),
+            );
+        }
+        self.seen_header = true;
+        let fields = std::mem::take(&mut self.pending);
+        self.check_fields(Location::Header, &fields);
+        if !fields.iter().any(|f| f.name == "ADIF_VER") {
+            self.push(
+                Severity::Warning,
+                Location::Header,
+                None,
+                "ADIF_VER is missing".into(),
+            );
+        }
+    }
+
+    fn end_record(&mut self) {
+        self.records += 1;
+        let loc = Location::Record(self.records);
+        let fields = std::mem::take(&mut self.pending);
+        if fields.is_empty() {
+            self.push(Severity::Warning, loc, None, "empty record".into());
+        }
+        self.check_fields(loc, &fields);
+        self.check_submode(loc, &fields);
+    }
+
+    /// Checks for duplicates and validates each value against its data type.
+    fn check_fields(&mut self, loc: Location, fields: &[RawField]) {
+        for (i, field) in fields.iter().enumerate() {
+            let name = field.name.as_str();
+            if fields[..i].iter().any(|f| f.name == name) {
+                self.push(Severity::Error, loc, Some(name), "duplicate field".into());
+            }
+            if loc == Location::Header {
+                if let Some(msg) = check_header_field(name, &field.value) {
+                    self.push(Severity::Error, loc, Some(name), msg);
+                }
+                // USERDEF values are field names; their type applies to records.
+                if name.starts_with("USERDEF") {
+                    continue;
+                }
+            }
+            if let Some(ty) = data_type(name) {
+                if let Some(msg) = check_value(ty, &field.value) {
+                    let severity = match ty {
+                        DataType::Mode if parent_mode(&field.value).is_some() => Severity::Warning,
+                        _ => Severity::Error,
+                    };
+                    self.push(severity, loc, Some(name), msg);
+                }
+            } else if let Some(c) = field.type_indicator
+                && let Some(msg) = check_indicated_type(c, &field.value)
+            {
+                self.push(Severity::Error, loc, Some(name), msg);
+            }
+        }
+    }
+
+    /// Checks that `SUBMODE` belongs to the record's `MODE`.
+    fn check_submode(&mut self, loc: Location, fields: &[RawField]) {
+        let get = |name: &str| fields.iter().find(|f| f.name == name).map(|f| &f.value);
+        let (Some(mode), Some(submode)) = (get("MODE"), get("SUBMODE")) else {
+            return;
+        };
+        if let (Some(subs), Some(parent)) = (submodes_of(mode), parent_mode(submode))
+            && !subs.iter().any(|s| s.eq_ignore_ascii_case(submode))
+        {
+            self.push(
+                Severity::Error,
+                loc,
+                Some("SUBMODE"),
+                format!("{submode} is a submode of {parent}, not {mode}"),
+            );
+        }
+    }
+}
+
+/// Field names may not be empty and may only contain letters, digits and `_`.
+fn is_valid_name(name: &str) -> bool {
+    !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Checks header-only fields, returning a message for an invalid value.
+fn check_header_field(name: &str, value: &str) -> Option<String> {
+    match name {
+        "ADIF_VER" if !ADIF_VER.is_match(value) => {
+            Some(format!("{value:?} is not a version like \"3.1.6\""))
+        }
+        "CREATED_TIMESTAMP"
+            if chrono::NaiveDateTime::parse_from_str(value, "%Y%m%d %H%M%S").is_err()
+                || value.len() != 15 =>
+        {
+            Some(format!(
+                "{value:?} is not a timestamp like \"20260216 143000\""
+            ))
+        }
+        _ => None,
+    }
+}
+
+/// Checks a value against the type given by its data type indicator.
+fn check_indicated_type(indicator: char, value: &str) -> Option<String> {
+    let ty = match indicator {
+        'B' => DataType::Boolean,
+        'N' => DataType::Number,
+        'D' => DataType::Date,
+        'T' => DataType::Time,
+        'L' => DataType::Location,
+        _ => return None,
+    };
+    check_value(ty, value)
+}
+
+/// Checks `value` against `ty`, returning a message if it does not conform.
+fn check_value(ty: DataType, value: &str) -> Option<String> {
+    let ok = match ty {
+        DataType::Date => is_date(value),
+        DataType::Time => is_time(value),
+        DataType::Number => is_number(value),
+        DataType::Integer => is_integer(value),
+        DataType::PositiveInteger => is_integer(value) && value.parse::<u64>().is_ok_and(|n| n > 0),
+        DataType::Boolean => matches!(value, "Y" | "N" | "y" | "n"),
+        DataType::GridSquare => GRID_SQUARE.is_match(value),
+        DataType::Location => LOCATION.is_match(value),
+        DataType::Band => Band::from_adif_str(value).is_some(),
+        DataType::Mode => submodes_of(value).is_some(),
+        DataType::Submode => parent_mode(value).is_some(),
+    };
+    if ok {
+        return None;
+    }
+    Some(match ty {
+        DataType::Date => format!("{value:?} is not a date like \"20260216\" (1930 or later)"),
+        DataType::Time => format!("{value:?} is not a time like \"1430\" or \"143005\""),
+        DataType::Number => format!("{value:?} is not a number"),
+        DataType::Integer => format!("{value:?} is not an integer"),
+        DataType::PositiveInteger => format!("{value:?} is not a positive integer"),
+        DataType::Boolean => format!("{value:?} is not Y or N"),
+        DataType::GridSquare => format!("{value:?} is not a grid square"),
+        DataType::Location => format!("{value:?} is not a location like \"N042 12.345\""),
+        DataType::Band => format!("unknown band {value:?}"),
+        DataType::Mode => match parent_mode(value) {
+            Some(parent) => format!("{value} is a submode; use MODE {parent} with SUBMODE {value}"),
+            None => format!("unknown mode {value:?}"),
+        },
+        DataType::Submode => format!("unknown submode {value:?}"),
+    })
+}
+
+fn is_date(value: &str) -> bool {
+    value.len() == 8
+        && value.bytes().all(|b| b.is_ascii_digit())
+        && NaiveDate::parse_from_str(value, "%Y%m%d").is_ok_and(|d| d >= MIN_DATE)
+}
+
+/// ADIF dates may not be before 1930.
+const MIN_DATE: NaiveDate = NaiveDate::from_ymd_opt(1930, 1, 1).expect("valid date");
+
+fn is_time(value: &str) -> bool {
+    let format = match value.len() {
+        4 => "%H%M",
+        6 => "%H%M%S",
+        _ => return false,
+    };
+    value.bytes().all(|b| b.is_ascii_digit()) && NaiveTime::parse_from_str(value, format).is_ok()
+}
+
+fn is_number(value: &str) -> bool {
+    let digits = value.strip_prefix('-').unwrap_or(value);
+    let (whole, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+    !(whole.is_empty() && fraction.is_empty())
+        && whole.bytes().all(|b| b.is_ascii_digit())
+        && fraction.bytes().all(|b| b.is_ascii_digit())
+}
+
+fn is_integer(value: &str) -> bool {
+    let digits = value.strip_prefix('-').unwrap_or(value);
+    !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit())
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+
+    use super::*;
+    use crate::adif::format_adif;
+    use crate::model::{
+        FdClass, FdPowerCategory, FieldDayLog, GeneralLog, Log, Mode, PotaLog, Qso, WfdClass,
+        WfdLog,
+    };
+
+    const HEADER: &str = "<ADIF_VER:5>3.1.6<PROGRAMID:6>duklog<eoh>\n";
+
+    fn record(fields: &str) -> Vec<Finding> {
+        validate(&format!("{HEADER}{fields}<eor>\n"))
+    }
+
+    fn messages(findings: &[Finding]) -> Vec<String> {
+        findings.iter().map(ToString::to_string).collect()
+    }
+
+    fn make_qso(mode: Mode) -> Qso {
+        Qso::new(
+            "KD9XYZ".to_string(),
+            "59".to_string(),
+            "57".to_string(),
+            Band::M20,
+            mode,
+            Utc.with_ymd_and_hms(2026, 2, 16, 14, 30, 0).unwrap(),
+            "nice signal".to_string(),
+            None,
+            Some("2A CT".to_string()),
+            Some(14_250_000),
+            None,
+        )
+        .unwrap()
+    }
+
+    mod duklog_output {
+        use super::*;
+
+        fn logs() -> Vec<Log> {
+            vec![
+                Log::General(
+                    GeneralLog::new("W1AW".to_string(), None, "FN31pr".to_string()).unwrap(),
+                ),
+                Log::Pota(
+                    PotaLog::new(
+                        "W1AW".to_string(),
+                        Some("KD9XYZ".to_string()),
+                        "K-0001".to_string(),
+                        "FN31".to_string(),
+                    )
+                    .unwrap(),
+                ),
+                Log::FieldDay(
+                    FieldDayLog::new(
+                        "W1AW".to_string(),
+                        None,
+                        2,
+                        FdClass::B,
+                        "EPA".to_string(),
+                        FdPowerCategory::Low,
+                        String::new(),
+                    )
+                    .unwrap(),
+                ),
+                Log::WinterFieldDay(
+                    WfdLog::new(
+                        "W1AW".to_string(),
+                        None,
+                        1,
+                        WfdClass::H,
+                        "EPA".to_string(),
+                        "FN31".to_string(),
+                    )
+                    .unwrap(),
+                ),
+            ]
+        }
+
+        #[test]
+        fn every_log_type_conforms() {
+            for mut log in logs() {
+                log.add_qso(make_qso(Mode::Ssb));
+                let adif = format_adif(&log).unwrap();
+                assert_eq!(messages(&validate(&adif)), Vec::<String>::new(), "{adif}");
+            }
+        }
+
+        #[test]
+        fn every_adif_mode_conforms() {
+            // DIGI is duklog's catch-all and not part of the ADIF enumeration.
+            for mode in Mode::all().iter().filter(|m| **m != Mode::Digi) {
+                let mut log = logs().remove(0);
+                log.add_qso(make_qso(*mode));
+                let adif = format_adif(&log).unwrap();
+                assert!(validate(&adif).is_empty(), "{mode}: {adif}");
+            }
+        }
+
+        #[test]
+        fn digi_mode_is_reported() {
+            let mut log = logs().remove(0);
+            log.add_qso(make_qso(Mode::Digi));
+            let findings = validate(&format_adif(&log).unwrap());
+            assert_eq!(
+                messages(&findings),
+                vec!["QSO 1 MODE: unknown mode \"DIGI\""]
+            );
+        }
+    }
+
+    mod structure {
+        use super::*;
+
+        #[test]
+        fn conforming_record_has_no_findings() {
+            let findings = record(
+                "<CALL:4>W1AW<QSO_DATE:8>20260216<TIME_ON:4>1430<BAND:3>20m<MODE:3>SSB\
+                 <SUBMODE:3>USB<FREQ:6>14.250",
+            );
+            assert_eq!(findings, vec![]);
+        }
+
+        #[test]
+        fn missing_header_is_a_warning() {
+            let findings = validate("<CALL:4>W1AW<eor>");
+            assert_eq!(
+                messages(&findings),
+                vec!["document: no header; ADIF_VER is not declared"]
+            );
+            assert!(!findings[0].is_error());
+        }
+
+        #[test]
+        fn header_after_record_is_an_error() {
+            let findings = validate("<CALL:4>W1AW<eor><ADIF_VER:5>3.1.6<eoh>");
+            assert_eq!(
+                messages(&findings),
+                vec!["header: <EOH> after the first record"]
+            );
+        }
+
+        #[test]
+        fn second_header_is_an_error() {
+            let findings = validate(&format!("{HEADER}{HEADER}"));
+            assert_eq!(messages(&findings), vec!["header: more than one <EOH>"]);
+        }
+
+        #[test]
+        fn header_fields_are_checked() {
+            let findings = validate("preamble\n<ADIF_VER:3>3.1<CREATED_TIMESTAMP:8>20260216<eoh>");
+            assert_eq!(
+                messages(&findings),
+                vec![
+                    "header ADIF_VER: \"3.1\" is not a version like \"3.1.6\"",
+                    "header CREATED_TIMESTAMP: \"20260216\" is not a timestamp like \
+                     \"20260216 143000\"",
+                ]
+            );
+        }
+
+        #[test]
+        fn missing_adif_ver_is_a_warning() {
+            let findings = validate("<PROGRAMID:6>duklog<eoh>");
+            assert_eq!(messages(&findings), vec!["header: ADIF_VER is missing"]);
+        }
+
+        #[test]
+        fn unterminated_record_is_an_error() {
+            let findings = validate(&format!("{HEADER}<CALL:4>W1AW<eor><CALL:5>N0CAL"));
+            assert_eq!(
+                messages(&findings),
+                vec!["QSO 2: record is not terminated by <EOR>"]
+            );
+        }
+
+        #[test]
+        fn duplicate_field_is_an_error() {
+            let findings = record("<CALL:4>W1AW<CALL:5>N0CAL");
+            assert_eq!(messages(&findings), vec!["QSO 1 CALL: duplicate field"]);
+        }
+
+        #[test]
+        fn records_are_numbered_in_order() {
+            let findings = validate(&format!(
+                "{HEADER}<BAND:3>20M<eor><BAND:3>21M<eor><BAND:3>40M<eor>"
+            ));
+            assert_eq!(
+                messages(&findings),
+                vec!["QSO 2 BAND: unknown band \"21M\""]
+            );
+            assert_eq!(findings[0].location, Location::Record(2));
+            assert_eq!(findings[0].field.as_deref(), Some("BAND"));
+        }
+    }
+
+    mod tags {
+        use super::*;
+
+        #[test]
+        fn unterminated_tag_stops_scanning() {
+            let findings = validate(&format!("{HEADER}<CALL:4"));
+            assert_eq!(messages(&findings), vec!["QSO 1: unterminated tag"]);
+        }
+
+        #[test]
+        fn missing_length_is_an_error() {
+            let findings = record("<CALL>W1AW");
+            assert_eq!(
+                messages(&findings)[0],
+                "QSO 1 CALL: field has no length prefix"
+            );
+        }
+
+        #[test]
+        fn invalid_length_is_an_error() {
+            let findings = record("<CALL:x>W1AW");
+            assert_eq!(
+                messages(&findings),
+                vec!["QSO 1 CALL: invalid length prefix \"x\""]
+            );
+        }
+
+        #[test]
+        fn length_past_end_is_an_error() {
+            let findings = validate(&format!("{HEADER}<CALL:40>W1AW<eor>"));
+            assert_eq!(
+                messages(&findings),
+                vec!["QSO 1 CALL: length 40 runs past the end of the document"]
+            );
+        }
+
+        #[test]
+        fn short_length_is_a_warning() {
+            let findings = record("<CALL:3>W1AW");
+            assert_eq!(
+                messages(&findings),
+                vec![
+                    "QSO 1 CALL: unexpected text \"W\" after value; the length prefix may be \
+                     too short"
+                ]
+            );
+            assert!(!findings[0].is_error());
+        }
+
+        #[test]
+        fn long_length_is_a_warning() {
+            let findings = validate(&format!("{HEADER}<CALL:15>W1AW<BAND:3>20M<eor>"));
+            assert_eq!(
+                messages(&findings),
+                vec![
+                    "QSO 1 CALL: value \"W1AW<BAND:3>20M\" contains a tag; the length prefix \
+                     may be too long"
+                ]
+            );
+        }
+
+        #[test]
+        fn invalid_name_is_an_error() {
+            let findings = record("<MY CALL:4>W1AW");
+            assert_eq!(
+                messages(&findings),
+                vec!["QSO 1: invalid field name \"MY CALL\""]
+            );
+        }
+
+        #[test]
+        fn type_indicators_are_checked() {
+            let findings = record("<APP_X_N:3:N>abc<APP_X_C:1:Q>a<COMMENT_INTL:2:I>hi");
+            assert_eq!(
+                messages(&findings),
+                vec![
+                    "QSO 1 APP_X_C: unknown data type indicator \"Q\"",
+                    "QSO 1 COMMENT_INTL: data type I is only allowed in ADX",
+                    "QSO 1 APP_X_N: \"abc\" is not a number",
+                ]
+            );
+        }
+
+        #[test]
+        fn non_ascii_is_a_warning() {
+            let findings = record("<NAME:5>José");
+            assert_eq!(
+                messages(&findings),
+                vec!["QSO 1 NAME: non-ASCII text is only allowed in ADX"]
+            );
+        }
+
+        #[test]
+        fn userdef_header_values_are_field_names() {
+            let findings = validate("<ADIF_VER:5>3.1.6<USERDEF1:8:N>EPC_NAME<eoh>");
+            assert_eq!(findings, vec![]);
+        }
+    }
+
+    mod values {
+        use super::*;
+
+        fn single(field: &str, value: &str) -> Vec<String> {
+            messages(&record(&format!("<{field}:{}>{value}", value.len())))
+        }
+
+        #[test]
+        fn dates() {
+            assert!(single("QSO_DATE", "20260216").is_empty());
+            assert_eq!(
+                single("QSO_DATE", "20260230"),
+                vec![
+                    "QSO 1 QSO_DATE: \"20260230\" is not a date like \"20260216\" (1930 or later)"
+                ]
+            );
+            assert_eq!(single("QSLSDATE", "19291231").len(), 1);
+            assert_eq!(single("QSO_DATE", "2026-02-16").len(), 1);
+        }
+
+        #[test]
+        fn times() {
+            assert!(single("TIME_ON", "1430").is_empty());
+            assert!(single("TIME_OFF", "143059").is_empty());
+            assert_eq!(
+                single("TIME_ON", "2460"),
+                vec!["QSO 1 TIME_ON: \"2460\" is not a time like \"1430\" or \"143005\""]
+            );
+            assert_eq!(single("TIME_ON", "14:30").len(), 1);
+        }
+
+        #[test]
+        fn numbers() {
+            assert!(single("FREQ", "14.250").is_empty());
+            assert!(single("FREQ", ".5").is_empty());
+            assert!(single("ANT_EL", "-5").is_empty());
+            assert_eq!(
+                single("FREQ", "14,250"),
+                vec!["QSO 1 FREQ: \"14,250\" is not a number"]
+            );
+            assert_eq!(single("FREQ", "-").len(), 1);
+            assert_eq!(single("SRX", "1.5").len(), 1);
+            assert!(single("CQZ", "5").is_empty());
+            assert_eq!(single("CQZ", "0").len(), 1);
+        }
+
+        #[test]
+        fn booleans() {
+            assert!(single("SWL", "Y").is_empty());
+            assert_eq!(
+                single("QSO_RANDOM", "yes"),
+                vec!["QSO 1 QSO_RANDOM: \"yes\" is not Y or N"]
+            );
+        }
+
+        #[test]
+        fn grid_squares_and_locations() {
+            assert!(single("GRIDSQUARE", "FN31pr").is_empty());
+            assert!(single("MY_GRIDSQUARE", "FN31pr45").is_empty());
+            assert_eq!(single("GRIDSQUARE", "FN3").len(), 1);
+            assert_eq!(single("GRIDSQUARE", "ZZ00").len(), 1);
+            assert!(single("LAT", "N042 12.345").is_empty());
+            assert_eq!(single("LON", "-72.5").len(), 1);
+        }
+
+        #[test]
+        fn bands() {
+            assert!(single("BAND", "2m").is_empty());
+            assert_eq!(
+                single("BAND_RX", "11M"),
+                vec!["QSO 1 BAND_RX: unknown band \"11M\""]
+            );
+        }
+
+        #[test]
+        fn modes() {
+            assert!(single("MODE", "DYNAMIC").is_empty());
+            assert_eq!(
+                single("MODE", "NOPE"),
+                vec!["QSO 1 MODE: unknown mode \"NOPE\""]
+            );
+        }
+
+        #[test]
+        fn submode_in_mode_field_is_a_warning() {
+            let findings = record("<MODE:3>FT4");
+            assert_eq!(
+                messages(&findings),
+                vec!["QSO 1 MODE: FT4 is a submode; use MODE MFSK with SUBMODE FT4"]
+            );
+            assert!(!findings[0].is_error());
+        }
+
+        #[test]
+        fn submodes() {
+            assert!(single("SUBMODE", "VARA HF").is_empty());
+            assert_eq!(
+                single("SUBMODE", "FT9"),
+                vec!["QSO 1 SUBMODE: unknown submode \"FT9\""]
+            );
+        }
+
+        #[test]
+        fn submode_must_match_mode() {
+            let findings = record("<MODE:3>SSB<SUBMODE:3>FT4");
+            assert_eq!(
+                messages(&findings),
+                vec!["QSO 1 SUBMODE: FT4 is a submode of MFSK, not SSB"]
+            );
+        }
+
+        #[test]
+        fn unknown_fields_are_not_checked() {
+            assert!(single("APP_OTHER_RATING", "five").is_empty());
+            assert!(single("NAME", "Hiram").is_empty());
+        }
+    }
+}
@@ -4,7 +4,7 @@ use difa::{Datum, Field, Tag};
 use tokio_util::codec::Encoder;
 
 use super::error::AdifError;
-use crate::model::{Log, Qso};
+use crate::model::{ExtraFields, Log, Mode, Qso};
 
 // Encodes a tag into the buffer.
 fn encode(encoder: &mut TagEncoder, buf: &mut BytesMut, tag: Tag) -> Result<(), AdifError> {
@@ -12,11 +12,6 @@ fn encode(encoder: &mut TagEncoder, buf: &mut BytesMut, tag: Tag) -> Result<(),
     Ok(())
 }
 
-// Creates a field tag from a name and value.
-fn field_tag(name: &str, value: impl Into<Datum>) -> Tag {
-    Tag::Field(Field::new(name, value))
-}
-
 // Converts a BytesMut buffer to a String.
 fn buf_to_string(buf: BytesMut) -> Result<String, AdifError> {
     Ok(String::from_utf8(buf.into())?)
@@ -28,193 +23,201 @@ const POTA_SIG: &str = "POTA";
 const FIELD_DAY_CONTEST_ID: &str = "ARRL-FIELD-DAY";
 const WFD_CONTEST_ID: &str = "WFD";
 
-/// Encodes log-type-specific ADIF fields.
+/// Appends log-type-specific QSO fields.
 ///
-/// - POTA: emits `MY_SIG`/`MY_SIG_INFO` (when log has a park ref) and
+/// - POTA: `MY_SIG`/`MY_SIG_INFO` (when log has a park ref) and
 ///   `SIG`/`SIG_INFO` (when QSO has their park set).
-/// - Field Day: emits `CONTEST_ID`, `STX_STRING`, and `SRX_STRING` (when present).
-/// - Winter Field Day: emits `CONTEST_ID`, `STX_STRING`, and `SRX_STRING` (when present).
-fn encode_type_specific_fields(
-    encoder: &mut TagEncoder,
-    buf: &mut BytesMut,
-    log: &Log,
-    qso: &Qso,
-) -> Result<(), AdifError> {
+/// - Field Day: `CONTEST_ID`, `STX_STRING`, and `SRX_STRING` (when present).
+/// - Winter Field Day: `CONTEST_ID`, `STX_STRING`, and `SRX_STRING` (when present).
+fn push_type_specific_fields(fields: &mut Vec<Field>, log: &Log, qso: &Qso) {
     match log {
         Log::General(_) => {}
         Log::Pota(pota) => {
-            encode(encoder, buf, field_tag("MY_SIG", POTA_SIG))?;
-            encode(
-                encoder,
-                buf,
-                field_tag("MY_SIG_INFO", pota.park_ref.as_str()),
-            )?;
+            fields.push(Field::new("MY_SIG", POTA_SIG));
+            fields.push(Field::new("MY_SIG_INFO", pota.park_ref.as_str()));
             if let Some(ref their_park) = qso.their_park {
-                encode(encoder, buf, field_tag("SIG", POTA_SIG))?;
-                encode(encoder, buf, field_tag("SIG_INFO", their_park.as_str()))?;
+                fields.push(Field::new("SIG", POTA_SIG));
+                fields.push(Field::new("SIG_INFO", their_park.as_str()));
             }
         }
         Log::FieldDay(fd) => {
-            encode(encoder, buf, field_tag("CONTEST_ID", FIELD_DAY_CONTEST_ID))?;
-            encode(
-                encoder,
-                buf,
-                field_tag("STX_STRING", fd.sent_exchange().as_str()),
-            )?;
+            fields.push(Field::new("CONTEST_ID", FIELD_DAY_CONTEST_ID));
+            fields.push(Field::new("STX_STRING", fd.sent_exchange()));
             if let Some(ref exch) = qso.exchange_rcvd {
-                encode(encoder, buf, field_tag("SRX_STRING", exch.as_str()))?;
+                fields.push(Field::new("SRX_STRING", exch.as_str()));
             }
         }
         Log::WinterFieldDay(wfd) => {
-            encode(encoder, buf, field_tag("CONTEST_ID", WFD_CONTEST_ID))?;
-            encode(
-                encoder,
-                buf,
-                field_tag("STX_STRING", wfd.sent_exchange().as_str()),
-            )?;
+            fields.push(Field::new("CONTEST_ID", WFD_CONTEST_ID));
+            fields.push(Field::new("STX_STRING", wfd.sent_exchange()));
             if let Some(ref exch) = qso.exchange_rcvd {
-                encode(encoder, buf, field_tag("SRX_STRING", exch.as_str()))?;
+                fields.push(Field::new("SRX_STRING", exch.as_str()));
             }
         }
     }
-    Ok(())
 }
 
-/// Formats the ADIF file header for a log.
-///
-/// Includes standard fields (`ADIF_VER`, `PROGRAMID`, `PROGRAMVERSION`,
-/// `CREATED_TIMESTAMP`, `STATION_CALLSIGN`, `OPERATOR`, `MY_GRIDSQUARE`) plus
-/// duklog-specific `APP_DUKLOG_*` fields that encode log type and
-/// type-specific metadata needed to reconstruct the [`Log`] on read.
+/// Returns the header fields for a log, in output order.
 ///
-/// Terminated by `<eoh>`.
-pub fn format_header(log: &Log) -> Result<String, AdifError> {
-    let mut encoder = TagEncoder::new();
-    let mut buf = BytesMut::new();
-
-    encode(&mut encoder, &mut buf, field_tag("ADIF_VER", "3.1.6"))?;
-    buf.extend_from_slice(b"\n");
-    encode(&mut encoder, &mut buf, field_tag("PROGRAMID", "duklog"))?;
-    buf.extend_from_slice(b"\n");
-    encode(
-        &mut encoder,
-        &mut buf,
-        field_tag("PROGRAMVERSION", env!("CARGO_PKG_VERSION")),
-    )?;
-    buf.extend_from_slice(b"\n");
-
-    let timestamp = log.header().created_at.format("%Y%m%d %H%M%S").to_string();
-    encode(
-        &mut encoder,
-        &mut buf,
-        field_tag("CREATED_TIMESTAMP", timestamp.as_str()),
-    )?;
-    buf.extend_from_slice(b"\n");
-    encode(
-        &mut encoder,
-        &mut buf,
-        field_tag("STATION_CALLSIGN", log.header().station_callsign.as_str()),
-    )?;
-    buf.extend_from_slice(b"\n");
-    if let Some(ref op) = log.header().operator {
-        encode(&mut encoder, &mut buf, field_tag("OPERATOR", op.as_str()))?;
-        buf.extend_from_slice(b"\n");
-    }
-    if !log.header().grid_square.is_empty() {
-        encode(
-            &mut encoder,
-            &mut buf,
-            field_tag("MY_GRIDSQUARE", log.header().grid_square.as_str()),
-        )?;
-        buf.extend_from_slice(b"\n");
-    }
-    encode(
-        &mut encoder,
-        &mut buf,
-        field_tag("APP_DUKLOG_LOG_ID", log.header().log_id.as_str()),
-    )?;
-    buf.extend_from_slice(b"\n");
+/// Shared by the ADIF and ADX writers so both carry the same data.
+pub(super) fn header_fields(log: &Log) -> Vec<Field> {
+    let header = log.header();
+    let mut fields = vec![
+        Field::new("ADIF_VER", "3.1.6"),
+        Field::new("PROGRAMID", "duklog"),
+        Field::new("PROGRAMVERSION", env!("CARGO_PKG_VERSION")),
+        Field::new(
+            "CREATED_TIMESTAMP",
+            header.created_at.format("%Y%m%d %H%M%S").to_string(),
+        ),
+        Field::new("STATION_CALLSIGN", header.station_callsign.as_str()),
+    ];
+    if let Some(ref op) = header.operator {
+        fields.push(Field::new("OPERATOR", op.as_str()));
+    }
+    if !header.grid_square.is_empty() {
+        fields.push(Field::new("MY_GRIDSQUARE", header.grid_square.as_str()));
+    }
+    fields.push(Field::new("APP_DUKLOG_LOG_ID", header.log_id.as_str()));
 
     match log {
         Log::General(_) => {
-            encode(
-                &mut encoder,
-                &mut buf,
-                field_tag("APP_DUKLOG_LOG_TYPE", "general"),
-            )?;
+            fields.push(Field::new("APP_DUKLOG_LOG_TYPE", "general"));
         }
         Log::Pota(pota) => {
-            encode(
-                &mut encoder,
-                &mut buf,
-                field_tag("APP_DUKLOG_PARK_REF", pota.park_ref.as_str()),
-            )?;
-            buf.extend_from_slice(b"\n");
-            encode(
-                &mut encoder,
-                &mut buf,
-                field_tag("APP_DUKLOG_LOG_TYPE", "pota"),
-            )?;
+            fields.push(Field::new("APP_DUKLOG_PARK_REF", pota.park_ref.as_str()));
+            fields.push(Field::new("APP_DUKLOG_LOG_TYPE", "pota"));
         }
         Log::FieldDay(fd) => {
-            encode(
-                &mut encoder,
-                &mut buf,
-                field_tag("APP_DUKLOG_LOG_TYPE", "field_day"),
-            )?;
-            buf.extend_from_slice(b"\n");
-            encode(
-                &mut encoder,
-                &mut buf,
-                field_tag("APP_DUKLOG_TX_COUNT", fd.tx_count.to_string().as_str()),
-            )?;
-            buf.extend_from_slice(b"\n");
-            encode(
-                &mut encoder,
-                &mut buf,
-                field_tag("APP_DUKLOG_FD_CLASS", fd.class.to_string().as_str()),
-            )?;
-            buf.extend_from_slice(b"\n");
-            encode(
-                &mut encoder,
-                &mut buf,
-                field_tag("APP_DUKLOG_SECTION", fd.section.as_str()),
-            )?;
-            buf.extend_from_slice(b"\n");
-            encode(
-                &mut encoder,
-                &mut buf,
-                field_tag("APP_DUKLOG_POWER", fd.power.adif_str()),
-            )?;
+            fields.push(Field::new("APP_DUKLOG_LOG_TYPE", "field_day"));
+            fields.push(Field::new("APP_DUKLOG_TX_COUNT", fd.tx_count.to_string()));
+            fields.push(Field::new("APP_DUKLOG_FD_CLASS", fd.class.to_string()));
+            fields.push(Field::new("APP_DUKLOG_SECTION", fd.section.as_str()));
+            fields.push(Field::new("APP_DUKLOG_POWER", fd.power.adif_str()));
         }
         Log::WinterFieldDay(wfd) => {
-            encode(
-                &mut encoder,
-                &mut buf,
-                field_tag("APP_DUKLOG_LOG_TYPE", "wfd"),
-            )?;
-            buf.extend_from_slice(b"\n");
-            encode(
-                &mut encoder,
-                &mut buf,
-                field_tag("APP_DUKLOG_TX_COUNT", wfd.tx_count.to_string().as_str()),
-            )?;
-            buf.extend_from_slice(b"\n");
-            encode(
-                &mut encoder,
-                &mut buf,
-                field_tag("APP_DUKLOG_WFD_CLASS", wfd.class.to_string().as_str()),
-            )?;
-            buf.extend_from_slice(b"\n");
-            encode(
-                &mut encoder,
-                &mut buf,
-                field_tag("APP_DUKLOG_SECTION", wfd.section.as_str()),
-            )?;
+            fields.push(Field::new("APP_DUKLOG_LOG_TYPE", "wfd"));
+            fields.push(Field::new("APP_DUKLOG_TX_COUNT", wfd.tx_count.to_string()));
+            fields.push(Field::new("APP_DUKLOG_WFD_CLASS", wfd.class.to_string()));
+            fields.push(Field::new("APP_DUKLOG_SECTION", wfd.section.as_str()));
         }
     }
-    buf.extend_from_slice(b"\n");
+    push_extra_fields(&mut fields, &header.extra_fields, &[]);
+    fields
+}
+
+/// Returns the fields of a QSO record, in output order.
+///
+/// Includes per-log fields (station callsign, park ref) alongside per-QSO
+/// fields. OPERATOR is the QSO's own operator when set; otherwise the log's
+/// operator, emitted only when set and different from the station callsign.
+/// POTA fields are only emitted when the relevant park references
+/// are present. FREQ is emitted for any log type when `qso.frequency` is set.
+/// The QSO's extra fields follow the ones duklog writes.
+pub(super) fn qso_fields(log: &Log, qso: &Qso) -> Vec<Field> {
+    let header = log.header();
+    let mut fields = vec![Field::new(
+        "STATION_CALLSIGN",
+        header.station_callsign.as_str(),
+    )];
+    let operator = qso.operator.as_ref().or(header
+        .operator
+        .as_ref()
+        .filter(|op| **op != header.station_callsign));
+    if let Some(op) = operator {
+        fields.push(Field::new("OPERATOR", op.as_str()));
+    }
+    fields.push(Field::new("CALL", qso.their_call.as_str()));
+    fields.push(Field::new(
+        "QSO_DATE",
+        Datum::Date(qso.timestamp.date_naive()),
+    ));
+    fields.push(Field::new("TIME_ON", Datum::Time(qso.timestamp.time())));
+    fields.push(Field::new("BAND", qso.band.adif_str()));
+    let (mode, submode) = mode_fields(qso);
+    fields.push(Field::new("MODE", mode));
+    if let Some(submode) = submode {
+        fields.push(Field::new("SUBMODE", submode));
+    }
+    fields.push(Field::new("RST_SENT", qso.rst_sent.as_str()));
+    fields.push(Field::new("RST_RCVD", qso.rst_rcvd.as_str()));
+    if !header.grid_square.is_empty() {
+        fields.push(Field::new("MY_GRIDSQUARE", header.grid_square.as_str()));
+    }
+
+    push_type_specific_fields(&mut fields, log, qso);
+
+    if let Some(freq) = qso.frequency {
+        fields.push(Field::new("FREQ", format_mhz(freq)));
+    }
+    if !qso.comments.is_empty() {
+        fields.push(Field::new("COMMENT", qso.comments.as_str()));
+    }
+    if let Some(ref id) = qso.id {
+        fields.push(Field::new("APP_DUKLOG_QSO_ID", id.as_str()));
+    }
+    push_extra_fields(&mut fields, &qso.extra_fields, &["MODE", "SUBMODE"]);
+    fields
+}
+
+/// Formats a frequency in Hz as MHz for `FREQ`, with at least three decimals
+/// and down to 1 Hz when needed: `14.225`, `0.1375`, `14.074125`.
+fn format_mhz(hz: u64) -> String {
+    let text = format!("{}.{:06}", hz / 1_000_000, hz % 1_000_000);
+    let trimmed = text.trim_end_matches('0');
+    let decimals = trimmed.len() - trimmed.find('.').unwrap_or(0) - 1;
+    text[..trimmed.len() + 3usize.saturating_sub(decimals)].to_string()
+}
+
+/// Returns the `MODE` and `SUBMODE` to write for `qso`.
+///
+/// A pair duklog has no exact variant for is kept in the QSO's extra fields
+/// when read, and is written back as it was unless the QSO's mode has since
+/// been changed to one the pair does not map to. [`Mode::Other`] always
+/// writes the kept pair.
+fn mode_fields(qso: &Qso) -> (&str, Option<&str>) {
+    let extra = &qso.extra_fields;
+    if qso.mode == Mode::Other
+        && let Some(mode) = extra.get("MODE")
+    {
+        return (mode, extra.get("SUBMODE"));
+    }
+    if let (Some(mode), Some(submode)) = (extra.get("MODE"), extra.get("SUBMODE"))
+        && Mode::from_adif_str(mode, Some(submode)) == Some(qso.mode)
+    {
+        return (mode, Some(submode));
+    }
+    (qso.mode.adif_mode(), qso.mode.adif_submode())
+}
+
+/// Appends fields duklog does not interpret, in the order they were read,
+/// except those named in `skip`.
+fn push_extra_fields(fields: &mut Vec<Field>, extra: &ExtraFields, skip: &[&str]) {
+    fields.extend(
+        extra
+            .iter()
+            .filter(|(name, _)| !skip.iter().any(|s| s.eq_ignore_ascii_case(name)))
+            .map(|(name, value)| Field::new(name, value)),
+    );
+}
+
+/// Formats the ADIF file header for a log.
+///
+/// Includes standard fields (`ADIF_VER`, `PROGRAMID`, `PROGRAMVERSION`,
+/// `CREATED_TIMESTAMP`, `STATION_CALLSIGN`, `OPERATOR`, `MY_GRIDSQUARE`) plus
+/// duklog-specific `APP_DUKLOG_*` fields that encode log type and
+/// type-specific metadata needed to reconstruct the [`Log`] on read, then the
+/// log's extra fields.
+///
+/// One field per line, terminated by `<eoh>`.
+pub fn format_header(log: &Log) -> Result<String, AdifError> {
+    let mut encoder = TagEncoder::new();
+    let mut buf = BytesMut::new();
 
+    for field in header_fields(log) {
+        encode(&mut encoder, &mut buf, Tag::Field(field))?;
+        buf.extend_from_slice(b"\n");
+    }
     encode(&mut encoder, &mut buf, Tag::Eoh)?;
     buf.extend_from_slice(b"\n");
 
@@ -223,82 +226,14 @@ pub fn format_header(log: &Log) -> Result<String, AdifError> {
 
 /// Formats a single QSO record ending with `<eor>`.
 ///
-/// Includes per-log fields (station callsign, park ref) alongside per-QSO
-/// fields. OPERATOR is emitted only when set and different from the station
-/// callsign. POTA fields are only emitted when the relevant park references
-/// are present. FREQ is emitted for any log type when `qso.frequency` is set.
+/// See [`qso_fields`] for the fields emitted.
 pub fn format_qso(log: &Log, qso: &Qso) -> Result<String, AdifError> {
     let mut encoder = TagEncoder::new();
     let mut buf = BytesMut::new();
 
-    encode(
-        &mut encoder,
-        &mut buf,
-        field_tag("STATION_CALLSIGN", log.header().station_callsign.as_str()),
-    )?;
-    if let Some(ref op) = log.header().operator
-        && op != &log.header().station_callsign
-    {
-        encode(&mut encoder, &mut buf, field_tag("OPERATOR", op.as_str()))?;
-    }
-    encode(
-        &mut encoder,
-        &mut buf,
-        field_tag("CALL", qso.their_call.as_str()),
-    )?;
-    encode(
-        &mut encoder,
-        &mut buf,
-        field_tag("QSO_DATE", Datum::Date(qso.timestamp.date_naive())),
-    )?;
-    encode(
-        &mut encoder,
-        &mut buf,
-        field_tag("TIME_ON", Datum::Time(qso.timestamp.time())),
-    )?;
-    encode(
-        &mut encoder,
-        &mut buf,
-        field_tag("BAND", qso.band.adif_str()),
-    )?;
-    encode(
-        &mut encoder,
-        &mut buf,
-        field_tag("MODE", qso.mode.adif_str()),
-    )?;
-    encode(
-        &mut encoder,
-        &mut buf,
-        field_tag("RST_SENT", qso.rst_sent.as_str()),
-    )?;
-    encode(
-        &mut encoder,
-        &mut buf,
-        field_tag("RST_RCVD", qso.rst_rcvd.as_str()),
-    )?;
-    if !log.header().grid_square.is_empty() {
-        encode(
-            &mut encoder,
-            &mut buf,
-            field_tag("MY_GRIDSQUARE", log.header().grid_square.as_str()),
-        )?;
-    }
-
-    encode_type_specific_fields(&mut encoder, &mut buf, log, qso)?;
-
-    if let Some(freq) = qso.frequency {
-        let mhz = format!("{:.3}", f64::from(freq) / 1000.0);
-        encode(&mut encoder, &mut buf, field_tag("FREQ", mhz.as_str()))?;
-    }
-
-    if !qso.comments.is_empty() {
-        encode(
-            &mut encoder,
-            &mut buf,
-            field_tag("COMMENT", qso.comments.as_str()),
-        )?;
+    for field in qso_fields(log, qso) {
+        encode(&mut encoder, &mut buf, Tag::Field(field))?;
     }
-
     encode(&mut encoder, &mut buf, Tag::Eor)?;
 
     buf_to_string(buf)
@@ -371,6 +306,7 @@ mod tests {
             None,
             None,
             None,
+            None,
         )
         .unwrap()
     }
@@ -387,6 +323,7 @@ mod tests {
             Some("K-1234".to_string()),
             None,
             None,
+            None,
         )
         .unwrap()
     }
@@ -403,6 +340,7 @@ mod tests {
             None,
             None,
             None,
+            None,
         )
         .unwrap()
     }
@@ -459,6 +397,42 @@ mod tests {
         assert!(record.contains("<MY_GRIDSQUARE:4>FN31"));
     }
 
+    #[test]
+    fn qso_without_submode_omits_submode_field() {
+        let record = format_qso(&make_log(), &make_qso()).unwrap();
+        assert!(!record.contains("SUBMODE"));
+    }
+
+    #[test]
+    fn qso_with_submode_emits_mode_and_submode() {
+        let mut qso = make_qso();
+        qso.mode = Mode::Ft4;
+        let record = format_qso(&make_log(), &qso).unwrap();
+        assert!(record.contains("<MODE:4>MFSK"), "record: {record}");
+        assert!(record.contains("<SUBMODE:3>FT4"), "record: {record}");
+    }
+
+    #[test]
+    fn usb_qso_is_ssb_with_usb_submode() {
+        let mut qso = make_qso();
+        qso.mode = Mode::Usb;
+        let record = format_qso(&make_log(), &qso).unwrap();
+        assert!(record.contains("<MODE:3>SSB"));
+        assert!(record.contains("<SUBMODE:3>USB"));
+    }
+
+    #[test]
+    fn changed_mode_replaces_kept_submode() {
+        let mut qso = make_qso();
+        qso.mode = Mode::Ft8;
+        qso.extra_fields = [("MODE", "MFSK"), ("SUBMODE", "FST4W")]
+            .into_iter()
+            .collect();
+        let record = format_qso(&make_log(), &qso).unwrap();
+        assert!(record.contains("<MODE:3>FT8"), "record: {record}");
+        assert!(!record.contains("FST4W"), "record: {record}");
+    }
+
     #[test]
     fn qso_same_operator_excludes_operator_field() {
         let record = format_qso(&make_log(), &make_qso()).unwrap();
@@ -479,6 +453,61 @@ mod tests {
         assert!(record.contains("<OPERATOR:6>N0CALL"));
     }
 
+    #[test]
+    fn qso_operator_overrides_log_operator() {
+        let mut qso = make_qso();
+        qso.operator = Some("K1ABC".to_string());
+        let record = format_qso(&make_log_distinct_operator(), &qso).unwrap();
+        assert!(record.contains("<OPERATOR:5>K1ABC"), "record: {record}");
+        assert!(!record.contains("N0CALL"), "record: {record}");
+    }
+
+    #[test]
+    fn qso_operator_emitted_even_when_station_callsign() {
+        let mut qso = make_qso();
+        qso.operator = Some("W1AW".to_string());
+        let record = format_qso(&make_log_distinct_operator(), &qso).unwrap();
+        assert!(record.contains("<OPERATOR:4>W1AW"), "record: {record}");
+    }
+
+    #[test]
+    fn qso_id_emitted_when_set() {
+        let mut qso = make_qso();
+        qso.id = Some("abc123".to_string());
+        let record = format_qso(&make_log(), &qso).unwrap();
+        assert!(
+            record.contains("<APP_DUKLOG_QSO_ID:6>abc123"),
+            "record: {record}"
+        );
+    }
+
+    #[test]
+    fn qso_without_id_omits_id_field() {
+        let record = format_qso(&make_log(), &make_qso()).unwrap();
+        assert!(!record.contains("APP_DUKLOG_QSO_ID"), "record: {record}");
+    }
+
+    #[test]
+    fn qso_extra_fields_follow_known_fields() {
+        let mut qso = make_qso();
+        qso.extra_fields = [("NAME", "Hiram"), ("APP_OTHER_RATING", "5")]
+            .into_iter()
+            .collect();
+        let record = format_qso(&make_log(), &qso).unwrap();
+        assert!(
+            record.contains("<MY_SIG_INFO:6>K-0001<NAME:5>Hiram<APP_OTHER_RATING:1>5<eor>"),
+            "record: {record}"
+        );
+    }
+
+    #[test]
+    fn header_extra_fields_precede_eoh() {
+        let mut log = make_log();
+        log.header_mut().extra_fields.insert("MY_RIG", "KX2");
+        let header = format_header(&log).unwrap();
+        assert!(header.contains("<MY_RIG:3>KX2\n<eoh>"), "header: {header}");
+    }
+
     #[test]
     fn qso_with_park_includes_my_sig() {
         let record = format_qso(&make_log(), &make_qso()).unwrap();
@@ -592,6 +621,7 @@ mod tests {
             None,
             None,
             None,
+            None,
         ) {
             Ok(q) => q,
             Err(_) => return true,
@@ -642,11 +672,12 @@ mod tests {
             None,
             Some(exchange.to_string()),
             None,
+            None,
         )
         .unwrap()
     }
 
-    fn make_qso_with_exchange_and_freq(exchange: &str, freq: u32) -> Qso {
+    fn make_qso_with_exchange_and_freq(exchange: &str, freq: u64) -> Qso {
         Qso::new(
             "KD9XYZ".to_string(),
             "59".to_string(),
@@ -658,6 +689,7 @@ mod tests {
             None,
             Some(exchange.to_string()),
             Some(freq),
+            None,
         )
         .unwrap()
     }
@@ -736,7 +768,7 @@ mod tests {
     fn fd_qso_with_frequency_contains_freq_field() {
         let record = format_qso(
             &make_fd_log(),
-            &make_qso_with_exchange_and_freq("3A CT", 14225),
+            &make_qso_with_exchange_and_freq("3A CT", 14_225_000),
         )
         .unwrap();
         assert!(
@@ -762,14 +794,14 @@ mod tests {
     fn wfd_qso_with_frequency_contains_freq_field() {
         let record = format_qso(
             &make_wfd_log(),
-            &make_qso_with_exchange_and_freq("2H EPA", 14225),
+            &make_qso_with_exchange_and_freq("2H EPA", 14_225_000),
         )
         .unwrap();
         assert!(
             record.contains("<FREQ:"),
             "WFD record with frequency must contain FREQ"
         );
-        // frequency stored as kHz; ADIF FREQ is in MHz
+        // frequency stored as Hz; ADIF FREQ is in MHz
         assert!(
             record.contains("14.225"),
             "FREQ must be emitted in MHz (not kHz)"
@@ -785,7 +817,7 @@ mod tests {
         );
     }
 
-    fn make_qso_with_freq(freq: u32) -> Qso {
+    fn make_qso_with_freq(freq: u64) -> Qso {
         Qso::new(
             "KD9XYZ".to_string(),
             "59".to_string(),
@@ -797,6 +829,7 @@ mod tests {
             None,
             None,
             Some(freq),
+            None,
         )
         .unwrap()
     }
@@ -810,7 +843,7 @@ mod tests {
     #[test]
     fn general_qso_with_frequency_emits_freq() {
         let log = make_general_log();
-        let record = format_qso(&log, &make_qso_with_freq(14225)).unwrap();
+        let record = format_qso(&log, &make_qso_with_freq(14_225_000)).unwrap();
         assert!(
             record.contains("<FREQ:"),
             "general QSO with freq must emit FREQ"
@@ -820,7 +853,7 @@ mod tests {
 
     #[test]
     fn pota_qso_with_frequency_emits_freq() {
-        let record = format_qso(&make_log(), &make_qso_with_freq(7200)).unwrap();
+        let record = format_qso(&make_log(), &make_qso_with_freq(7_200_000)).unwrap();
         assert!(
             record.contains("<FREQ:"),
             "POTA QSO with freq must emit FREQ"
@@ -838,6 +871,16 @@ mod tests {
         );
     }
 
+    #[test]
+    fn freq_keeps_sub_khz_digits() {
+        assert_eq!(format_mhz(14_225_000), "14.225");
+        assert_eq!(format_mhz(7_200_000), "7.200");
+        assert_eq!(format_mhz(137_500), "0.1375");
+        assert_eq!(format_mhz(472_000), "0.472");
+        assert_eq!(format_mhz(14_074_125), "14.074125");
+        assert_eq!(format_mhz(1), "0.000001");
+    }
+
     #[test]
     fn non_pota_qso_with_their_park_excludes_sig_info() {
         // General log with a QSO that has their_park set — should not emit SIG/SIG_INFO
@@ -0,0 +1,706 @@
+//! Progress toward common operating awards across every log.
+//!
+//! [`award_progress`] walks the QSOs of the given logs (normally
+//! [`LogManager::list_logs`](crate::storage::LogManager::list_logs)) and
+//! collects what has been worked for each [`Award`]. Like [`crate::export`],
+//! it is pure: the caller reads the logs and shows the result.
+//!
+//! duklog does not record the other station's state, DXCC entity, or grid, so
+//! those awards are credited from the ADIF fields other loggers write (`STATE`,
+//! `DXCC`/`COUNTRY`, `GRIDSQUARE`/`VUCC_GRIDS`) when a QSO carries them, and
+//! states also from the section in a Field Day or Winter Field Day exchange.
+
+mod sections;
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::model::{Band, Log, Mode, Qso};
+
+/// DXCC entities needed for the basic DXCC award.
+const DXCC_TARGET: usize = 100;
+
+/// DXCC entity codes of the United States, Alaska and Hawaii.
+const US_ENTITIES: &[u16] = &[291, 6, 110];
+
+/// `COUNTRY` names of the US entities.
+const US_COUNTRIES: &[&str] = &[
+    "United States",
+    "United States of America",
+    "USA",
+    "Alaska",
+    "Hawaii",
+];
+
+/// A trackable award.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Award {
+    /// Distinct POTA parks worked (from each QSO's park reference).
+    Parks,
+    /// Worked All States: the 50 US states.
+    States,
+    /// DXCC entities.
+    Dxcc,
+    /// VHF/UHF Century Club: grid squares worked on 6m and up.
+    Vucc,
+    /// ARRL and RAC sections worked in Field Day and Winter Field Day logs.
+    Sections,
+}
+
+impl Award {
+    /// Returns every award in display order.
+    pub fn all() -> &'static [Award] {
+        &[
+            Award::Parks,
+            Award::States,
+            Award::Dxcc,
+            Award::Vucc,
+            Award::Sections,
+        ]
+    }
+
+    /// Returns the display name (e.g. `"Worked All States"`).
+    pub fn name(self) -> &'static str {
+        match self {
+            Award::Parks => "POTA Parks Hunted",
+            Award::States => "Worked All States",
+            Award::Dxcc => "DXCC",
+            Award::Vucc => "VUCC",
+            Award::Sections => "ARRL Sections",
+        }
+    }
+
+    /// Returns what a QSO needs to count, for explaining uncredited QSOs.
+    pub fn source(self) -> &'static str {
+        match self {
+            Award::Parks => "park reference",
+            Award::States => "STATE field or FD/WFD section",
+            Award::Dxcc => "DXCC or COUNTRY field",
+            Award::Vucc => "GRIDSQUARE or VUCC_GRIDS field",
+            Award::Sections => "FD/WFD section",
+        }
+    }
+}
+
+/// Restricts award progress to QSOs on one band and/or mode.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AwardFilter {
+    pub band: Option<Band>,
+    pub mode: Option<Mode>,
+}
+
+impl AwardFilter {
+    /// Returns `true` if `qso` passes the filter.
+    pub fn matches(&self, qso: &Qso) -> bool {
+        self.band.is_none_or(|band| band == qso.band)
+            && self.mode.is_none_or(|mode| mode == qso.mode)
+    }
+}
+
+/// What has been worked toward one award.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AwardProgress {
+    pub award: Award,
+    /// Everything worked, sorted.
+    pub worked: Vec<String>,
+    /// What is still needed, sorted; empty when the award has no fixed list
+    /// (parks, DXCC, VUCC).
+    pub needed: Vec<String>,
+    /// How many are needed for the award, if there is a fixed number. VUCC
+    /// has one only when the filter picks a single band.
+    pub target: Option<usize>,
+    /// QSOs that could count but lack the field to credit them. For Worked
+    /// All States, only QSOs that may be with a US station.
+    pub uncredited: usize,
+}
+
+impl AwardProgress {
+    /// Returns how many more are needed to reach the target, if there is one.
+    pub fn remaining(&self) -> Option<usize> {
+        self.target
+            .map(|target| target.saturating_sub(self.worked.len()))
+    }
+}
+
+/// Returns progress toward every [`Award`], in display order, from the QSOs
+/// in `logs` that pass `filter`.
+pub fn award_progress(logs: &[Log], filter: &AwardFilter) -> Vec<AwardProgress> {
+    let mut parks = BTreeSet::new();
+    let mut states = BTreeSet::new();
+    let mut entities = Entities::default();
+    let mut grids = BTreeSet::new();
+    let mut worked_sections = BTreeSet::new();
+    let (mut no_state, mut no_entity, mut no_grid) = (0, 0, 0);
+
+    for log in logs {
+        let contest = matches!(log, Log::FieldDay(_) | Log::WinterFieldDay(_));
+        for qso in log.qsos().iter().filter(|q| filter.matches(q)) {
+            if let Some(park) = &qso.their_park {
+                parks.insert(park.to_uppercase());
+            }
+            let section = contest.then(|| exchange_section(qso)).flatten();
+            if let Some(section) = section {
+                worked_sections.insert(section);
+            }
+            match qso_state(qso, section) {
+                Some(state) => {
+                    states.insert(state);
+                }
+                None if may_be_us(qso, section) => no_state += 1,
+                None => {}
+            }
+            if !entities.insert(qso) {
+                no_entity += 1;
+            }
+            if vucc_target(qso.band).is_some() {
+                let qso_grids = qso_grids(qso);
+                if qso_grids.is_empty() {
+                    no_grid += 1;
+                }
+                grids.extend(qso_grids);
+            }
+        }
+    }
+
+    let states: Vec<String> = states.into_iter().map(String::from).collect();
+    let worked_sections: Vec<String> = worked_sections.into_iter().map(String::from).collect();
+    vec![
+        AwardProgress {
+            award: Award::Parks,
+            worked: parks.into_iter().collect(),
+            needed: Vec::new(),
+            target: None,
+            uncredited: 0,
+        },
+        AwardProgress {
+            award: Award::States,
+            needed: not_worked(sections::US_STATES.iter().copied(), &states),
+            target: Some(sections::US_STATES.len()),
+            worked: states,
+            uncredited: no_state,
+        },
+        AwardProgress {
+            award: Award::Dxcc,
+            worked: entities.labels(),
+            needed: Vec::new(),
+            target: Some(DXCC_TARGET),
+            uncredited: no_entity,
+        },
+        AwardProgress {
+            award: Award::Vucc,
+            worked: grids.into_iter().collect(),
+            needed: Vec::new(),
+            target: filter.band.and_then(vucc_target),
+            uncredited: no_grid,
+        },
+        AwardProgress {
+            award: Award::Sections,
+            needed: not_worked(
+                sections::SECTIONS.iter().map(|(name, _)| *name),
+                &worked_sections,
+            ),
+            target: Some(sections::SECTIONS.len()),
+            worked: worked_sections,
+            uncredited: 0,
+        },
+    ]
+}
+
+/// Returns the bands and modes used in `logs`, in [`Band::all`] and
+/// [`Mode::all`] order, for choosing a filter.
+pub fn bands_and_modes(logs: &[Log]) -> (Vec<Band>, Vec<Mode>) {
+    let qsos = || logs.iter().flat_map(Log::qsos);
+    let bands = Band::all()
+        .iter()
+        .filter(|band| qsos().any(|q| q.band == **band))
+        .copied()
+        .collect();
+    let modes = Mode::all()
+        .iter()
+        .filter(|mode| qsos().any(|q| q.mode == **mode))
+        .copied()
+        .collect();
+    (bands, modes)
+}
+
+/// Returns the entries of `all` missing from `worked`, sorted.
+fn not_worked<'a>(all: impl Iterator<Item = &'a str>, worked: &[String]) -> Vec<String> {
+    let mut needed: Vec<String> = all
+        .filter(|name| !worked.iter().any(|w| w == name))
+        .map(String::from)
+        .collect();
+    needed.sort();
+    needed
+}
+
+/// Returns the section at the end of a received contest exchange (`"3A CT"`),
+/// if it is an ARRL or RAC section.
+fn exchange_section(qso: &Qso) -> Option<&'static str> {
+    let exchange = qso.exchange_rcvd.as_deref()?;
+    sections::section(exchange.split_whitespace().last()?)
+}
+
+/// Returns the US state worked: the ADIF `STATE` field, else the state of the
+/// exchange `section`.
+///
+/// `STATE` holds a subdivision of any entity (`WA` is Western Australia as
+/// well as Washington), so it counts only for a US `DXCC` entity or, with no
+/// `DXCC`, a station that [may be in the US](may_be_us).
+fn qso_state(qso: &Qso, section: Option<&str>) -> Option<&'static str> {
+    let us = match dxcc_code(qso) {
+        Some(code) => US_ENTITIES.contains(&code),
+        None => may_be_us(qso, None),
+    };
+    qso.extra_fields
+        .get("STATE")
+        .filter(|_| us)
+        .and_then(|s| sections::us_state(s.trim()))
+        .or_else(|| section.and_then(sections::section_state))
+}
+
+/// Returns `true` unless the QSO is known to be with a station outside the 50
+/// states: an exchange section in no state, a non-US DXCC entity or
+/// `COUNTRY`, or else a callsign outside the US prefixes (K, N, W, and AA
+/// through AL).
+fn may_be_us(qso: &Qso, section: Option<&str>) -> bool {
+    if section.is_some() {
+        // qso_state found no state for it: a Canadian section, PR or VI.
+        return false;
+    }
+    let fields = &qso.extra_fields;
+    if let Some(code) = dxcc_code(qso) {
+        return US_ENTITIES.contains(&code);
+    }
+    if let Some(country) = fields
+        .get("COUNTRY")
+        .map(str::trim)
+        .filter(|c| !c.is_empty())
+    {
+        return US_COUNTRIES
+            .iter()
+            .any(|us| us.eq_ignore_ascii_case(country));
+    }
+    matches!(
+        qso.their_call.to_ascii_uppercase().as_bytes(),
+        [b'K' | b'N' | b'W', ..] | [b'A', b'A'..=b'L', ..]
+    )
+}
+
+/// Returns the QSO's ADIF `DXCC` entity code, if it has a valid one.
+fn dxcc_code(qso: &Qso) -> Option<u16> {
+    qso.extra_fields
+        .get("DXCC")
+        .and_then(|c| c.trim().parse::<u16>().ok())
+        .filter(|&c| c > 0)
+}
+
+/// The DXCC entities worked, from each QSO's `DXCC` code or `COUNTRY` name.
+///
+/// A QSO with only a `COUNTRY` counts as its own entity unless another QSO
+/// gives the same country alongside a code, so an entity logged both ways is
+/// counted once.
+#[derive(Debug, Default)]
+struct Entities {
+    /// Entity codes, with the first `COUNTRY` name seen for each.
+    codes: BTreeMap<u16, Option<String>>,
+    /// Every uppercased `COUNTRY` name seen alongside a code.
+    coded_names: BTreeSet<String>,
+    /// Uppercased `COUNTRY` names from QSOs without a code, with the name as
+    /// first written.
+    names: BTreeMap<String, String>,
+}
+
+impl Entities {
+    /// Records the entity of `qso`, returning `false` if it has none.
+    fn insert(&mut self, qso: &Qso) -> bool {
+        let country = qso
+            .extra_fields
+            .get("COUNTRY")
+            .map(str::trim)
+            .filter(|c| !c.is_empty());
+        match (dxcc_code(qso), country) {
+            (Some(code), country) => {
+                let name = self.codes.entry(code).or_default();
+                if let Some(country) = country {
+                    self.coded_names.insert(country.to_uppercase());
+                    name.get_or_insert_with(|| country.to_string());
+                }
+            }
+            (None, Some(country)) => {
+                self.names
+                    .entry(country.to_uppercase())
+                    .or_insert_with(|| country.to_string());
+            }
+            (None, None) => return false,
+        }
+        true
+    }
+
+    /// Returns one sorted label per entity: `"England (223)"` or `"DXCC 223"`
+    /// for codes, the country name for the rest.
+    fn labels(self) -> Vec<String> {
+        let coded = self.codes.into_iter().map(|(code, country)| match country {
+            Some(country) => format!("{country} ({code})"),
+            None => format!("DXCC {code}"),
+        });
+        let named = self
+            .names
+            .into_iter()
+            .filter(|(upper, _)| !self.coded_names.contains(upper))
+            .map(|(_, name)| name);
+        let labels: BTreeSet<String> = coded.chain(named).collect();
+        labels.into_iter().collect()
+    }
+}
+
+/// Returns the four-character grid squares a QSO credits: every grid in
+/// `VUCC_GRIDS` (a station on a grid line or corner), else the square of
+/// `GRIDSQUARE`.
+fn qso_grids(qso: &Qso) -> Vec<String> {
+    let square = |s: &str| {
+        let s = s.trim();
+        let square = s.get(..4)?.to_uppercase();
+        let b = square.as_bytes();
+        (matches!(b[0], b'A'..=b'R')
+            && matches!(b[1], b'A'..=b'R')
+            && b[2].is_ascii_digit()
+            && b[3].is_ascii_digit())
+        .then_some(square)
+    };
+    let fields = &qso.extra_fields;
+    match fields.get("VUCC_GRIDS") {
+        Some(grids) => grids.split(',').filter_map(square).collect(),
+        None => fields
+            .get("GRIDSQUARE")
+            .and_then(square)
+            .into_iter()
+            .collect(),
+    }
+}
+
+/// Returns the grid squares needed for VUCC on `band`, or `None` if VUCC is
+/// not awarded there.
+fn vucc_target(band: Band) -> Option<usize> {
+    match band {
+        Band::M6 | Band::M2 => Some(100),
+        Band::M1_25 | Band::Cm70 => Some(50),
+        Band::Cm33 | Band::Cm23 => Some(25),
+        Band::Cm13 => Some(10),
+        Band::Cm9
+        | Band::Cm6
+        | Band::Cm3
+        | Band::Cm1_25
+        | Band::Mm6
+        | Band::Mm4
+        | Band::Mm2_5
+        | Band::Mm2
+        | Band::Mm1
+        | Band::Submm => Some(5),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+
+    use super::*;
+    use crate::model::{
+        FdClass, FdPowerCategory, FieldDayLog, GeneralLog, PotaLog, WfdClass, WfdLog,
+    };
+
+    fn make_qso(call: &str, band: Band, mode: Mode) -> Qso {
+        Qso::new(
+            call.into(),
+            "59".into(),
+            "59".into(),
+            band,
+            mode,
+            Utc.with_ymd_and_hms(2026, 3, 14, 15, 0, 0).unwrap(),
+            String::new(),
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap()
+    }
+
+    fn with_fields(mut qso: Qso, fields: &[(&str, &str)]) -> Qso {
+        for (name, value) in fields {
+            qso.extra_fields.insert(name, *value);
+        }
+        qso
+    }
+
+    fn general_log(qsos: Vec<Qso>) -> Log {
+        let mut log = GeneralLog::new("W1AW".into(), None, "FN31".into()).unwrap();
+        log.header.qsos = qsos;
+        Log::General(log)
+    }
+
+    fn field_day_log(exchanges: &[&str]) -> Log {
+        let mut log = FieldDayLog::new(
+            "W1AW".into(),
+            None,
+            1,
+            FdClass::B,
+            "EPA".into(),
+            FdPowerCategory::Low,
+            "FN31".into(),
+        )
+        .unwrap();
+        for exchange in exchanges {
+            let mut qso = make_qso("K4ABC", Band::M20, Mode::Ssb);
+            qso.exchange_rcvd = Some((*exchange).into());
+            log.header.qsos.push(qso);
+        }
+        Log::FieldDay(log)
+    }
+
+    fn progress(logs: &[Log], award: Award) -> AwardProgress {
+        award_progress(logs, &AwardFilter::default())
+            .into_iter()
+            .find(|p| p.award == award)
+            .unwrap()
+    }
+
+    mod parks {
+        use super::*;
+
+        #[test]
+        fn counts_distinct_parks_across_logs() {
+            let mut pota =
+                PotaLog::new("W1AW".into(), None, "K-0001".into(), "FN31".into()).unwrap();
+            let mut a = make_qso("K4ABC", Band::M20, Mode::Ssb);
+            a.their_park = Some("K-1234".into());
+            pota.header.qsos.push(a.clone());
+            let mut b = make_qso("W1XYZ", Band::M40, Mode::Cw);
+            b.their_park = Some("VE-0001".into());
+            let logs = [Log::Pota(pota), general_log(vec![a, b])];
+            let parks = progress(&logs, Award::Parks);
+            assert_eq!(parks.worked, vec!["K-1234", "VE-0001"]);
+            assert_eq!(parks.remaining(), None);
+        }
+    }
+
+    mod states {
+        use super::*;
+
+        #[test]
+        fn state_field_and_contest_sections_count() {
+            let general = general_log(vec![with_fields(
+                make_qso("K4ABC", Band::M20, Mode::Ssb),
+                &[("STATE", "ga")],
+            )]);
+            let logs = [general, field_day_log(&["3A EMA", "1D WMA", "2A ONS"])];
+            let states = progress(&logs, Award::States);
+            assert_eq!(states.worked, vec!["GA", "MA"]);
+            assert_eq!(states.remaining(), Some(48));
+            assert_eq!(states.needed.len(), 48);
+            assert!(!states.needed.contains(&"MA".to_string()));
+            assert_eq!(states.uncredited, 0);
+        }
+
+        #[test]
+        fn only_possible_us_stations_are_uncredited() {
+            let logs = [general_log(vec![
+                make_qso("K4ABC", Band::M20, Mode::Ssb),
+                make_qso("AL7XYZ", Band::M20, Mode::Ssb),
+                make_qso("G4ABC", Band::M20, Mode::Ssb),
+                with_fields(make_qso("W1AW/MM", Band::M20, Mode::Ssb), &[("DXCC", "1")]),
+                with_fields(
+                    make_qso("KH6ABC", Band::M20, Mode::Ssb),
+                    &[("COUNTRY", "Hawaii")],
+                ),
+                with_fields(
+                    make_qso("JA1ABC", Band::M20, Mode::Ssb),
+                    &[("COUNTRY", "Japan")],
+                ),
+            ])];
+            assert_eq!(progress(&logs, Award::States).uncredited, 3);
+        }
+
+        #[test]
+        fn state_counts_only_for_us_stations() {
+            let logs = [general_log(vec![
+                with_fields(
+                    make_qso("VK6ABC", Band::M20, Mode::Ssb),
+                    &[("STATE", "WA"), ("DXCC", "150")],
+                ),
+                with_fields(make_qso("VE3ABC", Band::M20, Mode::Ssb), &[("STATE", "ON")]),
+                with_fields(
+                    make_qso("VK4ABC", Band::M20, Mode::Ssb),
+                    &[("STATE", "QLD"), ("COUNTRY", "Australia")],
+                ),
+                with_fields(
+                    make_qso("KL7ABC", Band::M20, Mode::Ssb),
+                    &[("STATE", "AK"), ("DXCC", "6")],
+                ),
+                with_fields(make_qso("VK6XYZ", Band::M20, Mode::Ssb), &[("STATE", "NV")]),
+                with_fields(make_qso("W7ABC", Band::M20, Mode::Ssb), &[("STATE", "OR")]),
+            ])];
+            let states = progress(&logs, Award::States);
+            assert_eq!(states.worked, vec!["AK", "OR"]);
+            assert_eq!(states.uncredited, 0);
+        }
+
+        #[test]
+        fn sections_only_count_in_contest_logs() {
+            let mut qso = make_qso("K4ABC", Band::M20, Mode::Ssb);
+            qso.exchange_rcvd = Some("3A CT".into());
+            let logs = [general_log(vec![qso])];
+            assert!(progress(&logs, Award::States).worked.is_empty());
+            assert!(progress(&logs, Award::Sections).worked.is_empty());
+        }
+    }
+
+    mod dxcc {
+        use super::*;
+
+        #[test]
+        fn entity_code_identifies_entity() {
+            let logs = [general_log(vec![
+                with_fields(
+                    make_qso("G4ABC", Band::M20, Mode::Ssb),
+                    &[("DXCC", "223"), ("COUNTRY", "England")],
+                ),
+                with_fields(make_qso("G4XYZ", Band::M20, Mode::Ssb), &[("DXCC", "223")]),
+                with_fields(
+                    make_qso("JA1ABC", Band::M20, Mode::Ssb),
+                    &[("COUNTRY", "Japan")],
+                ),
+                make_qso("K4ABC", Band::M20, Mode::Ssb),
+            ])];
+            let dxcc = progress(&logs, Award::Dxcc);
+            assert_eq!(dxcc.worked, vec!["England (223)", "Japan"]);
+            assert_eq!(dxcc.remaining(), Some(98));
+            assert_eq!(dxcc.uncredited, 1);
+        }
+
+        #[test]
+        fn country_name_merges_into_code_with_same_country() {
+            let logs = [general_log(vec![
+                with_fields(
+                    make_qso("G4ABC", Band::M20, Mode::Ssb),
+                    &[("DXCC", "223"), ("COUNTRY", "England")],
+                ),
+                with_fields(
+                    make_qso("G4XYZ", Band::M40, Mode::Cw),
+                    &[("COUNTRY", "ENGLAND")],
+                ),
+                with_fields(
+                    make_qso("F5ABC", Band::M20, Mode::Ssb),
+                    &[("COUNTRY", "France")],
+                ),
+                with_fields(
+                    make_qso("F5XYZ", Band::M40, Mode::Ssb),
+                    &[("COUNTRY", "france")],
+                ),
+            ])];
+            let dxcc = progress(&logs, Award::Dxcc);
+            assert_eq!(dxcc.worked, vec!["England (223)", "France"]);
+        }
+    }
+
+    mod vucc {
+        use super::*;
+
+        #[test]
+        fn counts_four_character_grids_on_vhf() {
+            let logs = [general_log(vec![
+                with_fields(
+                    make_qso("K4ABC", Band::M6, Mode::Ft8),
+                    &[("GRIDSQUARE", "em73tu")],
+                ),
+                with_fields(
+                    make_qso("W1XYZ", Band::M2, Mode::Fm),
+                    &[("VUCC_GRIDS", "FN31,FN32")],
+                ),
+                with_fields(
+                    make_qso("N0CALL", Band::M20, Mode::Ssb),
+                    &[("GRIDSQUARE", "DM79")],
+                ),
+                make_qso("KD9XYZ", Band::M6, Mode::Ssb),
+            ])];
+            let vucc = progress(&logs, Award::Vucc);
+            assert_eq!(vucc.worked, vec!["EM73", "FN31", "FN32"]);
+            assert_eq!(vucc.target, None);
+            assert_eq!(vucc.uncredited, 1);
+        }
+
+        #[test]
+        fn band_filter_sets_target() {
+            let logs = [general_log(vec![with_fields(
+                make_qso("K4ABC", Band::M6, Mode::Ft8),
+                &[("GRIDSQUARE", "EM73")],
+            )])];
+            let filter = AwardFilter {
+                band: Some(Band::M6),
+                mode: None,
+            };
+            let vucc = &award_progress(&logs, &filter)[3];
+            assert_eq!(vucc.award, Award::Vucc);
+            assert_eq!(vucc.remaining(), Some(99));
+        }
+    }
+
+    mod sections {
+        use super::*;
+
+        #[test]
+        fn fd_and_wfd_exchanges_count() {
+            let mut wfd = WfdLog::new(
+                "W1AW".into(),
+                None,
+                1,
+                WfdClass::H,
+                "EPA".into(),
+                "FN31".into(),
+            )
+            .unwrap();
+            let mut qso = make_qso("K4ABC", Band::M40, Mode::Cw);
+            qso.exchange_rcvd = Some("2H sdg".into());
+            wfd.header.qsos.push(qso);
+            let logs = [field_day_log(&["3A CT", "1F DX"]), Log::WinterFieldDay(wfd)];
+            let sections = progress(&logs, Award::Sections);
+            assert_eq!(sections.worked, vec!["CT", "SDG"]);
+            assert_eq!(sections.remaining(), Some(83));
+            assert!(!sections.needed.contains(&"SDG".to_string()));
+            assert!(sections.needed.contains(&"EPA".to_string()));
+        }
+    }
+
+    mod filter {
+        use super::*;
+
+        #[test]
+        fn band_and_mode_restrict_qsos() {
+            let logs = [field_day_log(&["3A CT"]), {
+                let mut log = field_day_log(&["1D EPA"]);
+                if let Log::FieldDay(fd) = &mut log {
+                    fd.header.qsos[0].mode = Mode::Cw;
+                }
+                log
+            }];
+            let filter = AwardFilter {
+                band: Some(Band::M20),
+                mode: Some(Mode::Cw),
+            };
+            let sections = &award_progress(&logs, &filter)[4];
+            assert_eq!(sections.worked, vec!["EPA"]);
+        }
+
+        #[test]
+        fn bands_and_modes_lists_those_used() {
+            let logs = [general_log(vec![
+                make_qso("K4ABC", Band::M40, Mode::Cw),
+                make_qso("W1XYZ", Band::M20, Mode::Ssb),
+                make_qso("N0CALL", Band::M40, Mode::Ssb),
+            ])];
+            let (bands, modes) = bands_and_modes(&logs);
+            assert_eq!(bands, vec![Band::M40, Band::M20]);
+            assert_eq!(modes.len(), 2);
+            assert!(modes.contains(&Mode::Cw) && modes.contains(&Mode::Ssb));
+        }
+    }
+}
@@ -0,0 +1,172 @@
+/// The 50 US states, by postal code, as counted for Worked All States.
+pub(super) static US_STATES: &[&str] = &[
+    "AK", "AL", "AR", "AZ", "CA", "CO", "CT", "DE", "FL", "GA", "HI", "IA", "ID", "IL", "IN", "KS",
+    "KY", "LA", "MA", "MD", "ME", "MI", "MN", "MO", "MS", "MT", "NC", "ND", "NE", "NH", "NJ", "NM",
+    "NV", "NY", "OH", "OK", "OR", "PA", "RI", "SC", "SD", "TN", "TX", "UT", "VA", "VT", "WA", "WI",
+    "WV", "WY",
+];
+
+/// ARRL and RAC sections, with the US state each US section lies in.
+///
+/// Puerto Rico, the US Virgin Islands and the Canadian sections are in no
+/// state. `PAC` (Pacific) is credited to Hawaii.
+pub(super) static SECTIONS: &[(&str, Option<&str>)] = &[
+    // Call area 1
+    ("CT", Some("CT")),
+    ("EMA", Some("MA")),
+    ("ME", Some("ME")),
+    ("NH", Some("NH")),
+    ("RI", Some("RI")),
+    ("VT", Some("VT")),
+    ("WMA", Some("MA")),
+    // Call area 2
+    ("ENY", Some("NY")),
+    ("NLI", Some("NY")),
+    ("NNJ", Some("NJ")),
+    ("NNY", Some("NY")),
+    ("SNJ", Some("NJ")),
+    ("WNY", Some("NY")),
+    // Call area 3
+    ("DE", Some("DE")),
+    ("EPA", Some("PA")),
+    ("MDC", Some("MD")),
+    ("WPA", Some("PA")),
+    // Call area 4
+    ("AL", Some("AL")),
+    ("GA", Some("GA")),
+    ("KY", Some("KY")),
+    ("NC", Some("NC")),
+    ("NFL", Some("FL")),
+    ("PR", None),
+    ("SC", Some("SC")),
+    ("SFL", Some("FL")),
+    ("TN", Some("TN")),
+    ("VA", Some("VA")),
+    ("VI", None),
+    ("WCF", Some("FL")),
+    // Call area 5
+    ("AR", Some("AR")),
+    ("LA", Some("LA")),
+    ("MS", Some("MS")),
+    ("NM", Some("NM")),
+    ("NTX", Some("TX")),
+    ("OK", Some("OK")),
+    ("STX", Some("TX")),
+    ("WTX", Some("TX")),
+    // Call area 6
+    ("EB", Some("CA")),
+    ("LAX", Some("CA")),
+    ("ORG", Some("CA")),
+    ("PAC", Some("HI")),
+    ("SB", Some("CA")),
+    ("SCV", Some("CA")),
+    ("SDG", Some("CA")),
+    ("SF", Some("CA")),
+    ("SJV", Some("CA")),
+    ("SV", Some("CA")),
+    // Call area 7
+    ("AK", Some("AK")),
+    ("AZ", Some("AZ")),
+    ("EWA", Some("WA")),
+    ("ID", Some("ID")),
+    ("MT", Some("MT")),
+    ("NV", Some("NV")),
+    ("OR", Some("OR")),
+    ("UT", Some("UT")),
+    ("WWA", Some("WA")),
+    ("WY", Some("WY")),
+    // Call area 8
+    ("MI", Some("MI")),
+    ("OH", Some("OH")),
+    ("WV", Some("WV")),
+    // Call area 9
+    ("IL", Some("IL")),
+    ("IN", Some("IN")),
+    ("WI", Some("WI")),
+    // Call area 0
+    ("CO", Some("CO")),
+    ("IA", Some("IA")),
+    ("KS", Some("KS")),
+    ("MN", Some("MN")),
+    ("MO", Some("MO")),
+    ("ND", Some("ND")),
+    ("NE", Some("NE")),
+    ("SD", Some("SD")),
+    // Canada
+    ("AB", None),
+    ("BC", None),
+    ("GH", None),
+    ("MB", None),
+    ("NB", None),
+    ("NL", None),
+    ("NS", None),
+    ("ONE", None),
+    ("ONN", None),
+    ("ONS", None),
+    ("PE", None),
+    ("QC", None),
+    ("SK", None),
+    ("TER", None),
+];
+
+/// Returns the canonical section for `s` (case-insensitive), if it is one.
+pub(super) fn section(s: &str) -> Option<&'static str> {
+    SECTIONS
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(s))
+        .map(|(name, _)| *name)
+}
+
+/// Returns the US state `section` lies in, if any.
+pub(super) fn section_state(section: &str) -> Option<&'static str> {
+    SECTIONS
+        .iter()
+        .find(|(name, _)| *name == section)
+        .and_then(|(_, state)| *state)
+}
+
+/// Returns the canonical state code for `s` (case-insensitive), if it is one
+/// of the 50 states.
+pub(super) fn us_state(s: &str) -> Option<&'static str> {
+    US_STATES
+        .iter()
+        .find(|state| state.eq_ignore_ascii_case(s))
+        .copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use super::*;
+
+    #[test]
+    fn every_state_has_a_section() {
+        let states: BTreeSet<_> = SECTIONS.iter().filter_map(|(_, state)| *state).collect();
+        assert_eq!(states.into_iter().collect::<Vec<_>>(), US_STATES);
+    }
+
+    #[test]
+    fn section_states_are_states() {
+        for (name, state) in SECTIONS {
+            if let Some(state) = state {
+                assert_eq!(us_state(state), Some(*state), "{name}");
+            }
+        }
+    }
+
+    #[test]
+    fn section_lookup_is_case_insensitive() {
+        assert_eq!(section("epa"), Some("EPA"));
+        assert_eq!(section_state("EPA"), Some("PA"));
+        assert_eq!(section("DX"), None);
+        assert_eq!(section_state("ONS"), None);
+    }
+
+    #[test]
+    fn sections_are_unique() {
+        let names: BTreeSet<_> = SECTIONS.iter().map(|(name, _)| name).collect();
+        assert_eq!(names.len(), SECTIONS.len());
+        assert_eq!(SECTIONS.len(), 85);
+    }
+}
@@ -0,0 +1,22 @@
+use std::path::PathBuf;
+
+/// Errors that can occur while loading the user configuration.
+#[derive(Debug, thiserror::Error)]
+pub enum ConfigError {
+    /// An I/O error occurred while reading the config file.
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// The config file is not valid JSON or contains an invalid setting.
+    #[error("invalid config file {path}: {source}")]
+    Invalid {
+        /// Path to the offending config file.
+        path: PathBuf,
+        /// The underlying parse error.
+        source: serde_json::Error,
+    },
+
+    /// The platform does not provide a data directory.
+    #[error("could not determine XDG data directory")]
+    NoDataDir,
+}
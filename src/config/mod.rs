@@ -0,0 +1,10 @@
+//! User preferences loaded from `~/.local/share/duklog/config.json`.
+//!
+//! The file is optional; every setting has a default, and a missing file is
+//! equivalent to an empty JSON object.
+
+mod error;
+mod settings;
+
+pub use error::ConfigError;
+pub use settings::{Config, HooksConfig, SyncConfig, TimersConfig};
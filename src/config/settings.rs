@@ -0,0 +1,482 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use super::error::ConfigError;
+use crate::model::{Band, Mode};
+
+/// User preferences.
+///
+/// Every field falls back to its default when absent from the config file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    /// Bands offered by Alt+B on the QSO entry screen, as ADIF band strings
+    /// (e.g. `["6M", "2M", "1.25M", "70CM"]`), in cycle order.
+    #[serde(with = "adif_bands")]
+    pub band_cycle: Vec<Band>,
+    /// Favourite modes offered by Alt+M, by display name (e.g. `["USB", "CW", "FT8"]`),
+    /// in cycle order.
+    #[serde(with = "mode_names")]
+    pub mode_cycle: Vec<Mode>,
+    /// Key binding overrides: command name → keys (e.g. `{"next_band": ["F5"]}`).
+    ///
+    /// Validated when the TUI builds its keymap; listed commands replace their
+    /// default keys, unlisted commands keep them.
+    pub keys: BTreeMap<String, Vec<String>>,
+    /// Color theme name (e.g. `"high-contrast"`).
+    ///
+    /// Validated when the TUI starts. When absent, `NO_COLOR` selects the
+    /// monochrome theme; otherwise the dark theme is used.
+    pub theme: Option<String>,
+    /// LAN sync with other duklog stations. Off unless an address is set.
+    pub sync: SyncConfig,
+    /// Commands run when QSOs are logged or changed and when logs are created
+    /// or exported.
+    pub hooks: HooksConfig,
+    /// Status bar timers.
+    pub timers: TimersConfig,
+}
+
+/// LAN sync settings.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SyncConfig {
+    /// Address to accept connections from other stations on (e.g. `"0.0.0.0:7373"`).
+    pub listen: Option<String>,
+    /// Addresses of other stations to connect to (e.g. `["192.168.1.20:7373"]`).
+    pub peers: Vec<String>,
+}
+
+impl SyncConfig {
+    /// Returns `true` if a listen address or at least one peer is configured.
+    pub fn is_enabled(&self) -> bool {
+        self.listen.is_some() || !self.peers.is_empty()
+    }
+}
+
+/// Default for [`TimersConfig::station_id_minutes`].
+const DEFAULT_STATION_ID_MINUTES: u64 = 10;
+
+/// Status bar timer settings.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TimersConfig {
+    /// Minutes between station ID reminders; `0` turns the reminder off.
+    pub station_id_minutes: u64,
+}
+
+impl Default for TimersConfig {
+    fn default() -> Self {
+        Self {
+            station_id_minutes: DEFAULT_STATION_ID_MINUTES,
+        }
+    }
+}
+
+/// Default for [`HooksConfig::timeout_secs`].
+const DEFAULT_HOOK_TIMEOUT_SECS: u64 = 5;
+
+/// User commands run on duklog events, each given as a program followed by its
+/// arguments (e.g. `["notify-send", "QSO logged"]`). No shell is involved;
+/// use `["sh", "-c", "..."]` for pipes or redirection.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HooksConfig {
+    /// Run after a QSO is added to the active log.
+    pub qso_added: Option<Vec<String>>,
+    /// Run after a QSO is edited, once per QSO for a bulk edit.
+    pub qso_updated: Option<Vec<String>>,
+    /// Run after a QSO is deleted.
+    pub qso_deleted: Option<Vec<String>>,
+    /// Run after a log is created, including by a merge.
+    pub log_created: Option<Vec<String>>,
+    /// Run after a log is exported.
+    pub log_exported: Option<Vec<String>>,
+    /// Seconds a hook may run before it is stopped.
+    pub timeout_secs: u64,
+}
+
+impl Default for HooksConfig {
+    fn default() -> Self {
+        Self {
+            qso_added: None,
+            qso_updated: None,
+            qso_deleted: None,
+            log_created: None,
+            log_exported: None,
+            timeout_secs: DEFAULT_HOOK_TIMEOUT_SECS,
+        }
+    }
+}
+
+impl HooksConfig {
+    /// Returns the command configured for the event named `event`
+    /// (e.g. `"qso_added"`), if any.
+    pub fn command(&self, event: &str) -> Option<&[String]> {
+        match event {
+            "qso_added" => self.qso_added.as_deref(),
+            "qso_updated" => self.qso_updated.as_deref(),
+            "qso_deleted" => self.qso_deleted.as_deref(),
+            "log_created" => self.log_created.as_deref(),
+            "log_exported" => self.log_exported.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            band_cycle: Band::default_cycle().to_vec(),
+            mode_cycle: Mode::default_cycle().to_vec(),
+            keys: BTreeMap::new(),
+            theme: None,
+            sync: SyncConfig::default(),
+            hooks: HooksConfig::default(),
+            timers: TimersConfig::default(),
+        }
+    }
+}
+
+impl Config {
+    /// Returns the default config file location: `~/.local/share/duklog/config.json`.
+    pub fn default_path() -> Result<PathBuf, ConfigError> {
+        let data_dir = dirs::data_dir().ok_or(ConfigError::NoDataDir)?;
+        Ok(data_dir.join("duklog").join("config.json"))
+    }
+
+    /// Loads the config from the default location.
+    pub fn load() -> Result<Self, ConfigError> {
+        Self::load_from(&Self::default_path()?)
+    }
+
+    /// Loads the config from `path`, returning defaults if the file does not exist.
+    pub fn load_from(path: &Path) -> Result<Self, ConfigError> {
+        match fs::read_to_string(path) {
+            Ok(contents) => {
+                serde_json::from_str(&contents).map_err(|source| ConfigError::Invalid {
+                    path: path.to_path_buf(),
+                    source,
+                })
+            }
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
+
+/// (De)serializes a band list as ADIF band strings rather than enum variant names.
+mod adif_bands {
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    use crate::model::Band;
+
+    pub fn serialize<S: Serializer>(bands: &[Band], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(bands.iter().map(Band::adif_str))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<Band>, D::Error> {
+        let names = Vec::<String>::deserialize(deserializer)?;
+        if names.is_empty() {
+            return Err(D::Error::custom("band_cycle must list at least one band"));
+        }
+        names
+            .iter()
+            .map(|name| {
+                Band::from_adif_str(name)
+                    .ok_or_else(|| D::Error::custom(format!("unknown ADIF band {name:?}")))
+            })
+            .collect()
+    }
+}
+
+/// (De)serializes a mode list by display name rather than enum variant name.
+mod mode_names {
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    use crate::model::Mode;
+
+    pub fn serialize<S: Serializer>(modes: &[Mode], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(modes.iter().map(Mode::name))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<Mode>, D::Error> {
+        let names = Vec::<String>::deserialize(deserializer)?;
+        if names.is_empty() {
+            return Err(D::Error::custom("mode_cycle must list at least one mode"));
+        }
+        names
+            .iter()
+            .map(|name| {
+                Mode::from_name(name)
+                    .ok_or_else(|| D::Error::custom(format!("unknown mode {name:?}")))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::TempDir;
+
+    use super::*;
+
+    fn write_config(contents: &str) -> (TempDir, PathBuf) {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("config.json");
+        fs::write(&path, contents).unwrap();
+        (dir, path)
+    }
+
+    mod load {
+        use super::*;
+
+        #[test]
+        fn missing_file_returns_default() {
+            let dir = TempDir::new().unwrap();
+            let config = Config::load_from(&dir.path().join("config.json")).unwrap();
+            assert_eq!(config, Config::default());
+        }
+
+        #[test]
+        fn empty_object_returns_default() {
+            let (_dir, path) = write_config("{}");
+            assert_eq!(Config::load_from(&path).unwrap(), Config::default());
+        }
+
+        #[test]
+        fn malformed_json_is_invalid() {
+            let (_dir, path) = write_config("{ not json");
+            let err = Config::load_from(&path).unwrap_err();
+            assert!(matches!(err, ConfigError::Invalid { .. }));
+        }
+
+        #[test]
+        fn directory_path_is_io_error() {
+            let dir = TempDir::new().unwrap();
+            let err = Config::load_from(dir.path()).unwrap_err();
+            assert!(matches!(err, ConfigError::Io(_)));
+        }
+    }
+
+    mod band_cycle {
+        use super::*;
+
+        #[test]
+        fn default_is_band_default_cycle() {
+            assert_eq!(Config::default().band_cycle, Band::default_cycle());
+        }
+
+        #[test]
+        fn parses_adif_band_strings_case_insensitively() {
+            let (_dir, path) = write_config(r#"{"band_cycle": ["6m", "2M", "1.25M", "70cm"]}"#);
+            let config = Config::load_from(&path).unwrap();
+            assert_eq!(
+                config.band_cycle,
+                vec![Band::M6, Band::M2, Band::M1_25, Band::Cm70]
+            );
+        }
+
+        #[test]
+        fn unknown_band_is_invalid() {
+            let (_dir, path) = write_config(r#"{"band_cycle": ["20M", "3M"]}"#);
+            let err = Config::load_from(&path).unwrap_err();
+            assert!(
+                err.to_string().contains("unknown ADIF band \"3M\""),
+                "{err}"
+            );
+        }
+
+        #[test]
+        fn empty_list_is_invalid() {
+            let (_dir, path) = write_config(r#"{"band_cycle": []}"#);
+            assert!(matches!(
+                Config::load_from(&path).unwrap_err(),
+                ConfigError::Invalid { .. }
+            ));
+        }
+
+        #[test]
+        fn serializes_as_adif_strings() {
+            let config = Config {
+                band_cycle: vec![Band::M1_25, Band::Cm33],
+                ..Config::default()
+            };
+            let json = serde_json::to_value(&config).unwrap();
+            assert_eq!(json["band_cycle"], serde_json::json!(["1.25M", "33CM"]));
+        }
+    }
+
+    mod keys {
+        use super::*;
+
+        #[test]
+        fn default_is_empty() {
+            assert!(Config::default().keys.is_empty());
+        }
+
+        #[test]
+        fn parses_command_to_key_lists() {
+            let (_dir, path) =
+                write_config(r#"{"keys": {"next_band": ["F5", "Ctrl+b"], "delete_qso": []}}"#);
+            let config = Config::load_from(&path).unwrap();
+            assert_eq!(config.keys["next_band"], vec!["F5", "Ctrl+b"]);
+            assert!(config.keys["delete_qso"].is_empty());
+        }
+
+        #[test]
+        fn non_list_value_is_invalid() {
+            let (_dir, path) = write_config(r#"{"keys": {"help": "F2"}}"#);
+            assert!(matches!(
+                Config::load_from(&path).unwrap_err(),
+                ConfigError::Invalid { .. }
+            ));
+        }
+    }
+
+    mod theme {
+        use super::*;
+
+        #[test]
+        fn default_is_unset() {
+            assert_eq!(Config::default().theme, None);
+        }
+
+        #[test]
+        fn parses_theme_name() {
+            let (_dir, path) = write_config(r#"{"theme": "high-contrast"}"#);
+            let config = Config::load_from(&path).unwrap();
+            assert_eq!(config.theme.as_deref(), Some("high-contrast"));
+        }
+
+        #[test]
+        fn non_string_value_is_invalid() {
+            let (_dir, path) = write_config(r#"{"theme": 3}"#);
+            assert!(matches!(
+                Config::load_from(&path).unwrap_err(),
+                ConfigError::Invalid { .. }
+            ));
+        }
+    }
+
+    mod sync {
+        use super::*;
+
+        #[test]
+        fn default_is_disabled() {
+            assert!(!Config::default().sync.is_enabled());
+        }
+
+        #[test]
+        fn parses_listen_and_peers() {
+            let (_dir, path) = write_config(
+                r#"{"sync": {"listen": "0.0.0.0:7373", "peers": ["192.168.1.20:7373"]}}"#,
+            );
+            let config = Config::load_from(&path).unwrap();
+            assert_eq!(config.sync.listen.as_deref(), Some("0.0.0.0:7373"));
+            assert_eq!(config.sync.peers, vec!["192.168.1.20:7373"]);
+            assert!(config.sync.is_enabled());
+        }
+
+        #[test]
+        fn peers_alone_enable_sync() {
+            let (_dir, path) = write_config(r#"{"sync": {"peers": ["10.0.0.2:7373"]}}"#);
+            let config = Config::load_from(&path).unwrap();
+            assert_eq!(config.sync.listen, None);
+            assert!(config.sync.is_enabled());
+        }
+    }
+
+    mod hooks {
+        use super::*;
+
+        #[test]
+        fn default_has_no_hooks() {
+            let hooks = Config::default().hooks;
+            assert_eq!(hooks.command("qso_added"), None);
+            assert_eq!(hooks.timeout_secs, 5);
+        }
+
+        #[test]
+        fn parses_commands_per_event() {
+            let (_dir, path) = write_config(
+                r#"{"hooks": {"qso_added": ["beep"], "log_exported": ["sh", "-c", "cp \"$1\" /media/usb"], "timeout_secs": 2}}"#,
+            );
+            let hooks = Config::load_from(&path).unwrap().hooks;
+            assert_eq!(hooks.command("qso_added"), Some(&["beep".to_string()][..]));
+            assert_eq!(hooks.command("log_exported").unwrap().len(), 3);
+            assert_eq!(hooks.command("qso_deleted"), None);
+            assert_eq!(hooks.command("unknown"), None);
+            assert_eq!(hooks.timeout_secs, 2);
+        }
+    }
+
+    mod timers {
+        use super::*;
+
+        #[test]
+        fn default_reminds_every_ten_minutes() {
+            assert_eq!(Config::default().timers.station_id_minutes, 10);
+        }
+
+        #[test]
+        fn parses_station_id_minutes() {
+            let (_dir, path) = write_config(r#"{"timers": {"station_id_minutes": 0}}"#);
+            let config = Config::load_from(&path).unwrap();
+            assert_eq!(config.timers.station_id_minutes, 0);
+        }
+    }
+
+    mod mode_cycle {
+        use super::*;
+
+        #[test]
+        fn default_is_mode_default_cycle() {
+            assert_eq!(Config::default().mode_cycle, Mode::default_cycle());
+        }
+
+        #[test]
+        fn parses_mode_names_case_insensitively() {
+            let (_dir, path) = write_config(r#"{"mode_cycle": ["usb", "CW", "Olivia"]}"#);
+            let config = Config::load_from(&path).unwrap();
+            assert_eq!(config.mode_cycle, vec![Mode::Usb, Mode::Cw, Mode::Olivia]);
+            assert_eq!(
+                config.band_cycle,
+                Band::default_cycle(),
+                "unset keeps default"
+            );
+        }
+
+        #[test]
+        fn unknown_mode_is_invalid() {
+            let (_dir, path) = write_config(r#"{"mode_cycle": ["CW", "MFSK16"]}"#);
+            let err = Config::load_from(&path).unwrap_err();
+            assert!(err.to_string().contains("unknown mode \"MFSK16\""), "{err}");
+        }
+
+        #[test]
+        fn empty_list_is_invalid() {
+            let (_dir, path) = write_config(r#"{"mode_cycle": []}"#);
+            assert!(matches!(
+                Config::load_from(&path).unwrap_err(),
+                ConfigError::Invalid { .. }
+            ));
+        }
+
+        #[test]
+        fn serializes_as_names() {
+            let config = Config {
+                mode_cycle: vec![Mode::Ft4, Mode::Js8],
+                ..Config::default()
+            };
+            let json = serde_json::to_value(&config).unwrap();
+            assert_eq!(json["mode_cycle"], serde_json::json!(["FT4", "JS8"]));
+        }
+    }
+}
@@ -0,0 +1,191 @@
+use super::{log_type_id, power, sent_exchange};
+use crate::model::{Log, format_khz};
+
+/// Column names of the CSV export, in order.
+///
+/// The first [`LOG_COLUMNS`] (eight) columns describe the log and repeat on
+/// every row; the rest describe the QSO. Empty cells mean the value does not apply or was not
+/// recorded.
+pub const CSV_COLUMNS: &[&str] = &[
+    "log_id",
+    "log_type",
+    "station_callsign",
+    "log_operator",
+    "my_grid",
+    "my_park",
+    "my_exchange",
+    "my_power",
+    "qso_id",
+    "date",
+    "time",
+    "call",
+    "band",
+    "mode",
+    "frequency_khz",
+    "rst_sent",
+    "rst_rcvd",
+    "their_park",
+    "exchange_rcvd",
+    "operator",
+    "comments",
+];
+
+/// Number of leading [`CSV_COLUMNS`] that describe the log rather than the QSO.
+pub const LOG_COLUMNS: usize = 8;
+
+/// Formats a log as CSV: a header row of [`CSV_COLUMNS`], then one row per QSO.
+///
+/// Dates are `YYYY-MM-DD` and times `HH:MM:SS`, both UTC. `operator` is the
+/// callsign that made the contact, falling back to the log operator and then
+/// the station callsign. Fields are quoted per RFC 4180 when needed, and lines
+/// end with CRLF.
+pub fn format_csv(log: &Log) -> String {
+    let header = log.header();
+    let log_type = log_type_id(log);
+    let operator = header.operator.clone().unwrap_or_default();
+    let park = log.park_ref().unwrap_or_default();
+    let exchange = sent_exchange(log).unwrap_or_default();
+    let power = power(log).unwrap_or_default();
+
+    let mut out = String::new();
+    push_row(&mut out, CSV_COLUMNS.iter().copied());
+    for qso in log.qsos() {
+        let date = qso.timestamp.format("%Y-%m-%d").to_string();
+        let time = qso.timestamp.format("%H:%M:%S").to_string();
+        let frequency = qso.frequency.map(format_khz).unwrap_or_default();
+        push_row(
+            &mut out,
+            [
+                header.log_id.as_str(),
+                log_type,
+                header.station_callsign.as_str(),
+                operator.as_str(),
+                header.grid_square.as_str(),
+                park,
+                exchange.as_str(),
+                power.as_str(),
+                qso.id.as_deref().unwrap_or_default(),
+                date.as_str(),
+                time.as_str(),
+                qso.their_call.as_str(),
+                qso.band.adif_str(),
+                qso.mode_name(),
+                frequency.as_str(),
+                qso.rst_sent.as_str(),
+                qso.rst_rcvd.as_str(),
+                qso.their_park.as_deref().unwrap_or_default(),
+                qso.exchange_rcvd.as_deref().unwrap_or_default(),
+                log.qso_operator(qso),
+                qso.comments.as_str(),
+            ],
+        );
+    }
+    out
+}
+
+/// Appends one CSV row terminated by CRLF.
+fn push_row<'a>(out: &mut String, fields: impl IntoIterator<Item = &'a str>) {
+    for (i, field) in fields.into_iter().enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+        push_field(out, field);
+    }
+    out.push_str("\r\n");
+}
+
+/// Appends a field, quoting it if it contains a comma, quote or line break.
+fn push_field(out: &mut String, field: &str) {
+    if field.contains([',', '"', '\r', '\n']) {
+        out.push('"');
+        out.push_str(&field.replace('"', "\"\""));
+        out.push('"');
+    } else {
+        out.push_str(field);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::{make_fd_log, make_pota_log, make_qso};
+    use super::*;
+    use crate::model::Band;
+
+    fn rows(csv: &str) -> Vec<&str> {
+        csv.split("\r\n").filter(|l| !l.is_empty()).collect()
+    }
+
+    #[test]
+    fn header_row_lists_columns() {
+        let csv = format_csv(&make_pota_log());
+        assert_eq!(rows(&csv), vec![CSV_COLUMNS.join(",")]);
+    }
+
+    #[test]
+    fn log_columns_come_before_qso_columns() {
+        assert_eq!(CSV_COLUMNS[LOG_COLUMNS - 1], "my_power");
+        assert_eq!(CSV_COLUMNS[LOG_COLUMNS], "qso_id");
+    }
+
+    #[test]
+    fn qso_row_includes_log_metadata() {
+        let mut log = make_pota_log();
+        log.add_qso(make_qso());
+        let csv = format_csv(&log);
+        let rows = rows(&csv);
+        assert_eq!(rows.len(), 2);
+        assert_eq!(
+            rows[1],
+            "K-0001-20260216-120000,pota,W1AW,KD9XYZ,FN31,K-0001,,,,2026-02-16,14:30:05,\
+             N0CALL,20M,SSB,14250,59,57,K-0002,,KD9XYZ,\"nice, \"\"loud\"\" signal\""
+        );
+    }
+
+    #[test]
+    fn contest_row_has_exchange_and_power() {
+        let mut log = make_fd_log();
+        let mut qso = make_qso();
+        qso.their_park = None;
+        qso.comments = String::new();
+        qso.exchange_rcvd = Some("1D CT".to_string());
+        qso.frequency = None;
+        qso.operator = Some("N1OP".to_string());
+        qso.id = Some("abc123".to_string());
+        log.add_qso(qso);
+        let csv = format_csv(&log);
+        assert_eq!(
+            rows(&csv)[1],
+            "FD-W1AW-20260627-180000,field_day,W1AW,,,,3B EPA,QRP,abc123,2026-02-16,\
+             14:30:05,N0CALL,20M,SSB,,59,57,,1D CT,N1OP,"
+        );
+    }
+
+    #[test]
+    fn sub_khz_frequency_keeps_decimals() {
+        let mut log = make_pota_log();
+        let mut qso = make_qso();
+        qso.band = Band::M630;
+        qso.frequency = Some(475_500);
+        log.add_qso(qso);
+        assert!(rows(&format_csv(&log))[1].contains(",630M,SSB,475.5,"));
+    }
+
+    #[test]
+    fn every_row_has_every_column() {
+        let mut log = make_pota_log();
+        let mut qso = make_qso();
+        qso.comments = "plain".to_string();
+        log.add_qso(qso);
+        let csv = format_csv(&log);
+        for row in rows(&csv) {
+            assert_eq!(row.split(',').count(), CSV_COLUMNS.len(), "{row}");
+        }
+    }
+
+    #[test]
+    fn quotes_line_breaks() {
+        let mut out = String::new();
+        push_field(&mut out, "two\nlines");
+        assert_eq!(out, "\"two\nlines\"");
+    }
+}
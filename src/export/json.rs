@@ -0,0 +1,227 @@
+use chrono::{DateTime, SecondsFormat, Utc};
+use serde::Serialize;
+use serde_json::Number;
+
+use super::{log_type_id, power, sent_exchange};
+use crate::model::{Log, Qso};
+
+/// Version of the JSON export schema, written as `schema_version`.
+pub const JSON_SCHEMA_VERSION: u32 = 1;
+
+#[derive(Serialize)]
+struct Document<'a> {
+    schema_version: u32,
+    program: &'static str,
+    program_version: &'static str,
+    log: LogMetadata<'a>,
+    qsos: Vec<QsoRecord<'a>>,
+}
+
+/// The `log` object of the JSON export, also sent to hooks.
+#[derive(Serialize)]
+pub(crate) struct LogMetadata<'a> {
+    log_id: &'a str,
+    log_type: &'static str,
+    station_callsign: &'a str,
+    operator: Option<&'a str>,
+    grid_square: Option<&'a str>,
+    created_at: String,
+    park_ref: Option<&'a str>,
+    tx_count: Option<u8>,
+    class: Option<String>,
+    section: Option<&'a str>,
+    power: Option<String>,
+    sent_exchange: Option<String>,
+    qso_count: usize,
+}
+
+/// One element of the JSON export's `qsos` array, also sent to hooks.
+#[derive(Serialize)]
+pub(crate) struct QsoRecord<'a> {
+    id: Option<&'a str>,
+    timestamp: String,
+    call: &'a str,
+    band: &'static str,
+    mode: &'a str,
+    frequency_khz: Option<Number>,
+    rst_sent: &'a str,
+    rst_rcvd: &'a str,
+    their_park: Option<&'a str>,
+    exchange_rcvd: Option<&'a str>,
+    operator: &'a str,
+    comments: &'a str,
+}
+
+/// Formats a log as a pretty-printed JSON document.
+///
+/// The document has `schema_version`, `program`, `program_version`, a `log`
+/// object with the log metadata, and a `qsos` array in logged order. Every
+/// key is always present; values that do not apply to the log type are
+/// `null`. Timestamps are RFC 3339 in UTC.
+pub fn format_json(log: &Log) -> Result<String, serde_json::Error> {
+    let document = Document {
+        schema_version: JSON_SCHEMA_VERSION,
+        program: "duklog",
+        program_version: env!("CARGO_PKG_VERSION"),
+        log: log_metadata(log),
+        qsos: log.qsos().iter().map(|qso| qso_record(log, qso)).collect(),
+    };
+    serde_json::to_string_pretty(&document)
+}
+
+/// Returns the log metadata as written to the JSON export's `log` object.
+pub(crate) fn log_metadata(log: &Log) -> LogMetadata<'_> {
+    let header = log.header();
+    let (tx_count, class, section) = match log {
+        Log::FieldDay(f) => (
+            Some(f.tx_count),
+            Some(f.class.to_string()),
+            Some(f.section.as_str()),
+        ),
+        Log::WinterFieldDay(w) => (
+            Some(w.tx_count),
+            Some(w.class.to_string()),
+            Some(w.section.as_str()),
+        ),
+        Log::General(_) | Log::Pota(_) => (None, None, None),
+    };
+    LogMetadata {
+        log_id: &header.log_id,
+        log_type: log_type_id(log),
+        station_callsign: &header.station_callsign,
+        operator: header.operator.as_deref(),
+        grid_square: Some(header.grid_square.as_str()).filter(|g| !g.is_empty()),
+        created_at: rfc3339(&header.created_at),
+        park_ref: log.park_ref(),
+        tx_count,
+        class,
+        section,
+        power: power(log),
+        sent_exchange: sent_exchange(log),
+        qso_count: header.qsos.len(),
+    }
+}
+
+/// Returns a frequency in Hz as a kHz number: whole when it is a whole kHz,
+/// fractional otherwise.
+fn khz_number(hz: u64) -> Option<Number> {
+    if hz.is_multiple_of(1000) {
+        Some(Number::from(hz / 1000))
+    } else {
+        Number::from_f64(hz as f64 / 1000.0)
+    }
+}
+
+/// Returns `qso` as written to the JSON export's `qsos` array.
+pub(crate) fn qso_record<'a>(log: &'a Log, qso: &'a Qso) -> QsoRecord<'a> {
+    QsoRecord {
+        id: qso.id.as_deref(),
+        timestamp: rfc3339(&qso.timestamp),
+        call: &qso.their_call,
+        band: qso.band.adif_str(),
+        mode: qso.mode_name(),
+        frequency_khz: qso.frequency.and_then(khz_number),
+        rst_sent: &qso.rst_sent,
+        rst_rcvd: &qso.rst_rcvd,
+        their_park: qso.their_park.as_deref(),
+        exchange_rcvd: qso.exchange_rcvd.as_deref(),
+        operator: log.qso_operator(qso),
+        comments: &qso.comments,
+    }
+}
+
+fn rfc3339(timestamp: &DateTime<Utc>) -> String {
+    timestamp.to_rfc3339_opts(SecondsFormat::Secs, true)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{Value, json};
+
+    use super::super::tests::{make_fd_log, make_pota_log, make_qso};
+    use super::*;
+    use crate::model::Band;
+
+    fn parse(log: &Log) -> Value {
+        serde_json::from_str(&format_json(log).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn document_has_schema_and_program() {
+        let doc = parse(&make_pota_log());
+        assert_eq!(doc["schema_version"], json!(JSON_SCHEMA_VERSION));
+        assert_eq!(doc["program"], json!("duklog"));
+        assert_eq!(doc["qsos"], json!([]));
+    }
+
+    #[test]
+    fn pota_log_metadata() {
+        let doc = parse(&make_pota_log());
+        assert_eq!(
+            doc["log"],
+            json!({
+                "log_id": "K-0001-20260216-120000",
+                "log_type": "pota",
+                "station_callsign": "W1AW",
+                "operator": "KD9XYZ",
+                "grid_square": "FN31",
+                "created_at": "2026-02-16T12:00:00Z",
+                "park_ref": "K-0001",
+                "tx_count": null,
+                "class": null,
+                "section": null,
+                "power": null,
+                "sent_exchange": null,
+                "qso_count": 0,
+            })
+        );
+    }
+
+    #[test]
+    fn field_day_metadata() {
+        let doc = parse(&make_fd_log());
+        let log = &doc["log"];
+        assert_eq!(log["log_type"], json!("field_day"));
+        assert_eq!(log["grid_square"], Value::Null);
+        assert_eq!(log["tx_count"], json!(3));
+        assert_eq!(log["class"], json!("B"));
+        assert_eq!(log["section"], json!("EPA"));
+        assert_eq!(log["power"], json!("QRP"));
+        assert_eq!(log["sent_exchange"], json!("3B EPA"));
+    }
+
+    #[test]
+    fn qso_record_fields() {
+        let mut log = make_pota_log();
+        log.add_qso(make_qso());
+        let doc = parse(&log);
+        assert_eq!(doc["log"]["qso_count"], json!(1));
+        assert_eq!(
+            doc["qsos"][0],
+            json!({
+                "id": null,
+                "timestamp": "2026-02-16T14:30:05Z",
+                "call": "N0CALL",
+                "band": "20M",
+                "mode": "SSB",
+                "frequency_khz": 14250,
+                "rst_sent": "59",
+                "rst_rcvd": "57",
+                "their_park": "K-0002",
+                "exchange_rcvd": null,
+                "operator": "KD9XYZ",
+                "comments": "nice, \"loud\" signal",
+            })
+        );
+    }
+
+    #[test]
+    fn sub_khz_frequency_is_fractional() {
+        let mut log = make_pota_log();
+        let mut qso = make_qso();
+        qso.band = Band::M2190;
+        qso.frequency = Some(137_500);
+        log.add_qso(qso);
+        assert_eq!(parse(&log)["qsos"][0]["frequency_khz"], json!(137.5));
+    }
+}
@@ -0,0 +1,197 @@
+//! CSV and JSON formatting of logs for spreadsheets and dashboards, the
+//! [`ExportFormat`] choice offered on the export screen, and the POTA upload
+//! [preflight](pota_preflight) check run before writing.
+//!
+//! Like [`crate::adif`], these functions only build text; the storage layer
+//! writes it to disk. Both formats carry the log-level metadata alongside
+//! every QSO and have a fixed schema, documented in `docs/user-guide.md`:
+//! new fields may be added at the end, but existing ones are never renamed,
+//! reordered, or removed without bumping [`JSON_SCHEMA_VERSION`].
+
+mod csv;
+mod json;
+mod preflight;
+
+pub use csv::{CSV_COLUMNS, LOG_COLUMNS, format_csv};
+pub use json::{JSON_SCHEMA_VERSION, format_json};
+pub(crate) use json::{LogMetadata, QsoRecord, log_metadata, qso_record};
+pub use preflight::{DaySummary, PotaPreflight, pota_preflight};
+
+use crate::model::Log;
+
+/// File format for exporting a log.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ExportFormat {
+    /// ADIF, as stored internally; accepted by POTA, LoTW and other loggers.
+    #[default]
+    Adif,
+    /// ADX, the XML form of ADIF, carrying the same fields.
+    Adx,
+    /// One row per QSO with the log metadata repeated on each row.
+    Csv,
+    /// One document with the log metadata and an array of QSOs.
+    Json,
+}
+
+impl ExportFormat {
+    /// Returns all formats in selector order.
+    pub fn all() -> &'static [ExportFormat] {
+        &[
+            ExportFormat::Adif,
+            ExportFormat::Adx,
+            ExportFormat::Csv,
+            ExportFormat::Json,
+        ]
+    }
+
+    /// Returns the file extension, without the leading dot.
+    pub fn extension(self) -> &'static str {
+        match self {
+            ExportFormat::Adif => "adif",
+            ExportFormat::Adx => "adx",
+            ExportFormat::Csv => "csv",
+            ExportFormat::Json => "json",
+        }
+    }
+
+    /// Returns the display name (e.g. `"CSV"`).
+    pub fn name(self) -> &'static str {
+        match self {
+            ExportFormat::Adif => "ADIF",
+            ExportFormat::Adx => "ADX",
+            ExportFormat::Csv => "CSV",
+            ExportFormat::Json => "JSON",
+        }
+    }
+
+    /// Returns the next format in selector order, wrapping around.
+    pub fn next(self) -> ExportFormat {
+        let all = Self::all();
+        let i = all.iter().position(|f| *f == self).unwrap_or(0);
+        all[(i + 1) % all.len()]
+    }
+}
+
+/// Returns the log type identifier used in exports.
+///
+/// Matches `APP_DUKLOG_LOG_TYPE` in ADIF: `general`, `pota`, `field_day` or `wfd`.
+fn log_type_id(log: &Log) -> &'static str {
+    match log {
+        Log::General(_) => "general",
+        Log::Pota(_) => "pota",
+        Log::FieldDay(_) => "field_day",
+        Log::WinterFieldDay(_) => "wfd",
+    }
+}
+
+/// Returns the sent contest exchange (e.g. `"3B EPA"`), or `None` for
+/// non-contest logs.
+fn sent_exchange(log: &Log) -> Option<String> {
+    match log {
+        Log::FieldDay(f) => Some(f.sent_exchange()),
+        Log::WinterFieldDay(w) => Some(w.sent_exchange()),
+        Log::General(_) | Log::Pota(_) => None,
+    }
+}
+
+/// Returns the Field Day power category (`"QRP"`, `"LOW"` or `"HIGH"`), or
+/// `None` for other log types.
+fn power(log: &Log) -> Option<String> {
+    match log {
+        Log::FieldDay(f) => Some(f.power.adif_str().to_uppercase()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+
+    use super::*;
+    use crate::model::{Band, FdClass, FdPowerCategory, FieldDayLog, Mode, PotaLog, Qso};
+
+    pub(super) fn make_pota_log() -> Log {
+        let mut log = PotaLog::new(
+            "W1AW".to_string(),
+            Some("KD9XYZ".to_string()),
+            "K-0001".to_string(),
+            "FN31".to_string(),
+        )
+        .unwrap();
+        log.header.log_id = "K-0001-20260216-120000".to_string();
+        log.header.created_at = Utc.with_ymd_and_hms(2026, 2, 16, 12, 0, 0).unwrap();
+        Log::Pota(log)
+    }
+
+    pub(super) fn make_fd_log() -> Log {
+        let mut log = FieldDayLog::new(
+            "W1AW".to_string(),
+            None,
+            3,
+            FdClass::B,
+            "EPA".to_string(),
+            FdPowerCategory::Qrp,
+            String::new(),
+        )
+        .unwrap();
+        log.header.log_id = "FD-W1AW-20260627-180000".to_string();
+        log.header.created_at = Utc.with_ymd_and_hms(2026, 6, 27, 18, 0, 0).unwrap();
+        Log::FieldDay(log)
+    }
+
+    pub(super) fn make_qso() -> Qso {
+        Qso::new(
+            "N0CALL".to_string(),
+            "59".to_string(),
+            "57".to_string(),
+            Band::M20,
+            Mode::Ssb,
+            Utc.with_ymd_and_hms(2026, 2, 16, 14, 30, 5).unwrap(),
+            "nice, \"loud\" signal".to_string(),
+            Some("K-0002".to_string()),
+            None,
+            Some(14_250_000),
+            None,
+        )
+        .unwrap()
+    }
+
+    mod format {
+        use super::*;
+
+        #[test]
+        fn extensions() {
+            assert_eq!(ExportFormat::Adif.extension(), "adif");
+            assert_eq!(ExportFormat::Adx.extension(), "adx");
+            assert_eq!(ExportFormat::Csv.extension(), "csv");
+            assert_eq!(ExportFormat::Json.extension(), "json");
+        }
+
+        #[test]
+        fn next_cycles_through_all() {
+            assert_eq!(ExportFormat::default(), ExportFormat::Adif);
+            assert_eq!(ExportFormat::Adif.next(), ExportFormat::Adx);
+            assert_eq!(ExportFormat::Adx.next(), ExportFormat::Csv);
+            assert_eq!(ExportFormat::Csv.next(), ExportFormat::Json);
+            assert_eq!(ExportFormat::Json.next(), ExportFormat::Adif);
+        }
+    }
+
+    mod metadata {
+        use super::*;
+
+        #[test]
+        fn log_type_ids() {
+            assert_eq!(log_type_id(&make_pota_log()), "pota");
+            assert_eq!(log_type_id(&make_fd_log()), "field_day");
+        }
+
+        #[test]
+        fn contest_fields_only_for_contest_logs() {
+            assert_eq!(sent_exchange(&make_pota_log()), None);
+            assert_eq!(power(&make_pota_log()), None);
+            assert_eq!(sent_exchange(&make_fd_log()).as_deref(), Some("3B EPA"));
+            assert_eq!(power(&make_fd_log()).as_deref(), Some("QRP"));
+        }
+    }
+}
@@ -0,0 +1,303 @@
+use std::path::Path;
+use std::sync::LazyLock;
+
+use chrono::NaiveDate;
+use regex::Regex;
+
+use crate::adif::{Finding, Location, Severity};
+use crate::model::{Log, validate_callsign, validate_park_ref};
+
+/// Minimum unique QSOs (callsign, band, mode) for a valid POTA activation.
+const ACTIVATION_THRESHOLD: usize = 10;
+
+/// The `YYYYMMDD` date at the end of a POTA filename stem, optionally
+/// followed by a multi-state suffix (`W8MSC@US-4239-20181231-US-MI`).
+static FILENAME_DATE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"-(\d{8})(?:-[A-Za-z]{2}-[A-Za-z0-9]{1,3})?$").expect("valid regex")
+});
+
+/// Unique QSO count for one UTC day of a POTA log.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DaySummary {
+    pub date: NaiveDate,
+    /// QSOs with a distinct (callsign, band, mode) on this day.
+    pub unique_qsos: usize,
+}
+
+impl DaySummary {
+    /// Returns `true` if this day meets the activation threshold.
+    pub fn is_activated(&self) -> bool {
+        self.unique_qsos >= ACTIVATION_THRESHOLD
+    }
+
+    /// Returns the number of unique QSOs still needed to activate.
+    pub fn needed(&self) -> usize {
+        ACTIVATION_THRESHOLD.saturating_sub(self.unique_qsos)
+    }
+}
+
+/// Result of checking a POTA log against the upload rules before export.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PotaPreflight {
+    /// The UTC day the file is for: the date in its name, or the log's
+    /// creation date when the name has none.
+    pub file_date: NaiveDate,
+    /// Problems POTA would reject or mis-credit, in log order.
+    pub findings: Vec<Finding>,
+    /// Unique QSO counts per UTC day, in date order.
+    pub days: Vec<DaySummary>,
+}
+
+impl PotaPreflight {
+    /// Returns the summary for [`file_date`](Self::file_date).
+    pub fn file_day(&self) -> DaySummary {
+        self.days
+            .iter()
+            .find(|d| d.date == self.file_date)
+            .copied()
+            .unwrap_or(DaySummary {
+                date: self.file_date,
+                unique_qsos: 0,
+            })
+    }
+}
+
+/// Checks a POTA log against the upload rules in
+/// `docs/reference/pota-rules-notes.md` for export to `path`.
+///
+/// Returns `None` for other log types. Findings cover the fields POTA
+/// requires (station callsign, park reference, and each QSO's callsign),
+/// park-to-park references, and QSOs from a different UTC day than the file.
+/// Activation validity is reported per day in [`PotaPreflight::days`] rather
+/// than as a finding, since partial activations should still be uploaded.
+pub fn pota_preflight(log: &Log, path: &Path) -> Option<PotaPreflight> {
+    let Log::Pota(pota) = log else {
+        return None;
+    };
+    let header = log.header();
+    let file_date = filename_date(path).unwrap_or_else(|| header.created_at.date_naive());
+    let mut findings = Vec::new();
+    let mut error = |location, field: &str, message: String| {
+        findings.push(Finding {
+            severity: Severity::Error,
+            location,
+            field: Some(field.to_string()),
+            message,
+        });
+    };
+
+    if validate_callsign(&header.station_callsign).is_err() {
+        error(
+            Location::Header,
+            "STATION_CALLSIGN",
+            format!("{:?} is not a valid callsign", header.station_callsign),
+        );
+    }
+    if validate_park_ref(&pota.park_ref).is_err() {
+        error(
+            Location::Header,
+            "MY_SIG_INFO",
+            format!("{:?} is not a park reference", pota.park_ref),
+        );
+    }
+
+    for (i, qso) in log.qsos().iter().enumerate() {
+        let location = Location::Record(i + 1);
+        if let Err(e) = validate_callsign(&qso.their_call) {
+            error(location, "CALL", e.to_string());
+        }
+        let operator = log.qso_operator(qso);
+        if validate_callsign(operator).is_err() {
+            error(
+                location,
+                "OPERATOR",
+                format!("{operator:?} is not a valid callsign"),
+            );
+        }
+        let date = qso.timestamp.date_naive();
+        if date != file_date {
+            error(
+                location,
+                "QSO_DATE",
+                format!(
+                    "{} ({}) is not the file's UTC day {}",
+                    qso.their_call,
+                    date.format("%Y-%m-%d"),
+                    file_date.format("%Y-%m-%d")
+                ),
+            );
+        }
+        if let Some(ref park) = qso.their_park {
+            if park.eq_ignore_ascii_case(&pota.park_ref) {
+                error(
+                    location,
+                    "SIG_INFO",
+                    format!("park-to-park with our own park {park}"),
+                );
+            } else if validate_park_ref(park).is_err() {
+                error(
+                    location,
+                    "SIG_INFO",
+                    format!("{park:?} is not a park reference"),
+                );
+            }
+        }
+    }
+
+    let mut dates: Vec<NaiveDate> = log
+        .qsos()
+        .iter()
+        .map(|q| q.timestamp.date_naive())
+        .collect();
+    dates.sort_unstable();
+    dates.dedup();
+    let days = dates
+        .into_iter()
+        .map(|date| DaySummary {
+            date,
+            unique_qsos: header.qso_count_on_date(date),
+        })
+        .collect();
+
+    Some(PotaPreflight {
+        file_date,
+        findings,
+        days,
+    })
+}
+
+/// Extracts the `YYYYMMDD` date from a POTA filename such as
+/// `W1AW@K-0001-20260216.adif`.
+fn filename_date(path: &Path) -> Option<NaiveDate> {
+    let stem = path.file_stem()?.to_str()?;
+    let digits = FILENAME_DATE.captures(stem)?.get(1)?.as_str();
+    NaiveDate::parse_from_str(digits, "%Y%m%d").ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+
+    use super::super::tests::{make_fd_log, make_pota_log, make_qso};
+    use super::*;
+    use crate::model::{Band, Qso};
+
+    const PATH: &str = "/tmp/W1AW@K-0001-20260216.adif";
+
+    fn qso_at(call: &str, day: u32, hour: u32) -> Qso {
+        let mut qso = make_qso();
+        qso.their_call = call.to_string();
+        qso.their_park = None;
+        qso.timestamp = Utc.with_ymd_and_hms(2026, 2, day, hour, 0, 0).unwrap();
+        qso
+    }
+
+    fn preflight(log: &Log) -> PotaPreflight {
+        pota_preflight(log, Path::new(PATH)).unwrap()
+    }
+
+    fn messages(p: &PotaPreflight) -> Vec<String> {
+        p.findings.iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn only_pota_logs_are_checked() {
+        assert_eq!(pota_preflight(&make_fd_log(), Path::new(PATH)), None);
+    }
+
+    #[test]
+    fn clean_log_has_no_findings() {
+        let mut log = make_pota_log();
+        log.add_qso(make_qso());
+        let p = preflight(&log);
+        assert_eq!(p.findings, vec![]);
+        assert_eq!(p.file_date, NaiveDate::from_ymd_opt(2026, 2, 16).unwrap());
+    }
+
+    #[test]
+    fn qso_from_another_day_is_an_error() {
+        let mut log = make_pota_log();
+        log.add_qso(qso_at("N0CALL", 16, 23));
+        log.add_qso(qso_at("K1ABC", 17, 0));
+        assert_eq!(
+            messages(&preflight(&log)),
+            vec!["QSO 2 QSO_DATE: K1ABC (2026-02-17) is not the file's UTC day 2026-02-16"]
+        );
+    }
+
+    #[test]
+    fn p2p_with_own_park_is_an_error() {
+        let mut log = make_pota_log();
+        let mut qso = make_qso();
+        qso.their_park = Some("k-0001".to_string());
+        log.add_qso(qso);
+        assert_eq!(
+            messages(&preflight(&log)),
+            vec!["QSO 1 SIG_INFO: park-to-park with our own park k-0001"]
+        );
+    }
+
+    #[test]
+    fn invalid_fields_are_errors() {
+        let mut log = make_pota_log();
+        log.header_mut().station_callsign = String::new();
+        if let Log::Pota(ref mut pota) = log {
+            pota.park_ref = "K-1".to_string();
+        }
+        let mut qso = make_qso();
+        qso.their_call = "N0 CALL".to_string();
+        qso.their_park = Some("PARK".to_string());
+        log.add_qso(qso);
+        assert_eq!(
+            messages(&preflight(&log)),
+            vec![
+                "header STATION_CALLSIGN: \"\" is not a valid callsign",
+                "header MY_SIG_INFO: \"K-1\" is not a park reference",
+                "QSO 1 CALL: invalid callsign: N0 CALL",
+                "QSO 1 SIG_INFO: \"PARK\" is not a park reference",
+            ]
+        );
+    }
+
+    #[test]
+    fn file_date_comes_from_path() {
+        let log = make_pota_log();
+        let p = pota_preflight(&log, Path::new("W8MSC@US-4239-20181231-US-MI.adi")).unwrap();
+        assert_eq!(p.file_date, NaiveDate::from_ymd_opt(2018, 12, 31).unwrap());
+    }
+
+    #[test]
+    fn file_date_falls_back_to_log_date() {
+        let log = make_pota_log();
+        let p = pota_preflight(&log, Path::new("/tmp/activation.adif")).unwrap();
+        assert_eq!(p.file_date, NaiveDate::from_ymd_opt(2026, 2, 16).unwrap());
+    }
+
+    #[test]
+    fn summarizes_unique_qsos_per_day() {
+        let mut log = make_pota_log();
+        for i in 0..10 {
+            log.add_qso(qso_at(&format!("W{i}AW"), 16, 14));
+        }
+        // Duplicate call/band/mode does not count; another band does.
+        log.add_qso(qso_at("W0AW", 16, 15));
+        let mut other_band = qso_at("W0AW", 16, 15);
+        other_band.band = Band::M40;
+        log.add_qso(other_band);
+        log.add_qso(qso_at("K1ABC", 17, 0));
+
+        let p = preflight(&log);
+        assert_eq!(p.days.len(), 2);
+        assert_eq!(p.file_day().unique_qsos, 11);
+        assert!(p.file_day().is_activated());
+        assert_eq!(p.days[1].unique_qsos, 1);
+        assert_eq!(p.days[1].needed(), 9);
+    }
+
+    #[test]
+    fn empty_log_has_no_qsos_on_file_day() {
+        let p = preflight(&make_pota_log());
+        assert_eq!(p.days, vec![]);
+        assert_eq!(p.file_day().needed(), 10);
+    }
+}
@@ -0,0 +1,11 @@
+/// Errors in the hooks configuration.
+#[derive(Debug, thiserror::Error)]
+pub enum HookError {
+    /// A hook is configured with an empty command.
+    #[error("hook {0} has an empty command")]
+    EmptyCommand(&'static str),
+
+    /// The hook timeout is zero.
+    #[error("hook timeout must be at least one second")]
+    ZeroTimeout,
+}
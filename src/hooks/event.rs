@@ -0,0 +1,238 @@
+//! The events hooks run on and the JSON they receive.
+
+use std::path::Path;
+
+use serde::Serialize;
+
+use crate::export::{ExportFormat, LogMetadata, QsoRecord, log_metadata, qso_record};
+use crate::model::{Log, Qso};
+
+/// Version of the hook event document, written as `schema_version`.
+pub const EVENT_SCHEMA_VERSION: u32 = 1;
+
+/// Something that happened in duklog that a hook can run on.
+#[derive(Debug, Clone, Copy)]
+pub enum HookEvent<'a> {
+    /// `qso` was added to `log`.
+    QsoAdded {
+        /// The log after the QSO was added.
+        log: &'a Log,
+        /// The new QSO.
+        qso: &'a Qso,
+    },
+    /// The QSO at `index` in `log` was edited.
+    QsoUpdated {
+        /// The log after the edit.
+        log: &'a Log,
+        /// The QSO as edited.
+        qso: &'a Qso,
+        /// Position of the QSO in the log, from 0.
+        index: usize,
+    },
+    /// `qso`, previously at `index`, was deleted from `log`.
+    QsoDeleted {
+        /// The log after the deletion.
+        log: &'a Log,
+        /// The deleted QSO.
+        qso: &'a Qso,
+        /// Position the QSO had in the log, from 0.
+        index: usize,
+    },
+    /// `log` was created.
+    LogCreated {
+        /// The new log.
+        log: &'a Log,
+    },
+    /// `log` was exported to `path` as `format`.
+    LogExported {
+        /// The exported log.
+        log: &'a Log,
+        /// Where the export was written.
+        path: &'a Path,
+        /// The export format.
+        format: ExportFormat,
+    },
+}
+
+/// The JSON document a hook receives on standard input.
+#[derive(Serialize)]
+struct Document<'a> {
+    schema_version: u32,
+    program: &'static str,
+    program_version: &'static str,
+    event: &'static str,
+    log: LogMetadata<'a>,
+    qso: Option<QsoRecord<'a>>,
+    index: Option<usize>,
+    path: Option<String>,
+    format: Option<&'static str>,
+}
+
+impl<'a> HookEvent<'a> {
+    /// Returns every event name, in the order they are documented.
+    pub const NAMES: [&'static str; 5] = [
+        "qso_added",
+        "qso_updated",
+        "qso_deleted",
+        "log_created",
+        "log_exported",
+    ];
+
+    /// Returns the event's name as used in the config file (e.g. `"qso_added"`).
+    pub fn name(&self) -> &'static str {
+        match self {
+            HookEvent::QsoAdded { .. } => "qso_added",
+            HookEvent::QsoUpdated { .. } => "qso_updated",
+            HookEvent::QsoDeleted { .. } => "qso_deleted",
+            HookEvent::LogCreated { .. } => "log_created",
+            HookEvent::LogExported { .. } => "log_exported",
+        }
+    }
+
+    /// Formats the event as the compact JSON document given to hooks.
+    ///
+    /// The document has `schema_version`, `program`, `program_version`,
+    /// `event`, a `log` object and a `qso` object in the same form as the
+    /// JSON export, and `index`, `path` and `format`. Every key is always
+    /// present; values that do not apply to the event are `null`.
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        let (log, qso, index, path, format) = match *self {
+            HookEvent::QsoAdded { log, qso } => (log, Some(qso), None, None, None),
+            HookEvent::QsoUpdated { log, qso, index }
+            | HookEvent::QsoDeleted { log, qso, index } => {
+                (log, Some(qso), Some(index), None, None)
+            }
+            HookEvent::LogCreated { log } => (log, None, None, None, None),
+            HookEvent::LogExported { log, path, format } => {
+                (log, None, None, Some(path), Some(format))
+            }
+        };
+        serde_json::to_string(&Document {
+            schema_version: EVENT_SCHEMA_VERSION,
+            program: "duklog",
+            program_version: env!("CARGO_PKG_VERSION"),
+            event: self.name(),
+            log: log_metadata(log),
+            qso: qso.map(|qso| qso_record(log, qso)),
+            index,
+            path: path.map(|p| p.display().to_string()),
+            format: format.map(ExportFormat::name),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use serde_json::{Value, json};
+
+    use super::*;
+    use crate::model::{Band, Mode, PotaLog};
+
+    fn make_log() -> Log {
+        let mut log = PotaLog::new(
+            "W1AW".to_string(),
+            None,
+            "K-0001".to_string(),
+            "FN31".to_string(),
+        )
+        .unwrap();
+        log.header.log_id = "pota-test".to_string();
+        log.header.created_at = Utc.with_ymd_and_hms(2026, 2, 16, 12, 0, 0).unwrap();
+        Log::Pota(log)
+    }
+
+    fn make_qso() -> Qso {
+        Qso::new(
+            "KD9XYZ".to_string(),
+            "59".to_string(),
+            "57".to_string(),
+            Band::M20,
+            Mode::Ssb,
+            Utc.with_ymd_and_hms(2026, 2, 16, 14, 30, 0).unwrap(),
+            String::new(),
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap()
+    }
+
+    fn parse(event: &HookEvent) -> Value {
+        serde_json::from_str(&event.to_json().unwrap()).unwrap()
+    }
+
+    #[test]
+    fn names_match_events() {
+        let log = make_log();
+        let qso = make_qso();
+        let events = [
+            HookEvent::QsoAdded {
+                log: &log,
+                qso: &qso,
+            },
+            HookEvent::QsoUpdated {
+                log: &log,
+                qso: &qso,
+                index: 0,
+            },
+            HookEvent::QsoDeleted {
+                log: &log,
+                qso: &qso,
+                index: 0,
+            },
+            HookEvent::LogCreated { log: &log },
+            HookEvent::LogExported {
+                log: &log,
+                path: Path::new("/tmp/x.adif"),
+                format: ExportFormat::Adif,
+            },
+        ];
+        let names: Vec<_> = events.iter().map(HookEvent::name).collect();
+        assert_eq!(names, HookEvent::NAMES);
+    }
+
+    #[test]
+    fn qso_event_carries_log_and_qso() {
+        let log = make_log();
+        let qso = make_qso();
+        let doc = parse(&HookEvent::QsoUpdated {
+            log: &log,
+            qso: &qso,
+            index: 3,
+        });
+        assert_eq!(doc["schema_version"], json!(1));
+        assert_eq!(doc["program"], json!("duklog"));
+        assert_eq!(doc["event"], json!("qso_updated"));
+        assert_eq!(doc["log"]["log_id"], json!("pota-test"));
+        assert_eq!(doc["log"]["park_ref"], json!("K-0001"));
+        assert_eq!(doc["qso"]["call"], json!("KD9XYZ"));
+        assert_eq!(doc["qso"]["rst_rcvd"], json!("57"));
+        assert_eq!(doc["qso"]["operator"], json!("W1AW"));
+        assert_eq!(doc["index"], json!(3));
+        assert_eq!(doc["path"], Value::Null);
+    }
+
+    #[test]
+    fn export_event_carries_path_and_format() {
+        let log = make_log();
+        let doc = parse(&HookEvent::LogExported {
+            log: &log,
+            path: Path::new("/tmp/W1AW@K-0001-20260216.csv"),
+            format: ExportFormat::Csv,
+        });
+        assert_eq!(doc["event"], json!("log_exported"));
+        assert_eq!(doc["path"], json!("/tmp/W1AW@K-0001-20260216.csv"));
+        assert_eq!(doc["format"], json!("CSV"));
+        assert_eq!(doc["qso"], Value::Null);
+        assert_eq!(doc["index"], Value::Null);
+    }
+
+    #[test]
+    fn document_is_one_line() {
+        let log = make_log();
+        let json = HookEvent::LogCreated { log: &log }.to_json().unwrap();
+        assert!(!json.contains('\n'), "{json}");
+    }
+}
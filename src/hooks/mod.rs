@@ -0,0 +1,15 @@
+//! User commands run when things happen in duklog.
+//!
+//! Each [`HookEvent`] can have a command configured in
+//! [`HooksConfig`](crate::config::HooksConfig). [`Hooks::fire`] starts the
+//! command on a background thread with the event as JSON on its standard
+//! input and returns at once; the UI thread collects failures and timeouts
+//! with [`Hooks::poll`], so a slow or broken hook never holds up logging.
+
+mod error;
+mod event;
+mod runner;
+
+pub use error::HookError;
+pub use event::{EVENT_SCHEMA_VERSION, HookEvent};
+pub use runner::{HookFailure, Hooks};
@@ -0,0 +1,331 @@
+//! Running hook commands in the background.
+//!
+//! Each fired hook runs on its own thread, which feeds the event to the
+//! command, waits for it up to the timeout, and reports the outcome on a
+//! channel. The UI thread drains the channel with [`Hooks::poll`].
+
+use std::fmt;
+use std::io::{Read, Write};
+use std::process::{Child, Command, ExitStatus, Stdio};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use super::error::HookError;
+use super::event::HookEvent;
+use crate::config::HooksConfig;
+
+/// How often a hook thread checks whether its command has exited.
+const WAIT_INTERVAL: Duration = Duration::from_millis(10);
+
+/// How long to wait for a command's error output after it exits. Output is
+/// given up on if a background process the command started still holds it.
+const STDERR_GRACE: Duration = Duration::from_millis(100);
+
+/// A hook that did not run to a successful exit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HookFailure {
+    /// The name of the event the hook ran on (e.g. `"qso_added"`).
+    pub event: &'static str,
+    /// What went wrong (e.g. `exit status: 1: scoreboard unreachable`).
+    pub reason: String,
+}
+
+impl fmt::Display for HookFailure {
+    /// Formats as e.g. `Hook qso_added failed: timed out after 5s`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Hook {} failed: {}", self.event, self.reason)
+    }
+}
+
+/// The configured hooks and the results of the ones still running.
+#[derive(Debug)]
+pub struct Hooks {
+    config: HooksConfig,
+    timeout: Duration,
+    running: usize,
+    sender: Sender<Option<HookFailure>>,
+    results: Receiver<Option<HookFailure>>,
+}
+
+impl Hooks {
+    /// Checks the hooks configuration and prepares to run its commands.
+    pub fn new(config: &HooksConfig) -> Result<Self, HookError> {
+        for event in HookEvent::NAMES {
+            if config.command(event).is_some_and(<[String]>::is_empty) {
+                return Err(HookError::EmptyCommand(event));
+            }
+        }
+        if config.timeout_secs == 0 {
+            return Err(HookError::ZeroTimeout);
+        }
+        let (sender, results) = mpsc::channel();
+        Ok(Self {
+            config: config.clone(),
+            timeout: Duration::from_secs(config.timeout_secs),
+            running: 0,
+            sender,
+            results,
+        })
+    }
+
+    /// Starts the hook configured for `event`, if any, without waiting for it.
+    ///
+    /// A failure to encode the event is reported by the next
+    /// [`poll`](Self::poll) like any other hook failure.
+    pub fn fire(&mut self, event: &HookEvent) {
+        let name = event.name();
+        let Some(command) = self.config.command(name) else {
+            return;
+        };
+        let command = command.to_vec();
+        let timeout = self.timeout;
+        let sender = self.sender.clone();
+        let payload = event.to_json();
+        self.running += 1;
+        thread::spawn(move || {
+            let result = payload
+                .map_err(|e| format!("could not encode event: {e}"))
+                .and_then(|payload| run(&command, payload, timeout));
+            let failure = result.err().map(|reason| HookFailure {
+                event: name,
+                reason,
+            });
+            let _ = sender.send(failure);
+        });
+    }
+
+    /// Returns `true` while any fired hook has not yet finished.
+    pub fn is_running(&self) -> bool {
+        self.running > 0
+    }
+
+    /// Collects the hooks that have finished since the last call, returning
+    /// the ones that failed.
+    pub fn poll(&mut self) -> Vec<HookFailure> {
+        let mut failures = Vec::new();
+        while let Ok(result) = self.results.try_recv() {
+            self.running = self.running.saturating_sub(1);
+            failures.extend(result);
+        }
+        failures
+    }
+}
+
+/// Runs `command` with `payload` on its standard input, stopping it after
+/// `timeout`. Returns why it failed, if it did.
+fn run(command: &[String], payload: String, timeout: Duration) -> Result<(), String> {
+    let (program, args) = command.split_first().ok_or("empty command")?;
+    let mut child = Command::new(program)
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("could not start {program}: {e}"))?;
+
+    // Feed stdin and drain stderr on their own threads so that a hook that
+    // ignores its input, writes a lot of output, or leaves a background
+    // process holding the pipes cannot stall the wait.
+    if let Some(mut stdin) = child.stdin.take() {
+        thread::spawn(move || {
+            let _ = stdin.write_all(payload.as_bytes());
+        });
+    }
+    let (output_sender, output) = mpsc::channel();
+    if let Some(mut stderr) = child.stderr.take() {
+        thread::spawn(move || {
+            let mut text = String::new();
+            let _ = stderr.read_to_string(&mut text);
+            let _ = output_sender.send(text);
+        });
+    }
+
+    let status = wait(&mut child, timeout).map_err(|e| format!("could not wait: {e}"))?;
+    let stderr = output.recv_timeout(STDERR_GRACE).unwrap_or_default();
+    match status {
+        None => Err(format!("timed out after {}s", timeout.as_secs_f32())),
+        Some(status) if status.success() => Ok(()),
+        Some(status) => Err(match stderr.lines().find(|l| !l.trim().is_empty()) {
+            Some(line) => format!("{status}: {}", line.trim()),
+            None => status.to_string(),
+        }),
+    }
+}
+
+/// Waits up to `timeout` for `child` to exit, killing it if it does not.
+/// Returns `None` if it was killed.
+fn wait(child: &mut Child, timeout: Duration) -> std::io::Result<Option<ExitStatus>> {
+    let deadline = Instant::now() + timeout;
+    loop {
+        if let Some(status) = child.try_wait()? {
+            return Ok(Some(status));
+        }
+        if Instant::now() >= deadline {
+            child.kill()?;
+            child.wait()?;
+            return Ok(None);
+        }
+        thread::sleep(WAIT_INTERVAL);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+
+    use super::*;
+    use crate::model::{GeneralLog, Log};
+
+    fn make_log() -> Log {
+        let mut log = GeneralLog::new("W1AW".to_string(), None, "FN31".to_string()).unwrap();
+        log.header.log_id = "general-test".to_string();
+        log.header.created_at = Utc::now();
+        Log::General(log)
+    }
+
+    fn shell(script: &str) -> Option<Vec<String>> {
+        Some(vec!["sh".into(), "-c".into(), script.into()])
+    }
+
+    fn make_hooks(config: HooksConfig) -> Hooks {
+        Hooks::new(&config).unwrap()
+    }
+
+    /// Polls until every fired hook has finished, returning the failures.
+    fn finish(hooks: &mut Hooks) -> Vec<HookFailure> {
+        let deadline = Instant::now() + Duration::from_secs(10);
+        let mut failures = Vec::new();
+        while hooks.is_running() {
+            assert!(Instant::now() < deadline, "hook did not finish");
+            failures.extend(hooks.poll());
+            thread::sleep(WAIT_INTERVAL);
+        }
+        failures
+    }
+
+    mod config {
+        use super::*;
+
+        #[test]
+        fn empty_command_is_rejected() {
+            let config = HooksConfig {
+                qso_deleted: Some(vec![]),
+                ..HooksConfig::default()
+            };
+            assert!(matches!(
+                Hooks::new(&config),
+                Err(HookError::EmptyCommand("qso_deleted"))
+            ));
+        }
+
+        #[test]
+        fn zero_timeout_is_rejected() {
+            let config = HooksConfig {
+                timeout_secs: 0,
+                ..HooksConfig::default()
+            };
+            assert!(matches!(Hooks::new(&config), Err(HookError::ZeroTimeout)));
+        }
+    }
+
+    mod fire {
+        use super::*;
+
+        #[test]
+        fn unconfigured_event_runs_nothing() {
+            let mut hooks = make_hooks(HooksConfig::default());
+            let log = make_log();
+            hooks.fire(&HookEvent::LogCreated { log: &log });
+            assert!(!hooks.is_running());
+        }
+
+        #[test]
+        fn command_receives_event_on_stdin() {
+            let dir = tempfile::tempdir().unwrap();
+            let out = dir.path().join("event.json");
+            let mut hooks = make_hooks(HooksConfig {
+                log_created: shell(&format!("cat > '{}'", out.display())),
+                ..HooksConfig::default()
+            });
+            let log = make_log();
+            let event = HookEvent::LogCreated { log: &log };
+            hooks.fire(&event);
+            assert!(hooks.is_running());
+            assert_eq!(finish(&mut hooks), vec![]);
+            assert_eq!(
+                std::fs::read_to_string(out).unwrap(),
+                event.to_json().unwrap()
+            );
+        }
+
+        #[test]
+        fn failing_command_reports_status_and_stderr() {
+            let mut hooks = make_hooks(HooksConfig {
+                log_created: shell("echo 'scoreboard unreachable' >&2; exit 3"),
+                ..HooksConfig::default()
+            });
+            let log = make_log();
+            hooks.fire(&HookEvent::LogCreated { log: &log });
+            let failures = finish(&mut hooks);
+            assert_eq!(failures.len(), 1);
+            assert_eq!(
+                failures[0].to_string(),
+                "Hook log_created failed: exit status: 3: scoreboard unreachable"
+            );
+        }
+
+        #[test]
+        fn missing_program_is_reported() {
+            let mut hooks = make_hooks(HooksConfig {
+                log_created: Some(vec!["/nonexistent/duklog-hook".into()]),
+                ..HooksConfig::default()
+            });
+            let log = make_log();
+            hooks.fire(&HookEvent::LogCreated { log: &log });
+            let failures = finish(&mut hooks);
+            assert!(
+                failures[0]
+                    .reason
+                    .starts_with("could not start /nonexistent/duklog-hook"),
+                "{failures:?}"
+            );
+        }
+
+        #[test]
+        fn background_process_does_not_hold_up_result() {
+            let mut hooks = make_hooks(HooksConfig {
+                log_created: shell("sleep 30 & exit 0"),
+                ..HooksConfig::default()
+            });
+            let log = make_log();
+            let started = Instant::now();
+            hooks.fire(&HookEvent::LogCreated { log: &log });
+            assert_eq!(finish(&mut hooks), vec![]);
+            assert!(started.elapsed() < Duration::from_secs(5));
+        }
+
+        #[test]
+        fn slow_command_is_stopped_at_timeout() {
+            let mut hooks = make_hooks(HooksConfig {
+                log_created: shell("sleep 30"),
+                ..HooksConfig::default()
+            });
+            hooks.timeout = Duration::from_millis(100);
+            let log = make_log();
+            let started = Instant::now();
+            hooks.fire(&HookEvent::LogCreated { log: &log });
+            // Firing returns without waiting for the hook.
+            assert!(started.elapsed() < Duration::from_millis(100));
+            let failures = finish(&mut hooks);
+            assert!(started.elapsed() < Duration::from_secs(10));
+            assert_eq!(
+                failures,
+                vec![HookFailure {
+                    event: "log_created",
+                    reason: "timed out after 0.1s".into(),
+                }]
+            );
+        }
+    }
+}
@@ -1,6 +1,11 @@
 #![cfg_attr(coverage_nightly, feature(coverage_attribute))]
 
 pub mod adif;
+pub mod awards;
+pub mod config;
+pub mod export;
+pub mod hooks;
 pub mod model;
 pub mod storage;
+pub mod sync;
 pub mod tui;
@@ -9,12 +9,15 @@ use crossterm::terminal::{
 use ratatui::Terminal;
 use ratatui::backend::CrosstermBackend;
 
+use duklog::config::Config;
 use duklog::storage::LogManager;
 use duklog::tui::App;
 
 #[cfg_attr(coverage_nightly, coverage(off))]
 #[mutants::skip]
 fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let config = Config::load()?;
+
     let original_hook = std::panic::take_hook();
     std::panic::set_hook(Box::new(move |info| {
         let _ = restore_terminal();
@@ -26,7 +29,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let mut terminal = Terminal::new(CrosstermBackend::new(stdout()))?;
 
     let manager = LogManager::new()?;
-    let mut app = App::new(manager)?;
+    let mut app = App::with_config(manager, config)?;
     let result = app.run(&mut terminal);
 
     let restore_result = restore_terminal();
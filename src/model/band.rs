@@ -3,8 +3,13 @@ use std::fmt;
 use serde::{Deserialize, Serialize};
 
 /// Amateur radio frequency band.
+///
+/// Covers every band in the ADIF v3.1.4 band enumeration, from 2190m to submm.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub enum Band {
+    M2190,
+    M630,
+    M560,
     M160,
     M80,
     M60,
@@ -16,12 +21,69 @@ pub enum Band {
     M15,
     M12,
     M10,
+    M8,
     M6,
+    M5,
+    M4,
     M2,
+    M1_25,
     Cm70,
+    Cm33,
+    Cm23,
+    Cm13,
+    Cm9,
+    Cm6,
+    Cm3,
+    Cm1_25,
+    Mm6,
+    Mm4,
+    Mm2_5,
+    Mm2,
+    Mm1,
+    Submm,
 }
 
 static ALL_BANDS: &[Band] = &[
+    Band::M2190,
+    Band::M630,
+    Band::M560,
+    Band::M160,
+    Band::M80,
+    Band::M60,
+    Band::M40,
+    Band::M30,
+    Band::M20,
+    Band::M17,
+    Band::M15,
+    Band::M12,
+    Band::M10,
+    Band::M8,
+    Band::M6,
+    Band::M5,
+    Band::M4,
+    Band::M2,
+    Band::M1_25,
+    Band::Cm70,
+    Band::Cm33,
+    Band::Cm23,
+    Band::Cm13,
+    Band::Cm9,
+    Band::Cm6,
+    Band::Cm3,
+    Band::Cm1_25,
+    Band::Mm6,
+    Band::Mm4,
+    Band::Mm2_5,
+    Band::Mm2,
+    Band::Mm1,
+    Band::Submm,
+];
+
+/// Bands offered by Alt+B when the user has not configured a cycle list.
+///
+/// The HF/VHF/UHF bands most common in portable operation; the full set is
+/// long enough to make cycling tedious.
+static DEFAULT_CYCLE: &[Band] = &[
     Band::M160,
     Band::M80,
     Band::M60,
@@ -41,6 +103,9 @@ impl Band {
     /// Returns the ADIF string representation of this band.
     pub fn adif_str(&self) -> &'static str {
         match self {
+            Band::M2190 => "2190M",
+            Band::M630 => "630M",
+            Band::M560 => "560M",
             Band::M160 => "160M",
             Band::M80 => "80M",
             Band::M60 => "60M",
@@ -51,9 +116,26 @@ impl Band {
             Band::M15 => "15M",
             Band::M12 => "12M",
             Band::M10 => "10M",
+            Band::M8 => "8M",
             Band::M6 => "6M",
+            Band::M5 => "5M",
+            Band::M4 => "4M",
             Band::M2 => "2M",
+            Band::M1_25 => "1.25M",
             Band::Cm70 => "70CM",
+            Band::Cm33 => "33CM",
+            Band::Cm23 => "23CM",
+            Band::Cm13 => "13CM",
+            Band::Cm9 => "9CM",
+            Band::Cm6 => "6CM",
+            Band::Cm3 => "3CM",
+            Band::Cm1_25 => "1.25CM",
+            Band::Mm6 => "6MM",
+            Band::Mm4 => "4MM",
+            Band::Mm2_5 => "2.5MM",
+            Band::Mm2 => "2MM",
+            Band::Mm1 => "1MM",
+            Band::Submm => "SUBMM",
         }
     }
 
@@ -62,12 +144,20 @@ impl Band {
         ALL_BANDS
     }
 
+    /// Returns the default Alt+B cycle list, in wavelength order.
+    pub fn default_cycle() -> &'static [Band] {
+        DEFAULT_CYCLE
+    }
+
     /// Parses a band from its ADIF string representation.
     ///
     /// Accepts the same strings returned by [`adif_str`](Self::adif_str),
     /// case-insensitively.
     pub fn from_adif_str(s: &str) -> Option<Band> {
         match s.to_uppercase().as_str() {
+            "2190M" => Some(Band::M2190),
+            "630M" => Some(Band::M630),
+            "560M" => Some(Band::M560),
             "160M" => Some(Band::M160),
             "80M" => Some(Band::M80),
             "60M" => Some(Band::M60),
@@ -78,32 +168,71 @@ impl Band {
             "15M" => Some(Band::M15),
             "12M" => Some(Band::M12),
             "10M" => Some(Band::M10),
+            "8M" => Some(Band::M8),
             "6M" => Some(Band::M6),
+            "5M" => Some(Band::M5),
+            "4M" => Some(Band::M4),
             "2M" => Some(Band::M2),
+            "1.25M" => Some(Band::M1_25),
             "70CM" => Some(Band::Cm70),
+            "33CM" => Some(Band::Cm33),
+            "23CM" => Some(Band::Cm23),
+            "13CM" => Some(Band::Cm13),
+            "9CM" => Some(Band::Cm9),
+            "6CM" => Some(Band::Cm6),
+            "3CM" => Some(Band::Cm3),
+            "1.25CM" => Some(Band::Cm1_25),
+            "6MM" => Some(Band::Mm6),
+            "4MM" => Some(Band::Mm4),
+            "2.5MM" => Some(Band::Mm2_5),
+            "2MM" => Some(Band::Mm2),
+            "1MM" => Some(Band::Mm1),
+            "SUBMM" => Some(Band::Submm),
             _ => None,
         }
     }
 
-    /// Returns the band that contains `freq_khz`, or `None` if the frequency
-    /// does not fall within any amateur allocation supported by this enum.
+    /// Returns the band that contains `freq_hz`, or `None` if the frequency
+    /// does not fall within any amateur allocation.
     ///
-    /// Ranges are the ADIF v3.1.4 standard band edges.
-    pub fn from_frequency_khz(freq_khz: u32) -> Option<Band> {
-        match freq_khz {
-            1_800..=2_000 => Some(Band::M160),
-            3_500..=4_000 => Some(Band::M80),
-            5_060..=5_450 => Some(Band::M60),
-            7_000..=7_300 => Some(Band::M40),
-            10_100..=10_150 => Some(Band::M30),
-            14_000..=14_350 => Some(Band::M20),
-            18_068..=18_168 => Some(Band::M17),
-            21_000..=21_450 => Some(Band::M15),
-            24_890..=24_990 => Some(Band::M12),
-            28_000..=29_700 => Some(Band::M10),
-            50_000..=54_000 => Some(Band::M6),
-            144_000..=148_000 => Some(Band::M2),
-            420_000..=450_000 => Some(Band::Cm70),
+    /// Ranges are the ADIF v3.1.4 standard band edges, inclusive. The argument
+    /// is in Hz so the sub-kHz edges of the LF and MF bands (135.7 kHz, say)
+    /// are exact.
+    pub fn from_frequency_hz(freq_hz: u64) -> Option<Band> {
+        match freq_hz {
+            135_700..=137_800 => Some(Band::M2190),
+            472_000..=479_000 => Some(Band::M630),
+            501_000..=504_000 => Some(Band::M560),
+            1_800_000..=2_000_000 => Some(Band::M160),
+            3_500_000..=4_000_000 => Some(Band::M80),
+            5_060_000..=5_450_000 => Some(Band::M60),
+            7_000_000..=7_300_000 => Some(Band::M40),
+            10_100_000..=10_150_000 => Some(Band::M30),
+            14_000_000..=14_350_000 => Some(Band::M20),
+            18_068_000..=18_168_000 => Some(Band::M17),
+            21_000_000..=21_450_000 => Some(Band::M15),
+            24_890_000..=24_990_000 => Some(Band::M12),
+            28_000_000..=29_700_000 => Some(Band::M10),
+            40_000_000..=45_000_000 => Some(Band::M8),
+            50_000_000..=54_000_000 => Some(Band::M6),
+            54_000_001..=69_900_000 => Some(Band::M5),
+            70_000_000..=71_000_000 => Some(Band::M4),
+            144_000_000..=148_000_000 => Some(Band::M2),
+            222_000_000..=225_000_000 => Some(Band::M1_25),
+            420_000_000..=450_000_000 => Some(Band::Cm70),
+            902_000_000..=928_000_000 => Some(Band::Cm33),
+            1_240_000_000..=1_300_000_000 => Some(Band::Cm23),
+            2_300_000_000..=2_450_000_000 => Some(Band::Cm13),
+            3_300_000_000..=3_500_000_000 => Some(Band::Cm9),
+            5_650_000_000..=5_925_000_000 => Some(Band::Cm6),
+            10_000_000_000..=10_500_000_000 => Some(Band::Cm3),
+            24_000_000_000..=24_250_000_000 => Some(Band::Cm1_25),
+            47_000_000_000..=47_200_000_000 => Some(Band::Mm6),
+            75_500_000_000..=81_000_000_000 => Some(Band::Mm4),
+            119_980_000_000..=123_000_000_000 => Some(Band::Mm2_5),
+            134_000_000_000..=149_000_000_000 => Some(Band::Mm2),
+            241_000_000_000..=250_000_000_000 => Some(Band::Mm1),
+            300_000_000_000..=7_500_000_000_000 => Some(Band::Submm),
             _ => None,
         }
     }
@@ -123,68 +252,95 @@ mod tests {
     use super::*;
 
     quickcheck! {
-        fn from_frequency_khz_returns_valid_band_or_none(freq: u32) -> bool {
-            match Band::from_frequency_khz(freq) {
+        fn from_frequency_hz_returns_valid_band_or_none(freq: u64) -> bool {
+            match Band::from_frequency_hz(freq) {
                 Some(band) => Band::all().contains(&band),
                 None => true,
             }
         }
     }
 
+    /// Each band with its ADIF lower and upper edges in Hz.
+    const EDGES: &[(Band, u64, u64)] = &[
+        (Band::M2190, 135_700, 137_800),
+        (Band::M630, 472_000, 479_000),
+        (Band::M560, 501_000, 504_000),
+        (Band::M160, 1_800_000, 2_000_000),
+        (Band::M80, 3_500_000, 4_000_000),
+        (Band::M60, 5_060_000, 5_450_000),
+        (Band::M40, 7_000_000, 7_300_000),
+        (Band::M30, 10_100_000, 10_150_000),
+        (Band::M20, 14_000_000, 14_350_000),
+        (Band::M17, 18_068_000, 18_168_000),
+        (Band::M15, 21_000_000, 21_450_000),
+        (Band::M12, 24_890_000, 24_990_000),
+        (Band::M10, 28_000_000, 29_700_000),
+        (Band::M8, 40_000_000, 45_000_000),
+        (Band::M6, 50_000_000, 54_000_000),
+        (Band::M5, 54_000_001, 69_900_000),
+        (Band::M4, 70_000_000, 71_000_000),
+        (Band::M2, 144_000_000, 148_000_000),
+        (Band::M1_25, 222_000_000, 225_000_000),
+        (Band::Cm70, 420_000_000, 450_000_000),
+        (Band::Cm33, 902_000_000, 928_000_000),
+        (Band::Cm23, 1_240_000_000, 1_300_000_000),
+        (Band::Cm13, 2_300_000_000, 2_450_000_000),
+        (Band::Cm9, 3_300_000_000, 3_500_000_000),
+        (Band::Cm6, 5_650_000_000, 5_925_000_000),
+        (Band::Cm3, 10_000_000_000, 10_500_000_000),
+        (Band::Cm1_25, 24_000_000_000, 24_250_000_000),
+        (Band::Mm6, 47_000_000_000, 47_200_000_000),
+        (Band::Mm4, 75_500_000_000, 81_000_000_000),
+        (Band::Mm2_5, 119_980_000_000, 123_000_000_000),
+        (Band::Mm2, 134_000_000_000, 149_000_000_000),
+        (Band::Mm1, 241_000_000_000, 250_000_000_000),
+        (Band::Submm, 300_000_000_000, 7_500_000_000_000),
+    ];
+
     #[test]
-    fn from_frequency_khz_known_frequencies() {
-        assert_eq!(Band::from_frequency_khz(1_800), Some(Band::M160));
-        assert_eq!(Band::from_frequency_khz(1_900), Some(Band::M160));
-        assert_eq!(Band::from_frequency_khz(2_000), Some(Band::M160));
-        assert_eq!(Band::from_frequency_khz(3_500), Some(Band::M80));
-        assert_eq!(Band::from_frequency_khz(3_750), Some(Band::M80));
-        assert_eq!(Band::from_frequency_khz(4_000), Some(Band::M80));
-        assert_eq!(Band::from_frequency_khz(5_060), Some(Band::M60));
-        assert_eq!(Band::from_frequency_khz(5_450), Some(Band::M60));
-        assert_eq!(Band::from_frequency_khz(7_000), Some(Band::M40));
-        assert_eq!(Band::from_frequency_khz(7_200), Some(Band::M40));
-        assert_eq!(Band::from_frequency_khz(7_300), Some(Band::M40));
-        assert_eq!(Band::from_frequency_khz(10_100), Some(Band::M30));
-        assert_eq!(Band::from_frequency_khz(10_150), Some(Band::M30));
-        assert_eq!(Band::from_frequency_khz(14_000), Some(Band::M20));
-        assert_eq!(Band::from_frequency_khz(14_225), Some(Band::M20));
-        assert_eq!(Band::from_frequency_khz(14_350), Some(Band::M20));
-        assert_eq!(Band::from_frequency_khz(18_068), Some(Band::M17));
-        assert_eq!(Band::from_frequency_khz(18_168), Some(Band::M17));
-        assert_eq!(Band::from_frequency_khz(21_000), Some(Band::M15));
-        assert_eq!(Band::from_frequency_khz(21_450), Some(Band::M15));
-        assert_eq!(Band::from_frequency_khz(24_890), Some(Band::M12));
-        assert_eq!(Band::from_frequency_khz(24_990), Some(Band::M12));
-        assert_eq!(Band::from_frequency_khz(28_000), Some(Band::M10));
-        assert_eq!(Band::from_frequency_khz(29_700), Some(Band::M10));
-        assert_eq!(Band::from_frequency_khz(50_000), Some(Band::M6));
-        assert_eq!(Band::from_frequency_khz(54_000), Some(Band::M6));
-        assert_eq!(Band::from_frequency_khz(144_000), Some(Band::M2));
-        assert_eq!(Band::from_frequency_khz(148_000), Some(Band::M2));
-        assert_eq!(Band::from_frequency_khz(420_000), Some(Band::Cm70));
-        assert_eq!(Band::from_frequency_khz(450_000), Some(Band::Cm70));
+    fn from_frequency_hz_includes_each_band_edge() {
+        assert_eq!(EDGES.len(), Band::all().len());
+        for &(band, lower, upper) in EDGES {
+            assert_eq!(Band::from_frequency_hz(lower), Some(band), "{band} lower");
+            assert_eq!(Band::from_frequency_hz(upper), Some(band), "{band} upper");
+        }
     }
 
     #[test]
-    fn from_frequency_khz_gaps_return_none() {
-        assert_eq!(Band::from_frequency_khz(0), None);
-        assert_eq!(Band::from_frequency_khz(1_799), None);
-        assert_eq!(Band::from_frequency_khz(2_001), None);
-        assert_eq!(Band::from_frequency_khz(3_499), None);
-        assert_eq!(Band::from_frequency_khz(4_001), None);
-        assert_eq!(Band::from_frequency_khz(5_059), None);
-        assert_eq!(Band::from_frequency_khz(5_451), None);
-        assert_eq!(Band::from_frequency_khz(6_999), None);
-        assert_eq!(Band::from_frequency_khz(7_301), None);
-        assert_eq!(Band::from_frequency_khz(10_099), None);
-        assert_eq!(Band::from_frequency_khz(10_151), None);
-        assert_eq!(Band::from_frequency_khz(13_999), None);
-        assert_eq!(Band::from_frequency_khz(14_351), None);
-        assert_eq!(Band::from_frequency_khz(29_701), None);
-        assert_eq!(Band::from_frequency_khz(54_001), None);
-        assert_eq!(Band::from_frequency_khz(419_999), None);
-        assert_eq!(Band::from_frequency_khz(450_001), None);
-        assert_eq!(Band::from_frequency_khz(u32::MAX), None);
+    fn from_frequency_hz_excludes_just_outside_each_band() {
+        for &(band, lower, upper) in EDGES {
+            assert_ne!(
+                Band::from_frequency_hz(lower - 1),
+                Some(band),
+                "{band} lower"
+            );
+            assert_ne!(
+                Band::from_frequency_hz(upper + 1),
+                Some(band),
+                "{band} upper"
+            );
+        }
+    }
+
+    #[test]
+    fn from_frequency_hz_known_frequencies() {
+        assert_eq!(Band::from_frequency_hz(137_500), Some(Band::M2190));
+        assert_eq!(Band::from_frequency_hz(475_500), Some(Band::M630));
+        assert_eq!(Band::from_frequency_hz(3_750_000), Some(Band::M80));
+        assert_eq!(Band::from_frequency_hz(14_074_000), Some(Band::M20));
+        assert_eq!(Band::from_frequency_hz(14_225_000), Some(Band::M20));
+        assert_eq!(Band::from_frequency_hz(146_520_000), Some(Band::M2));
+    }
+
+    #[test]
+    fn from_frequency_hz_gaps_return_none() {
+        assert_eq!(Band::from_frequency_hz(0), None);
+        assert_eq!(Band::from_frequency_hz(135_699), None);
+        assert_eq!(Band::from_frequency_hz(137_801), None);
+        assert_eq!(Band::from_frequency_hz(1_799_999), None);
+        assert_eq!(Band::from_frequency_hz(14_350_001), None);
+        assert_eq!(Band::from_frequency_hz(7_500_000_000_001), None);
+        assert_eq!(Band::from_frequency_hz(u64::MAX), None);
     }
 
     #[test]
@@ -202,17 +358,42 @@ mod tests {
         assert_eq!(Band::M6.adif_str(), "6M");
         assert_eq!(Band::M2.adif_str(), "2M");
         assert_eq!(Band::Cm70.adif_str(), "70CM");
+        assert_eq!(Band::M2190.adif_str(), "2190M");
+        assert_eq!(Band::M1_25.adif_str(), "1.25M");
+        assert_eq!(Band::Cm1_25.adif_str(), "1.25CM");
+        assert_eq!(Band::Mm2_5.adif_str(), "2.5MM");
+        assert_eq!(Band::Submm.adif_str(), "SUBMM");
+    }
+
+    #[test]
+    fn from_adif_str_is_case_insensitive() {
+        assert_eq!(Band::from_adif_str("1.25m"), Some(Band::M1_25));
+        assert_eq!(Band::from_adif_str("33cm"), Some(Band::Cm33));
+        assert_eq!(Band::from_adif_str("submm"), Some(Band::Submm));
+        assert_eq!(Band::from_adif_str("3M"), None);
+    }
+
+    #[test]
+    fn all_returns_33_bands() {
+        assert_eq!(Band::all().len(), 33);
     }
 
     #[test]
-    fn all_returns_13_bands() {
-        assert_eq!(Band::all().len(), 13);
+    fn all_starts_with_2190m_ends_with_submm() {
+        assert_eq!(Band::all().first(), Some(&Band::M2190));
+        assert_eq!(Band::all().last(), Some(&Band::Submm));
     }
 
     #[test]
-    fn all_starts_with_160m_ends_with_70cm() {
-        assert_eq!(Band::all().first(), Some(&Band::M160));
-        assert_eq!(Band::all().last(), Some(&Band::Cm70));
+    fn default_cycle_is_160m_through_70cm() {
+        assert_eq!(Band::default_cycle().len(), 13);
+        assert_eq!(Band::default_cycle().first(), Some(&Band::M160));
+        assert_eq!(Band::default_cycle().last(), Some(&Band::Cm70));
+        assert!(
+            Band::default_cycle()
+                .iter()
+                .all(|band| Band::all().contains(band))
+        );
     }
 
     #[test]
@@ -0,0 +1,126 @@
+use serde::{Deserialize, Serialize};
+
+/// ADIF fields duklog does not interpret, in the order they were read.
+///
+/// Carried on [`Qso`](super::Qso) and [`LogHeader`](super::LogHeader) so
+/// that hand-edited or imported data (e.g. `NAME`, `STATE`, `MY_RIG`, or
+/// another program's `APP_*` fields) is written back unchanged. Names are
+/// stored upper-case and compared case-insensitively, as in ADIF.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct ExtraFields(Vec<(String, String)>);
+
+impl ExtraFields {
+    /// Returns `true` if there are no extra fields.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the number of extra fields.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns the value of `name`, if present.
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.0
+            .iter()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// Sets `name` to `value`.
+    ///
+    /// An existing field keeps its position; a new one is appended.
+    pub fn insert(&mut self, name: &str, value: impl Into<String>) {
+        let value = value.into();
+        match self
+            .0
+            .iter_mut()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+        {
+            Some((_, v)) => *v = value,
+            None => self.0.push((name.to_ascii_uppercase(), value)),
+        }
+    }
+
+    /// Removes `name`, returning its value if it was present.
+    pub fn remove(&mut self, name: &str) -> Option<String> {
+        let idx = self
+            .0
+            .iter()
+            .position(|(n, _)| n.eq_ignore_ascii_case(name))?;
+        Some(self.0.remove(idx).1)
+    }
+
+    /// Returns `(name, value)` pairs in order.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.0.iter().map(|(n, v)| (n.as_str(), v.as_str()))
+    }
+}
+
+impl<N: AsRef<str>, V: Into<String>> FromIterator<(N, V)> for ExtraFields {
+    fn from_iter<I: IntoIterator<Item = (N, V)>>(iter: I) -> Self {
+        let mut fields = Self::default();
+        for (name, value) in iter {
+            fields.insert(name.as_ref(), value);
+        }
+        fields
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn insert_keeps_order_and_uppercases_names() {
+        let fields: ExtraFields = [("name", "Hiram"), ("STATE", "CT"), ("my_rig", "KX2")]
+            .into_iter()
+            .collect();
+        assert_eq!(
+            fields.iter().collect::<Vec<_>>(),
+            vec![("NAME", "Hiram"), ("STATE", "CT"), ("MY_RIG", "KX2")]
+        );
+        assert_eq!(fields.len(), 3);
+    }
+
+    #[test]
+    fn insert_replaces_in_place() {
+        let mut fields: ExtraFields = [("NAME", "Hiram"), ("STATE", "CT")].into_iter().collect();
+        fields.insert("name", "Percy");
+        assert_eq!(
+            fields.iter().collect::<Vec<_>>(),
+            vec![("NAME", "Percy"), ("STATE", "CT")]
+        );
+    }
+
+    #[test]
+    fn get_is_case_insensitive() {
+        let fields: ExtraFields = [("STATE", "CT")].into_iter().collect();
+        assert_eq!(fields.get("state"), Some("CT"));
+        assert_eq!(fields.get("NAME"), None);
+        assert!(ExtraFields::default().is_empty());
+    }
+
+    #[test]
+    fn remove_returns_value_and_keeps_order() {
+        let mut fields: ExtraFields = [("NAME", "Hiram"), ("STATE", "CT"), ("MY_RIG", "KX2")]
+            .into_iter()
+            .collect();
+        assert_eq!(fields.remove("state"), Some("CT".to_string()));
+        assert_eq!(fields.remove("STATE"), None);
+        assert_eq!(
+            fields.iter().collect::<Vec<_>>(),
+            vec![("NAME", "Hiram"), ("MY_RIG", "KX2")]
+        );
+    }
+
+    #[test]
+    fn serializes_as_ordered_pairs() {
+        let fields: ExtraFields = [("NAME", "Hiram"), ("STATE", "CT")].into_iter().collect();
+        let json = serde_json::to_string(&fields).unwrap();
+        assert_eq!(json, r#"[["NAME","Hiram"],["STATE","CT"]]"#);
+        assert_eq!(serde_json::from_str::<ExtraFields>(&json).unwrap(), fields);
+    }
+}
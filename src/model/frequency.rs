@@ -0,0 +1,62 @@
+//! Frequencies are stored in Hz and typed and shown in kHz.
+
+/// Parses a frequency typed in kHz, such as `14225` or `137.5`, into Hz.
+///
+/// Returns `None` unless the text is a positive number; digits beyond 1 Hz
+/// are rounded.
+pub fn parse_khz(text: &str) -> Option<u64> {
+    let khz = text.trim().parse::<f64>().ok()?;
+    let hz = (khz * 1000.0).round();
+    (hz.is_finite() && hz >= 1.0 && hz <= u64::MAX as f64).then_some(hz as u64)
+}
+
+/// Formats a frequency in Hz as kHz, with only the decimals it needs:
+/// `14225`, `137.5`, `14074.125`.
+pub fn format_khz(hz: u64) -> String {
+    let (khz, rest) = (hz / 1000, hz % 1000);
+    if rest == 0 {
+        khz.to_string()
+    } else {
+        format!("{khz}.{rest:03}").trim_end_matches('0').to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_whole_and_fractional_khz() {
+        assert_eq!(parse_khz("14225"), Some(14_225_000));
+        assert_eq!(parse_khz(" 137.5 "), Some(137_500));
+        assert_eq!(parse_khz("14074.125"), Some(14_074_125));
+        assert_eq!(parse_khz("0.001"), Some(1));
+    }
+
+    #[test]
+    fn rounds_below_one_hz() {
+        assert_eq!(parse_khz("135.6996"), Some(135_700));
+    }
+
+    #[test]
+    fn rejects_non_positive_and_garbage() {
+        for text in ["", "0", "0.0001", "-7030", "abc", "inf", "NaN"] {
+            assert_eq!(parse_khz(text), None, "{text:?}");
+        }
+    }
+
+    #[test]
+    fn formats_without_needless_decimals() {
+        assert_eq!(format_khz(14_225_000), "14225");
+        assert_eq!(format_khz(137_500), "137.5");
+        assert_eq!(format_khz(14_074_125), "14074.125");
+        assert_eq!(format_khz(135_700), "135.7");
+    }
+
+    #[test]
+    fn format_round_trips_through_parse() {
+        for hz in [1, 135_700, 475_500, 14_074_125, 7_500_000_000_000] {
+            assert_eq!(parse_khz(&format_khz(hz)), Some(hz));
+        }
+    }
+}
@@ -6,6 +6,7 @@ use regex::Regex;
 use serde::{Deserialize, Serialize};
 
 use super::{DefaultFilename, LogHeader};
+use crate::model::extra_fields::ExtraFields;
 use crate::model::validation::{
     ValidationError, validate_callsign, validate_section, validate_tx_count,
 };
@@ -153,6 +154,7 @@ impl FieldDayLog {
                 qsos: Vec::new(),
                 created_at: now,
                 log_id,
+                extra_fields: ExtraFields::default(),
             },
             tx_count,
             class,
@@ -293,6 +295,7 @@ mod tests {
             None,
             None,
             None,
+            None,
         )
         .unwrap();
         log.add_qso(old_qso);
@@ -308,6 +311,7 @@ mod tests {
             None,
             None,
             None,
+            None,
         )
         .unwrap();
         // FD logs scope duplicates across ALL dates — yesterday's QSO is found
@@ -1,6 +1,7 @@
 use chrono::Utc;
 
 use super::{DefaultFilename, LogHeader};
+use crate::model::extra_fields::ExtraFields;
 use crate::model::validation::{ValidationError, validate_callsign, validate_grid_square};
 
 /// General-purpose log — no type-specific setup fields.
@@ -38,6 +39,7 @@ impl GeneralLog {
                 qsos: Vec::new(),
                 created_at: now,
                 log_id,
+                extra_fields: ExtraFields::default(),
             },
         })
     }
@@ -82,6 +84,7 @@ mod tests {
                 None,
                 None,
                 None,
+                None,
             )
             .unwrap();
             log.add_qso(qso);
@@ -1,8 +1,9 @@
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashSet};
 
 use chrono::{DateTime, NaiveDate, Utc};
 
 use crate::model::band::Band;
+use crate::model::extra_fields::ExtraFields;
 use crate::model::mode::Mode;
 use crate::model::qso::Qso;
 
@@ -21,6 +22,8 @@ pub struct LogHeader {
     pub(crate) qsos: Vec<Qso>,
     pub(crate) created_at: DateTime<Utc>,
     pub(crate) log_id: String,
+    /// Header fields duklog does not interpret, written back unchanged.
+    pub(crate) extra_fields: ExtraFields,
 }
 
 impl LogHeader {
@@ -57,6 +60,30 @@ impl LogHeader {
             .collect()
     }
 
+    /// Returns the callsign of the operator who made `qso`.
+    ///
+    /// Falls back to the log's operator, then to the station callsign.
+    pub(crate) fn qso_operator<'a>(&'a self, qso: &'a Qso) -> &'a str {
+        qso.operator
+            .as_deref()
+            .or(self.operator.as_deref())
+            .unwrap_or(&self.station_callsign)
+    }
+
+    /// Counts QSOs per operator, most active first (ties broken by callsign).
+    pub(crate) fn qso_counts_by_operator(&self) -> Vec<(String, usize)> {
+        let mut counts = BTreeMap::<&str, usize>::new();
+        for qso in &self.qsos {
+            *counts.entry(self.qso_operator(qso)).or_default() += 1;
+        }
+        let mut counts: Vec<(String, usize)> = counts
+            .into_iter()
+            .map(|(op, count)| (op.to_string(), count))
+            .collect();
+        counts.sort_by_key(|&(_, count)| std::cmp::Reverse(count));
+        counts
+    }
+
     /// Replaces the QSO at `index` with `qso`, returning the old QSO.
     ///
     /// Returns `None` if `index` is out of bounds.
@@ -95,6 +122,7 @@ mod tests {
             qsos: vec![],
             created_at: Utc::now(),
             log_id: "test".into(),
+            extra_fields: ExtraFields::default(),
         };
         for i in 0..n {
             let qso = Qso::new(
@@ -108,6 +136,7 @@ mod tests {
                 None,
                 None,
                 None,
+                None,
             )
             .unwrap();
             header.add_qso(qso);
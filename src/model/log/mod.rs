@@ -1,16 +1,18 @@
-use chrono::{NaiveDate, Utc};
+use chrono::{Datelike, NaiveDate, Utc};
 
 use super::qso::Qso;
 
 mod field_day;
 mod general;
 mod header;
+mod period;
 mod pota;
 mod wfd;
 
 pub use field_day::{FdClass, FdPowerCategory, FieldDayLog, parse_fd_class, validate_fd_exchange};
 pub use general::GeneralLog;
 pub use header::LogHeader;
+pub use period::OperatingPeriod;
 pub use pota::PotaLog;
 pub use wfd::{WfdClass, WfdLog, parse_wfd_class, validate_wfd_exchange};
 
@@ -111,6 +113,26 @@ impl Log {
         }
     }
 
+    /// Returns the contest window this log was created for.
+    ///
+    /// Field Day and Winter Field Day logs use the first period that has not
+    /// ended by the log's creation time. Other log types have no fixed window
+    /// and return `None`.
+    pub fn operating_period(&self) -> Option<OperatingPeriod> {
+        let period: fn(i32) -> OperatingPeriod = match self {
+            Self::FieldDay(_) => OperatingPeriod::field_day,
+            Self::WinterFieldDay(_) => OperatingPeriod::winter_field_day,
+            _ => return None,
+        };
+        let created = self.header().created_at;
+        let this_year = period(created.year());
+        Some(if created < this_year.end {
+            this_year
+        } else {
+            period(created.year() + 1)
+        })
+    }
+
     /// Returns QSOs matching the given callsign, band, and mode within the
     /// applicable scope for this log type.
     ///
@@ -136,6 +158,32 @@ impl Log {
         self.header().find_duplicates_on(qso, on)
     }
 
+    /// Returns the callsign of the operator who made `qso`.
+    ///
+    /// Uses the QSO's own operator, falling back to the log's operator and then
+    /// the station callsign.
+    pub fn qso_operator<'a>(&'a self, qso: &'a Qso) -> &'a str {
+        self.header().qso_operator(qso)
+    }
+
+    /// Counts QSOs per operator, most active first (ties broken by callsign).
+    pub fn qso_counts_by_operator(&self) -> Vec<(String, usize)> {
+        self.header().qso_counts_by_operator()
+    }
+
+    /// Returns the QSOs in this log, in the order they were logged.
+    pub fn qsos(&self) -> &[Qso] {
+        &self.header().qsos
+    }
+
+    /// Returns the index of the QSO with the given [`id`](Qso::id).
+    pub fn qso_index_by_id(&self, id: &str) -> Option<usize> {
+        self.header()
+            .qsos
+            .iter()
+            .position(|qso| qso.id.as_deref() == Some(id))
+    }
+
     /// Replaces the QSO at `index` with `qso`, returning the old QSO.
     ///
     /// Returns `None` if `index` is out of bounds.
@@ -150,6 +198,20 @@ impl Log {
         self.header_mut().remove_qso(index)
     }
 
+    /// Returns `details` with this log's identity and QSOs.
+    ///
+    /// Used to apply edited log details: station settings come from `details`,
+    /// while `log_id`, `created_at`, the QSOs and the header's extra fields are
+    /// kept, so the log keeps its file name and sync identity (see ADR-0007).
+    pub fn with_details(&self, mut details: Log) -> Log {
+        let header = details.header_mut();
+        header.log_id.clone_from(&self.header().log_id);
+        header.created_at = self.header().created_at;
+        header.qsos = self.header().qsos.clone();
+        header.extra_fields = self.header().extra_fields.clone();
+        details
+    }
+
     /// Returns the short type name used in table columns and UI labels.
     ///
     /// Returns `"General"`, `"POTA"`, `"FD"`, or `"WFD"`.
@@ -229,6 +291,7 @@ mod tests {
             None,
             None,
             None,
+            None,
         )
         .unwrap()
     }
@@ -245,6 +308,7 @@ mod tests {
             None,
             None,
             None,
+            None,
         )
         .unwrap()
     }
@@ -262,6 +326,7 @@ mod tests {
                 None,
                 None,
                 None,
+                None,
             )
             .unwrap();
             log.add_qso(qso);
@@ -293,6 +358,7 @@ mod tests {
             None,
             None,
             None,
+            None,
         )
         .unwrap();
         log.add_qso(qso);
@@ -361,6 +427,7 @@ mod tests {
             None,
             None,
             None,
+            None,
         )
         .unwrap();
         let qso2 = Qso::new(
@@ -374,6 +441,7 @@ mod tests {
             None,
             None,
             None,
+            None,
         )
         .unwrap();
 
@@ -439,6 +507,7 @@ mod tests {
             None,
             None,
             None,
+            None,
         )
         .unwrap();
         let qso2 = Qso::new(
@@ -452,6 +521,7 @@ mod tests {
             None,
             None,
             None,
+            None,
         )
         .unwrap();
         log.add_qso(qso1);
@@ -540,6 +610,7 @@ mod tests {
             None,
             None,
             None,
+            None,
         )
         .unwrap();
         log.add_qso(old_qso);
@@ -565,6 +636,7 @@ mod tests {
             None,
             None,
             None,
+            None,
         )
         .unwrap();
         log.add_qso(old_qso);
@@ -591,6 +663,7 @@ mod tests {
             None,
             None,
             None,
+            None,
         )
         .unwrap();
         log.add_qso(old_qso);
@@ -672,4 +745,157 @@ mod tests {
         let qso = make_qso_on_date(NaiveDate::from_ymd_opt(2026, 1, 15).unwrap());
         assert_eq!(log.replace_qso(0, qso), None);
     }
+
+    // --- operators ---
+
+    fn with_operator(mut qso: Qso, op: &str) -> Qso {
+        qso.operator = Some(op.to_string());
+        qso
+    }
+
+    #[test]
+    fn qso_operator_prefers_qso_then_log_then_station() {
+        let qso = make_candidate("KD9XYZ", Band::M20, Mode::Ssb);
+        let log = Log::General(
+            GeneralLog::new(
+                "W1AW".to_string(),
+                Some("N0CALL".to_string()),
+                "FN31".to_string(),
+            )
+            .unwrap(),
+        );
+        assert_eq!(log.qso_operator(&qso), "N0CALL");
+        let own = with_operator(qso.clone(), "K1ABC");
+        assert_eq!(log.qso_operator(&own), "K1ABC");
+
+        let solo =
+            Log::General(GeneralLog::new("W1AW".to_string(), None, "FN31".to_string()).unwrap());
+        assert_eq!(solo.qso_operator(&qso), "W1AW");
+    }
+
+    #[test]
+    fn qso_counts_by_operator_sorted_by_count() {
+        let mut log = make_log();
+        let qso = make_candidate("KD9XYZ", Band::M20, Mode::Ssb);
+        log.add_qso(with_operator(qso.clone(), "K1ABC"));
+        log.add_qso(qso.clone());
+        log.add_qso(with_operator(qso.clone(), "N0CALL"));
+        log.add_qso(with_operator(qso, "K1ABC"));
+        assert_eq!(
+            log.qso_counts_by_operator(),
+            vec![
+                ("K1ABC".to_string(), 2),
+                ("N0CALL".to_string(), 1),
+                ("W1AW".to_string(), 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn qso_counts_by_operator_empty_log() {
+        assert!(make_log().qso_counts_by_operator().is_empty());
+    }
+
+    // --- qso_index_by_id ---
+
+    #[test]
+    fn qso_index_by_id_finds_matching_qso() {
+        let mut log = make_log();
+        log.add_qso(make_candidate("KD9XYZ", Band::M20, Mode::Ssb));
+        let mut qso = make_candidate("N0CALL", Band::M20, Mode::Ssb);
+        qso.id = Some("q2".to_string());
+        log.add_qso(qso);
+        assert_eq!(log.qso_index_by_id("q2"), Some(1));
+        assert_eq!(log.qso_index_by_id("missing"), None);
+        assert_eq!(log.qsos()[1].their_call, "N0CALL");
+    }
+
+    mod with_details {
+        use super::*;
+
+        #[test]
+        fn keeps_identity_and_qsos() {
+            let mut log = make_log();
+            log.add_qso(make_qso_on_date(
+                NaiveDate::from_ymd_opt(2026, 2, 16).unwrap(),
+            ));
+            log.header_mut().extra_fields.insert("MY_RIG", "KX2");
+            let details = Log::Pota(
+                PotaLog::new(
+                    "W1AW".to_string(),
+                    Some("K1ABC".to_string()),
+                    "K-0002".to_string(),
+                    "FN42".to_string(),
+                )
+                .unwrap(),
+            );
+            let edited = log.with_details(details);
+            assert_eq!(edited.header().log_id, log.header().log_id);
+            assert_eq!(edited.header().created_at, log.header().created_at);
+            assert_eq!(edited.qsos(), log.qsos());
+            assert_eq!(edited.header().extra_fields.get("MY_RIG"), Some("KX2"));
+            assert_eq!(edited.park_ref(), Some("K-0002"));
+            assert_eq!(edited.header().grid_square, "FN42");
+            assert_eq!(edited.header().operator.as_deref(), Some("K1ABC"));
+        }
+    }
+    mod operating_period {
+        use super::*;
+
+        fn field_day_created_at(created_at: chrono::DateTime<Utc>) -> Log {
+            let mut log = FieldDayLog::new(
+                "W1AW".to_string(),
+                None,
+                1,
+                FdClass::B,
+                "EPA".to_string(),
+                FdPowerCategory::Low,
+                "FN31".to_string(),
+            )
+            .unwrap();
+            log.header.created_at = created_at;
+            Log::FieldDay(log)
+        }
+
+        #[test]
+        fn field_day_uses_creation_year() {
+            let log = field_day_created_at(Utc.with_ymd_and_hms(2025, 6, 1, 0, 0, 0).unwrap());
+            assert_eq!(
+                log.operating_period(),
+                Some(OperatingPeriod::field_day(2025))
+            );
+        }
+
+        #[test]
+        fn log_created_after_period_uses_next_year() {
+            let log = field_day_created_at(Utc.with_ymd_and_hms(2025, 7, 4, 0, 0, 0).unwrap());
+            assert_eq!(
+                log.operating_period(),
+                Some(OperatingPeriod::field_day(2026))
+            );
+        }
+
+        #[test]
+        fn wfd_uses_winter_period() {
+            let mut wfd = WfdLog::new(
+                "W1AW".to_string(),
+                None,
+                1,
+                WfdClass::H,
+                "EPA".to_string(),
+                "FN31".to_string(),
+            )
+            .unwrap();
+            wfd.header.created_at = Utc.with_ymd_and_hms(2026, 1, 20, 0, 0, 0).unwrap();
+            assert_eq!(
+                Log::WinterFieldDay(wfd).operating_period(),
+                Some(OperatingPeriod::winter_field_day(2026))
+            );
+        }
+
+        #[test]
+        fn other_logs_have_no_period() {
+            assert_eq!(make_log().operating_period(), None);
+        }
+    }
 }
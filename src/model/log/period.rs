@@ -0,0 +1,114 @@
+use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveTime, TimeZone, Utc, Weekday};
+
+/// The fixed operating window of a contest such as Field Day.
+///
+/// `start` is inclusive and `end` exclusive, so a QSO at exactly `end` is
+/// outside the period.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OperatingPeriod {
+    /// First moment of the contest (UTC).
+    pub start: DateTime<Utc>,
+    /// First moment after the contest ends (UTC).
+    pub end: DateTime<Utc>,
+}
+
+impl OperatingPeriod {
+    /// ARRL Field Day: fourth full weekend of June, 18:00 UTC Saturday
+    /// through 20:59 UTC Sunday.
+    pub fn field_day(year: i32) -> Self {
+        Self::weekend(fourth_full_weekend(year, 6))
+    }
+
+    /// Winter Field Day: last full weekend of January, 18:00 UTC Saturday
+    /// through 21:00 UTC Sunday.
+    pub fn winter_field_day(year: i32) -> Self {
+        Self::weekend(last_full_weekend(year, 1))
+    }
+
+    /// Returns `true` if `at` falls within the period.
+    pub fn contains(&self, at: DateTime<Utc>) -> bool {
+        self.start <= at && at < self.end
+    }
+
+    /// 18:00 UTC on `saturday` through 21:00 UTC the following Sunday.
+    fn weekend(saturday: NaiveDate) -> Self {
+        let at = |date: NaiveDate, hour| {
+            Utc.from_utc_datetime(&date.and_time(NaiveTime::from_hms_opt(hour, 0, 0).unwrap()))
+        };
+        Self {
+            start: at(saturday, 18),
+            end: at(saturday + Duration::days(1), 21),
+        }
+    }
+}
+
+/// Returns the Saturday of the fourth weekend that falls wholly in `month`.
+fn fourth_full_weekend(year: i32, month: u32) -> NaiveDate {
+    // The fourth Saturday is at most the 28th, so its Sunday is in the month.
+    NaiveDate::from_weekday_of_month_opt(year, month, Weekday::Sat, 4)
+        .expect("every month has four Saturdays")
+}
+
+/// Returns the Saturday of the last weekend whose Sunday falls in `month`.
+fn last_full_weekend(year: i32, month: u32) -> NaiveDate {
+    let (next_year, next_month) = if month == 12 {
+        (year + 1, 1)
+    } else {
+        (year, month + 1)
+    };
+    let mut sunday = NaiveDate::from_ymd_opt(next_year, next_month, 1)
+        .expect("first of month is a valid date")
+        .pred_opt()
+        .expect("last of month is a valid date");
+    while sunday.weekday() != Weekday::Sun {
+        sunday = sunday.pred_opt().expect("valid date");
+    }
+    sunday - Duration::days(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utc(y: i32, m: u32, d: u32, h: u32, min: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(y, m, d, h, min, 0).unwrap()
+    }
+
+    #[test]
+    fn field_day_2025() {
+        let period = OperatingPeriod::field_day(2025);
+        assert_eq!(period.start, utc(2025, 6, 28, 18, 0));
+        assert_eq!(period.end, utc(2025, 6, 29, 21, 0));
+    }
+
+    #[test]
+    fn weekend_split_across_months_is_not_full() {
+        // January 2026 ends on a Saturday; the last full weekend is the 24th–25th.
+        let period = OperatingPeriod::winter_field_day(2026);
+        assert_eq!(period.start, utc(2026, 1, 24, 18, 0));
+        assert_eq!(period.end, utc(2026, 1, 25, 21, 0));
+    }
+
+    #[test]
+    fn field_day_is_fourth_not_last_weekend() {
+        // June 2024 has five full weekends; Field Day was the 22nd–23rd.
+        let period = OperatingPeriod::field_day(2024);
+        assert_eq!(period.start, utc(2024, 6, 22, 18, 0));
+    }
+
+    #[test]
+    fn month_ending_on_sunday_uses_that_weekend() {
+        // January 2027 ends on a Sunday.
+        let period = OperatingPeriod::winter_field_day(2027);
+        assert_eq!(period.start, utc(2027, 1, 30, 18, 0));
+    }
+
+    #[test]
+    fn contains_is_start_inclusive_end_exclusive() {
+        let period = OperatingPeriod::field_day(2025);
+        assert!(!period.contains(utc(2025, 6, 28, 17, 59)));
+        assert!(period.contains(utc(2025, 6, 28, 18, 0)));
+        assert!(period.contains(utc(2025, 6, 29, 20, 59)));
+        assert!(!period.contains(utc(2025, 6, 29, 21, 0)));
+    }
+}
@@ -1,6 +1,7 @@
 use chrono::Utc;
 
 use super::{DefaultFilename, LogHeader};
+use crate::model::extra_fields::ExtraFields;
 use crate::model::validation::{
     ValidationError, validate_callsign, validate_grid_square, validate_park_ref,
 };
@@ -43,6 +44,7 @@ impl PotaLog {
                 qsos: Vec::new(),
                 created_at: now,
                 log_id,
+                extra_fields: ExtraFields::default(),
             },
             park_ref,
         })
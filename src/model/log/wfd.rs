@@ -6,6 +6,7 @@ use regex::Regex;
 use serde::{Deserialize, Serialize};
 
 use super::{DefaultFilename, LogHeader};
+use crate::model::extra_fields::ExtraFields;
 use crate::model::validation::{
     ValidationError, validate_callsign, validate_section, validate_tx_count,
 };
@@ -104,6 +105,7 @@ impl WfdLog {
                 qsos: Vec::new(),
                 created_at: now,
                 log_id,
+                extra_fields: ExtraFields::default(),
             },
             tx_count,
             class,
@@ -232,6 +234,7 @@ mod tests {
             None,
             None,
             None,
+            None,
         )
         .unwrap();
         log.add_qso(old_qso);
@@ -247,6 +250,7 @@ mod tests {
             None,
             None,
             None,
+            None,
         )
         .unwrap();
         // WFD logs scope duplicates across ALL dates
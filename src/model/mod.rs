@@ -1,16 +1,23 @@
 mod band;
+mod extra_fields;
+mod frequency;
 mod log;
 mod mode;
 mod qso;
+mod query;
 mod validation;
 
 pub use band::Band;
+pub use extra_fields::ExtraFields;
+pub use frequency::{format_khz, parse_khz};
 pub use log::{
-    DefaultFilename, FdClass, FdPowerCategory, FieldDayLog, GeneralLog, Log, LogHeader, PotaLog,
-    WfdClass, WfdLog, parse_fd_class, parse_wfd_class, validate_fd_exchange, validate_wfd_exchange,
+    DefaultFilename, FdClass, FdPowerCategory, FieldDayLog, GeneralLog, Log, LogHeader,
+    OperatingPeriod, PotaLog, WfdClass, WfdLog, parse_fd_class, parse_wfd_class,
+    validate_fd_exchange, validate_wfd_exchange,
 };
 pub use mode::Mode;
-pub use qso::Qso;
+pub use qso::{Qso, QsoChanges};
+pub use query::QsoQuery;
 pub use validation::{
     ValidationError, normalize_grid_square, normalize_park_ref, validate_callsign,
     validate_grid_square, validate_park_ref, validate_section, validate_tx_count,
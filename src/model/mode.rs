@@ -3,6 +3,12 @@ use std::fmt;
 use serde::{Deserialize, Serialize};
 
 /// Amateur radio operating mode.
+///
+/// Each variant maps to an ADIF `MODE` and, where ADIF defines one, a `SUBMODE`
+/// (e.g. [`Mode::Ft4`] is `MODE=MFSK SUBMODE=FT4`). Variants without a submode
+/// (such as [`Mode::Ssb`] and [`Mode::Mfsk`]) also stand in for submodes duklog
+/// does not model individually, and [`Mode::Other`] stands in for any other
+/// ADIF mode (HELL, JT9, MT63, ...).
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub enum Mode {
     #[default]
@@ -16,9 +22,53 @@ pub enum Mode {
     Fm,
     Am,
     Digi,
+    Usb,
+    Lsb,
+    Fst4,
+    Q65,
+    Mfsk,
+    Jt65,
+    Msk144,
+    Psk,
+    Olivia,
+    Dstar,
+    C4fm,
+    Dmr,
+    Sstv,
+    /// An ADIF mode duklog does not model. The ADIF reader keeps the `MODE`
+    /// and `SUBMODE` it was read with in the QSO's extra fields, and they are
+    /// written back unchanged. Not offered for entry and never parsed.
+    Other,
 }
 
 static ALL_MODES: &[Mode] = &[
+    Mode::Ssb,
+    Mode::Usb,
+    Mode::Lsb,
+    Mode::Cw,
+    Mode::Ft8,
+    Mode::Ft4,
+    Mode::Js8,
+    Mode::Fst4,
+    Mode::Q65,
+    Mode::Mfsk,
+    Mode::Jt65,
+    Mode::Msk144,
+    Mode::Psk31,
+    Mode::Psk,
+    Mode::Rtty,
+    Mode::Olivia,
+    Mode::Fm,
+    Mode::Am,
+    Mode::Dstar,
+    Mode::C4fm,
+    Mode::Dmr,
+    Mode::Sstv,
+    Mode::Digi,
+];
+
+/// Modes offered by Alt+M when the user has not configured a favourites list.
+static DEFAULT_CYCLE: &[Mode] = &[
     Mode::Ssb,
     Mode::Cw,
     Mode::Ft8,
@@ -32,56 +82,123 @@ static ALL_MODES: &[Mode] = &[
 ];
 
 impl Mode {
-    /// Returns the ADIF string representation of this mode.
-    pub fn adif_str(&self) -> &'static str {
+    /// Returns `(name, ADIF MODE, ADIF SUBMODE)` for this mode.
+    fn parts(&self) -> (&'static str, &'static str, Option<&'static str>) {
         match self {
-            Mode::Ssb => "SSB",
-            Mode::Cw => "CW",
-            Mode::Ft8 => "FT8",
-            Mode::Ft4 => "FT4",
-            Mode::Js8 => "JS8",
-            Mode::Psk31 => "PSK31",
-            Mode::Rtty => "RTTY",
-            Mode::Fm => "FM",
-            Mode::Am => "AM",
-            Mode::Digi => "DIGI",
+            Mode::Ssb => ("SSB", "SSB", None),
+            Mode::Usb => ("USB", "SSB", Some("USB")),
+            Mode::Lsb => ("LSB", "SSB", Some("LSB")),
+            Mode::Cw => ("CW", "CW", None),
+            Mode::Ft8 => ("FT8", "FT8", None),
+            Mode::Ft4 => ("FT4", "MFSK", Some("FT4")),
+            Mode::Js8 => ("JS8", "MFSK", Some("JS8")),
+            Mode::Fst4 => ("FST4", "MFSK", Some("FST4")),
+            Mode::Q65 => ("Q65", "MFSK", Some("Q65")),
+            Mode::Mfsk => ("MFSK", "MFSK", None),
+            Mode::Jt65 => ("JT65", "JT65", None),
+            Mode::Msk144 => ("MSK144", "MSK144", None),
+            Mode::Psk31 => ("PSK31", "PSK", Some("PSK31")),
+            Mode::Psk => ("PSK", "PSK", None),
+            Mode::Rtty => ("RTTY", "RTTY", None),
+            Mode::Olivia => ("OLIVIA", "OLIVIA", None),
+            Mode::Fm => ("FM", "FM", None),
+            Mode::Am => ("AM", "AM", None),
+            Mode::Dstar => ("DSTAR", "DIGITALVOICE", Some("DSTAR")),
+            Mode::C4fm => ("C4FM", "DIGITALVOICE", Some("C4FM")),
+            Mode::Dmr => ("DMR", "DIGITALVOICE", Some("DMR")),
+            Mode::Sstv => ("SSTV", "SSTV", None),
+            Mode::Digi => ("DIGI", "DIGI", None),
+            Mode::Other => ("OTHER", "OTHER", None),
         }
     }
 
-    /// Parses a mode from its ADIF string representation.
+    /// Returns the short display name (the submode when there is one, e.g. `"FT4"`).
+    pub fn name(&self) -> &'static str {
+        self.parts().0
+    }
+
+    /// Returns the ADIF `MODE` value (e.g. `"MFSK"` for FT4).
+    pub fn adif_mode(&self) -> &'static str {
+        self.parts().1
+    }
+
+    /// Returns the ADIF `SUBMODE` value, or `None` if this mode has no submode.
+    pub fn adif_submode(&self) -> Option<&'static str> {
+        self.parts().2
+    }
+
+    /// Parses a mode from an ADIF `MODE`/`SUBMODE` pair, case-insensitively.
     ///
-    /// Accepts the same strings returned by [`adif_str`](Self::adif_str),
-    /// case-insensitively.
-    pub fn from_adif_str(s: &str) -> Option<Mode> {
-        match s.to_uppercase().as_str() {
-            "SSB" => Some(Mode::Ssb),
-            "CW" => Some(Mode::Cw),
-            "FT8" => Some(Mode::Ft8),
-            "FT4" => Some(Mode::Ft4),
-            "JS8" => Some(Mode::Js8),
-            "PSK31" => Some(Mode::Psk31),
-            "RTTY" => Some(Mode::Rtty),
-            "FM" => Some(Mode::Fm),
-            "AM" => Some(Mode::Am),
-            "DIGI" => Some(Mode::Digi),
-            _ => None,
-        }
+    /// Resolution order:
+    /// 1. An exact `MODE`/`SUBMODE` match (`MFSK`/`FT4` → [`Mode::Ft4`]).
+    /// 2. The submode-less variant for `MODE`, so unmodelled submodes keep their
+    ///    family (`MFSK`/`FST4W` → [`Mode::Mfsk`]). The ADIF reader keeps such
+    ///    a pair in the QSO's extra fields so it is written back unchanged.
+    /// 3. A match on [`name`](Self::name), which accepts files written by older
+    ///    duklog versions and other loggers that put the submode in `MODE`
+    ///    (`MODE=FT4`, `MODE=USB`).
+    pub fn from_adif_str(mode: &str, submode: Option<&str>) -> Option<Mode> {
+        let by_pair = submode.filter(|s| !s.is_empty()).and_then(|sub| {
+            ALL_MODES.iter().find(|m| {
+                m.adif_mode().eq_ignore_ascii_case(mode)
+                    && m.adif_submode()
+                        .is_some_and(|s| s.eq_ignore_ascii_case(sub))
+            })
+        });
+        by_pair
+            .or_else(|| {
+                ALL_MODES.iter().find(|m| {
+                    m.adif_submode().is_none() && m.adif_mode().eq_ignore_ascii_case(mode)
+                })
+            })
+            .copied()
+            .or_else(|| Self::from_name(mode))
     }
 
-    /// Returns all modes.
+    /// Parses a mode from its display [`name`](Self::name), case-insensitively.
+    pub fn from_name(s: &str) -> Option<Mode> {
+        ALL_MODES
+            .iter()
+            .find(|m| m.name().eq_ignore_ascii_case(s))
+            .copied()
+    }
+
+    /// Returns all modes duklog models (every variant but [`Mode::Other`]).
     pub fn all() -> &'static [Mode] {
         ALL_MODES
     }
 
+    /// Returns the default Alt+M favourites list.
+    pub fn default_cycle() -> &'static [Mode] {
+        DEFAULT_CYCLE
+    }
+
     /// Returns the default RST (signal report) for this mode.
     ///
     /// `Digi` uses dB reports (`-10`) like FT8/FT4/JS8, as the generic digital
     /// mode in duklog is intended for weak-signal digital modes.
     pub fn default_rst(&self) -> &'static str {
         match self {
-            Mode::Ssb | Mode::Fm | Mode::Am => "59",
-            Mode::Cw | Mode::Psk31 | Mode::Rtty => "599",
-            Mode::Ft8 | Mode::Ft4 | Mode::Js8 | Mode::Digi => "-10",
+            Mode::Ssb
+            | Mode::Usb
+            | Mode::Lsb
+            | Mode::Fm
+            | Mode::Am
+            | Mode::Dstar
+            | Mode::C4fm
+            | Mode::Dmr
+            | Mode::Sstv
+            | Mode::Other => "59",
+            Mode::Cw | Mode::Psk31 | Mode::Psk | Mode::Rtty | Mode::Olivia => "599",
+            Mode::Ft8
+            | Mode::Ft4
+            | Mode::Js8
+            | Mode::Fst4
+            | Mode::Q65
+            | Mode::Mfsk
+            | Mode::Jt65
+            | Mode::Msk144
+            | Mode::Digi => "-10",
         }
     }
 }
@@ -89,7 +206,7 @@ impl Mode {
 #[mutants::skip]
 impl fmt::Display for Mode {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_str(self.adif_str())
+        f.write_str(self.name())
     }
 }
 
@@ -98,17 +215,38 @@ mod tests {
     use super::*;
 
     #[test]
-    fn adif_str_all_modes() {
-        assert_eq!(Mode::Ssb.adif_str(), "SSB");
-        assert_eq!(Mode::Cw.adif_str(), "CW");
-        assert_eq!(Mode::Ft8.adif_str(), "FT8");
-        assert_eq!(Mode::Ft4.adif_str(), "FT4");
-        assert_eq!(Mode::Js8.adif_str(), "JS8");
-        assert_eq!(Mode::Psk31.adif_str(), "PSK31");
-        assert_eq!(Mode::Rtty.adif_str(), "RTTY");
-        assert_eq!(Mode::Fm.adif_str(), "FM");
-        assert_eq!(Mode::Am.adif_str(), "AM");
-        assert_eq!(Mode::Digi.adif_str(), "DIGI");
+    fn name_all_default_cycle_modes() {
+        assert_eq!(Mode::Ssb.name(), "SSB");
+        assert_eq!(Mode::Cw.name(), "CW");
+        assert_eq!(Mode::Ft8.name(), "FT8");
+        assert_eq!(Mode::Ft4.name(), "FT4");
+        assert_eq!(Mode::Js8.name(), "JS8");
+        assert_eq!(Mode::Psk31.name(), "PSK31");
+        assert_eq!(Mode::Rtty.name(), "RTTY");
+        assert_eq!(Mode::Fm.name(), "FM");
+        assert_eq!(Mode::Am.name(), "AM");
+        assert_eq!(Mode::Digi.name(), "DIGI");
+    }
+
+    #[test]
+    fn adif_mode_and_submode_pairs() {
+        assert_eq!(Mode::Ssb.adif_mode(), "SSB");
+        assert_eq!(Mode::Ssb.adif_submode(), None);
+        assert_eq!(Mode::Usb.adif_mode(), "SSB");
+        assert_eq!(Mode::Usb.adif_submode(), Some("USB"));
+        assert_eq!(Mode::Lsb.adif_submode(), Some("LSB"));
+        assert_eq!(Mode::Ft8.adif_mode(), "FT8");
+        assert_eq!(Mode::Ft8.adif_submode(), None);
+        assert_eq!(Mode::Ft4.adif_mode(), "MFSK");
+        assert_eq!(Mode::Ft4.adif_submode(), Some("FT4"));
+        assert_eq!(Mode::Js8.adif_mode(), "MFSK");
+        assert_eq!(Mode::Js8.adif_submode(), Some("JS8"));
+        assert_eq!(Mode::Psk31.adif_mode(), "PSK");
+        assert_eq!(Mode::Psk31.adif_submode(), Some("PSK31"));
+        assert_eq!(Mode::Olivia.adif_mode(), "OLIVIA");
+        assert_eq!(Mode::Olivia.adif_submode(), None);
+        assert_eq!(Mode::Dmr.adif_mode(), "DIGITALVOICE");
+        assert_eq!(Mode::Dmr.adif_submode(), Some("DMR"));
     }
 
     #[test]
@@ -123,11 +261,34 @@ mod tests {
         assert_eq!(Mode::Fm.default_rst(), "59");
         assert_eq!(Mode::Am.default_rst(), "59");
         assert_eq!(Mode::Digi.default_rst(), "-10");
+        assert_eq!(Mode::Usb.default_rst(), "59");
+        assert_eq!(Mode::Olivia.default_rst(), "599");
+        assert_eq!(Mode::Q65.default_rst(), "-10");
+        assert_eq!(Mode::C4fm.default_rst(), "59");
     }
 
     #[test]
-    fn all_returns_10_modes() {
-        assert_eq!(Mode::all().len(), 10);
+    fn all_returns_23_modes() {
+        assert_eq!(Mode::all().len(), 23);
+    }
+
+    #[test]
+    fn names_are_unique() {
+        let mut names: Vec<_> = Mode::all().iter().map(Mode::name).collect();
+        names.sort_unstable();
+        names.dedup();
+        assert_eq!(names.len(), Mode::all().len());
+    }
+
+    #[test]
+    fn default_cycle_is_subset_of_all() {
+        assert_eq!(Mode::default_cycle().len(), 10);
+        assert_eq!(Mode::default_cycle().first(), Some(&Mode::Ssb));
+        assert!(
+            Mode::default_cycle()
+                .iter()
+                .all(|mode| Mode::all().contains(mode))
+        );
     }
 
     #[test]
@@ -139,14 +300,61 @@ mod tests {
     fn from_adif_str_round_trips_all_modes() {
         for mode in Mode::all() {
             assert_eq!(
-                Mode::from_adif_str(mode.adif_str()),
+                Mode::from_adif_str(mode.adif_mode(), mode.adif_submode()),
                 Some(*mode),
-                "from_adif_str({:?}) failed",
-                mode.adif_str()
+                "from_adif_str({:?}, {:?}) failed",
+                mode.adif_mode(),
+                mode.adif_submode()
             );
         }
     }
 
+    #[test]
+    fn from_adif_str_is_case_insensitive() {
+        assert_eq!(Mode::from_adif_str("mfsk", Some("ft4")), Some(Mode::Ft4));
+        assert_eq!(Mode::from_adif_str("ssb", Some("usb")), Some(Mode::Usb));
+        assert_eq!(Mode::from_adif_str("cw", None), Some(Mode::Cw));
+    }
+
+    #[test]
+    fn from_adif_str_unknown_submode_falls_back_to_family() {
+        assert_eq!(Mode::from_adif_str("MFSK", Some("FST4W")), Some(Mode::Mfsk));
+        assert_eq!(Mode::from_adif_str("PSK", Some("PSK63")), Some(Mode::Psk));
+        assert_eq!(
+            Mode::from_adif_str("OLIVIA", Some("OLIVIA 8/250")),
+            Some(Mode::Olivia)
+        );
+    }
+
+    #[test]
+    fn from_adif_str_empty_submode_is_ignored() {
+        assert_eq!(Mode::from_adif_str("SSB", Some("")), Some(Mode::Ssb));
+    }
+
+    #[test]
+    fn from_adif_str_accepts_legacy_submode_as_mode() {
+        // Written by duklog before SUBMODE support, and by some other loggers
+        assert_eq!(Mode::from_adif_str("FT4", None), Some(Mode::Ft4));
+        assert_eq!(Mode::from_adif_str("JS8", None), Some(Mode::Js8));
+        assert_eq!(Mode::from_adif_str("PSK31", None), Some(Mode::Psk31));
+        assert_eq!(Mode::from_adif_str("USB", None), Some(Mode::Usb));
+    }
+
+    #[test]
+    fn from_adif_str_unknown_returns_none() {
+        assert_eq!(Mode::from_adif_str("NOPE", None), None);
+        assert_eq!(Mode::from_adif_str("NOPE", Some("FT4")), None);
+    }
+
+    #[test]
+    fn from_name_round_trips_all_modes() {
+        for mode in Mode::all() {
+            assert_eq!(Mode::from_name(mode.name()), Some(*mode));
+        }
+        assert_eq!(Mode::from_name("olivia"), Some(Mode::Olivia));
+        assert_eq!(Mode::from_name("MFSK16"), None);
+    }
+
     #[test]
     fn serde_round_trip() {
         for mode in Mode::all() {
@@ -2,6 +2,7 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 use super::band::Band;
+use super::extra_fields::ExtraFields;
 use super::mode::Mode;
 use super::validation::{ValidationError, validate_callsign, validate_park_ref};
 
@@ -21,15 +22,30 @@ pub struct Qso {
     /// `None` for POTA and General logs.
     #[serde(default)]
     pub exchange_rcvd: Option<String>,
-    /// Frequency in kHz.
+    /// Frequency in Hz, so the sub-kHz edges of the LF and MF bands are kept.
     ///
     /// Required for WFD ADIF export (`FREQ` field); optional otherwise.
     #[serde(default)]
-    pub frequency: Option<u32>,
+    pub frequency: Option<u64>,
+    /// Callsign of the operator who made this contact.
+    ///
+    /// `None` means the log's operator (or the station callsign if the log has none).
+    #[serde(default)]
+    pub operator: Option<String>,
+    /// Stable identifier matching this QSO across synced stations.
+    ///
+    /// Assigned when the QSO is logged; `None` for QSOs logged before
+    /// identifiers existed.
+    #[serde(default)]
+    pub id: Option<String>,
+    /// ADIF fields duklog does not interpret, written back unchanged.
+    #[serde(default)]
+    pub extra_fields: ExtraFields,
 }
 
 impl Qso {
-    /// Creates a new QSO, validating the callsign and optional park reference.
+    /// Creates a new QSO, validating the callsign, optional park reference and
+    /// optional operator callsign.
     #[allow(clippy::too_many_arguments)]
     pub fn new(
         their_call: String,
@@ -41,12 +57,16 @@ impl Qso {
         comments: String,
         their_park: Option<String>,
         exchange_rcvd: Option<String>,
-        frequency: Option<u32>,
+        frequency: Option<u64>,
+        operator: Option<String>,
     ) -> Result<Self, ValidationError> {
         validate_callsign(&their_call)?;
         if let Some(ref park) = their_park {
             validate_park_ref(park)?;
         }
+        if let Some(ref op) = operator {
+            validate_callsign(op)?;
+        }
         Ok(Self {
             their_call,
             rst_sent,
@@ -58,8 +78,82 @@ impl Qso {
             their_park,
             exchange_rcvd,
             frequency,
+            operator,
+            id: None,
+            extra_fields: ExtraFields::default(),
         })
     }
+
+    /// Returns the mode's display name.
+    ///
+    /// For [`Mode::Other`] this is the `SUBMODE`, or else the `MODE`, the QSO
+    /// was read with (e.g. `"HELL"`).
+    pub fn mode_name(&self) -> &str {
+        let raw = || {
+            let extra = &self.extra_fields;
+            extra
+                .get("SUBMODE")
+                .filter(|s| !s.is_empty())
+                .or_else(|| extra.get("MODE"))
+        };
+        match self.mode {
+            Mode::Other => raw().unwrap_or(self.mode.name()),
+            mode => mode.name(),
+        }
+    }
+}
+
+/// Field changes applied to several QSOs at once by a bulk edit.
+///
+/// `None` leaves the field unchanged.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct QsoChanges {
+    pub band: Option<Band>,
+    pub mode: Option<Mode>,
+    /// Frequency in Hz.
+    pub frequency: Option<u64>,
+    pub rst_sent: Option<String>,
+    pub rst_rcvd: Option<String>,
+    pub comments: Option<String>,
+}
+
+impl QsoChanges {
+    /// Returns `true` if no field would change.
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Applies the changes to `qso`.
+    ///
+    /// Changing the band without a new frequency clears a recorded frequency
+    /// that lies outside the new band, so the two never disagree.
+    pub fn apply(&self, qso: &mut Qso) {
+        if let Some(band) = self.band {
+            qso.band = band;
+            if self.frequency.is_none()
+                && qso
+                    .frequency
+                    .is_some_and(|f| Band::from_frequency_hz(f) != Some(band))
+            {
+                qso.frequency = None;
+            }
+        }
+        if let Some(mode) = self.mode {
+            qso.mode = mode;
+        }
+        if let Some(frequency) = self.frequency {
+            qso.frequency = Some(frequency);
+        }
+        if let Some(ref rst) = self.rst_sent {
+            qso.rst_sent.clone_from(rst);
+        }
+        if let Some(ref rst) = self.rst_rcvd {
+            qso.rst_rcvd.clone_from(rst);
+        }
+        if let Some(ref comments) = self.comments {
+            qso.comments.clone_from(comments);
+        }
+    }
 }
 
 #[cfg(test)]
@@ -80,6 +174,7 @@ mod tests {
             None,
             None,
             None,
+            None,
         )
         .unwrap()
     }
@@ -96,6 +191,19 @@ mod tests {
         assert_eq!(qso.their_park, None);
         assert_eq!(qso.exchange_rcvd, None);
         assert_eq!(qso.frequency, None);
+        assert_eq!(qso.operator, None);
+        assert_eq!(qso.id, None);
+    }
+
+    #[test]
+    fn mode_name_shows_raw_mode_for_other() {
+        let mut qso = make_qso();
+        assert_eq!(qso.mode_name(), "SSB");
+        qso.mode = Mode::Other;
+        qso.extra_fields.insert("MODE", "HELL");
+        assert_eq!(qso.mode_name(), "HELL");
+        qso.extra_fields.insert("SUBMODE", "FMHELL");
+        assert_eq!(qso.mode_name(), "FMHELL");
     }
 
     #[test]
@@ -110,11 +218,12 @@ mod tests {
             String::new(),
             None,
             Some("3A CT".to_string()),
-            Some(14_225),
+            Some(14_225_000),
+            None,
         )
         .unwrap();
         assert_eq!(qso.exchange_rcvd, Some("3A CT".to_string()));
-        assert_eq!(qso.frequency, Some(14_225));
+        assert_eq!(qso.frequency, Some(14_225_000));
     }
 
     #[test]
@@ -130,6 +239,7 @@ mod tests {
             Some("K-1234".to_string()),
             None,
             None,
+            None,
         )
         .unwrap();
         assert_eq!(qso.their_call, "KD9XYZ");
@@ -150,6 +260,7 @@ mod tests {
             None,
             None,
             None,
+            None,
         );
         assert_eq!(result, Err(ValidationError::EmptyCallsign));
     }
@@ -167,6 +278,7 @@ mod tests {
             Some("bad".to_string()),
             None,
             None,
+            None,
         );
         assert_eq!(
             result,
@@ -188,6 +300,7 @@ mod tests {
             None,
             None,
             None,
+            None,
         )
         .unwrap();
         assert_eq!(qso.their_call, "N0CALL/P");
@@ -199,6 +312,55 @@ mod tests {
         assert_eq!(qso.comments, "test comment");
     }
 
+    #[test]
+    fn qso_with_operator() {
+        let qso = Qso::new(
+            "W1AW".to_string(),
+            "59".to_string(),
+            "59".to_string(),
+            Band::M20,
+            Mode::Ssb,
+            Utc::now(),
+            String::new(),
+            None,
+            None,
+            None,
+            Some("KD9XYZ".to_string()),
+        )
+        .unwrap();
+        assert_eq!(qso.operator, Some("KD9XYZ".to_string()));
+    }
+
+    #[test]
+    fn invalid_operator_rejected() {
+        let result = Qso::new(
+            "W1AW".to_string(),
+            "59".to_string(),
+            "59".to_string(),
+            Band::M20,
+            Mode::Ssb,
+            Utc::now(),
+            String::new(),
+            None,
+            None,
+            None,
+            Some("bad op".to_string()),
+        );
+        assert_eq!(
+            result,
+            Err(ValidationError::InvalidCallsign("bad op".to_string()))
+        );
+    }
+
+    #[test]
+    fn serde_defaults_missing_operator() {
+        let qso = make_qso();
+        let mut json: serde_json::Value = serde_json::to_value(&qso).unwrap();
+        json.as_object_mut().unwrap().remove("operator");
+        let deserialized: Qso = serde_json::from_value(json).unwrap();
+        assert_eq!(deserialized.operator, None);
+    }
+
     #[test]
     fn serde_round_trip() {
         let qso = make_qso();
@@ -206,4 +368,73 @@ mod tests {
         let deserialized: Qso = serde_json::from_str(&json).unwrap();
         assert_eq!(qso, deserialized);
     }
+
+    mod changes {
+        use super::*;
+
+        #[test]
+        fn default_is_empty() {
+            assert!(QsoChanges::default().is_empty());
+            let changes = QsoChanges {
+                comments: Some(String::new()),
+                ..QsoChanges::default()
+            };
+            assert!(!changes.is_empty());
+        }
+
+        #[test]
+        fn empty_changes_leave_qso_unchanged() {
+            let mut qso = make_qso();
+            let before = qso.clone();
+            QsoChanges::default().apply(&mut qso);
+            assert_eq!(qso, before);
+        }
+
+        #[test]
+        fn apply_sets_every_given_field() {
+            let mut qso = make_qso();
+            let changes = QsoChanges {
+                band: Some(Band::M40),
+                mode: Some(Mode::Cw),
+                frequency: Some(7_030_000),
+                rst_sent: Some("599".to_string()),
+                rst_rcvd: Some("579".to_string()),
+                comments: Some("paper log".to_string()),
+            };
+            changes.apply(&mut qso);
+            assert_eq!(qso.band, Band::M40);
+            assert_eq!(qso.mode, Mode::Cw);
+            assert_eq!(qso.frequency, Some(7_030_000));
+            assert_eq!(qso.rst_sent, "599");
+            assert_eq!(qso.rst_rcvd, "579");
+            assert_eq!(qso.comments, "paper log");
+            assert_eq!(qso.their_call, "W1AW");
+        }
+
+        #[test]
+        fn band_change_clears_frequency_outside_new_band() {
+            let mut qso = make_qso();
+            qso.frequency = Some(14_225_000);
+            let changes = QsoChanges {
+                band: Some(Band::M40),
+                ..QsoChanges::default()
+            };
+            changes.apply(&mut qso);
+            assert_eq!(qso.band, Band::M40);
+            assert_eq!(qso.frequency, None);
+        }
+
+        #[test]
+        fn band_change_keeps_frequency_inside_new_band() {
+            let mut qso = make_qso();
+            qso.band = Band::M40;
+            qso.frequency = Some(14_225_000);
+            let changes = QsoChanges {
+                band: Some(Band::M20),
+                ..QsoChanges::default()
+            };
+            changes.apply(&mut qso);
+            assert_eq!(qso.frequency, Some(14_225_000));
+        }
+    }
 }
@@ -0,0 +1,160 @@
+use chrono::NaiveDate;
+
+use super::band::Band;
+use super::mode::Mode;
+use super::qso::Qso;
+
+/// Criteria for finding QSOs; a QSO matches when it meets every criterion set.
+///
+/// Text criteria match case-insensitively anywhere in the field, so `K4A`
+/// finds `K4ABC` and `K4ABC/P`. `None` places no restriction on the field.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct QsoQuery {
+    pub callsign: Option<String>,
+    /// Their park reference.
+    pub park: Option<String>,
+    pub band: Option<Band>,
+    pub mode: Option<Mode>,
+    /// First UTC date to include.
+    pub from: Option<NaiveDate>,
+    /// Last UTC date to include.
+    pub to: Option<NaiveDate>,
+    /// Received contest exchange.
+    pub exchange: Option<String>,
+    pub comments: Option<String>,
+}
+
+impl QsoQuery {
+    /// Returns `true` if no criterion is set, so every QSO would match.
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Returns `true` if `qso` meets every criterion set.
+    pub fn matches(&self, qso: &Qso) -> bool {
+        let date = qso.timestamp.date_naive();
+        contains(self.callsign.as_deref(), Some(&qso.their_call))
+            && contains(self.park.as_deref(), qso.their_park.as_deref())
+            && self.band.is_none_or(|band| band == qso.band)
+            && self.mode.is_none_or(|mode| mode == qso.mode)
+            && self.from.is_none_or(|from| date >= from)
+            && self.to.is_none_or(|to| date <= to)
+            && contains(self.exchange.as_deref(), qso.exchange_rcvd.as_deref())
+            && contains(self.comments.as_deref(), Some(&qso.comments))
+    }
+}
+
+/// Returns `true` if there is no `needle`, or `haystack` contains it ignoring case.
+fn contains(needle: Option<&str>, haystack: Option<&str>) -> bool {
+    match needle {
+        None => true,
+        Some(needle) => haystack.is_some_and(|h| h.to_uppercase().contains(&needle.to_uppercase())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+
+    use super::*;
+
+    fn make_qso() -> Qso {
+        Qso::new(
+            "K4ABC/P".into(),
+            "59".into(),
+            "59".into(),
+            Band::M20,
+            Mode::Cw,
+            Utc.with_ymd_and_hms(2026, 3, 14, 15, 0, 0).unwrap(),
+            "Great signal from the summit".into(),
+            Some("K-1234".into()),
+            Some("3A CT".into()),
+            None,
+            None,
+        )
+        .unwrap()
+    }
+
+    fn date(d: u32) -> Option<NaiveDate> {
+        NaiveDate::from_ymd_opt(2026, 3, d)
+    }
+
+    #[test]
+    fn empty_query_matches_everything() {
+        assert!(QsoQuery::default().is_empty());
+        assert!(QsoQuery::default().matches(&make_qso()));
+    }
+
+    #[test]
+    fn text_fields_match_case_insensitive_substrings() {
+        let qso = make_qso();
+        for query in [
+            QsoQuery {
+                callsign: Some("k4a".into()),
+                ..QsoQuery::default()
+            },
+            QsoQuery {
+                park: Some("k-1234".into()),
+                ..QsoQuery::default()
+            },
+            QsoQuery {
+                exchange: Some("ct".into()),
+                ..QsoQuery::default()
+            },
+            QsoQuery {
+                comments: Some("SUMMIT".into()),
+                ..QsoQuery::default()
+            },
+        ] {
+            assert!(query.matches(&qso), "{query:?}");
+        }
+    }
+
+    #[test]
+    fn text_criterion_fails_on_missing_field() {
+        let mut qso = make_qso();
+        qso.their_park = None;
+        let query = QsoQuery {
+            park: Some("K-".into()),
+            ..QsoQuery::default()
+        };
+        assert!(!query.matches(&qso));
+    }
+
+    #[test]
+    fn band_and_mode_must_be_equal() {
+        let qso = make_qso();
+        let mut query = QsoQuery {
+            band: Some(Band::M20),
+            mode: Some(Mode::Cw),
+            ..QsoQuery::default()
+        };
+        assert!(query.matches(&qso));
+        query.mode = Some(Mode::Ssb);
+        assert!(!query.matches(&qso));
+    }
+
+    #[test]
+    fn date_range_is_inclusive() {
+        let qso = make_qso();
+        let within = |from, to| QsoQuery {
+            from,
+            to,
+            ..QsoQuery::default()
+        };
+        assert!(within(date(14), date(14)).matches(&qso));
+        assert!(within(date(1), None).matches(&qso));
+        assert!(!within(date(15), None).matches(&qso));
+        assert!(!within(None, date(13)).matches(&qso));
+    }
+
+    #[test]
+    fn every_criterion_must_match() {
+        let query = QsoQuery {
+            callsign: Some("K4ABC".into()),
+            band: Some(Band::M40),
+            ..QsoQuery::default()
+        };
+        assert!(!query.matches(&make_qso()));
+    }
+}
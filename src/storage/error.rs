@@ -43,4 +43,47 @@ pub enum StorageError {
     /// A log file contains valid JSON but is missing required fields for its declared log type.
     #[error("corrupt log metadata: {0}")]
     CorruptMetadata(String),
+
+    /// Records in a log file are damaged, so the log cannot safely be used.
+    ///
+    /// Produced by [`crate::storage::LogManager::load_log`]; the file can be
+    /// fixed with [`crate::storage::LogManager::repair_log`].
+    #[error("log {log_id} is damaged ({damage}); repair it before use")]
+    DamagedLog {
+        /// The ID of the damaged log.
+        log_id: String,
+        /// The damaged records of the file.
+        damage: crate::adif::Damage,
+    },
+
+    /// Another duklog process has the log open.
+    ///
+    /// Produced by [`crate::storage::LogManager::lock_log`] and by writes to a
+    /// log locked elsewhere. `pid` is `None` if the other process's ID could
+    /// not be read.
+    #[error(
+        "log is open in another duklog{}",
+        .pid.map(|pid| format!(" (pid {pid})")).unwrap_or_default()
+    )]
+    LogLocked {
+        /// The ID of the locked log.
+        log_id: String,
+        /// The process ID of the duklog holding the lock.
+        pid: Option<u32>,
+    },
+
+    /// The active log's file was changed by another program since duklog
+    /// last read or wrote it.
+    ///
+    /// Produced by [`crate::storage::LogManager::check_unmodified`] and by
+    /// writes to the active log, so that the changes are not overwritten.
+    #[error("log {log_id} was changed on disk by another program")]
+    LogModified {
+        /// The ID of the changed log.
+        log_id: String,
+    },
+
+    /// The logs chosen for a merge cannot be combined into one log.
+    #[error("cannot merge logs: {0}")]
+    IncompatibleLogs(String),
 }
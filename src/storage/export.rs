@@ -2,6 +2,8 @@ use std::fs;
 use std::path::{Path, PathBuf};
 
 use super::error::StorageError;
+use crate::adif::format_adx;
+use crate::export::{ExportFormat, format_csv, format_json};
 use crate::model::{DefaultFilename, Log};
 
 /// Copies the internal ADIF file to the given export path.
@@ -15,12 +17,36 @@ pub fn export_adif(internal_path: &Path, export_path: &Path) -> Result<(), Stora
     Ok(())
 }
 
-/// Returns the default export path for a log.
+/// Exports `log` in `format` to the given path.
+///
+/// ADIF exports copy the internal file at `internal_path`; ADX, CSV and JSON
+/// are formatted from `log`. Creates any missing parent directories.
+pub fn export_log(
+    internal_path: &Path,
+    log: &Log,
+    format: ExportFormat,
+    export_path: &Path,
+) -> Result<(), StorageError> {
+    let content = match format {
+        ExportFormat::Adif => return export_adif(internal_path, export_path),
+        ExportFormat::Adx => format_adx(log),
+        ExportFormat::Csv => format_csv(log),
+        ExportFormat::Json => format_json(log)?,
+    };
+    if let Some(parent) = export_path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(export_path, content)?;
+    Ok(())
+}
+
+/// Returns the default export path for a log in the given format.
 ///
 /// Files are written to `~/Documents/duklog/`, falling back to `~/duklog/` if
 /// the documents directory is unavailable.
 ///
-/// Filename formats by log type:
+/// Filename formats by log type (shown for ADIF; other formats use the same
+/// name with their own extension, e.g. `.adx`):
 /// - POTA: `{CALLSIGN}@{PARK}-{YYYYMMDD}.adif`
 /// - General: `{CALLSIGN}-{YYYYMMDD}.adif`
 /// - Field Day: `{CALLSIGN}-FD-{YYYYMMDD}.adif`
@@ -30,12 +56,14 @@ pub fn export_adif(internal_path: &Path, export_path: &Path) -> Result<(), Stora
 ///
 /// Returns `StorageError::NoHomeDir` if no suitable directory can be
 /// determined.
-pub fn default_export_path(log: &Log) -> Result<PathBuf, StorageError> {
+pub fn default_export_path(log: &Log, format: ExportFormat) -> Result<PathBuf, StorageError> {
     let base = dirs::document_dir()
         .or_else(dirs::home_dir)
         .map(|d| d.join("duklog"))
         .ok_or(StorageError::NoHomeDir)?;
-    Ok(base.join(log.default_filename()))
+    Ok(base
+        .join(log.default_filename())
+        .with_extension(format.extension()))
 }
 
 #[cfg(test)]
@@ -109,6 +137,7 @@ mod tests {
             None,
             None,
             None,
+            None,
         )
         .unwrap()
     }
@@ -162,12 +191,71 @@ mod tests {
         assert!(export_path.exists());
     }
 
+    // --- export_log tests ---
+
+    #[test]
+    fn export_log_adif_copies_internal_file() {
+        let dir = tempdir().unwrap();
+        let log = make_pota_log();
+        let internal = write_internal(dir.path(), &log);
+        let export_path = dir.path().join("out.adif");
+
+        export_log(&internal, &log, ExportFormat::Adif, &export_path).unwrap();
+
+        assert_eq!(
+            fs::read_to_string(&export_path).unwrap(),
+            fs::read_to_string(&internal).unwrap()
+        );
+    }
+
+    #[test]
+    fn export_log_csv_writes_rows() {
+        let dir = tempdir().unwrap();
+        let mut log = make_pota_log();
+        log.add_qso(make_qso());
+        let export_path = dir.path().join("nested").join("out.csv");
+
+        export_log(Path::new("unused"), &log, ExportFormat::Csv, &export_path).unwrap();
+
+        let content = fs::read_to_string(&export_path).unwrap();
+        assert!(content.starts_with("log_id,log_type,"));
+        assert!(content.contains(",KD9XYZ,20M,SSB,"));
+    }
+
+    #[test]
+    fn export_log_adx_writes_document() {
+        let dir = tempdir().unwrap();
+        let mut log = make_pota_log();
+        log.add_qso(make_qso());
+        let export_path = dir.path().join("out.adx");
+
+        export_log(Path::new("unused"), &log, ExportFormat::Adx, &export_path).unwrap();
+
+        let content = fs::read_to_string(&export_path).unwrap();
+        assert_eq!(content, format_adx(&log));
+    }
+
+    #[test]
+    fn export_log_json_writes_document() {
+        let dir = tempdir().unwrap();
+        let mut log = make_pota_log();
+        log.add_qso(make_qso());
+        let export_path = dir.path().join("out.json");
+
+        export_log(Path::new("unused"), &log, ExportFormat::Json, &export_path).unwrap();
+
+        let doc: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&export_path).unwrap()).unwrap();
+        assert_eq!(doc["log"]["park_ref"], "K-0001");
+        assert_eq!(doc["qsos"][0]["call"], "KD9XYZ");
+    }
+
     // --- default_export_path tests ---
 
     #[test]
     fn default_path_with_park_ref() {
         let log = make_pota_log();
-        let path = default_export_path(&log).unwrap();
+        let path = default_export_path(&log, ExportFormat::Adif).unwrap();
         let filename = path.file_name().unwrap().to_str().unwrap();
         assert_eq!(filename, "W1AW@K-0001-20260216.adif");
     }
@@ -176,7 +264,7 @@ mod tests {
     fn default_path_sanitizes_portable_callsign_with_park_ref() {
         let mut log = make_pota_log();
         log.header_mut().station_callsign = "W1AW/P".to_string();
-        let path = default_export_path(&log).unwrap();
+        let path = default_export_path(&log, ExportFormat::Adif).unwrap();
         let filename = path.file_name().unwrap().to_str().unwrap();
         assert_eq!(filename, "W1AW_P@K-0001-20260216.adif");
     }
@@ -185,7 +273,7 @@ mod tests {
     fn default_path_general_sanitizes_portable_callsign() {
         let mut log = make_general_log();
         log.header_mut().station_callsign = "W1AW/P".to_string();
-        let path = default_export_path(&log).unwrap();
+        let path = default_export_path(&log, ExportFormat::Adif).unwrap();
         let filename = path.file_name().unwrap().to_str().unwrap();
         assert_eq!(filename, "W1AW_P-20260216.adif");
     }
@@ -193,7 +281,7 @@ mod tests {
     #[test]
     fn default_path_general_log() {
         let log = make_general_log();
-        let path = default_export_path(&log).unwrap();
+        let path = default_export_path(&log, ExportFormat::Adif).unwrap();
         let filename = path.file_name().unwrap().to_str().unwrap();
         assert_eq!(filename, "W1AW-20260216.adif");
     }
@@ -201,7 +289,7 @@ mod tests {
     #[test]
     fn default_path_fd_log() {
         let log = make_fd_log();
-        let path = default_export_path(&log).unwrap();
+        let path = default_export_path(&log, ExportFormat::Adif).unwrap();
         let filename = path.file_name().unwrap().to_str().unwrap();
         assert_eq!(filename, "W1AW-FD-20260216.adif");
     }
@@ -209,15 +297,24 @@ mod tests {
     #[test]
     fn default_path_wfd_log() {
         let log = make_wfd_log();
-        let path = default_export_path(&log).unwrap();
+        let path = default_export_path(&log, ExportFormat::Adif).unwrap();
         let filename = path.file_name().unwrap().to_str().unwrap();
         assert_eq!(filename, "W1AW-WFD-20260216.adif");
     }
 
+    #[test]
+    fn default_path_uses_format_extension() {
+        let log = make_pota_log();
+        let csv = default_export_path(&log, ExportFormat::Csv).unwrap();
+        assert_eq!(csv.file_name().unwrap(), "W1AW@K-0001-20260216.csv");
+        let json = default_export_path(&log, ExportFormat::Json).unwrap();
+        assert_eq!(json.file_name().unwrap(), "W1AW@K-0001-20260216.json");
+    }
+
     #[test]
     fn default_path_is_in_duklog_subdirectory() {
         let log = make_pota_log();
-        let path = default_export_path(&log).unwrap();
+        let path = default_export_path(&log, ExportFormat::Adif).unwrap();
         assert_eq!(path.parent().unwrap().file_name().unwrap(), "duklog");
     }
 }
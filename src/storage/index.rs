@@ -0,0 +1,196 @@
+//! Index of every QSO across all logs by the callsign worked.
+//!
+//! [`LogManager`](super::LogManager) builds the index from every listed log
+//! the first time it is asked for a callsign, then keeps it up to date as it
+//! writes, deletes, and archives logs, so lookups and searches never read the
+//! disk.
+
+use std::collections::HashMap;
+
+use crate::model::{Log, Qso, QsoQuery};
+
+/// An indexed QSO and the log it is in.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WorkedEntry {
+    /// ID of the log the QSO is in.
+    pub log_id: String,
+    /// Short label of that log: park reference, contest exchange, or callsign.
+    pub log_label: String,
+    /// The QSO itself.
+    pub qso: Qso,
+}
+
+/// QSOs from all logs, keyed by the callsign worked (uppercase).
+#[derive(Debug, Clone, Default)]
+pub(super) struct CallsignIndex {
+    by_call: HashMap<String, Vec<WorkedEntry>>,
+}
+
+impl CallsignIndex {
+    /// Indexes every QSO in `logs`.
+    pub(super) fn from_logs(logs: &[Log]) -> Self {
+        let mut index = Self::default();
+        for log in logs {
+            index.add_log(log);
+        }
+        index
+    }
+
+    /// Returns the QSOs with `call` (case-insensitive), newest first.
+    pub(super) fn lookup(&self, call: &str) -> Vec<WorkedEntry> {
+        let mut entries = self
+            .by_call
+            .get(&call.to_uppercase())
+            .cloned()
+            .unwrap_or_default();
+        entries.sort_by_key(|e| std::cmp::Reverse(e.qso.timestamp));
+        entries
+    }
+
+    /// Returns the QSOs in every log that match `query`, newest first.
+    pub(super) fn search(&self, query: &QsoQuery) -> Vec<WorkedEntry> {
+        let mut entries: Vec<WorkedEntry> = self
+            .by_call
+            .values()
+            .flatten()
+            .filter(|e| query.matches(&e.qso))
+            .cloned()
+            .collect();
+        entries.sort_by_key(|e| std::cmp::Reverse(e.qso.timestamp));
+        entries
+    }
+
+    /// Adds one QSO from `log`.
+    pub(super) fn add_qso(&mut self, log: &Log, qso: &Qso) {
+        self.by_call
+            .entry(qso.their_call.to_uppercase())
+            .or_default()
+            .push(WorkedEntry {
+                log_id: log.header().log_id.clone(),
+                log_label: log.display_label(),
+                qso: qso.clone(),
+            });
+    }
+
+    /// Replaces everything indexed for `log` with its current QSOs.
+    pub(super) fn replace_log(&mut self, log: &Log) {
+        self.remove_log(&log.header().log_id);
+        self.add_log(log);
+    }
+
+    /// Removes every QSO from the log with `log_id`.
+    pub(super) fn remove_log(&mut self, log_id: &str) {
+        self.by_call.retain(|_, entries| {
+            entries.retain(|e| e.log_id != log_id);
+            !entries.is_empty()
+        });
+    }
+
+    fn add_log(&mut self, log: &Log) {
+        for qso in log.qsos() {
+            self.add_qso(log, qso);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, Utc};
+
+    use super::*;
+    use crate::model::{Band, GeneralLog, Mode, PotaLog};
+
+    fn make_qso(call: &str, minutes_ago: i64) -> Qso {
+        Qso::new(
+            call.into(),
+            "59".into(),
+            "59".into(),
+            Band::M20,
+            Mode::Ssb,
+            Utc::now() - Duration::minutes(minutes_ago),
+            String::new(),
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap()
+    }
+
+    fn make_log(id: &str, qsos: &[Qso]) -> Log {
+        let mut log =
+            Log::Pota(PotaLog::new("W1AW".into(), None, "K-0001".into(), "FN31".into()).unwrap());
+        log.header_mut().log_id = id.into();
+        for qso in qsos {
+            log.add_qso(qso.clone());
+        }
+        log
+    }
+
+    fn log_ids(entries: &[WorkedEntry]) -> Vec<&str> {
+        entries.iter().map(|e| e.log_id.as_str()).collect()
+    }
+
+    #[test]
+    fn lookup_spans_logs_newest_first() {
+        let a = make_log("a", &[make_qso("KD9XYZ", 60), make_qso("N0CALL", 50)]);
+        let b = make_log("b", &[make_qso("KD9XYZ", 10)]);
+        let index = CallsignIndex::from_logs(&[a, b]);
+        let entries = index.lookup("KD9XYZ");
+        assert_eq!(log_ids(&entries), vec!["b", "a"]);
+        assert_eq!(entries[0].log_label, "K-0001");
+    }
+
+    #[test]
+    fn lookup_is_case_insensitive() {
+        let index = CallsignIndex::from_logs(&[make_log("a", &[make_qso("KD9XYZ", 1)])]);
+        assert_eq!(index.lookup("kd9xyz").len(), 1);
+    }
+
+    #[test]
+    fn unknown_call_has_no_entries() {
+        let index = CallsignIndex::from_logs(&[make_log("a", &[make_qso("KD9XYZ", 1)])]);
+        assert!(index.lookup("KD9XY").is_empty());
+    }
+
+    #[test]
+    fn search_matches_across_calls_newest_first() {
+        let a = make_log("a", &[make_qso("KD9XYZ", 60), make_qso("N0CALL", 50)]);
+        let b = make_log("b", &[make_qso("W1XYZ", 10)]);
+        let index = CallsignIndex::from_logs(&[a, b]);
+        let query = QsoQuery {
+            callsign: Some("xyz".into()),
+            ..QsoQuery::default()
+        };
+        let entries = index.search(&query);
+        let calls: Vec<_> = entries.iter().map(|e| e.qso.their_call.as_str()).collect();
+        assert_eq!(calls, vec!["W1XYZ", "KD9XYZ"]);
+    }
+
+    #[test]
+    fn add_qso_is_found() {
+        let log = Log::General(GeneralLog::new("W1AW".into(), None, "FN31".into()).unwrap());
+        let mut index = CallsignIndex::default();
+        index.add_qso(&log, &make_qso("KD9XYZ", 1));
+        assert_eq!(index.lookup("KD9XYZ")[0].log_label, "W1AW");
+    }
+
+    #[test]
+    fn replace_log_drops_removed_qsos() {
+        let mut index = CallsignIndex::from_logs(&[
+            make_log("a", &[make_qso("KD9XYZ", 2)]),
+            make_log("b", &[make_qso("KD9XYZ", 1)]),
+        ]);
+        index.replace_log(&make_log("a", &[make_qso("N0CALL", 1)]));
+        assert_eq!(log_ids(&index.lookup("KD9XYZ")), vec!["b"]);
+        assert_eq!(log_ids(&index.lookup("N0CALL")), vec!["a"]);
+    }
+
+    #[test]
+    fn remove_log_drops_its_qsos() {
+        let mut index = CallsignIndex::from_logs(&[make_log("a", &[make_qso("KD9XYZ", 1)])]);
+        index.remove_log("a");
+        assert!(index.lookup("KD9XYZ").is_empty());
+        assert!(index.by_call.is_empty());
+    }
+}
@@ -0,0 +1,104 @@
+//! Advisory locks that keep two duklog processes from writing the same log.
+//!
+//! Each log file `{id}.adif` has a companion `{id}.adif.lock` holding the
+//! process ID of the duklog that has the log open. The lock itself is an OS
+//! advisory lock on that file ([`File::try_lock`]), so it is released when
+//! the process exits, even after a crash; the PID is only used to name the
+//! other process in the error.
+
+use std::fs::{File, OpenOptions};
+use std::io::{Read, Seek, Write};
+use std::path::{Path, PathBuf};
+
+use super::error::StorageError;
+
+/// Extension of a log's lock file, appended to the log file's name.
+const LOCK_EXTENSION: &str = "adif.lock";
+
+/// An exclusive lock on a log, held until dropped.
+#[derive(Debug)]
+pub(super) struct LogLock {
+    log_id: String,
+    _file: File,
+}
+
+impl LogLock {
+    /// Locks the log stored at `log_path`.
+    ///
+    /// Returns [`StorageError::LogLocked`] if another process holds the lock.
+    pub(super) fn acquire(log_id: &str, log_path: &Path) -> Result<Self, StorageError> {
+        let mut file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(lock_path(log_path))?;
+        match file.try_lock() {
+            Ok(()) => {
+                file.set_len(0)?;
+                file.rewind()?;
+                write!(file, "{}", std::process::id())?;
+                Ok(Self {
+                    log_id: log_id.to_string(),
+                    _file: file,
+                })
+            }
+            Err(std::fs::TryLockError::WouldBlock) => {
+                let mut pid = String::new();
+                file.read_to_string(&mut pid)?;
+                Err(StorageError::LogLocked {
+                    log_id: log_id.to_string(),
+                    pid: pid.trim().parse().ok(),
+                })
+            }
+            Err(std::fs::TryLockError::Error(e)) => Err(e.into()),
+        }
+    }
+
+    /// Returns the ID of the locked log.
+    pub(super) fn log_id(&self) -> &str {
+        &self.log_id
+    }
+}
+
+/// Returns the lock file path for the log stored at `log_path`.
+pub(super) fn lock_path(log_path: &Path) -> PathBuf {
+    log_path.with_extension(LOCK_EXTENSION)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn second_lock_reports_holder_pid() {
+        let dir = tempfile::tempdir().unwrap();
+        let log_path = dir.path().join("log1.adif");
+        let held = LogLock::acquire("log1", &log_path).unwrap();
+        assert_eq!(held.log_id(), "log1");
+
+        let Err(StorageError::LogLocked { log_id, pid }) = LogLock::acquire("log1", &log_path)
+        else {
+            panic!("expected LogLocked");
+        };
+        assert_eq!(log_id, "log1");
+        assert_eq!(pid, Some(std::process::id()));
+    }
+
+    #[test]
+    fn dropping_releases_lock() {
+        let dir = tempfile::tempdir().unwrap();
+        let log_path = dir.path().join("log1.adif");
+        drop(LogLock::acquire("log1", &log_path).unwrap());
+        assert!(LogLock::acquire("log1", &log_path).is_ok());
+    }
+
+    #[test]
+    fn lock_file_sits_beside_log() {
+        let log_path = Path::new("/logs/W1AW_P-20260216.adif");
+        assert_eq!(
+            lock_path(log_path),
+            Path::new("/logs/W1AW_P-20260216.adif.lock")
+        );
+    }
+}
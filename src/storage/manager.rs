@@ -1,3 +1,5 @@
+use std::cell::{Cell, RefCell};
+use std::collections::BTreeMap;
 use std::fs::{self, OpenOptions};
 use std::io::{BufRead, BufReader, Write};
 use std::path::{Path, PathBuf};
@@ -6,11 +8,46 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 use super::error::StorageError;
+use super::index::{CallsignIndex, WorkedEntry};
+use super::lock::{LogLock, lock_path};
+use super::merge::{MERGE_DUPLICATE_WINDOW, MergedLog, merge_logs};
+use super::stamp::FileStamp;
+use crate::adif::{Damage, salvage_log};
 use crate::model::{
-    FdClass, FdPowerCategory, FieldDayLog, GeneralLog, Log, LogHeader, PotaLog, Qso, WfdClass,
-    WfdLog, validate_tx_count,
+    ExtraFields, FdClass, FdPowerCategory, FieldDayLog, GeneralLog, Log, LogHeader, PotaLog, Qso,
+    QsoQuery, WfdClass, WfdLog, validate_tx_count,
 };
 
+/// File in the logs directory holding the QSO list columns chosen per log type.
+const LIST_COLUMNS_FILE: &str = "list_columns.json";
+
+/// Extension appended to a log file's name for the bytes removed by
+/// [`LogManager::repair_log`].
+const DAMAGED_EXTENSION: &str = "adif.damaged";
+
+/// The logs found by [`LogManager::scan_logs`], with any files that could not
+/// be read in full.
+#[derive(Debug, Clone, Default)]
+pub struct LogListing {
+    /// Readable logs, newest first. Damaged logs are included with their
+    /// readable records.
+    pub logs: Vec<Log>,
+    /// Files that were damaged or could not be read, in directory order.
+    pub problems: Vec<LogFileProblem>,
+}
+
+/// A log file that [`LogManager::scan_logs`] could not read in full.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LogFileProblem {
+    /// The log is listed, but some of its file's records are damaged and
+    /// must be removed with [`LogManager::repair_log`] before the log is used.
+    Damaged { log_id: String, damage: Damage },
+    /// The file's header, or a well-formed record that is not a valid QSO
+    /// (e.g. an unknown `BAND`), could not be read, so it is not listed. Such a
+    /// file is left for the user to fix; [`LogManager::repair_log`] refuses it.
+    Unreadable { path: PathBuf, error: String },
+}
+
 // ─── Migration only — delete after 1.0 ──────────────────────────────────────
 
 /// Storage-internal log type discriminant used by the legacy JSONL format.
@@ -57,6 +94,7 @@ impl LogMetadata {
             qsos,
             created_at: self.created_at,
             log_id: self.log_id,
+            extra_fields: ExtraFields::default(),
         };
         match self.log_type {
             StoredLogType::Pota => {
@@ -153,7 +191,10 @@ fn load_jsonl_from_path(path: &Path) -> Result<Log, StorageError> {
     let qsos = lines
         .map(|line| {
             let line = line?;
-            serde_json::from_str(&line).map_err(StorageError::Json)
+            let mut qso: Qso = serde_json::from_str(&line)?;
+            // Legacy logs stored frequencies in kHz; they are now kept in Hz.
+            qso.frequency = qso.frequency.map(|khz| khz * 1000);
+            Ok(qso)
         })
         .collect::<Result<Vec<Qso>, StorageError>>()?;
 
@@ -167,9 +208,26 @@ fn load_jsonl_from_path(path: &Path) -> Result<Log, StorageError> {
 /// Each log is stored as a single `.adif` file in the logs directory.
 /// The ADIF header encodes all log metadata; records encode individual QSOs.
 /// Appending a QSO is an O(1) file append — no read required.
+///
+/// The active log is locked with [`lock_log`](Self::lock_log) so that a
+/// second duklog process cannot write it; every write refuses a log that is
+/// locked by another process. Writes to the active log also refuse to
+/// overwrite changes made to its file by other programs since duklog last
+/// read or wrote it.
+///
+/// The manager also keeps an index of every QSO by callsign for
+/// [`worked_before`](Self::worked_before) and
+/// [`search_qsos`](Self::search_qsos), updated as it writes logs.
 pub struct LogManager {
     base_path: PathBuf,
     runtime: tokio::runtime::Runtime,
+    active_lock: Option<LogLock>,
+    /// Stamp of the active log's file when it was last read or written.
+    active_stamp: Cell<Option<FileStamp>>,
+    /// The active log as its file held it when last read or written.
+    active_snapshot: RefCell<Option<Log>>,
+    /// Built on the first lookup, then kept up to date by every write.
+    callsign_index: RefCell<Option<CallsignIndex>>,
 }
 
 impl LogManager {
@@ -183,7 +241,14 @@ impl LogManager {
         let base_path = data_dir.join("duklog").join("logs");
         fs::create_dir_all(&base_path)?;
         let runtime = tokio::runtime::Builder::new_current_thread().build()?;
-        let manager = Self { base_path, runtime };
+        let manager = Self {
+            base_path,
+            runtime,
+            active_lock: None,
+            active_stamp: Cell::new(None),
+            active_snapshot: RefCell::new(None),
+            callsign_index: RefCell::new(None),
+        };
         manager.migrate_jsonl_files();
         Ok(manager)
     }
@@ -193,7 +258,14 @@ impl LogManager {
         let base_path = path.into();
         fs::create_dir_all(&base_path)?;
         let runtime = tokio::runtime::Builder::new_current_thread().build()?;
-        let manager = Self { base_path, runtime };
+        let manager = Self {
+            base_path,
+            runtime,
+            active_lock: None,
+            active_stamp: Cell::new(None),
+            active_snapshot: RefCell::new(None),
+            callsign_index: RefCell::new(None),
+        };
         manager.migrate_jsonl_files();
         Ok(manager)
     }
@@ -229,14 +301,140 @@ impl LogManager {
         }
     }
 
+    /// Locks the log with `log_id` for this process, releasing any log
+    /// locked before, and makes it the active log.
+    ///
+    /// The lock is held until [`unlock_log`](Self::unlock_log), another call
+    /// to `lock_log`, or the manager is dropped (including on exit). Returns
+    /// [`StorageError::LogLocked`] if another duklog has the log open; the
+    /// previous lock is then kept.
+    ///
+    /// Returns the log as its file holds it now, or `None` if there is no file
+    /// yet. Later writes are checked against this version, so the caller must
+    /// use it rather than a copy read earlier: changes made to the file in
+    /// between would otherwise be overwritten unnoticed. If the file cannot be
+    /// read (e.g. it is damaged), the lock is released and the error returned.
+    pub fn lock_log(&mut self, log_id: &str) -> Result<Option<Log>, StorageError> {
+        if self.locked_log_id() != Some(log_id) {
+            self.active_lock = Some(LogLock::acquire(log_id, &self.log_path(log_id))?);
+        }
+        let path = self.log_path(log_id);
+        self.active_stamp.set(FileStamp::read(&path)?);
+        let log = if path.exists() {
+            match self.load_log(log_id) {
+                Ok(log) => Some(log),
+                Err(e) => {
+                    self.unlock_log();
+                    return Err(e);
+                }
+            }
+        } else {
+            None
+        };
+        if let Some(log) = &log {
+            self.update_index(|index| index.replace_log(log));
+        }
+        *self.active_snapshot.borrow_mut() = log.clone();
+        Ok(log)
+    }
+
+    /// Releases the lock taken by [`lock_log`](Self::lock_log), if any.
+    pub fn unlock_log(&mut self) {
+        self.active_lock = None;
+        self.active_stamp.set(None);
+        *self.active_snapshot.borrow_mut() = None;
+    }
+
+    /// Returns the active log `log_id` as its file held it when this manager
+    /// last read or wrote it, before any change made by another program.
+    ///
+    /// `None` if `log_id` is not the active log.
+    pub fn last_read_log(&self, log_id: &str) -> Option<Log> {
+        if self.locked_log_id() != Some(log_id) {
+            return None;
+        }
+        self.active_snapshot.borrow().clone()
+    }
+
+    /// Returns the ID of the log locked by this manager, if any.
+    pub fn locked_log_id(&self) -> Option<&str> {
+        self.active_lock.as_ref().map(LogLock::log_id)
+    }
+
+    /// Returns [`StorageError::LogLocked`] if another process holds the lock
+    /// on `log_id`.
+    fn check_writable(&self, log_id: &str) -> Result<(), StorageError> {
+        if self.locked_log_id() != Some(log_id) {
+            LogLock::acquire(log_id, &self.log_path(log_id))?;
+        }
+        Ok(())
+    }
+
+    /// Returns [`StorageError::LogModified`] if `log_id` is the active log and
+    /// its file has changed since this manager last read or wrote it.
+    ///
+    /// A file that no longer exists is left for the write to report. Writes
+    /// run this check themselves; callers use it to find out before changing
+    /// the log in memory.
+    pub fn check_unmodified(&self, log_id: &str) -> Result<(), StorageError> {
+        if self.locked_log_id() == Some(log_id)
+            && let Some(stamp) = FileStamp::read(&self.log_path(log_id))?
+            && Some(stamp) != self.active_stamp.get()
+        {
+            return Err(StorageError::LogModified {
+                log_id: log_id.to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Reads the active log back from its file, accepting any changes made
+    /// by other programs so that the next write no longer refuses them.
+    pub fn reload_log(&self, log_id: &str) -> Result<Log, StorageError> {
+        let stamp = FileStamp::read(&self.log_path(log_id))?;
+        let log = self.load_log(log_id)?;
+        if self.locked_log_id() == Some(log_id) {
+            self.active_stamp.set(stamp);
+            *self.active_snapshot.borrow_mut() = Some(log.clone());
+        }
+        self.update_index(|index| index.replace_log(&log));
+        Ok(log)
+    }
+
+    /// Records the stamp of `log_id`'s file after this manager wrote it, and
+    /// brings the snapshot of the file up to date with `update`.
+    fn record_write(
+        &self,
+        log_id: &str,
+        update: impl FnOnce(&mut Option<Log>),
+    ) -> Result<(), StorageError> {
+        if self.locked_log_id() == Some(log_id) {
+            update(&mut self.active_snapshot.borrow_mut());
+            self.active_stamp
+                .set(FileStamp::read(&self.log_path(log_id))?);
+        }
+        Ok(())
+    }
+
+    /// Removes the lock file of a log that no longer exists under its name.
+    fn remove_lock_file(&self, log_id: &str) -> Result<(), StorageError> {
+        match fs::remove_file(lock_path(&self.log_path(log_id))) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e.into()),
+            _ => Ok(()),
+        }
+    }
+
     /// Writes a complete log to disk as an ADIF file (header + all QSOs).
     ///
     /// Overwrites any existing file for this log ID.
     pub fn save_log(&self, log: &Log) -> Result<(), StorageError> {
-        let path = self.log_path(&log.header().log_id);
+        let log_id = &log.header().log_id;
+        self.check_writable(log_id)?;
+        self.check_unmodified(log_id)?;
         let content = crate::adif::format_adif(log)?;
-        fs::write(&path, content)?;
-        Ok(())
+        fs::write(self.log_path(log_id), content)?;
+        self.update_index(|index| index.replace_log(log));
+        self.record_write(log_id, |snapshot| *snapshot = Some(log.clone()))
     }
 
     /// Appends a single QSO record to an existing log file.
@@ -245,36 +443,120 @@ impl LogManager {
     /// The log must have been previously created with [`save_log`](Self::save_log).
     /// Returns `StorageError::Io` if the file does not exist.
     pub fn append_qso(&self, log: &Log, qso: &Qso) -> Result<(), StorageError> {
-        let path = self.log_path(&log.header().log_id);
-        let mut file = OpenOptions::new().append(true).open(&path)?;
+        let log_id = &log.header().log_id;
+        self.check_writable(log_id)?;
+        self.check_unmodified(log_id)?;
+        let mut file = OpenOptions::new()
+            .append(true)
+            .open(self.log_path(log_id))?;
         let record = crate::adif::format_qso(log, qso)?;
         file.write_all(record.as_bytes())?;
-        Ok(())
+        drop(file);
+        self.update_index(|index| index.add_qso(log, qso));
+        self.record_write(log_id, |snapshot| {
+            if let Some(snapshot) = snapshot {
+                snapshot.add_qso(qso.clone());
+            }
+        })
     }
 
     /// Loads a log from its ADIF file.
+    ///
+    /// Returns [`StorageError::DamagedLog`] if any record in the file is
+    /// damaged, since QSOs appended after a cut-off record could not be read
+    /// back and saving the log would drop the damaged records; see
+    /// [`repair_log`](Self::repair_log).
     pub fn load_log(&self, log_id: &str) -> Result<Log, StorageError> {
         let path = self.log_path(log_id);
-        Ok(self.runtime.block_on(crate::adif::read_log(&path))?)
+        let salvaged = self.runtime.block_on(salvage_log(&path))?;
+        match salvaged.damage {
+            Some(damage) => Err(StorageError::DamagedLog {
+                log_id: log_id.to_string(),
+                damage,
+            }),
+            None => Ok(salvaged.log),
+        }
     }
 
     /// Lists all logs sorted by `created_at` descending (newest first).
+    ///
+    /// Damaged and unreadable files are handled as in
+    /// [`scan_logs`](Self::scan_logs), without reporting them.
     pub fn list_logs(&self) -> Result<Vec<Log>, StorageError> {
-        let mut logs: Vec<Log> = fs::read_dir(&self.base_path)?
-            .collect::<Result<Vec<_>, _>>()?
-            .into_iter()
-            .filter(|entry| {
-                let path = entry.path();
-                path.is_file() && path.extension().is_some_and(|ext| ext == "adif")
-            })
-            .map(|entry| {
-                let path = entry.path();
-                Ok(self.runtime.block_on(crate::adif::read_log(&path))?)
-            })
-            .collect::<Result<Vec<_>, StorageError>>()?;
-
-        logs.sort_by(|a, b| b.header().created_at.cmp(&a.header().created_at));
-        Ok(logs)
+        Ok(self.scan_logs()?.logs)
+    }
+
+    /// Lists all logs sorted by `created_at` descending (newest first),
+    /// reporting files that could not be read in full.
+    ///
+    /// A file whose end is damaged (e.g. a record cut off by a power cut) is
+    /// listed with its complete records and reported as
+    /// [`LogFileProblem::Damaged`]. A file whose header cannot be read, or
+    /// that holds a well-formed record that is not a valid QSO, is skipped and
+    /// reported as [`LogFileProblem::Unreadable`]. Only failing
+    /// to read the directory itself is an error.
+    pub fn scan_logs(&self) -> Result<LogListing, StorageError> {
+        let mut listing = LogListing::default();
+        for entry in fs::read_dir(&self.base_path)? {
+            let path = entry?.path();
+            if !path.is_file() || path.extension().is_none_or(|ext| ext != "adif") {
+                continue;
+            }
+            match self.runtime.block_on(salvage_log(&path)) {
+                Ok(salvaged) => {
+                    if let Some(damage) = salvaged.damage {
+                        listing.problems.push(LogFileProblem::Damaged {
+                            log_id: salvaged.log.header().log_id.clone(),
+                            damage,
+                        });
+                    }
+                    listing.logs.push(salvaged.log);
+                }
+                Err(e) => listing.problems.push(LogFileProblem::Unreadable {
+                    path,
+                    error: e.to_string(),
+                }),
+            }
+        }
+        listing
+            .logs
+            .sort_by_key(|log| std::cmp::Reverse(log.header().created_at));
+        Ok(listing)
+    }
+
+    /// Removes the damaged records from a log file, keeping every readable
+    /// record.
+    ///
+    /// Each damaged record is cut out up to its `<EOR>`, and a record cut off
+    /// at the end of the file is truncated. The removed bytes are appended to
+    /// `{log_id}.adif.damaged` beside the log so nothing is lost. Returns the
+    /// damage that was removed, or `None` if the file was intact and left
+    /// unchanged.
+    pub fn repair_log(&self, log_id: &str) -> Result<Option<Damage>, StorageError> {
+        self.check_writable(log_id)?;
+        let path = self.log_path(log_id);
+        let salvaged = self.runtime.block_on(salvage_log(&path))?;
+        let Some(damage) = salvaged.damage else {
+            return Ok(None);
+        };
+        let content = fs::read(&path)?;
+        let mut kept = Vec::with_capacity(content.len());
+        let mut removed = Vec::new();
+        let mut pos = 0;
+        for record in &damage.records {
+            let end = record.offset + record.len;
+            kept.extend_from_slice(&content[pos..record.offset]);
+            removed.extend_from_slice(&content[record.offset..end]);
+            pos = end;
+        }
+        kept.extend_from_slice(&content[pos..]);
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path.with_extension(DAMAGED_EXTENSION))?
+            .write_all(&removed)?;
+        fs::write(&path, kept)?;
+        Ok(Some(damage))
     }
 
     /// Creates a new log, checking for duplicates before saving.
@@ -288,9 +570,17 @@ impl LogManager {
     /// The caller must ensure `log` has a unique `log_id`; this method compares
     /// on fields rather than identity.
     pub fn create_log(&self, log: &Log) -> Result<(), StorageError> {
+        self.create_log_superseding(log, &[])
+    }
+
+    /// Like [`create_log`](Self::create_log), but logs in `superseded` are
+    /// ignored by the duplicate check.
+    fn create_log_superseding(&self, log: &Log, superseded: &[String]) -> Result<(), StorageError> {
         let new_date = log.header().created_at.date_naive();
         for existing in self.list_logs()? {
-            if is_duplicate_log(&existing, log, new_date) {
+            if !superseded.contains(&existing.header().log_id)
+                && is_duplicate_log(&existing, log, new_date)
+            {
                 return Err(StorageError::DuplicateLog {
                     callsign: log.header().station_callsign.clone(),
                     date: new_date,
@@ -302,8 +592,112 @@ impl LogManager {
 
     /// Deletes a log file.
     pub fn delete_log(&self, log_id: &str) -> Result<(), StorageError> {
+        self.check_writable(log_id)?;
         let path = self.log_path(log_id);
         fs::remove_file(&path)?;
+        self.update_index(|index| index.remove_log(log_id));
+        self.remove_lock_file(log_id)
+    }
+
+    /// Merges the logs identified by `log_ids` into a new log and saves it.
+    ///
+    /// See [`merge_logs`] for how QSOs are combined. The sources are
+    /// superseded by the merged log, so they do not trigger
+    /// [`StorageError::DuplicateLog`]. When `archive_sources` is `true`, each
+    /// source is moved to the archive directory once the merged log is saved.
+    pub fn merge_logs(
+        &self,
+        log_ids: &[String],
+        archive_sources: bool,
+    ) -> Result<MergedLog, StorageError> {
+        let sources = log_ids
+            .iter()
+            .map(|id| self.load_log(id))
+            .collect::<Result<Vec<_>, _>>()?;
+        let merged = merge_logs(&sources, MERGE_DUPLICATE_WINDOW)?;
+        if archive_sources {
+            for log_id in log_ids {
+                self.check_writable(log_id)?;
+            }
+        }
+        self.create_log_superseding(&merged.log, log_ids)?;
+        if archive_sources {
+            for log_id in log_ids {
+                self.archive_log(log_id)?;
+            }
+        }
+        Ok(merged)
+    }
+
+    /// Moves a log file to the archive directory (`logs/archive/`).
+    ///
+    /// Archived logs no longer appear in [`list_logs`](Self::list_logs) but
+    /// stay on disk as ADIF files.
+    pub fn archive_log(&self, log_id: &str) -> Result<(), StorageError> {
+        self.check_writable(log_id)?;
+        let path = self.log_path(log_id);
+        let archive_dir = self.base_path.join("archive");
+        fs::create_dir_all(&archive_dir)?;
+        let file_name = path.file_name().expect("log paths always have a file name");
+        fs::rename(&path, archive_dir.join(file_name))?;
+        self.update_index(|index| index.remove_log(log_id));
+        self.remove_lock_file(log_id)
+    }
+
+    /// Returns every QSO with `call` (case-insensitive) in the listed logs,
+    /// newest first.
+    ///
+    /// The first call reads every log to build the callsign index; later calls
+    /// answer from the index, which this manager updates as it saves, appends
+    /// to, reloads, deletes, and archives logs. Changes other programs make to
+    /// logs other than the active one are not seen until restart.
+    pub fn worked_before(&self, call: &str) -> Result<Vec<WorkedEntry>, StorageError> {
+        self.with_index(|index| index.lookup(call))
+    }
+
+    /// Returns every QSO in the listed logs that matches `query`, newest
+    /// first, answered from the callsign index as for
+    /// [`worked_before`](Self::worked_before).
+    pub fn search_qsos(&self, query: &QsoQuery) -> Result<Vec<WorkedEntry>, StorageError> {
+        self.with_index(|index| index.search(query))
+    }
+
+    /// Runs `read` on the callsign index, building it first if needed.
+    fn with_index<T>(&self, read: impl FnOnce(&CallsignIndex) -> T) -> Result<T, StorageError> {
+        if self.callsign_index.borrow().is_none() {
+            let index = CallsignIndex::from_logs(&self.list_logs()?);
+            *self.callsign_index.borrow_mut() = Some(index);
+        }
+        let index = self.callsign_index.borrow();
+        Ok(read(index.as_ref().expect("index was just built")))
+    }
+
+    /// Applies `change` to the callsign index, if it has been built.
+    fn update_index(&self, change: impl FnOnce(&mut CallsignIndex)) {
+        if let Some(index) = self.callsign_index.borrow_mut().as_mut() {
+            change(index);
+        }
+    }
+
+    /// Loads the QSO list columns chosen for each log type, as column names
+    /// keyed by log type name.
+    ///
+    /// Returns an empty map if no choice has been saved yet.
+    pub fn load_list_columns(&self) -> Result<BTreeMap<String, Vec<String>>, StorageError> {
+        match fs::read_to_string(self.base_path.join(LIST_COLUMNS_FILE)) {
+            Ok(contents) => Ok(serde_json::from_str(&contents)?),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(BTreeMap::new()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Saves the QSO list columns chosen for each log type.
+    pub fn save_list_columns(
+        &self,
+        columns: &BTreeMap<String, Vec<String>>,
+    ) -> Result<(), StorageError> {
+        let content = serde_json::to_string_pretty(columns)?;
+        fs::write(self.base_path.join(LIST_COLUMNS_FILE), content)?;
         Ok(())
     }
 }
@@ -339,7 +733,7 @@ fn operator_eq(a: &Option<String>, b: &Option<String>) -> bool {
 /// Logs of different types are never considered equal. Within the same type,
 /// type-specific fields are compared (e.g., park reference for POTA logs,
 /// tx_count/class/section/power for Field Day, tx_count/class/section for WFD).
-fn log_config_eq(a: &Log, b: &Log) -> bool {
+pub(super) fn log_config_eq(a: &Log, b: &Log) -> bool {
     match (a, b) {
         (Log::Pota(pa), Log::Pota(pb)) => park_ref_eq(&pa.park_ref, &pb.park_ref),
         (Log::General(_), Log::General(_)) => true,
@@ -424,6 +818,7 @@ mod tests {
             None,
             None,
             None,
+            None,
         )
         .unwrap()
     }
@@ -440,6 +835,7 @@ mod tests {
             Some("K-1234".to_string()),
             None,
             None,
+            None,
         )
         .unwrap()
     }
@@ -556,6 +952,467 @@ mod tests {
         assert_eq!(logs.len(), 1);
     }
 
+    // --- Damaged file tests ---
+
+    /// Saves a log with two QSOs, then cuts its file off partway through the
+    /// second record. Returns the length of the intact part.
+    fn save_truncated_log(manager: &LogManager) -> usize {
+        let mut log = make_log();
+        log.add_qso(make_qso());
+        log.add_qso(make_qso());
+        manager.save_log(&log).unwrap();
+        let path = manager.log_path(&log.header().log_id);
+        let content = fs::read_to_string(&path).unwrap();
+        let intact = content.find("<eor>").unwrap() + "<eor>".len();
+        fs::write(&path, &content[..content.rfind("<eor>").unwrap() - 4]).unwrap();
+        intact
+    }
+
+    #[test]
+    fn scan_logs_lists_damaged_log_with_complete_records() {
+        let (_dir, manager) = make_manager();
+        let intact = save_truncated_log(&manager);
+
+        let listing = manager.scan_logs().unwrap();
+        assert_eq!(listing.logs.len(), 1);
+        assert_eq!(listing.logs[0].header().qsos.len(), 1);
+        let [LogFileProblem::Damaged { log_id, damage }] = listing.problems.as_slice() else {
+            panic!("expected one damaged log, got {:?}", listing.problems);
+        };
+        assert_eq!(log_id, "test-log");
+        assert_eq!(damage.records[0].offset, intact);
+    }
+
+    #[test]
+    fn scan_logs_skips_and_reports_unreadable_files() {
+        let (dir, manager) = make_manager();
+        manager.save_log(&make_log()).unwrap();
+        fs::write(dir.path().join("bad.adif"), "this is not adif content").unwrap();
+
+        let listing = manager.scan_logs().unwrap();
+        assert_eq!(listing.logs.len(), 1);
+        let [LogFileProblem::Unreadable { path, error }] = listing.problems.as_slice() else {
+            panic!("expected one unreadable file, got {:?}", listing.problems);
+        };
+        assert_eq!(path, &dir.path().join("bad.adif"));
+        assert!(error.contains("header"), "{error}");
+        assert_eq!(manager.list_logs().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn damaged_log_does_not_load() {
+        let (_dir, manager) = make_manager();
+        save_truncated_log(&manager);
+        let result = manager.load_log("test-log");
+        assert!(
+            matches!(result, Err(StorageError::DamagedLog { ref log_id, .. }) if log_id == "test-log"),
+            "got {result:?}"
+        );
+    }
+
+    #[test]
+    fn repair_log_removes_damaged_tail_and_keeps_it_aside() {
+        let (dir, manager) = make_manager();
+        let intact = save_truncated_log(&manager);
+        let path = manager.log_path("test-log");
+        let original = fs::read(&path).unwrap();
+
+        let damage = manager.repair_log("test-log").unwrap().unwrap();
+        assert_eq!(damage.records[0].offset, intact);
+        assert_eq!(fs::read(&path).unwrap(), original[..intact]);
+        assert_eq!(
+            fs::read(dir.path().join("test-log.adif.damaged")).unwrap(),
+            original[intact..]
+        );
+
+        let loaded = manager.load_log("test-log").unwrap();
+        assert_eq!(loaded.header().qsos.len(), 1);
+        assert!(manager.scan_logs().unwrap().problems.is_empty());
+    }
+
+    #[test]
+    fn repair_log_cuts_out_bad_record_in_the_middle() {
+        let (dir, manager) = make_manager();
+        let mut log = make_log();
+        for _ in 0..3 {
+            log.add_qso(make_qso());
+        }
+        manager.save_log(&log).unwrap();
+        let path = manager.log_path("test-log");
+        let original = fs::read_to_string(&path).unwrap();
+        // Garble a length prefix in the middle record so it is no longer ADIF.
+        let first_end = original.find("<eor>").unwrap() + "<eor>".len();
+        let second_end = first_end + original[first_end..].find("<eor>").unwrap() + "<eor>".len();
+        let garbled = original[first_end..second_end].replace("<BAND:3>20M", "<BAND:x>20M");
+        let content = format!(
+            "{}{garbled}{}",
+            &original[..first_end],
+            &original[second_end..]
+        );
+        fs::write(&path, &content).unwrap();
+
+        let listing = manager.scan_logs().unwrap();
+        assert_eq!(listing.logs[0].header().qsos.len(), 2);
+
+        let damage = manager.repair_log("test-log").unwrap().unwrap();
+        assert_eq!(damage.records.len(), 1);
+        assert_eq!(damage.records[0].offset, first_end);
+        assert_eq!(
+            fs::read_to_string(dir.path().join("test-log.adif.damaged")).unwrap(),
+            garbled
+        );
+        let expected = format!("{}{}", &original[..first_end], &original[second_end..]);
+        assert_eq!(fs::read_to_string(&path).unwrap(), expected);
+        assert_eq!(manager.load_log("test-log").unwrap().header().qsos.len(), 2);
+    }
+
+    #[test]
+    fn invalid_qso_is_reported_and_not_repaired() {
+        let (dir, manager) = make_manager();
+        let mut log = make_log();
+        log.add_qso(make_qso());
+        manager.save_log(&log).unwrap();
+        let path = manager.log_path("test-log");
+        let content = fs::read_to_string(&path)
+            .unwrap()
+            .replace("<BAND:3>20M", "<BAND:4>NOPE");
+        fs::write(&path, &content).unwrap();
+
+        let listing = manager.scan_logs().unwrap();
+        assert!(listing.logs.is_empty());
+        let [LogFileProblem::Unreadable { error, .. }] = listing.problems.as_slice() else {
+            panic!("expected one unreadable file, got {:?}", listing.problems);
+        };
+        assert!(error.contains("unknown BAND: NOPE"), "{error}");
+
+        assert!(matches!(
+            manager.repair_log("test-log"),
+            Err(StorageError::Adif(_))
+        ));
+        assert_eq!(fs::read_to_string(&path).unwrap(), content);
+        assert!(!dir.path().join("test-log.adif.damaged").exists());
+        assert!(matches!(
+            manager.load_log("test-log"),
+            Err(StorageError::Adif(_))
+        ));
+    }
+
+    #[test]
+    fn repair_then_append_keeps_new_qso() {
+        let (_dir, manager) = make_manager();
+        save_truncated_log(&manager);
+        manager.repair_log("test-log").unwrap();
+        let mut log = manager.load_log("test-log").unwrap();
+        let qso = make_qso();
+        manager.append_qso(&log, &qso).unwrap();
+        log.add_qso(qso);
+        assert_eq!(manager.load_log("test-log").unwrap().header().qsos.len(), 2);
+    }
+
+    #[test]
+    fn repair_intact_log_is_noop() {
+        let (dir, manager) = make_manager();
+        let log = make_log();
+        manager.save_log(&log).unwrap();
+        let before = fs::read(manager.log_path("test-log")).unwrap();
+        assert_eq!(manager.repair_log("test-log").unwrap(), None);
+        assert_eq!(fs::read(manager.log_path("test-log")).unwrap(), before);
+        assert!(!dir.path().join("test-log.adif.damaged").exists());
+    }
+
+    // --- Locking tests ---
+
+    /// Returns a second manager on the same directory, standing in for
+    /// another duklog process.
+    fn other_manager(dir: &tempfile::TempDir) -> LogManager {
+        LogManager::with_path(dir.path()).unwrap()
+    }
+
+    fn assert_locked(result: Result<(), StorageError>) {
+        assert!(
+            matches!(
+                result,
+                Err(StorageError::LogLocked { ref log_id, pid: Some(pid) })
+                    if log_id == "test-log" && pid == std::process::id()
+            ),
+            "got {result:?}"
+        );
+    }
+
+    #[test]
+    fn locked_log_cannot_be_locked_elsewhere() {
+        let (dir, mut manager) = make_manager();
+        manager.save_log(&make_log()).unwrap();
+        manager.lock_log("test-log").unwrap();
+        assert_eq!(manager.locked_log_id(), Some("test-log"));
+
+        assert_locked(other_manager(&dir).lock_log("test-log").map(|_| ()));
+        // Locking the same log again is a no-op.
+        manager.lock_log("test-log").unwrap();
+    }
+
+    #[test]
+    fn locked_log_rejects_writes_from_elsewhere() {
+        let (dir, mut manager) = make_manager();
+        let log = make_log();
+        manager.save_log(&log).unwrap();
+        manager.lock_log("test-log").unwrap();
+
+        let other = other_manager(&dir);
+        assert_locked(other.save_log(&log));
+        assert_locked(other.append_qso(&log, &make_qso()));
+        assert_locked(other.delete_log("test-log"));
+        assert_locked(other.archive_log("test-log"));
+        assert_locked(other.repair_log("test-log").map(|_| ()));
+        assert_eq!(other.load_log("test-log").unwrap(), log);
+
+        manager.append_qso(&log, &make_qso()).unwrap();
+    }
+
+    #[test]
+    fn unlock_and_relock_release_previous_log() {
+        let (dir, mut manager) = make_manager();
+        manager.save_log(&make_log()).unwrap();
+        manager
+            .save_log(&make_log_with_id("other-log", 2025))
+            .unwrap();
+        let mut other = other_manager(&dir);
+
+        manager.lock_log("test-log").unwrap();
+        manager.lock_log("other-log").unwrap();
+        other.lock_log("test-log").unwrap();
+        other.unlock_log();
+        assert_eq!(other.locked_log_id(), None);
+
+        drop(manager);
+        other.lock_log("other-log").unwrap();
+    }
+
+    #[test]
+    fn merge_with_archive_of_locked_log_changes_nothing() {
+        let (dir, manager) = make_manager();
+        manager.save_log(&make_log()).unwrap();
+        manager
+            .save_log(&make_log_with_id("other-log", 2025))
+            .unwrap();
+        let mut holder = other_manager(&dir);
+        holder.lock_log("test-log").unwrap();
+
+        let ids = vec!["test-log".to_string(), "other-log".to_string()];
+        assert_locked(manager.merge_logs(&ids, true).map(|_| ()));
+        assert_eq!(manager.list_logs().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn delete_removes_lock_file() {
+        let (dir, mut manager) = make_manager();
+        manager.save_log(&make_log()).unwrap();
+        manager.lock_log("test-log").unwrap();
+        manager.delete_log("test-log").unwrap();
+        manager.unlock_log();
+        assert!(!dir.path().join("test-log.adif.lock").exists());
+        assert!(manager.scan_logs().unwrap().problems.is_empty());
+    }
+
+    // --- External change tests ---
+
+    /// Appends a QSO to the log's file behind the manager's back, as an
+    /// editor or another tool would.
+    fn append_externally(manager: &LogManager, log: &Log) {
+        let mut file = OpenOptions::new()
+            .append(true)
+            .open(manager.log_path(&log.header().log_id))
+            .unwrap();
+        let record = crate::adif::format_qso(log, &make_p2p_qso()).unwrap();
+        file.write_all(record.as_bytes()).unwrap();
+    }
+
+    fn assert_modified(result: Result<(), StorageError>) {
+        assert!(
+            matches!(result, Err(StorageError::LogModified { ref log_id }) if log_id == "test-log"),
+            "got {result:?}"
+        );
+    }
+
+    #[test]
+    fn own_writes_do_not_count_as_changes() {
+        let (_dir, mut manager) = make_manager();
+        let mut log = make_log();
+        manager.save_log(&log).unwrap();
+        manager.lock_log("test-log").unwrap();
+        for _ in 0..2 {
+            manager.append_qso(&log, &make_qso()).unwrap();
+            log.add_qso(make_qso());
+        }
+        manager.save_log(&log).unwrap();
+        manager.check_unmodified("test-log").unwrap();
+    }
+
+    #[test]
+    fn external_change_blocks_writes_until_reload() {
+        let (_dir, mut manager) = make_manager();
+        let log = make_log();
+        manager.save_log(&log).unwrap();
+        manager.lock_log("test-log").unwrap();
+        append_externally(&manager, &log);
+
+        assert_modified(manager.check_unmodified("test-log"));
+        assert_modified(manager.save_log(&log));
+        assert_modified(manager.append_qso(&log, &make_qso()));
+
+        let reloaded = manager.reload_log("test-log").unwrap();
+        assert_eq!(reloaded.header().qsos.len(), 1);
+        manager.append_qso(&reloaded, &make_qso()).unwrap();
+        assert_eq!(manager.load_log("test-log").unwrap().header().qsos.len(), 2);
+    }
+
+    #[test]
+    fn last_read_log_follows_own_writes_only() {
+        let (_dir, mut manager) = make_manager();
+        let log = make_log();
+        manager.save_log(&log).unwrap();
+        assert!(manager.last_read_log("test-log").is_none());
+        manager.lock_log("test-log").unwrap();
+        manager.append_qso(&log, &make_qso()).unwrap();
+        append_externally(&manager, &log);
+
+        let last_read = manager.last_read_log("test-log").unwrap();
+        assert_eq!(last_read.header().qsos.len(), 1);
+        let reloaded = manager.reload_log("test-log").unwrap();
+        assert_eq!(manager.last_read_log("test-log").unwrap(), reloaded);
+        manager.unlock_log();
+        assert!(manager.last_read_log("test-log").is_none());
+    }
+
+    #[test]
+    fn lock_log_returns_file_as_it_is_now() {
+        let (_dir, mut manager) = make_manager();
+        let log = make_log();
+        manager.save_log(&log).unwrap();
+        append_externally(&manager, &log);
+
+        let locked = manager.lock_log("test-log").unwrap().unwrap();
+        assert_eq!(locked.header().qsos.len(), 1);
+        assert_eq!(manager.last_read_log("test-log").unwrap(), locked);
+        manager.save_log(&locked).unwrap();
+    }
+
+    #[test]
+    fn lock_log_of_damaged_file_releases_lock() {
+        let (dir, mut manager) = make_manager();
+        let log = make_log();
+        manager.save_log(&log).unwrap();
+        let mut file = OpenOptions::new()
+            .append(true)
+            .open(manager.log_path("test-log"))
+            .unwrap();
+        file.write_all(b"<CALL:6>KD9").unwrap();
+
+        assert!(matches!(
+            manager.lock_log("test-log"),
+            Err(StorageError::DamagedLog { .. })
+        ));
+        assert_eq!(manager.locked_log_id(), None);
+        other_manager(&dir).lock_log("test-log").unwrap_err();
+    }
+
+    #[test]
+    fn unlocked_logs_are_not_watched() {
+        let (_dir, mut manager) = make_manager();
+        let log = make_log();
+        manager.save_log(&log).unwrap();
+        manager.lock_log("test-log").unwrap();
+        manager.unlock_log();
+        append_externally(&manager, &log);
+        manager.save_log(&log).unwrap();
+    }
+
+    #[test]
+    fn deleted_file_is_left_to_the_write() {
+        let (_dir, mut manager) = make_manager();
+        let log = make_log();
+        manager.save_log(&log).unwrap();
+        manager.lock_log("test-log").unwrap();
+        fs::remove_file(manager.log_path("test-log")).unwrap();
+        manager.check_unmodified("test-log").unwrap();
+        assert!(matches!(
+            manager.append_qso(&log, &make_qso()),
+            Err(StorageError::Io(_))
+        ));
+    }
+
+    // --- Callsign index tests ---
+
+    #[test]
+    fn worked_before_finds_qsos_in_every_log() {
+        let (_dir, manager) = make_manager();
+        let mut a = make_log_with_id("a", 2025);
+        a.add_qso(make_qso());
+        manager.save_log(&a).unwrap();
+        let mut b = make_log_with_id("b", 2026);
+        b.add_qso(make_qso());
+        b.add_qso(make_p2p_qso());
+        manager.save_log(&b).unwrap();
+
+        let entries = manager.worked_before("kd9xyz").unwrap();
+        let mut ids: Vec<_> = entries.iter().map(|e| e.log_id.as_str()).collect();
+        ids.sort_unstable();
+        assert_eq!(ids, vec!["a", "b"]);
+        assert!(manager.worked_before("W9NONE").unwrap().is_empty());
+    }
+
+    #[test]
+    fn worked_before_follows_appends_saves_and_deletes() {
+        let (_dir, manager) = make_manager();
+        let mut log = make_log();
+        manager.save_log(&log).unwrap();
+        assert!(manager.worked_before("KD9XYZ").unwrap().is_empty());
+
+        let qso = make_qso();
+        manager.append_qso(&log, &qso).unwrap();
+        log.add_qso(qso);
+        assert_eq!(manager.worked_before("KD9XYZ").unwrap().len(), 1);
+
+        log.header_mut().qsos[0].their_call = "N0CALL".into();
+        manager.save_log(&log).unwrap();
+        assert!(manager.worked_before("KD9XYZ").unwrap().is_empty());
+        assert_eq!(manager.worked_before("N0CALL").unwrap().len(), 1);
+
+        manager.delete_log("test-log").unwrap();
+        assert!(manager.worked_before("N0CALL").unwrap().is_empty());
+    }
+
+    #[test]
+    fn search_qsos_filters_every_log() {
+        let (_dir, manager) = make_manager();
+        let mut a = make_log_with_id("a", 2025);
+        a.add_qso(make_qso());
+        a.add_qso(make_p2p_qso());
+        manager.save_log(&a).unwrap();
+        let mut b = make_log_with_id("b", 2026);
+        b.add_qso(make_p2p_qso());
+        manager.save_log(&b).unwrap();
+
+        let query = QsoQuery {
+            park: Some("k-1234".into()),
+            ..QsoQuery::default()
+        };
+        let hits = manager.search_qsos(&query).unwrap();
+        assert_eq!(hits.len(), 2);
+        assert!(hits.iter().all(|h| h.qso.their_call == "N0CALL"));
+    }
+
+    #[test]
+    fn worked_before_drops_archived_logs() {
+        let (_dir, manager) = make_manager();
+        let mut log = make_log();
+        log.add_qso(make_qso());
+        manager.save_log(&log).unwrap();
+        assert_eq!(manager.worked_before("KD9XYZ").unwrap().len(), 1);
+        manager.archive_log("test-log").unwrap();
+        assert!(manager.worked_before("KD9XYZ").unwrap().is_empty());
+    }
+
     // --- Delete tests ---
 
     #[test]
@@ -792,6 +1649,23 @@ mod tests {
         assert_eq!(loaded.park_ref(), Some("K-0001"));
     }
 
+    #[test]
+    fn jsonl_frequency_in_khz_migrates_to_hz() {
+        let (dir, _manager) = make_manager();
+        let json = r#"{"station_callsign":"W1AW","operator":"W1AW","park_ref":"K-0001","grid_square":"FN31","created_at":"2026-02-16T12:00:00Z","log_id":"compat-freq"}"#;
+        let mut qso = make_qso();
+        qso.frequency = Some(14_225);
+        let qso_json = serde_json::to_string(&qso).unwrap();
+        fs::write(
+            dir.path().join("compat-freq.jsonl"),
+            format!("{json}\n{qso_json}\n"),
+        )
+        .unwrap();
+        let manager2 = LogManager::with_path(dir.path()).unwrap();
+        let loaded = manager2.load_log("compat-freq").unwrap();
+        assert_eq!(loaded.header().qsos[0].frequency, Some(14_225_000));
+    }
+
     #[test]
     fn fd_jsonl_with_valid_section_migrates_to_adif() {
         // Exercises the !s.is_empty() filter in reconstruct_field_day.
@@ -1007,13 +1881,12 @@ mod tests {
     }
 
     #[test]
-    fn create_log_propagates_list_logs_error() {
+    fn create_log_ignores_unreadable_files() {
         let (dir, manager) = make_manager();
         let log = make_pota_log_for_today("new");
-        // Write a corrupt ADIF file (no records → Adif error)
         fs::write(dir.path().join("corrupt.adif"), "").unwrap();
-        let result = manager.create_log(&log);
-        assert!(matches!(result, Err(StorageError::Adif(_))));
+        manager.create_log(&log).unwrap();
+        assert!(manager.log_path("new").exists());
     }
 
     #[test]
@@ -1273,4 +2146,142 @@ mod tests {
         let loaded = manager.load_log("W1AW/P-20260216-120000").unwrap();
         assert_eq!(loaded.header().log_id, "W1AW/P-20260216-120000");
     }
+
+    // --- Merge and archive ---
+
+    fn save_fd_with_qsos(manager: &LogManager, id: &str, qsos: &[Qso]) {
+        let mut log = make_fd_log_for_today(id);
+        for qso in qsos {
+            log.add_qso(qso.clone());
+        }
+        manager.save_log(&log).unwrap();
+    }
+
+    fn ids(ids: &[&str]) -> Vec<String> {
+        ids.iter().map(|id| id.to_string()).collect()
+    }
+
+    #[test]
+    fn merge_logs_saves_combined_log_and_keeps_sources() {
+        let (_dir, manager) = make_manager();
+        save_fd_with_qsos(&manager, "fd-a", &[make_qso()]);
+        save_fd_with_qsos(&manager, "fd-b", &[make_qso(), make_p2p_qso()]);
+
+        let merged = manager.merge_logs(&ids(&["fd-a", "fd-b"]), false).unwrap();
+        assert_eq!(merged.duplicates, 1);
+
+        let loaded = manager.load_log(&merged.log.header().log_id).unwrap();
+        assert_eq!(loaded.qsos().len(), 2);
+        assert_eq!(manager.list_logs().unwrap().len(), 3);
+    }
+
+    #[test]
+    fn merge_logs_with_archive_moves_sources() {
+        let (dir, manager) = make_manager();
+        save_fd_with_qsos(&manager, "fd-a", &[make_qso()]);
+        save_fd_with_qsos(&manager, "fd-b", &[make_p2p_qso()]);
+
+        let merged = manager.merge_logs(&ids(&["fd-a", "fd-b"]), true).unwrap();
+        let logs = manager.list_logs().unwrap();
+        assert_eq!(logs.len(), 1);
+        assert_eq!(logs[0].header().log_id, merged.log.header().log_id);
+        assert!(dir.path().join("archive").join("fd-a.adif").is_file());
+        assert!(dir.path().join("archive").join("fd-b.adif").is_file());
+    }
+
+    #[test]
+    fn merge_logs_rejects_incompatible_logs_without_writing() {
+        let (_dir, manager) = make_manager();
+        save_fd_with_qsos(&manager, "fd-a", &[make_qso()]);
+        manager.save_log(&make_wfd_log_for_today("wfd-b")).unwrap();
+
+        let err = manager
+            .merge_logs(&ids(&["fd-a", "wfd-b"]), true)
+            .unwrap_err();
+        assert!(matches!(err, StorageError::IncompatibleLogs(_)));
+        assert_eq!(manager.list_logs().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn merge_logs_still_rejects_duplicate_of_unrelated_log() {
+        let (_dir, manager) = make_manager();
+        save_fd_with_qsos(&manager, "fd-a", &[make_qso()]);
+        save_fd_with_qsos(&manager, "fd-b", &[make_p2p_qso()]);
+        save_fd_with_qsos(&manager, "fd-c", &[]);
+
+        let err = manager
+            .merge_logs(&ids(&["fd-a", "fd-b"]), true)
+            .unwrap_err();
+        assert!(matches!(err, StorageError::DuplicateLog { .. }));
+        assert_eq!(
+            manager.list_logs().unwrap().len(),
+            3,
+            "sources not archived"
+        );
+    }
+
+    #[test]
+    fn merge_logs_missing_source_returns_error() {
+        let (_dir, manager) = make_manager();
+        save_fd_with_qsos(&manager, "fd-a", &[make_qso()]);
+        assert!(matches!(
+            manager.merge_logs(&ids(&["fd-a", "missing"]), false),
+            Err(StorageError::Adif(_) | StorageError::Io(_))
+        ));
+    }
+
+    #[test]
+    fn archive_log_hides_log_from_list() {
+        let (_dir, manager) = make_manager();
+        manager.save_log(&make_log()).unwrap();
+        manager.archive_log("test-log").unwrap();
+        assert!(manager.list_logs().unwrap().is_empty());
+    }
+
+    #[test]
+    fn archive_missing_log_returns_error() {
+        let (_dir, manager) = make_manager();
+        assert!(matches!(
+            manager.archive_log("missing"),
+            Err(StorageError::Io(_))
+        ));
+    }
+
+    #[test]
+    fn list_columns_default_to_empty() {
+        let (_dir, manager) = make_manager();
+        assert!(manager.load_list_columns().unwrap().is_empty());
+    }
+
+    #[test]
+    fn list_columns_round_trip() {
+        let (_dir, manager) = make_manager();
+        let columns = BTreeMap::from([
+            (
+                "POTA".to_string(),
+                vec!["call".to_string(), "park".to_string()],
+            ),
+            ("FD".to_string(), vec!["exchange".to_string()]),
+        ]);
+        manager.save_list_columns(&columns).unwrap();
+        assert_eq!(manager.load_list_columns().unwrap(), columns);
+    }
+
+    #[test]
+    fn list_columns_file_is_not_listed_as_log() {
+        let (_dir, manager) = make_manager();
+        manager.save_log(&make_log()).unwrap();
+        manager.save_list_columns(&BTreeMap::new()).unwrap();
+        assert_eq!(manager.list_logs().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn corrupt_list_columns_returns_error() {
+        let (dir, manager) = make_manager();
+        fs::write(dir.path().join(LIST_COLUMNS_FILE), "not json").unwrap();
+        assert!(matches!(
+            manager.load_list_columns(),
+            Err(StorageError::Json(_))
+        ));
+    }
 }
@@ -0,0 +1,531 @@
+//! Combining several logs of the same event into one.
+
+use chrono::{TimeDelta, Utc};
+
+use super::error::StorageError;
+use super::manager::log_config_eq;
+use crate::model::{Log, Qso};
+
+/// How far apart two otherwise identical QSOs may be and still count as one
+/// contact — allows for clock drift between the laptops that logged them.
+pub const MERGE_DUPLICATE_WINDOW: TimeDelta = TimeDelta::minutes(2);
+
+/// The result of [`merge_logs`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct MergedLog {
+    /// The combined log, with a fresh log ID and QSOs in timestamp order.
+    pub log: Log,
+    /// Number of duplicate QSOs dropped.
+    pub duplicates: usize,
+}
+
+/// Combines the QSOs of `sources` into a new log.
+///
+/// All sources must be the same log type with the same station callsign and
+/// type-specific settings (park reference, class, section, ...). The new log
+/// takes its settings and creation date from the oldest source. QSOs are
+/// sorted by timestamp; a QSO with the same callsign (case-insensitive), band
+/// and mode as a kept QSO no more than `window` earlier is dropped as a
+/// duplicate. QSOs inherit their source log's operator when it differs from
+/// the new log's, so multi-op attribution survives the merge.
+pub fn merge_logs(sources: &[Log], window: TimeDelta) -> Result<MergedLog, StorageError> {
+    let [first, rest @ ..] = sources else {
+        return Err(too_few_logs());
+    };
+    if rest.is_empty() {
+        return Err(too_few_logs());
+    }
+    for other in rest {
+        check_compatible(first, other)?;
+    }
+
+    let oldest = sources
+        .iter()
+        .min_by_key(|log| log.header().created_at)
+        .unwrap_or(first);
+    let mut merged = oldest.clone();
+    merged.header_mut().log_id = format!(
+        "{}-merged-{}",
+        oldest.header().log_id,
+        Utc::now().format("%Y%m%d-%H%M%S")
+    );
+    let default_operator = merged.header().operator.clone();
+
+    let mut qsos: Vec<Qso> = sources
+        .iter()
+        .flat_map(|source| {
+            let default_operator = default_operator.as_deref();
+            source.header().qsos.iter().map(move |qso| {
+                let mut qso = qso.clone();
+                if qso.operator.is_none() && source.header().operator.as_deref() != default_operator
+                {
+                    qso.operator = Some(source.qso_operator(&qso).to_string());
+                }
+                qso
+            })
+        })
+        .collect();
+    qsos.sort_by_key(|qso| qso.timestamp);
+
+    let total = qsos.len();
+    let mut kept: Vec<Qso> = Vec::with_capacity(total);
+    for qso in qsos {
+        let duplicate = kept
+            .iter()
+            .rev()
+            .take_while(|k| qso.timestamp - k.timestamp <= window)
+            .any(|k| is_same_contact(k, &qso));
+        if !duplicate {
+            kept.push(qso);
+        }
+    }
+    let duplicates = total - kept.len();
+    merged.header_mut().qsos = kept;
+    Ok(MergedLog {
+        log: merged,
+        duplicates,
+    })
+}
+
+/// Combines the active log as held in memory with its file after another
+/// program changed it.
+///
+/// `last_read` is the file as duklog last read or wrote it. The file's
+/// version wins: its header and every QSO in it are kept as they are, so hand
+/// edits survive, and QSOs deleted from it stay deleted. Only QSOs added in
+/// memory since `last_read` (by sync ID, or by callsign, band, mode and
+/// timestamp) that are not in the file are added, in timestamp order.
+pub fn merge_disk_changes(on_disk: &Log, in_memory: &Log, last_read: &Log) -> Log {
+    let mut merged = on_disk.clone();
+    let is_in = |log: &Log, qso: &Qso| log.header().qsos.iter().any(|q| is_same_qso(q, qso));
+    let missing: Vec<Qso> = in_memory
+        .header()
+        .qsos
+        .iter()
+        .filter(|qso| !is_in(last_read, qso) && !is_in(on_disk, qso))
+        .cloned()
+        .collect();
+    merged.header_mut().qsos.extend(missing);
+    merged.header_mut().qsos.sort_by_key(|qso| qso.timestamp);
+    merged
+}
+
+fn too_few_logs() -> StorageError {
+    StorageError::IncompatibleLogs("at least two logs are needed".into())
+}
+
+/// Returns an error naming the first setting in which `other` differs from `first`.
+fn check_compatible(first: &Log, other: &Log) -> Result<(), StorageError> {
+    let describe = |log: &Log| format!("{} {}", log.log_type_name(), log.display_label());
+    if first.log_type_name() != other.log_type_name() {
+        return Err(StorageError::IncompatibleLogs(format!(
+            "{} and {} are different log types",
+            describe(first),
+            describe(other)
+        )));
+    }
+    if !first
+        .header()
+        .station_callsign
+        .eq_ignore_ascii_case(&other.header().station_callsign)
+    {
+        return Err(StorageError::IncompatibleLogs(format!(
+            "{} and {} have different station callsigns",
+            first.header().station_callsign,
+            other.header().station_callsign
+        )));
+    }
+    if !log_config_eq(first, other) {
+        return Err(StorageError::IncompatibleLogs(format!(
+            "{} and {} have different log settings",
+            describe(first),
+            describe(other)
+        )));
+    }
+    Ok(())
+}
+
+/// Returns `true` if `a` and `b` are the same callsign (case-insensitive), band and mode.
+fn is_same_contact(a: &Qso, b: &Qso) -> bool {
+    a.their_call.eq_ignore_ascii_case(&b.their_call) && a.band == b.band && a.mode == b.mode
+}
+
+/// Returns `true` if `a` and `b` are one QSO: the same sync ID, or the same
+/// contact at the same time.
+fn is_same_qso(a: &Qso, b: &Qso) -> bool {
+    match (&a.id, &b.id) {
+        (Some(a_id), Some(b_id)) => a_id == b_id,
+        _ => is_same_contact(a, b) && a.timestamp == b.timestamp,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, TimeZone};
+
+    use super::*;
+    use crate::model::{Band, FdClass, FdPowerCategory, FieldDayLog, GeneralLog, Mode, PotaLog};
+
+    fn at(hour: u32, minute: u32, second: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 6, 27, hour, minute, second)
+            .unwrap()
+    }
+
+    fn make_fd_log(id: &str, operator: Option<&str>, created_hour: u32) -> Log {
+        let mut log = FieldDayLog::new(
+            "W1AW".to_string(),
+            operator.map(String::from),
+            2,
+            FdClass::A,
+            "EPA".to_string(),
+            FdPowerCategory::Low,
+            "FN31".to_string(),
+        )
+        .unwrap();
+        log.header.log_id = id.to_string();
+        log.header.created_at = at(created_hour, 0, 0);
+        Log::FieldDay(log)
+    }
+
+    fn make_qso(call: &str, band: Band, timestamp: DateTime<Utc>) -> Qso {
+        Qso::new(
+            call.to_string(),
+            "59".to_string(),
+            "59".to_string(),
+            band,
+            Mode::Ssb,
+            timestamp,
+            String::new(),
+            None,
+            Some("3A CT".to_string()),
+            None,
+            None,
+        )
+        .unwrap()
+    }
+
+    fn with_qsos(mut log: Log, qsos: Vec<Qso>) -> Log {
+        for qso in qsos {
+            log.add_qso(qso);
+        }
+        log
+    }
+
+    fn calls(log: &Log) -> Vec<&str> {
+        log.qsos().iter().map(|q| q.their_call.as_str()).collect()
+    }
+
+    mod combining {
+        use super::*;
+
+        #[test]
+        fn sorts_qsos_by_timestamp() {
+            let a = with_qsos(
+                make_fd_log("a", None, 17),
+                vec![
+                    make_qso("K1AAA", Band::M20, at(18, 0, 0)),
+                    make_qso("K1CCC", Band::M20, at(20, 0, 0)),
+                ],
+            );
+            let b = with_qsos(
+                make_fd_log("b", None, 17),
+                vec![make_qso("K1BBB", Band::M40, at(19, 0, 0))],
+            );
+            let merged = merge_logs(&[a, b], MERGE_DUPLICATE_WINDOW).unwrap();
+            assert_eq!(calls(&merged.log), ["K1AAA", "K1BBB", "K1CCC"]);
+            assert_eq!(merged.duplicates, 0);
+        }
+
+        #[test]
+        fn takes_settings_from_oldest_source() {
+            let newer = make_fd_log("newer", None, 18);
+            let older = make_fd_log("older", None, 16);
+            let merged = merge_logs(&[newer, older], MERGE_DUPLICATE_WINDOW).unwrap();
+            assert_eq!(merged.log.header().created_at, at(16, 0, 0));
+            assert!(merged.log.header().log_id.starts_with("older-merged-"));
+        }
+
+        #[test]
+        fn merges_more_than_two_logs() {
+            let logs: Vec<Log> = ["K1AAA", "K1BBB", "K1CCC"]
+                .iter()
+                .enumerate()
+                .map(|(i, call)| {
+                    with_qsos(
+                        make_fd_log(call, None, 17),
+                        vec![make_qso(call, Band::M20, at(18, i as u32, 0))],
+                    )
+                })
+                .collect();
+            let merged = merge_logs(&logs, MERGE_DUPLICATE_WINDOW).unwrap();
+            assert_eq!(merged.log.qsos().len(), 3);
+        }
+
+        #[test]
+        fn source_operator_is_kept_per_qso() {
+            let a = with_qsos(
+                make_fd_log("a", Some("K1ABC"), 17),
+                vec![make_qso("K1AAA", Band::M20, at(18, 0, 0))],
+            );
+            let b = with_qsos(
+                make_fd_log("b", Some("N0OPR"), 17),
+                vec![make_qso("K1BBB", Band::M40, at(19, 0, 0))],
+            );
+            let merged = merge_logs(&[a, b], MERGE_DUPLICATE_WINDOW).unwrap();
+            let log = &merged.log;
+            assert_eq!(log.header().operator.as_deref(), Some("K1ABC"));
+            assert_eq!(log.qsos()[0].operator, None, "matches the log operator");
+            assert_eq!(log.qsos()[1].operator.as_deref(), Some("N0OPR"));
+            assert_eq!(log.qso_operator(&log.qsos()[1]), "N0OPR");
+        }
+
+        #[test]
+        fn source_without_operator_attributes_station_callsign() {
+            let a = make_fd_log("a", Some("K1ABC"), 17);
+            let b = with_qsos(
+                make_fd_log("b", None, 17),
+                vec![make_qso("K1BBB", Band::M40, at(19, 0, 0))],
+            );
+            let merged = merge_logs(&[a, b], MERGE_DUPLICATE_WINDOW).unwrap();
+            assert_eq!(merged.log.qsos()[0].operator.as_deref(), Some("W1AW"));
+        }
+    }
+
+    mod duplicates {
+        use super::*;
+
+        #[test]
+        fn drops_same_contact_within_window() {
+            let a = with_qsos(
+                make_fd_log("a", None, 17),
+                vec![make_qso("K1AAA", Band::M20, at(18, 0, 0))],
+            );
+            let b = with_qsos(
+                make_fd_log("b", None, 17),
+                vec![make_qso("k1aaa", Band::M20, at(18, 1, 30))],
+            );
+            let merged = merge_logs(&[a, b], MERGE_DUPLICATE_WINDOW).unwrap();
+            assert_eq!(calls(&merged.log), ["K1AAA"]);
+            assert_eq!(merged.duplicates, 1);
+        }
+
+        #[test]
+        fn keeps_same_contact_outside_window() {
+            let a = with_qsos(
+                make_fd_log("a", None, 17),
+                vec![make_qso("K1AAA", Band::M20, at(18, 0, 0))],
+            );
+            let b = with_qsos(
+                make_fd_log("b", None, 17),
+                vec![make_qso("K1AAA", Band::M20, at(18, 2, 1))],
+            );
+            let merged = merge_logs(&[a, b], MERGE_DUPLICATE_WINDOW).unwrap();
+            assert_eq!(merged.log.qsos().len(), 2);
+            assert_eq!(merged.duplicates, 0);
+        }
+
+        #[test]
+        fn keeps_same_call_on_other_band() {
+            let a = with_qsos(
+                make_fd_log("a", None, 17),
+                vec![make_qso("K1AAA", Band::M20, at(18, 0, 0))],
+            );
+            let b = with_qsos(
+                make_fd_log("b", None, 17),
+                vec![make_qso("K1AAA", Band::M40, at(18, 0, 0))],
+            );
+            let merged = merge_logs(&[a, b], MERGE_DUPLICATE_WINDOW).unwrap();
+            assert_eq!(merged.log.qsos().len(), 2);
+        }
+
+        #[test]
+        fn drops_identical_copies_of_shared_log() {
+            let qsos = vec![
+                make_qso("K1AAA", Band::M20, at(18, 0, 0)),
+                make_qso("K1BBB", Band::M20, at(18, 5, 0)),
+            ];
+            let a = with_qsos(make_fd_log("a", None, 17), qsos.clone());
+            let b = with_qsos(make_fd_log("b", None, 17), qsos);
+            let merged = merge_logs(&[a, b], MERGE_DUPLICATE_WINDOW).unwrap();
+            assert_eq!(calls(&merged.log), ["K1AAA", "K1BBB"]);
+            assert_eq!(merged.duplicates, 2);
+        }
+
+        #[test]
+        fn zero_window_drops_only_exact_timestamps() {
+            let a = with_qsos(
+                make_fd_log("a", None, 17),
+                vec![
+                    make_qso("K1AAA", Band::M20, at(18, 0, 0)),
+                    make_qso("K1AAA", Band::M20, at(18, 0, 1)),
+                ],
+            );
+            let b = with_qsos(
+                make_fd_log("b", None, 17),
+                vec![make_qso("K1AAA", Band::M20, at(18, 0, 0))],
+            );
+            let merged = merge_logs(&[a, b], TimeDelta::zero()).unwrap();
+            assert_eq!(merged.log.qsos().len(), 2);
+            assert_eq!(merged.duplicates, 1);
+        }
+    }
+
+    mod disk_changes {
+        use super::*;
+
+        #[test]
+        fn keeps_disk_edits_and_adds_memory_only_qsos() {
+            let base = make_fd_log("fd", None, 0);
+            let mut edited = make_qso("W1AW", Band::M20, at(14, 0, 0));
+            edited.comments = "fixed by hand".into();
+            let on_disk = with_qsos(
+                base.clone(),
+                vec![edited, make_qso("K1ABC", Band::M40, at(16, 0, 0))],
+            );
+            let last_read = with_qsos(
+                base.clone(),
+                vec![make_qso("W1AW", Band::M20, at(14, 0, 0))],
+            );
+            let in_memory = with_qsos(
+                base,
+                vec![
+                    make_qso("W1AW", Band::M20, at(14, 0, 0)),
+                    make_qso("N0CALL", Band::M20, at(15, 0, 0)),
+                ],
+            );
+
+            let merged = merge_disk_changes(&on_disk, &in_memory, &last_read);
+            assert_eq!(calls(&merged), vec!["W1AW", "N0CALL", "K1ABC"]);
+            assert_eq!(merged.header().qsos[0].comments, "fixed by hand");
+            assert_eq!(merged.header().log_id, "fd");
+        }
+
+        #[test]
+        fn qso_deleted_on_disk_is_not_resurrected() {
+            let base = make_fd_log("fd", None, 0);
+            let saved = vec![
+                make_qso("W1AW", Band::M20, at(14, 0, 0)),
+                make_qso("K1ABC", Band::M40, at(15, 0, 0)),
+            ];
+            let last_read = with_qsos(base.clone(), saved.clone());
+            let on_disk = with_qsos(base.clone(), vec![saved[1].clone()]);
+            let in_memory = with_qsos(base, saved);
+
+            let merged = merge_disk_changes(&on_disk, &in_memory, &last_read);
+            assert_eq!(calls(&merged), vec!["K1ABC"]);
+        }
+
+        #[test]
+        fn qso_corrected_by_hand_is_not_duplicated() {
+            let base = make_fd_log("fd", None, 0);
+            let saved = make_qso("W1AX", Band::M20, at(14, 0, 0));
+            let last_read = with_qsos(base.clone(), vec![saved.clone()]);
+            let on_disk = with_qsos(
+                base.clone(),
+                vec![make_qso("W1AW", Band::M20, at(14, 0, 0))],
+            );
+            let in_memory = with_qsos(base, vec![saved]);
+
+            let merged = merge_disk_changes(&on_disk, &in_memory, &last_read);
+            assert_eq!(calls(&merged), vec!["W1AW"]);
+        }
+
+        #[test]
+        fn matches_by_sync_id_when_both_have_one() {
+            let base = make_fd_log("fd", None, 0);
+            let mut disk_qso = make_qso("W1AW", Band::M20, at(14, 0, 0));
+            disk_qso.id = Some("a:1".into());
+            let mut moved = disk_qso.clone();
+            moved.timestamp = at(14, 5, 0);
+            let mut other = disk_qso.clone();
+            other.id = Some("a:2".into());
+
+            let merged = merge_disk_changes(
+                &with_qsos(base.clone(), vec![disk_qso]),
+                &with_qsos(base.clone(), vec![moved, other]),
+                &base,
+            );
+            assert_eq!(merged.header().qsos.len(), 2);
+            assert_eq!(merged.header().qsos[0].timestamp, at(14, 0, 0));
+        }
+    }
+
+    mod compatibility {
+        use super::*;
+
+        fn make_pota_log(park_ref: &str) -> Log {
+            Log::Pota(
+                PotaLog::new(
+                    "W1AW".to_string(),
+                    None,
+                    park_ref.to_string(),
+                    "FN31".to_string(),
+                )
+                .unwrap(),
+            )
+        }
+
+        #[test]
+        fn needs_two_logs() {
+            let err =
+                merge_logs(&[make_fd_log("a", None, 17)], MERGE_DUPLICATE_WINDOW).unwrap_err();
+            assert!(matches!(err, StorageError::IncompatibleLogs(_)));
+            assert!(matches!(
+                merge_logs(&[], MERGE_DUPLICATE_WINDOW),
+                Err(StorageError::IncompatibleLogs(_))
+            ));
+        }
+
+        #[test]
+        fn rejects_different_types() {
+            let general = Log::General(
+                GeneralLog::new("W1AW".to_string(), None, "FN31".to_string()).unwrap(),
+            );
+            let err = merge_logs(
+                &[make_fd_log("a", None, 17), general],
+                MERGE_DUPLICATE_WINDOW,
+            )
+            .unwrap_err();
+            assert!(err.to_string().contains("different log types"), "{err}");
+        }
+
+        #[test]
+        fn rejects_different_station_callsigns() {
+            let other = Log::Pota(
+                PotaLog::new(
+                    "K1ABC".to_string(),
+                    None,
+                    "K-0001".to_string(),
+                    "FN31".to_string(),
+                )
+                .unwrap(),
+            );
+            let err =
+                merge_logs(&[make_pota_log("K-0001"), other], MERGE_DUPLICATE_WINDOW).unwrap_err();
+            assert!(
+                err.to_string().contains("different station callsigns"),
+                "{err}"
+            );
+        }
+
+        #[test]
+        fn rejects_different_parks() {
+            let err = merge_logs(
+                &[make_pota_log("K-0001"), make_pota_log("K-0002")],
+                MERGE_DUPLICATE_WINDOW,
+            )
+            .unwrap_err();
+            assert!(err.to_string().contains("different log settings"), "{err}");
+        }
+
+        #[test]
+        fn accepts_same_park_case_insensitively() {
+            let mut lower = make_pota_log("K-0001");
+            if let Log::Pota(ref mut pota) = lower {
+                pota.park_ref = "k-0001".to_string();
+            }
+            let merged = merge_logs(&[make_pota_log("K-0001"), lower], MERGE_DUPLICATE_WINDOW);
+            assert!(merged.is_ok());
+        }
+    }
+}
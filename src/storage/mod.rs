@@ -1,13 +1,24 @@
-//! Log persistence (ADIF) and file export.
+//! Log persistence (ADIF) and file export (ADIF, CSV, JSON).
 //!
 //! Each log is stored as a single `.adif` file. The ADIF header encodes all
 //! log metadata; subsequent records encode individual QSOs. Appending a QSO
-//! is an O(1) file append — no read or rewrite required.
+//! is an O(1) file append — no read or rewrite required. A file whose end was
+//! damaged by a crash is still listed, and can be repaired by cutting off the
+//! damaged records. The active log is locked so that two duklog processes
+//! cannot write the same file, and writes to it refuse to overwrite changes
+//! made to the file by other programs. Every QSO is indexed by callsign so
+//! earlier contacts with a station can be looked up across logs.
 
 mod error;
 mod export;
+mod index;
+mod lock;
 mod manager;
+mod merge;
+mod stamp;
 
 pub use error::StorageError;
-pub use export::{default_export_path, export_adif};
-pub use manager::LogManager;
+pub use export::{default_export_path, export_adif, export_log};
+pub use index::WorkedEntry;
+pub use manager::{LogFileProblem, LogListing, LogManager};
+pub use merge::{MERGE_DUPLICATE_WINDOW, MergedLog, merge_disk_changes, merge_logs};
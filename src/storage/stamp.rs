@@ -0,0 +1,67 @@
+//! Detection of changes made to a log file by other programs.
+//!
+//! A [`FileStamp`] records a file's size and modification time. Comparing the
+//! stamp taken when duklog last read or wrote the active log with the file's
+//! current stamp shows whether an editor or another tool has changed it since.
+
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::time::SystemTime;
+
+/// The size and modification time of a file at one moment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) struct FileStamp {
+    modified: SystemTime,
+    len: u64,
+}
+
+impl FileStamp {
+    /// Reads the stamp of the file at `path`, or `None` if it does not exist.
+    pub(super) fn read(path: &Path) -> io::Result<Option<Self>> {
+        match fs::metadata(path) {
+            Ok(meta) => Ok(Some(Self {
+                modified: meta.modified()?,
+                len: meta.len(),
+            })),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::File;
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn missing_file_has_no_stamp() {
+        let dir = tempfile::tempdir().unwrap();
+        assert_eq!(
+            FileStamp::read(&dir.path().join("none.adif")).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn stamp_changes_with_size_and_mtime() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("log.adif");
+        fs::write(&path, "abc").unwrap();
+        let before = FileStamp::read(&path).unwrap().unwrap();
+        assert_eq!(FileStamp::read(&path).unwrap(), Some(before));
+
+        fs::write(&path, "abcd").unwrap();
+        assert_ne!(FileStamp::read(&path).unwrap(), Some(before));
+
+        // Same size, different modification time.
+        fs::write(&path, "abc").unwrap();
+        let file = File::options().write(true).open(&path).unwrap();
+        file.set_modified(SystemTime::UNIX_EPOCH + Duration::from_secs(1))
+            .unwrap();
+        assert_ne!(FileStamp::read(&path).unwrap(), Some(before));
+    }
+}
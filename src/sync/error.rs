@@ -0,0 +1,15 @@
+/// Errors that can occur in LAN sync.
+#[derive(Debug, thiserror::Error)]
+pub enum SyncError {
+    /// An I/O error occurred while binding or connecting a socket.
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// A configured listen or peer address could not be resolved.
+    #[error("invalid sync address {0:?}")]
+    InvalidAddress(String),
+
+    /// A received line is not a valid sync message.
+    #[error("invalid sync message: {0}")]
+    InvalidMessage(#[from] serde_json::Error),
+}
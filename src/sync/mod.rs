@@ -0,0 +1,16 @@
+//! LAN sync between duklog instances logging to the same log.
+//!
+//! Stations exchange newline-delimited JSON messages over TCP. Each QSO has a
+//! stable ID; adds, edits and deletes are versioned with a Lamport clock and
+//! merged last-writer-wins, so every connected station converges on the same
+//! combined log. See `docs/adr/0006-lan-sync.md` for the design.
+
+mod error;
+mod node;
+mod protocol;
+mod replica;
+
+pub use error::SyncError;
+pub use node::{SyncNode, SyncUpdate};
+pub use protocol::{Entry, Message, Version};
+pub use replica::{MergeOutcome, Replica, assign_id, ensure_ids};
@@ -0,0 +1,418 @@
+//! TCP transport for LAN sync.
+//!
+//! Each connection runs a reader thread that decodes lines into events on a
+//! channel; the UI thread drains the channel with [`SyncNode::poll`], so the
+//! log itself is only ever touched from one thread.
+
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, ErrorKind, Read, Write};
+use std::net::{Shutdown, SocketAddr, TcpListener, TcpStream, ToSocketAddrs};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use super::error::SyncError;
+use super::protocol::{Entry, Message};
+use super::replica::{Replica, ensure_ids};
+use crate::model::{Log, Qso};
+
+/// How long to wait between attempts to reach an unreachable peer.
+const RECONNECT_DELAY: Duration = Duration::from_millis(500);
+
+/// How long the listener sleeps when no connection is pending.
+const ACCEPT_DELAY: Duration = Duration::from_millis(50);
+
+/// Timeout for connecting to a peer and for each write.
+const IO_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Longest message line accepted from a peer, in bytes. A snapshot of a log
+/// with tens of thousands of QSOs fits; a peer sending a longer line is
+/// dropped rather than buffered without bound.
+const MAX_LINE_LEN: usize = 32 * 1024 * 1024;
+
+/// Something that happened on a connection, reported to the UI thread.
+enum Event {
+    Connected(u64),
+    Received(u64, Message),
+}
+
+/// State shared between the node and its connection threads.
+struct Shared {
+    peers: Mutex<HashMap<u64, TcpStream>>,
+    next_peer: AtomicU64,
+    shutdown: AtomicBool,
+    events: Sender<Event>,
+}
+
+impl Shared {
+    fn peers(&self) -> MutexGuard<'_, HashMap<u64, TcpStream>> {
+        self.peers.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn emit(&self, event: Event) -> bool {
+        self.events.send(event).is_ok()
+    }
+
+    /// Registers `stream` as a peer and forwards its messages until it closes.
+    fn run_connection(&self, stream: TcpStream) {
+        if self.shutdown.load(Ordering::Relaxed) {
+            return;
+        }
+        let _ = stream.set_nodelay(true);
+        let _ = stream.set_write_timeout(Some(IO_TIMEOUT));
+        let Ok(writer) = stream.try_clone() else {
+            return;
+        };
+        let peer = self.next_peer.fetch_add(1, Ordering::Relaxed);
+        self.peers().insert(peer, writer);
+        if self.emit(Event::Connected(peer)) {
+            let mut reader = BufReader::new(stream);
+            let mut line = Vec::new();
+            while read_line(&mut reader, &mut line, MAX_LINE_LEN) {
+                // A malformed line is dropped rather than closing the connection
+                let Some(message) = std::str::from_utf8(&line)
+                    .ok()
+                    .and_then(|line| Message::decode(line).ok())
+                else {
+                    continue;
+                };
+                if !self.emit(Event::Received(peer, message)) {
+                    break;
+                }
+            }
+        }
+        if let Some(stream) = self.peers().remove(&peer) {
+            let _ = stream.shutdown(Shutdown::Both);
+        }
+    }
+}
+
+/// What changed during a [`SyncNode::poll`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct SyncUpdate {
+    /// `true` if the log was modified by changes from other stations.
+    pub changed: bool,
+    /// Number of local edits replaced by a concurrent edit from another station.
+    pub conflicts: usize,
+}
+
+/// A running sync endpoint: an optional listener plus outgoing peer connections.
+pub struct SyncNode {
+    replica: Replica,
+    local_addr: Option<SocketAddr>,
+    events: Receiver<Event>,
+    shared: Arc<Shared>,
+}
+
+impl std::fmt::Debug for SyncNode {
+    #[mutants::skip]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SyncNode")
+            .field("station", &self.replica.station())
+            .field("local_addr", &self.local_addr)
+            .finish_non_exhaustive()
+    }
+}
+
+impl SyncNode {
+    /// Starts listening on `listen` (if given) and connecting to each of `peers`.
+    ///
+    /// Addresses are `host:port`; listening on port `0` picks a free port (see
+    /// [`local_addr`](Self::local_addr)). Unreachable peers are retried in the
+    /// background until they come up.
+    pub fn start(listen: Option<&str>, peers: &[String]) -> Result<Self, SyncError> {
+        let (sender, events) = mpsc::channel();
+        let shared = Arc::new(Shared {
+            peers: Mutex::new(HashMap::new()),
+            next_peer: AtomicU64::new(0),
+            shutdown: AtomicBool::new(false),
+            events: sender,
+        });
+        let peer_addrs = peers
+            .iter()
+            .map(|peer| resolve(peer))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let local_addr = match listen {
+            Some(addr) => {
+                let listener = TcpListener::bind(resolve(addr)?)?;
+                listener.set_nonblocking(true)?;
+                let local_addr = listener.local_addr()?;
+                let shared = Arc::clone(&shared);
+                thread::spawn(move || accept_loop(&shared, &listener));
+                Some(local_addr)
+            }
+            None => None,
+        };
+        for addr in peer_addrs {
+            let shared = Arc::clone(&shared);
+            thread::spawn(move || connect_loop(&shared, addr));
+        }
+
+        Ok(Self {
+            replica: Replica::new(station_id()),
+            local_addr,
+            events,
+            shared,
+        })
+    }
+
+    /// Returns the address this node is listening on, if any.
+    pub fn local_addr(&self) -> Option<SocketAddr> {
+        self.local_addr
+    }
+
+    /// Returns the number of currently connected stations.
+    pub fn peer_count(&self) -> usize {
+        self.shared.peers().len()
+    }
+
+    /// Announces `log` to all stations so they exchange any QSOs either side lacks.
+    ///
+    /// Call when a log is opened. The clock is advanced past the versions saved
+    /// in the log, and QSOs without an ID are given one; returns `true` if any
+    /// were assigned and the log should be saved.
+    pub fn announce(&mut self, log: &mut Log) -> bool {
+        self.replica.load(log);
+        let assigned = ensure_ids(log);
+        self.broadcast(&self.snapshot(log), None);
+        assigned
+    }
+
+    /// Stamps a local add or edit of `qso` with a new version before it is saved.
+    pub fn stamp(&mut self, qso: &mut Qso) {
+        self.replica.stamp(qso);
+    }
+
+    /// Records a local delete of `removed` in `log` before it is saved.
+    pub fn stamp_deletion(&mut self, log: &mut Log, removed: &Qso) {
+        self.replica.stamp_deletion(log, removed);
+    }
+
+    /// Sends the stamped add, edit or delete of the QSO `id` to all stations.
+    pub fn publish(&self, log: &Log, id: &str) {
+        if let Some(entry) = self.replica.entry(log, id) {
+            self.broadcast(&changes(log, vec![entry]), None);
+        }
+    }
+
+    /// Applies everything received since the last poll to `log`.
+    ///
+    /// Messages for other logs, or received while no log is open, are dropped;
+    /// they are exchanged again when that log is announced.
+    pub fn poll(&mut self, mut log: Option<&mut Log>) -> SyncUpdate {
+        let mut update = SyncUpdate::default();
+        while let Ok(event) = self.events.try_recv() {
+            match (event, log.as_deref_mut()) {
+                (Event::Connected(peer), Some(log)) => {
+                    self.send(peer, &self.snapshot(log));
+                }
+                (Event::Received(peer, message), Some(log)) => {
+                    let outcome = self.replica.merge(log, &message);
+                    update.conflicts += outcome.conflicts;
+                    if !outcome.applied.is_empty() {
+                        update.changed = true;
+                        self.broadcast(&changes(log, outcome.applied), Some(peer));
+                    }
+                    if !outcome.reply.is_empty() {
+                        self.send(peer, &changes(log, outcome.reply));
+                    }
+                }
+                (_, None) => {}
+            }
+        }
+        update
+    }
+
+    fn snapshot(&self, log: &Log) -> Message {
+        Message::Snapshot {
+            log_id: log.header().log_id.clone(),
+            entries: self.replica.snapshot(log),
+        }
+    }
+
+    fn send(&self, peer: u64, message: &Message) {
+        let mut peers = self.shared.peers();
+        if let Some(stream) = peers.get_mut(&peer) {
+            write_line(stream, message);
+        }
+    }
+
+    fn broadcast(&self, message: &Message, except: Option<u64>) {
+        let mut peers = self.shared.peers();
+        for (_, stream) in peers.iter_mut().filter(|(id, _)| Some(**id) != except) {
+            write_line(stream, message);
+        }
+    }
+}
+
+impl Drop for SyncNode {
+    fn drop(&mut self) {
+        self.shared.shutdown.store(true, Ordering::Relaxed);
+        for stream in self.shared.peers().values() {
+            let _ = stream.shutdown(Shutdown::Both);
+        }
+    }
+}
+
+fn changes(log: &Log, entries: Vec<Entry>) -> Message {
+    Message::Changes {
+        log_id: log.header().log_id.clone(),
+        entries,
+    }
+}
+
+/// Reads the next line into `line`, without its newline.
+///
+/// Returns `false` at the end of the stream, on a read error, or when the line
+/// is longer than `max_len` bytes; at most `max_len + 1` bytes are buffered.
+fn read_line(reader: &mut impl BufRead, line: &mut Vec<u8>, max_len: usize) -> bool {
+    line.clear();
+    match reader
+        .by_ref()
+        .take(max_len as u64 + 1)
+        .read_until(b'\n', line)
+    {
+        Ok(0) | Err(_) => false,
+        Ok(_) if line.last() == Some(&b'\n') => {
+            line.pop();
+            true
+        }
+        Ok(n) => n <= max_len,
+    }
+}
+
+/// Writes one message; a failed write closes the socket so its reader cleans up.
+fn write_line(stream: &mut TcpStream, message: &Message) {
+    if stream.write_all(message.encode().as_bytes()).is_err() {
+        let _ = stream.shutdown(Shutdown::Both);
+    }
+}
+
+fn resolve(addr: &str) -> Result<SocketAddr, SyncError> {
+    addr.to_socket_addrs()
+        .ok()
+        .and_then(|mut addrs| addrs.next())
+        .ok_or_else(|| SyncError::InvalidAddress(addr.to_string()))
+}
+
+fn accept_loop(shared: &Arc<Shared>, listener: &TcpListener) {
+    while !shared.shutdown.load(Ordering::Relaxed) {
+        match listener.accept() {
+            Ok((stream, _)) => {
+                if stream.set_nonblocking(false).is_err() {
+                    continue;
+                }
+                let shared = Arc::clone(shared);
+                thread::spawn(move || shared.run_connection(stream));
+            }
+            Err(e) if e.kind() == ErrorKind::Interrupted => {}
+            Err(_) => thread::sleep(ACCEPT_DELAY),
+        }
+    }
+}
+
+fn connect_loop(shared: &Shared, addr: SocketAddr) {
+    while !shared.shutdown.load(Ordering::Relaxed) {
+        if let Ok(stream) = TcpStream::connect_timeout(&addr, IO_TIMEOUT) {
+            shared.run_connection(stream);
+        }
+        thread::sleep(RECONNECT_DELAY);
+    }
+}
+
+/// Generates an ID unique to this process run, used to break version ties.
+fn station_id() -> String {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_nanos())
+        .unwrap_or_default();
+    format!("{:x}-{:x}", std::process::id(), nanos)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn invalid_listen_address_rejected() {
+        let err = SyncNode::start(Some("not an address"), &[]).unwrap_err();
+        assert!(matches!(err, SyncError::InvalidAddress(addr) if addr == "not an address"));
+    }
+
+    #[test]
+    fn invalid_peer_address_rejected() {
+        let err = SyncNode::start(None, &["nowhere".to_string()]).unwrap_err();
+        assert!(matches!(err, SyncError::InvalidAddress(_)));
+    }
+
+    #[test]
+    fn listening_on_port_zero_reports_bound_port() {
+        let node = SyncNode::start(Some("127.0.0.1:0"), &[]).unwrap();
+        let addr = node.local_addr().unwrap();
+        assert_ne!(addr.port(), 0);
+        assert_eq!(node.peer_count(), 0);
+    }
+
+    #[test]
+    fn without_listen_address_has_no_local_addr() {
+        let node = SyncNode::start(None, &[]).unwrap();
+        assert_eq!(node.local_addr(), None);
+    }
+
+    mod read_line {
+        use std::io::Cursor;
+
+        use super::*;
+
+        fn lines(input: &str, max_len: usize) -> Vec<String> {
+            let mut reader = Cursor::new(input);
+            let mut line = Vec::new();
+            let mut lines = Vec::new();
+            while read_line(&mut reader, &mut line, max_len) {
+                lines.push(String::from_utf8(line.clone()).unwrap());
+            }
+            lines
+        }
+
+        #[test]
+        fn splits_at_newlines() {
+            assert_eq!(lines("ab\ncd\nef", 4), ["ab", "cd", "ef"]);
+        }
+
+        #[test]
+        fn accepts_line_of_max_len() {
+            assert_eq!(lines("abcd\nef\n", 4), ["abcd", "ef"]);
+        }
+
+        #[test]
+        fn stops_at_line_over_max_len() {
+            assert_eq!(lines("ab\nabcde\nef\n", 4), ["ab"]);
+            assert_eq!(lines("abcde", 4), Vec::<String>::new());
+        }
+    }
+
+    #[test]
+    fn peer_sending_overlong_line_is_dropped() {
+        let node = SyncNode::start(Some("127.0.0.1:0"), &[]).unwrap();
+        let mut peer = TcpStream::connect(node.local_addr().unwrap()).unwrap();
+        let deadline = std::time::Instant::now() + Duration::from_secs(5);
+        while node.peer_count() == 0 && std::time::Instant::now() < deadline {
+            thread::sleep(Duration::from_millis(10));
+        }
+        assert_eq!(node.peer_count(), 1);
+
+        let chunk = vec![b'x'; 1024 * 1024];
+        for _ in 0..=MAX_LINE_LEN / chunk.len() {
+            if peer.write_all(&chunk).is_err() {
+                break;
+            }
+        }
+        while node.peer_count() == 1 && std::time::Instant::now() < deadline {
+            thread::sleep(Duration::from_millis(10));
+        }
+        assert_eq!(node.peer_count(), 0);
+    }
+}
@@ -0,0 +1,238 @@
+//! Sync wire format: one JSON-encoded [`Message`] per line.
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use super::error::SyncError;
+use crate::model::Qso;
+
+/// Logical timestamp of a QSO's latest change, used for last-writer-wins merging.
+///
+/// Ordered by clock, then station ID, so every station picks the same winner.
+/// The default version (clock 0) marks a QSO that has not changed since the
+/// stations connected.
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct Version {
+    /// Lamport clock value.
+    pub clock: u64,
+    /// ID of the station that made the change.
+    pub station: String,
+}
+
+impl Version {
+    /// Parses a version stored in a log as `clock:station` (see [`Display`](fmt::Display)).
+    pub fn parse(s: &str) -> Option<Version> {
+        let (clock, station) = s.split_once(':')?;
+        Some(Version {
+            clock: clock.parse().ok()?,
+            station: station.to_string(),
+        })
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.clock, self.station)
+    }
+}
+
+/// The state of one QSO at a given version.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Entry {
+    /// The QSO's stable [`id`](Qso::id).
+    pub id: String,
+    /// Version of this state.
+    pub version: Version,
+    /// Version of the state this one was edited from; the default for a new
+    /// QSO. An entry whose base is not the receiver's version was made
+    /// without seeing the receiver's copy.
+    #[serde(default)]
+    pub base: Version,
+    /// The QSO, or `None` if it was deleted.
+    pub qso: Option<Qso>,
+}
+
+/// A message exchanged between stations.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Message {
+    /// Every QSO in a log, sent on connect and when a log is opened.
+    ///
+    /// The receiver answers with the entries the sender is missing.
+    Snapshot {
+        /// The log these entries belong to.
+        log_id: String,
+        /// All QSOs, and tombstones for deleted ones.
+        entries: Vec<Entry>,
+    },
+    /// QSOs added, edited or deleted.
+    Changes {
+        /// The log these entries belong to.
+        log_id: String,
+        /// The changed QSOs.
+        entries: Vec<Entry>,
+    },
+}
+
+impl Message {
+    /// Returns the ID of the log this message is about.
+    pub fn log_id(&self) -> &str {
+        match self {
+            Message::Snapshot { log_id, .. } | Message::Changes { log_id, .. } => log_id,
+        }
+    }
+
+    /// Returns the entries carried by this message.
+    pub fn entries(&self) -> &[Entry] {
+        match self {
+            Message::Snapshot { entries, .. } | Message::Changes { entries, .. } => entries,
+        }
+    }
+
+    /// Encodes this message as a single newline-terminated JSON line.
+    pub fn encode(&self) -> String {
+        let mut line = serde_json::to_string(self).expect("sync messages always serialize");
+        line.push('\n');
+        line
+    }
+
+    /// Decodes a message from one line of JSON.
+    pub fn decode(line: &str) -> Result<Self, SyncError> {
+        Ok(serde_json::from_str(line.trim_end())?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+
+    use super::*;
+    use crate::model::{Band, Mode};
+
+    fn make_entry(id: &str, clock: u64) -> Entry {
+        let mut qso = Qso::new(
+            "KD9XYZ".to_string(),
+            "59".to_string(),
+            "59".to_string(),
+            Band::M20,
+            Mode::Ssb,
+            Utc.with_ymd_and_hms(2026, 6, 27, 18, 0, 0).unwrap(),
+            String::new(),
+            None,
+            None,
+            Some(14_225_000),
+            Some("K1ABC".to_string()),
+        )
+        .unwrap();
+        qso.id = Some(id.to_string());
+        Entry {
+            id: id.to_string(),
+            version: Version {
+                clock,
+                station: "a".to_string(),
+            },
+            base: Version::default(),
+            qso: Some(qso),
+        }
+    }
+
+    mod version {
+        use super::*;
+
+        fn version(clock: u64, station: &str) -> Version {
+            Version {
+                clock,
+                station: station.to_string(),
+            }
+        }
+
+        #[test]
+        fn orders_by_clock_first() {
+            assert!(version(2, "a") > version(1, "z"));
+        }
+
+        #[test]
+        fn ties_broken_by_station() {
+            assert!(version(1, "b") > version(1, "a"));
+        }
+
+        #[test]
+        fn default_is_oldest() {
+            assert!(Version::default() < version(1, ""));
+        }
+
+        #[test]
+        fn round_trips_as_text() {
+            let v = version(42, "1f2e-17a0");
+            assert_eq!(v.to_string(), "42:1f2e-17a0");
+            assert_eq!(Version::parse(&v.to_string()), Some(v));
+        }
+
+        #[test]
+        fn parse_rejects_malformed_text() {
+            assert_eq!(Version::parse("42"), None);
+            assert_eq!(Version::parse("x:a"), None);
+        }
+    }
+
+    mod encoding {
+        use super::*;
+
+        #[test]
+        fn round_trips_snapshot() {
+            let msg = Message::Snapshot {
+                log_id: "FD-W1AW".to_string(),
+                entries: vec![make_entry("q1", 0), make_entry("q2", 3)],
+            };
+            assert_eq!(Message::decode(&msg.encode()).unwrap(), msg);
+        }
+
+        #[test]
+        fn round_trips_deletion() {
+            let mut entry = make_entry("q1", 4);
+            entry.qso = None;
+            let msg = Message::Changes {
+                log_id: "FD-W1AW".to_string(),
+                entries: vec![entry],
+            };
+            assert_eq!(Message::decode(&msg.encode()).unwrap(), msg);
+        }
+
+        #[test]
+        fn missing_base_decodes_as_default() {
+            let line = r#"{"type":"changes","log_id":"FD-W1AW","entries":[{"id":"q1","version":{"clock":4,"station":"a"},"qso":null}]}"#;
+            let msg = Message::decode(line).unwrap();
+            assert_eq!(msg.entries()[0].base, Version::default());
+        }
+
+        #[test]
+        fn encodes_one_line() {
+            let msg = Message::Changes {
+                log_id: "FD-W1AW".to_string(),
+                entries: vec![make_entry("q1", 1)],
+            };
+            let line = msg.encode();
+            assert!(line.ends_with('\n'));
+            assert_eq!(line.matches('\n').count(), 1);
+        }
+
+        #[test]
+        fn decode_rejects_garbage() {
+            assert!(matches!(
+                Message::decode("not json"),
+                Err(SyncError::InvalidMessage(_))
+            ));
+        }
+
+        #[test]
+        fn accessors_return_log_id_and_entries() {
+            let msg = Message::Changes {
+                log_id: "FD-W1AW".to_string(),
+                entries: vec![make_entry("q1", 1)],
+            };
+            assert_eq!(msg.log_id(), "FD-W1AW");
+            assert_eq!(msg.entries().len(), 1);
+        }
+    }
+}
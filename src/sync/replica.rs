@@ -0,0 +1,824 @@
+//! Merge state for synced logs — pure bookkeeping, no networking.
+//!
+//! Every QSO carries a stable [`id`](Qso::id). Each local change bumps a
+//! Lamport clock and stamps the QSO with a [`Version`], along with the version
+//! it was edited from; incoming entries win when their version is newer, so all
+//! stations converge on the same log.
+//!
+//! Versions live in the log itself, so they are saved with it and survive a
+//! restart: each QSO's in `APP_DUKLOG_QSO_VERSION` and `APP_DUKLOG_QSO_BASE`
+//! fields, and deleted QSOs as tombstones in the header's
+//! `APP_DUKLOG_TOMBSTONES` field.
+
+use std::collections::HashSet;
+
+use super::protocol::{Entry, Message, Version};
+use crate::model::{Log, Qso};
+
+/// QSO field holding the version of the QSO's latest change, as `clock:station`.
+const VERSION_FIELD: &str = "APP_DUKLOG_QSO_VERSION";
+
+/// QSO field holding the version the latest change was edited from.
+const BASE_FIELD: &str = "APP_DUKLOG_QSO_BASE";
+
+/// Header field listing deleted QSOs as space-separated `id=clock:station`,
+/// followed by `,clock:station` for the version that was deleted if it was not
+/// the default.
+const TOMBSTONES_FIELD: &str = "APP_DUKLOG_TOMBSTONES";
+
+/// The result of merging a [`Message`] into a log.
+#[derive(Debug, Default, PartialEq)]
+pub struct MergeOutcome {
+    /// Entries that changed the local log; relay these to other stations.
+    pub applied: Vec<Entry>,
+    /// Local entries the sender is missing or holds an older version of.
+    pub reply: Vec<Entry>,
+    /// Number of local edits replaced by a concurrent edit from another station.
+    pub conflicts: usize,
+}
+
+/// One station's Lamport clock, used to version its changes to synced logs.
+#[derive(Debug)]
+pub struct Replica {
+    station: String,
+    clock: u64,
+}
+
+impl Replica {
+    /// Creates a replica for the station with the given unique ID.
+    pub fn new(station: impl Into<String>) -> Self {
+        Self {
+            station: station.into(),
+            clock: 0,
+        }
+    }
+
+    /// Returns this station's ID.
+    pub fn station(&self) -> &str {
+        &self.station
+    }
+
+    /// Advances the clock past every version saved in `log`.
+    ///
+    /// Call when a log is opened, so changes made after a restart are newer
+    /// than the ones saved before it.
+    pub fn load(&mut self, log: &Log) {
+        let live = log.header().qsos.iter().filter_map(saved_version);
+        let deleted = tombstones(log).into_iter().map(|deleted| deleted.version);
+        if let Some(clock) = live.chain(deleted).map(|v| v.clock).max() {
+            self.clock = self.clock.max(clock);
+        }
+    }
+
+    /// Stamps a local add or edit of `qso` with a new version, based on the
+    /// version it had.
+    ///
+    /// Call before writing the QSO to the log, so the version is saved with it.
+    pub fn stamp(&mut self, qso: &mut Qso) {
+        let base = saved_version(qso).unwrap_or_default();
+        let version = self.tick();
+        set_saved_version(qso, &version, &base);
+    }
+
+    /// Records a local delete of `removed` as a tombstone in `log`.
+    ///
+    /// Call after removing the QSO and before saving the log. A QSO without an
+    /// ID was never synced and leaves no tombstone.
+    pub fn stamp_deletion(&mut self, log: &mut Log, removed: &Qso) {
+        let Some(id) = removed.id.clone() else {
+            return;
+        };
+        let entry = Entry {
+            id,
+            version: self.tick(),
+            base: saved_version(removed).unwrap_or_default(),
+            qso: None,
+        };
+        set_tombstone(log, &entry.id, Some(&entry));
+    }
+
+    fn tick(&mut self) -> Version {
+        self.clock += 1;
+        Version {
+            clock: self.clock,
+            station: self.station.clone(),
+        }
+    }
+
+    /// Returns every QSO in `log`, and every tombstone, as entries.
+    ///
+    /// QSOs without an ID are skipped; see [`ensure_ids`].
+    pub fn snapshot(&self, log: &Log) -> Vec<Entry> {
+        let live = log
+            .header()
+            .qsos
+            .iter()
+            .filter_map(|qso| qso.id.as_deref().map(|id| live_entry(id, qso)));
+        live.chain(tombstones(log)).collect()
+    }
+
+    /// Returns the local state of the QSO `id`, or `None` if it was never seen.
+    pub fn entry(&self, log: &Log, id: &str) -> Option<Entry> {
+        match log.qso_index_by_id(id) {
+            Some(idx) => Some(live_entry(id, &log.header().qsos[idx])),
+            None => tombstones(log).into_iter().find(|deleted| deleted.id == id),
+        }
+    }
+
+    /// Merges an incoming message into `log`.
+    ///
+    /// Messages for another log are ignored. Each entry is applied when its
+    /// version is newer than the local one; when the versions are equal but the
+    /// contents differ, both stations deterministically keep the same copy.
+    /// A local copy replaced by an edit that was not based on it counts as a
+    /// conflict; an older copy of a QSO does not.
+    pub fn merge(&mut self, log: &mut Log, message: &Message) -> MergeOutcome {
+        let mut outcome = MergeOutcome::default();
+        if message.log_id() != log.header().log_id {
+            return outcome;
+        }
+        let mut seen = HashSet::new();
+        for incoming in message.entries() {
+            seen.insert(incoming.id.as_str());
+            self.clock = self.clock.max(incoming.version.clock);
+            let Some(local) = self.entry(log, &incoming.id) else {
+                apply(log, incoming);
+                if incoming.qso.is_some() {
+                    outcome.applied.push(incoming.clone());
+                }
+                continue;
+            };
+            if local.qso == incoming.qso {
+                if incoming.version > local.version {
+                    apply(log, incoming);
+                }
+                continue;
+            }
+            let incoming_wins = match incoming.version.cmp(&local.version) {
+                std::cmp::Ordering::Greater => true,
+                std::cmp::Ordering::Less => false,
+                std::cmp::Ordering::Equal => tiebreak(&incoming.qso) > tiebreak(&local.qso),
+            };
+            if incoming_wins {
+                if incoming.base != local.version {
+                    outcome.conflicts += 1;
+                }
+                apply(log, incoming);
+                outcome.applied.push(incoming.clone());
+            } else {
+                outcome.reply.push(local);
+            }
+        }
+        if let Message::Snapshot { .. } = message {
+            let missing = self
+                .snapshot(log)
+                .into_iter()
+                .filter(|entry| !seen.contains(entry.id.as_str()));
+            outcome.reply.extend(missing);
+        }
+        outcome
+    }
+}
+
+/// Writes `entry` into `log`: adds, replaces or removes the QSO.
+fn apply(log: &mut Log, entry: &Entry) {
+    let index = log.qso_index_by_id(&entry.id);
+    match &entry.qso {
+        Some(qso) => {
+            set_tombstone(log, &entry.id, None);
+            let mut qso = qso.clone();
+            qso.id = Some(entry.id.clone());
+            set_saved_version(&mut qso, &entry.version, &entry.base);
+            match index {
+                Some(idx) => {
+                    log.replace_qso(idx, qso);
+                }
+                None => log.add_qso(qso),
+            }
+        }
+        None => {
+            set_tombstone(log, &entry.id, Some(entry));
+            if let Some(idx) = index {
+                log.remove_qso(idx);
+            }
+        }
+    }
+}
+
+/// Returns `qso` as an entry, moving its saved versions out of its fields.
+fn live_entry(id: &str, qso: &Qso) -> Entry {
+    let mut qso = qso.clone();
+    let mut take = |field| {
+        qso.extra_fields
+            .remove(field)
+            .and_then(|v| Version::parse(&v))
+            .unwrap_or_default()
+    };
+    let version = take(VERSION_FIELD);
+    let base = take(BASE_FIELD);
+    Entry {
+        id: id.to_string(),
+        version,
+        base,
+        qso: Some(qso),
+    }
+}
+
+fn saved_version(qso: &Qso) -> Option<Version> {
+    qso.extra_fields.get(VERSION_FIELD).and_then(Version::parse)
+}
+
+/// Saves `version` and its `base` on `qso`; default versions are left unsaved.
+fn set_saved_version(qso: &mut Qso, version: &Version, base: &Version) {
+    for (field, version) in [(VERSION_FIELD, version), (BASE_FIELD, base)] {
+        if *version == Version::default() {
+            qso.extra_fields.remove(field);
+        } else {
+            qso.extra_fields.insert(field, version.to_string());
+        }
+    }
+}
+
+/// Returns the deleted QSOs saved in `log`'s header, as entries.
+fn tombstones(log: &Log) -> Vec<Entry> {
+    log.header()
+        .extra_fields
+        .get(TOMBSTONES_FIELD)
+        .unwrap_or_default()
+        .split_whitespace()
+        .filter_map(|tombstone| {
+            let (id, versions) = tombstone.split_once('=')?;
+            let (version, base) = match versions.split_once(',') {
+                Some((version, base)) => (version, Version::parse(base)?),
+                None => (versions, Version::default()),
+            };
+            Some(Entry {
+                id: id.to_string(),
+                version: Version::parse(version)?,
+                base,
+                qso: None,
+            })
+        })
+        .collect()
+}
+
+/// Sets the tombstone for the QSO `id` in `log` to `deletion`, or clears it.
+fn set_tombstone(log: &mut Log, id: &str, deletion: Option<&Entry>) {
+    let mut list = tombstones(log);
+    list.retain(|deleted| deleted.id != id);
+    list.extend(deletion.cloned());
+    let fields = &mut log.header_mut().extra_fields;
+    if list.is_empty() {
+        fields.remove(TOMBSTONES_FIELD);
+    } else {
+        let value = list
+            .iter()
+            .map(|deleted| {
+                if deleted.base == Version::default() {
+                    format!("{}={}", deleted.id, deleted.version)
+                } else {
+                    format!("{}={},{}", deleted.id, deleted.version, deleted.base)
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(" ");
+        fields.insert(TOMBSTONES_FIELD, value);
+    }
+}
+
+/// Orders two copies of a QSO with equal versions; the greater copy is kept.
+fn tiebreak(qso: &Option<Qso>) -> String {
+    serde_json::to_string(qso).expect("QSOs always serialize")
+}
+
+/// Derives an ID for `qso` from its content and gives it one if it has none.
+///
+/// The ID is built from the timestamp, callsign, band and mode, so copies of
+/// the same log on different stations derive the same IDs. A suffix keeps it
+/// unique within `log`.
+pub fn assign_id(log: &Log, qso: &mut Qso) {
+    if qso.id.is_some() {
+        return;
+    }
+    let base = format!(
+        "{}-{}-{}-{}",
+        qso.timestamp.format("%Y%m%dT%H%M%S%.f"),
+        qso.their_call,
+        qso.band.adif_str(),
+        qso.mode.name(),
+    );
+    let mut id = base.clone();
+    let mut n = 1;
+    while log.qso_index_by_id(&id).is_some() {
+        n += 1;
+        id = format!("{base}-{n}");
+    }
+    qso.id = Some(id);
+}
+
+/// Gives every QSO in `log` that lacks one an ID. Returns `true` if any were assigned.
+pub fn ensure_ids(log: &mut Log) -> bool {
+    let mut changed = false;
+    for idx in 0..log.header().qsos.len() {
+        if log.header().qsos[idx].id.is_none() {
+            let mut qso = log.header().qsos[idx].clone();
+            assign_id(log, &mut qso);
+            log.replace_qso(idx, qso);
+            changed = true;
+        }
+    }
+    changed
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+
+    use super::*;
+    use crate::model::{Band, FdClass, FdPowerCategory, FieldDayLog, Mode};
+
+    fn make_log() -> Log {
+        let mut log = FieldDayLog::new(
+            "W1AW".to_string(),
+            None,
+            3,
+            FdClass::A,
+            "EPA".to_string(),
+            FdPowerCategory::Low,
+            "FN31".to_string(),
+        )
+        .unwrap();
+        log.header.log_id = "FD-W1AW".to_string();
+        Log::FieldDay(log)
+    }
+
+    fn make_qso(call: &str, minute: u32) -> Qso {
+        Qso::new(
+            call.to_string(),
+            "59".to_string(),
+            "59".to_string(),
+            Band::M20,
+            Mode::Ssb,
+            Utc.with_ymd_and_hms(2026, 6, 27, 18, minute, 0).unwrap(),
+            String::new(),
+            None,
+            Some("3A EPA".to_string()),
+            Some(14_225_000),
+            None,
+        )
+        .unwrap()
+    }
+
+    /// Adds a QSO locally the way the app does: assign an ID, stamp, append.
+    fn add_local(replica: &mut Replica, log: &mut Log, mut qso: Qso) -> Entry {
+        assign_id(log, &mut qso);
+        replica.stamp(&mut qso);
+        let id = qso.id.clone().unwrap();
+        log.add_qso(qso);
+        replica.entry(log, &id).unwrap()
+    }
+
+    fn edit_local(replica: &mut Replica, log: &mut Log, id: &str, comments: &str) -> Entry {
+        let idx = log.qso_index_by_id(id).unwrap();
+        let mut qso = log.header().qsos[idx].clone();
+        qso.comments = comments.to_string();
+        replica.stamp(&mut qso);
+        log.replace_qso(idx, qso);
+        replica.entry(log, id).unwrap()
+    }
+
+    fn delete_local(replica: &mut Replica, log: &mut Log, id: &str) -> Entry {
+        let idx = log.qso_index_by_id(id).unwrap();
+        let removed = log.remove_qso(idx).unwrap();
+        replica.stamp_deletion(log, &removed);
+        replica.entry(log, id).unwrap()
+    }
+
+    fn changes(entries: Vec<Entry>) -> Message {
+        Message::Changes {
+            log_id: "FD-W1AW".to_string(),
+            entries,
+        }
+    }
+
+    fn snapshot(replica: &Replica, log: &Log) -> Message {
+        Message::Snapshot {
+            log_id: log.header().log_id.clone(),
+            entries: replica.snapshot(log),
+        }
+    }
+
+    fn calls(log: &Log) -> Vec<&str> {
+        let mut calls: Vec<&str> = log
+            .header()
+            .qsos
+            .iter()
+            .map(|q| q.their_call.as_str())
+            .collect();
+        calls.sort();
+        calls
+    }
+
+    mod ids {
+        use super::*;
+
+        #[test]
+        fn assign_id_derives_from_content() {
+            let log = make_log();
+            let mut qso = make_qso("KD9XYZ", 5);
+            assign_id(&log, &mut qso);
+            assert_eq!(qso.id.as_deref(), Some("20260627T180500-KD9XYZ-20M-SSB"));
+        }
+
+        #[test]
+        fn assign_id_keeps_existing() {
+            let log = make_log();
+            let mut qso = make_qso("KD9XYZ", 5);
+            qso.id = Some("mine".to_string());
+            assign_id(&log, &mut qso);
+            assert_eq!(qso.id.as_deref(), Some("mine"));
+        }
+
+        #[test]
+        fn assign_id_is_unique_within_log() {
+            let mut log = make_log();
+            let mut first = make_qso("KD9XYZ", 5);
+            assign_id(&log, &mut first);
+            log.add_qso(first);
+            let mut dupe = make_qso("KD9XYZ", 5);
+            assign_id(&log, &mut dupe);
+            assert_eq!(dupe.id.as_deref(), Some("20260627T180500-KD9XYZ-20M-SSB-2"));
+        }
+
+        #[test]
+        fn ensure_ids_fills_missing_only() {
+            let mut log = make_log();
+            let mut named = make_qso("N0CALL", 1);
+            named.id = Some("named".to_string());
+            log.add_qso(named);
+            log.add_qso(make_qso("KD9XYZ", 2));
+            assert!(ensure_ids(&mut log));
+            assert_eq!(log.header().qsos[0].id.as_deref(), Some("named"));
+            assert!(log.header().qsos[1].id.is_some());
+            assert!(!ensure_ids(&mut log), "nothing left to assign");
+        }
+
+        #[test]
+        fn copies_of_a_log_derive_the_same_ids() {
+            let mut a = make_log();
+            a.add_qso(make_qso("KD9XYZ", 1));
+            a.add_qso(make_qso("KD9XYZ", 1));
+            let mut b = a.clone();
+            ensure_ids(&mut a);
+            ensure_ids(&mut b);
+            assert_eq!(a, b);
+        }
+    }
+
+    mod local_changes {
+        use super::*;
+
+        #[test]
+        fn stamp_bumps_clock() {
+            let mut replica = Replica::new("a");
+            let mut log = make_log();
+            let first = add_local(&mut replica, &mut log, make_qso("KD9XYZ", 1));
+            let second = add_local(&mut replica, &mut log, make_qso("N0CALL", 2));
+            assert_eq!(first.version.clock, 1);
+            assert_eq!(second.version.clock, 2);
+            assert_eq!(second.version.station, "a");
+        }
+
+        #[test]
+        fn deletion_is_tombstone() {
+            let mut replica = Replica::new("a");
+            let mut log = make_log();
+            let added = add_local(&mut replica, &mut log, make_qso("KD9XYZ", 1));
+            let deleted = delete_local(&mut replica, &mut log, &added.id);
+            assert_eq!(deleted.qso, None);
+            assert_eq!(replica.snapshot(&log), vec![deleted]);
+        }
+
+        #[test]
+        fn snapshot_skips_qsos_without_id() {
+            let replica = Replica::new("a");
+            let mut log = make_log();
+            log.add_qso(make_qso("KD9XYZ", 1));
+            assert!(replica.snapshot(&log).is_empty());
+        }
+
+        #[test]
+        fn snapshot_uses_default_version_for_unchanged_qsos() {
+            let replica = Replica::new("a");
+            let mut log = make_log();
+            log.add_qso(make_qso("KD9XYZ", 1));
+            ensure_ids(&mut log);
+            assert_eq!(replica.snapshot(&log)[0].version, Version::default());
+        }
+
+        #[test]
+        fn entries_carry_version_outside_qso_fields() {
+            let mut replica = Replica::new("a");
+            let mut log = make_log();
+            let entry = add_local(&mut replica, &mut log, make_qso("KD9XYZ", 1));
+            assert_eq!(
+                log.header().qsos[0].extra_fields.get(VERSION_FIELD),
+                Some("1:a")
+            );
+            assert!(entry.qso.unwrap().extra_fields.is_empty());
+        }
+    }
+
+    mod persistence {
+        use tempfile::tempdir;
+
+        use super::*;
+
+        #[tokio::test]
+        async fn versions_and_tombstones_survive_save_and_reload() {
+            let mut replica = Replica::new("a");
+            let mut log = make_log();
+            let added = add_local(&mut replica, &mut log, make_qso("KD9XYZ", 1));
+            let gone = add_local(&mut replica, &mut log, make_qso("N0CALL", 2));
+            delete_local(&mut replica, &mut log, &gone.id);
+            let kept = edit_local(&mut replica, &mut log, &added.id, "fixed");
+            assert_eq!(kept.base, added.version);
+            assert_eq!(
+                log.header().extra_fields.get(TOMBSTONES_FIELD),
+                Some(format!("{}=3:a,2:a", gone.id).as_str())
+            );
+
+            let dir = tempdir().unwrap();
+            let path = dir.path().join("fd.adif");
+            tokio::fs::write(&path, crate::adif::format_adif(&log).unwrap())
+                .await
+                .unwrap();
+            let reloaded = crate::adif::read_log(&path).await.unwrap();
+
+            let restarted = Replica::new("a2");
+            assert_eq!(restarted.snapshot(&reloaded), replica.snapshot(&log));
+            assert_eq!(restarted.entry(&reloaded, &kept.id).unwrap(), kept);
+        }
+
+        #[test]
+        fn load_seeds_clock_past_saved_versions() {
+            let mut before = Replica::new("a");
+            let mut log = make_log();
+            let added = add_local(&mut before, &mut log, make_qso("KD9XYZ", 1));
+            edit_local(&mut before, &mut log, &added.id, "fixed");
+            let other = add_local(&mut before, &mut log, make_qso("N0CALL", 2));
+            delete_local(&mut before, &mut log, &other.id);
+
+            let mut after = Replica::new("a2");
+            after.load(&log);
+            let entry = add_local(&mut after, &mut log, make_qso("K1ABC", 3));
+            assert_eq!(entry.version.clock, 5);
+        }
+
+        #[test]
+        fn stale_copy_does_not_resurrect_qso_deleted_before_restart() {
+            let (mut a, mut b) = (Replica::new("a"), Replica::new("b"));
+            let (mut log_a, mut log_b) = (make_log(), make_log());
+            let added = add_local(&mut a, &mut log_a, make_qso("KD9XYZ", 1));
+            b.merge(&mut log_b, &changes(vec![added.clone()]));
+            delete_local(&mut b, &mut log_b, &added.id);
+
+            let mut restarted = Replica::new("b2");
+            restarted.load(&log_b);
+            let outcome = restarted.merge(&mut log_b, &changes(vec![added]));
+            assert!(log_b.header().qsos.is_empty());
+            assert_eq!(outcome.reply[0].qso, None);
+        }
+    }
+
+    mod merge {
+        use super::*;
+
+        #[test]
+        fn ignores_other_logs() {
+            let (mut a, mut b) = (Replica::new("a"), Replica::new("b"));
+            let (mut log_a, mut log_b) = (make_log(), make_log());
+            let entry = add_local(&mut a, &mut log_a, make_qso("KD9XYZ", 1));
+            log_b.header_mut().log_id = "other".to_string();
+            let outcome = b.merge(&mut log_b, &changes(vec![entry]));
+            assert_eq!(outcome, MergeOutcome::default());
+            assert!(log_b.header().qsos.is_empty());
+        }
+
+        #[test]
+        fn adds_new_qso() {
+            let (mut a, mut b) = (Replica::new("a"), Replica::new("b"));
+            let (mut log_a, mut log_b) = (make_log(), make_log());
+            let entry = add_local(&mut a, &mut log_a, make_qso("KD9XYZ", 1));
+            let outcome = b.merge(&mut log_b, &changes(vec![entry.clone()]));
+            assert_eq!(outcome.applied, vec![entry]);
+            assert_eq!(log_a.header().qsos, log_b.header().qsos);
+        }
+
+        #[test]
+        fn repeated_message_is_noop() {
+            let (mut a, mut b) = (Replica::new("a"), Replica::new("b"));
+            let (mut log_a, mut log_b) = (make_log(), make_log());
+            let msg = changes(vec![add_local(&mut a, &mut log_a, make_qso("KD9XYZ", 1))]);
+            b.merge(&mut log_b, &msg);
+            assert_eq!(b.merge(&mut log_b, &msg), MergeOutcome::default());
+            assert_eq!(log_b.header().qsos.len(), 1);
+        }
+
+        #[test]
+        fn applies_edit_and_delete() {
+            let (mut a, mut b) = (Replica::new("a"), Replica::new("b"));
+            let (mut log_a, mut log_b) = (make_log(), make_log());
+            let added = add_local(&mut a, &mut log_a, make_qso("KD9XYZ", 1));
+            b.merge(&mut log_b, &changes(vec![added.clone()]));
+
+            let edited = edit_local(&mut a, &mut log_a, &added.id, "fixed");
+            b.merge(&mut log_b, &changes(vec![edited]));
+            assert_eq!(log_b.header().qsos[0].comments, "fixed");
+
+            let deleted = delete_local(&mut a, &mut log_a, &added.id);
+            let outcome = b.merge(&mut log_b, &changes(vec![deleted]));
+            assert_eq!(outcome.applied.len(), 1);
+            assert!(log_b.header().qsos.is_empty());
+        }
+
+        #[test]
+        fn stale_copy_does_not_resurrect_deleted_qso() {
+            let (mut a, mut b) = (Replica::new("a"), Replica::new("b"));
+            let (mut log_a, mut log_b) = (make_log(), make_log());
+            let added = add_local(&mut a, &mut log_a, make_qso("KD9XYZ", 1));
+            b.merge(&mut log_b, &changes(vec![added.clone()]));
+            let deleted = delete_local(&mut a, &mut log_a, &added.id);
+            b.merge(&mut log_b, &changes(vec![deleted]));
+
+            let outcome = b.merge(&mut log_b, &changes(vec![added]));
+            assert!(log_b.header().qsos.is_empty());
+            assert_eq!(outcome.reply.len(), 1, "sender is told about the deletion");
+            assert_eq!(outcome.reply[0].qso, None);
+        }
+
+        #[test]
+        fn concurrent_edits_converge_on_newest() {
+            let (mut a, mut b) = (Replica::new("a"), Replica::new("b"));
+            let (mut log_a, mut log_b) = (make_log(), make_log());
+            let added = add_local(&mut a, &mut log_a, make_qso("KD9XYZ", 1));
+            b.merge(&mut log_b, &changes(vec![added.clone()]));
+
+            // Both stations edit the same QSO before hearing from each other
+            let edit_a = edit_local(&mut a, &mut log_a, &added.id, "from a");
+            let edit_b = edit_local(&mut b, &mut log_b, &added.id, "from b");
+            let outcome_a = a.merge(&mut log_a, &changes(vec![edit_b]));
+            let outcome_b = b.merge(&mut log_b, &changes(vec![edit_a]));
+
+            assert_eq!(log_a.header().qsos, log_b.header().qsos);
+            // Equal clocks: station "b" wins the tie on both sides
+            assert_eq!(log_a.header().qsos[0].comments, "from b");
+            assert_eq!(outcome_a.conflicts + outcome_b.conflicts, 1);
+        }
+
+        #[test]
+        fn edit_based_on_local_copy_is_not_a_conflict() {
+            let (mut a, mut b) = (Replica::new("a"), Replica::new("b"));
+            let (mut log_a, mut log_b) = (make_log(), make_log());
+            let added = add_local(&mut a, &mut log_a, make_qso("KD9XYZ", 1));
+            b.merge(&mut log_b, &changes(vec![added.clone()]));
+            let edited = edit_local(&mut b, &mut log_b, &added.id, "from b");
+            a.merge(&mut log_a, &changes(vec![edited]));
+
+            let edited = edit_local(&mut a, &mut log_a, &added.id, "from a");
+            let outcome = b.merge(&mut log_b, &changes(vec![edited]));
+            assert_eq!(outcome.conflicts, 0);
+            assert_eq!(log_b.header().qsos[0].comments, "from a");
+        }
+
+        #[test]
+        fn edit_not_based_on_local_copy_is_a_conflict_at_any_clock() {
+            let (mut a, mut b) = (Replica::new("a"), Replica::new("b"));
+            let (mut log_a, mut log_b) = (make_log(), make_log());
+            let added = add_local(&mut a, &mut log_a, make_qso("KD9XYZ", 1));
+            b.merge(&mut log_b, &changes(vec![added.clone()]));
+
+            // a edits twice before hearing of b's edit, so its clock is ahead
+            edit_local(&mut b, &mut log_b, &added.id, "from b");
+            edit_local(&mut a, &mut log_a, &added.id, "first");
+            let edited = edit_local(&mut a, &mut log_a, &added.id, "second");
+            let outcome = b.merge(&mut log_b, &changes(vec![edited]));
+            assert_eq!(outcome.conflicts, 1);
+            assert_eq!(log_b.header().qsos[0].comments, "second");
+        }
+
+        #[test]
+        fn delete_of_unseen_edit_is_a_conflict() {
+            let (mut a, mut b) = (Replica::new("a"), Replica::new("b"));
+            let (mut log_a, mut log_b) = (make_log(), make_log());
+            let added = add_local(&mut a, &mut log_a, make_qso("KD9XYZ", 1));
+            b.merge(&mut log_b, &changes(vec![added.clone()]));
+
+            edit_local(&mut b, &mut log_b, &added.id, "from b");
+            edit_local(&mut a, &mut log_a, &added.id, "from a");
+            let deleted = delete_local(&mut a, &mut log_a, &added.id);
+            let outcome = b.merge(&mut log_b, &changes(vec![deleted]));
+            assert_eq!(outcome.conflicts, 1);
+            assert!(log_b.header().qsos.is_empty());
+        }
+
+        #[test]
+        fn stale_copy_is_not_a_conflict() {
+            let (mut a, mut b) = (Replica::new("a"), Replica::new("b"));
+            let (mut log_a, mut log_b) = (make_log(), make_log());
+            let added = add_local(&mut a, &mut log_a, make_qso("KD9XYZ", 1));
+            b.merge(&mut log_b, &changes(vec![added.clone()]));
+            let edited = edit_local(&mut a, &mut log_a, &added.id, "fixed");
+            b.merge(&mut log_b, &changes(vec![edited]));
+
+            let outcome = b.merge(&mut log_b, &changes(vec![added]));
+            assert_eq!(outcome.conflicts, 0);
+            assert_eq!(outcome.reply.len(), 1, "sender is sent the newer copy");
+            assert_eq!(log_b.header().qsos[0].comments, "fixed");
+        }
+
+        #[test]
+        fn edit_newer_than_delete_wins() {
+            let (mut a, mut b) = (Replica::new("a"), Replica::new("b"));
+            let (mut log_a, mut log_b) = (make_log(), make_log());
+            let added = add_local(&mut a, &mut log_a, make_qso("KD9XYZ", 1));
+            b.merge(&mut log_b, &changes(vec![added.clone()]));
+
+            let deleted = delete_local(&mut a, &mut log_a, &added.id);
+            edit_local(&mut b, &mut log_b, &added.id, "first");
+            let edited = edit_local(&mut b, &mut log_b, &added.id, "second");
+            a.merge(&mut log_a, &changes(vec![edited]));
+            b.merge(&mut log_b, &changes(vec![deleted]));
+
+            assert_eq!(log_a.header().qsos, log_b.header().qsos);
+            assert_eq!(log_a.header().qsos[0].comments, "second");
+        }
+
+        #[test]
+        fn snapshot_exchange_merges_both_sides() {
+            let (mut a, mut b) = (Replica::new("a"), Replica::new("b"));
+            let (mut log_a, mut log_b) = (make_log(), make_log());
+            add_local(&mut a, &mut log_a, make_qso("KD9XYZ", 1));
+            add_local(&mut b, &mut log_b, make_qso("N0CALL", 2));
+
+            let outcome = b.merge(&mut log_b, &snapshot(&a, &log_a));
+            assert_eq!(outcome.applied.len(), 1);
+            assert_eq!(outcome.reply.len(), 1, "b replies with what a lacks");
+            let back = a.merge(&mut log_a, &changes(outcome.reply));
+            assert!(back.reply.is_empty(), "nothing left to exchange");
+
+            assert_eq!(calls(&log_a), ["KD9XYZ", "N0CALL"]);
+            assert_eq!(calls(&log_a), calls(&log_b));
+        }
+
+        #[test]
+        fn identical_copies_exchange_nothing() {
+            let (mut a, b) = (Replica::new("a"), Replica::new("b"));
+            let mut log_a = make_log();
+            log_a.add_qso(make_qso("KD9XYZ", 1));
+            ensure_ids(&mut log_a);
+            let log_b = log_a.clone();
+            let outcome = a.merge(&mut log_a, &snapshot(&b, &log_b));
+            assert_eq!(outcome, MergeOutcome::default());
+        }
+
+        #[test]
+        fn diverged_unversioned_copies_pick_same_winner() {
+            let (mut a, mut b) = (Replica::new("a"), Replica::new("b"));
+            let mut log_a = make_log();
+            log_a.add_qso(make_qso("KD9XYZ", 1));
+            ensure_ids(&mut log_a);
+            let mut log_b = log_a.clone();
+            // Edited on each laptop before sync was switched on
+            log_a.header_mut().qsos[0].comments = "aaa".to_string();
+            log_b.header_mut().qsos[0].comments = "zzz".to_string();
+
+            let msg_a = snapshot(&a, &log_a);
+            let msg_b = snapshot(&b, &log_b);
+            a.merge(&mut log_a, &msg_b);
+            b.merge(&mut log_b, &msg_a);
+            assert_eq!(log_a.header().qsos, log_b.header().qsos);
+        }
+
+        #[test]
+        fn merging_advances_clock_past_incoming() {
+            let (mut a, mut b) = (Replica::new("a"), Replica::new("b"));
+            let (mut log_a, mut log_b) = (make_log(), make_log());
+            for minute in 0..5 {
+                add_local(&mut a, &mut log_a, make_qso("KD9XYZ", minute));
+            }
+            b.merge(&mut log_b, &snapshot(&a, &log_a));
+            let entry = add_local(&mut b, &mut log_b, make_qso("N0CALL", 9));
+            assert_eq!(entry.version.clock, 6);
+        }
+
+        #[test]
+        fn duplicates_see_combined_log() {
+            let (mut a, mut b) = (Replica::new("a"), Replica::new("b"));
+            let (mut log_a, mut log_b) = (make_log(), make_log());
+            let entry = add_local(&mut a, &mut log_a, make_qso("KD9XYZ", 1));
+            b.merge(&mut log_b, &changes(vec![entry]));
+            assert_eq!(log_b.find_duplicates(&make_qso("KD9XYZ", 30)).len(), 1);
+        }
+    }
+}
@@ -1,6 +1,8 @@
 //! Actions returned by screen event handlers.
 
-use crate::model::{Log, Qso};
+use std::collections::BTreeMap;
+
+use crate::model::{Log, Qso, QsoChanges, QsoQuery};
 
 use super::app::Screen;
 
@@ -16,8 +18,17 @@ pub enum Action {
     Navigate(Screen),
     /// Select an existing log as the active session.
     SelectLog(Log),
+    /// Open a log that another duklog has locked, without saving changes to it.
+    SelectLogReadOnly(Log),
     /// Create and persist a new log, then make it active.
     CreateLog(Log),
+    /// Open the given log's details for editing.
+    EditLog(Log),
+    /// Start a new log pre-filled from the given one.
+    CopyLog(Log),
+    /// Apply new details to the log with the given ID, keeping its identity
+    /// and QSOs.
+    UpdateLogDetails(String, Log),
     /// Add a QSO to the active log.
     AddQso(Qso),
     /// Open the QSO at the given index for editing.
@@ -28,6 +39,19 @@ pub enum Action {
     ExportLog,
     /// Delete the log with the given ID from storage.
     DeleteLog(String),
+    /// Remove the damaged records from the file of the log with the given ID.
+    RepairLog(String),
+    /// Merge the logs with the given IDs into a new log, archiving the
+    /// originals if `true`.
+    MergeLogs(Vec<String>, bool),
+    /// Apply the same changes to the QSOs at the given indices.
+    BulkUpdateQsos(Vec<usize>, QsoChanges),
+    /// Persist the QSO list column choices (column names keyed by log type name).
+    SaveQsoColumns(BTreeMap<String, Vec<String>>),
+    /// Find QSOs in every log that match the query.
+    SearchQsos(QsoQuery),
+    /// Open the log with the given ID and show the given QSO in its QSO list.
+    OpenSearchHit(String, Qso),
     /// Delete the QSO at the given index from the active log.
     DeleteQso(usize),
     /// Quit the application.
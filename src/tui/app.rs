@@ -1,19 +1,40 @@
 use std::path::Path;
+use std::time::Duration;
+
+use chrono::Utc;
 
 use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use ratatui::layout::{Alignment, Rect};
+use ratatui::widgets::{Clear, Paragraph};
 use ratatui::{Frame, Terminal};
 
-use crate::model::{Log, Qso};
-use crate::storage::{self, LogManager, StorageError};
+use crate::config::Config;
+use crate::hooks::{HookEvent, Hooks};
+use crate::model::{Log, Qso, QsoChanges, QsoQuery};
+use crate::storage::{self, LogManager, StorageError, merge_disk_changes};
+use crate::sync::{SyncNode, assign_id};
 
 use super::action::Action;
 use super::error::AppError;
+use super::keymap::{Command, Keymap};
+use super::screens::awards::{AwardsState, draw_awards};
 use super::screens::export::{ExportState, draw_export};
 use super::screens::help::{HelpState, draw_help};
 use super::screens::log_create::{LogCreateState, draw_log_create};
 use super::screens::log_select::{LogSelectState, draw_log_select};
 use super::screens::qso_entry::{QsoEntryState, draw_qso_entry};
 use super::screens::qso_list::{QsoListState, draw_qso_list};
+use super::screens::search::{SearchState, draw_search};
+use super::theme::Theme;
+use super::timers::Timers;
+
+/// How often the event loop checks for changes from other stations while sync
+/// is on, and for finished hooks while any are running.
+const POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// How often the event loop wakes without input to advance the status bar
+/// timers.
+const TICK_INTERVAL: Duration = Duration::from_secs(1);
 
 /// All screens the app can navigate between.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -28,6 +49,10 @@ pub enum Screen {
     QsoList,
     /// Export the active log to ADIF.
     Export,
+    /// Search the QSOs in every log.
+    Search,
+    /// Show award progress across every log.
+    Awards,
     /// Show keybinding help.
     Help,
 }
@@ -36,39 +61,101 @@ pub enum Screen {
 pub struct App {
     screen: Screen,
     manager: LogManager,
+    keymap: Keymap,
+    theme: Theme,
     current_log: Option<Log>,
+    /// `true` when the active log was opened read-only because another
+    /// duklog holds its lock.
+    read_only: bool,
+    /// A change to the active log held back because its file was changed on
+    /// disk by another program, until the user chooses to reload or merge.
+    held_write: Option<Action>,
+    sync: Option<SyncNode>,
+    hooks: Hooks,
+    timers: Timers,
     should_quit: bool,
     log_select: LogSelectState,
     log_create: LogCreateState,
     qso_entry: QsoEntryState,
     qso_list: QsoListState,
     export: ExportState,
+    search: SearchState,
+    awards: AwardsState,
     help: HelpState,
 }
 
 impl App {
     /// Creates a new `App` starting on the [`Screen::LogSelect`] screen.
     ///
-    /// Loads the initial log list from storage.
+    /// Loads the initial log list from storage. Uses the default [`Config`].
     pub fn new(manager: LogManager) -> Result<Self, AppError> {
+        Self::with_config(manager, Config::default())
+    }
+
+    /// Creates a new `App` with the given user preferences applied.
+    ///
+    /// Fails if the configured key bindings, theme or hooks are invalid, or if
+    /// LAN sync is configured and cannot start. Saved QSO list columns that
+    /// cannot be read are reported on Log Select and the defaults used.
+    pub fn with_config(manager: LogManager, config: Config) -> Result<Self, AppError> {
+        let keymap = Keymap::with_overrides(&config.keys)?;
+        let no_color = std::env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty());
+        let theme = Theme::resolve(config.theme.as_deref(), no_color)?;
+        let sync = config
+            .sync
+            .is_enabled()
+            .then(|| SyncNode::start(config.sync.listen.as_deref(), &config.sync.peers))
+            .transpose()?;
+        let hooks = Hooks::new(&config.hooks)?;
+        let timers = Timers::new(&config.timers, Utc::now());
         let mut log_select = LogSelectState::new();
         log_select.load(&manager)?;
+        let mut qso_entry = QsoEntryState::new();
+        qso_entry.set_band_cycle(config.band_cycle);
+        qso_entry.set_mode_cycle(config.mode_cycle);
+        let mut qso_list = QsoListState::new();
+        match manager.load_list_columns() {
+            Ok(columns) => qso_list.set_column_names(&columns),
+            Err(e) => {
+                let msg = format!("Failed to read saved QSO list columns, using defaults: {e}");
+                let msg = match log_select.error() {
+                    Some(unreadable) => format!("{unreadable}; {msg}"),
+                    None => msg,
+                };
+                log_select.set_error(msg);
+            }
+        }
 
         Ok(Self {
             screen: Screen::LogSelect,
             manager,
+            keymap,
+            theme,
             current_log: None,
+            read_only: false,
+            held_write: None,
+            sync,
+            hooks,
+            timers,
             should_quit: false,
             log_select,
             log_create: LogCreateState::new(),
-            qso_entry: QsoEntryState::new(),
-            qso_list: QsoListState::new(),
+            qso_entry,
+            qso_list,
             export: ExportState::new(),
+            search: SearchState::new(),
+            awards: AwardsState::new(),
             help: HelpState::new(),
         })
     }
 
-    /// Main event loop: draw → read event → dispatch → check quit.
+    /// Main event loop: sync, hooks and timers → draw → read event → dispatch →
+    /// check quit.
+    ///
+    /// Waiting for input times out every [`TICK_INTERVAL`] so the status bar
+    /// timers count down without a key press, and every [`POLL_INTERVAL`] with
+    /// LAN sync on or a hook running so changes from other stations and hook
+    /// failures are shown promptly.
     #[cfg_attr(coverage_nightly, coverage(off))]
     #[mutants::skip]
     pub fn run<B: ratatui::backend::Backend>(
@@ -76,7 +163,18 @@ impl App {
         terminal: &mut Terminal<B>,
     ) -> Result<(), AppError> {
         while !self.should_quit {
+            self.poll_sync();
+            self.poll_hooks();
+            self.timers.tick(Utc::now());
             terminal.draw(|frame| self.draw(frame))?;
+            let timeout = if self.sync.is_some() || self.hooks.is_running() {
+                POLL_INTERVAL
+            } else {
+                TICK_INTERVAL
+            };
+            if !event::poll(timeout)? {
+                continue;
+            }
             if let Event::Key(key) = event::read()? {
                 self.handle_key(key);
             }
@@ -89,24 +187,37 @@ impl App {
     #[mutants::skip]
     fn draw(&self, frame: &mut Frame) {
         let area = frame.area();
+        let timers = self
+            .current_log
+            .as_ref()
+            .map(|log| self.timers.readings(log))
+            .unwrap_or_default();
 
         match self.screen {
-            Screen::LogSelect => draw_log_select(&self.log_select, frame, area),
-            Screen::LogCreate => draw_log_create(&self.log_create, frame, area),
+            Screen::LogSelect => draw_log_select(&self.log_select, &self.theme, frame, area),
+            Screen::LogCreate => draw_log_create(&self.log_create, &self.theme, frame, area),
             Screen::QsoEntry => {
-                draw_qso_entry(&self.qso_entry, self.current_log.as_ref(), frame, area);
+                let log = self.current_log.as_ref();
+                draw_qso_entry(&self.qso_entry, log, &timers, &self.theme, frame, area);
             }
             Screen::QsoList => {
-                draw_qso_list(&self.qso_list, self.current_log.as_ref(), frame, area);
+                let log = self.current_log.as_ref();
+                draw_qso_list(&self.qso_list, log, &timers, &self.theme, frame, area);
             }
             Screen::Export => {
-                draw_export(&self.export, self.current_log.as_ref(), frame, area);
+                let log = self.current_log.as_ref();
+                draw_export(&self.export, log, &timers, &self.theme, frame, area);
             }
-            Screen::Help => draw_help(&self.help, frame, area),
+            Screen::Search => draw_search(&self.search, &self.theme, frame, area),
+            Screen::Awards => draw_awards(&self.awards, &self.theme, frame, area),
+            Screen::Help => draw_help(&self.help, &self.keymap, &self.theme, frame, area),
+        }
+        if self.held_write.is_some() {
+            draw_disk_change_prompt(&self.theme, frame, area);
         }
     }
 
-    /// Handles a key event: global keys first, then screen-specific delegation.
+    /// Handles a key event: keymap commands first, then screen-specific delegation.
     pub fn handle_key(&mut self, key: KeyEvent) {
         if key.kind != KeyEventKind::Press {
             return;
@@ -124,50 +235,157 @@ impl App {
             key
         };
 
-        // Global F1 for help — works on every screen.
-        if key.code == KeyCode::F(1) {
-            if self.screen != Screen::Help {
-                self.navigate(Screen::Help);
-            }
+        if self.held_write.is_some() {
+            self.handle_disk_change_key(key);
             return;
         }
 
-        let action = match self.screen {
-            Screen::LogSelect => self.log_select.handle_key(key),
-            Screen::LogCreate => self.log_create.handle_key(key),
-            Screen::QsoEntry => self.qso_entry.handle_key(key),
-            Screen::QsoList => {
-                let count = self
-                    .current_log
-                    .as_ref()
-                    .map_or(0, |l| l.header().qsos.len());
-                self.qso_list.handle_key(key, count)
-            }
-            Screen::Export => self.export.handle_key(key),
-            Screen::Help => self.help.handle_key(key),
-        };
+        // Bound keys become commands. While a y/n confirmation or a typed prompt
+        // is pending only Help is honoured, so a bound key cannot pre-empt the answer.
+        if let Some(command) = self
+            .keymap
+            .resolve(self.screen, &key)
+            .filter(|&command| command == Command::Help || !self.awaiting_input())
+        {
+            self.handle_command(command);
+        } else {
+            let action = match self.screen {
+                Screen::LogSelect => self.log_select.handle_key(key),
+                Screen::LogCreate => self.log_create.handle_key(key),
+                Screen::QsoEntry => self.qso_entry.handle_key(key),
+                Screen::QsoList => self.qso_list.handle_key(key, self.current_log.as_ref()),
+                Screen::Export => self.export.handle_key(key),
+                Screen::Search => self.search.handle_key(key),
+                Screen::Awards => self.awards.handle_key(key),
+                Screen::Help => self.help.handle_key(key),
+            };
+            self.apply_action(action);
+        }
+        self.refresh_worked_before();
+    }
+
+    /// Looks up the callsign on QSO entry in the callsign index when it has
+    /// changed, filling the worked-before panel.
+    fn refresh_worked_before(&mut self) {
+        if self.screen != Screen::QsoEntry {
+            return;
+        }
+        let call = self.qso_entry.their_call();
+        if self.qso_entry.worked_before_call() == Some(call) {
+            return;
+        }
+        if call.is_empty() {
+            self.qso_entry.clear_worked_before();
+            return;
+        }
+        let call = call.to_string();
+        match self.manager.worked_before(&call) {
+            Ok(entries) => self.qso_entry.set_worked_before(call, entries),
+            Err(e) => {
+                self.qso_entry
+                    .set_error(format!("Failed to look up {call}: {e}"));
+                self.qso_entry.set_worked_before(call, Vec::new());
+            }
+        }
+    }
 
+    /// Dispatches a keymap [`Command`] to the active screen.
+    fn handle_command(&mut self, command: Command) {
+        let action = match (self.screen, command) {
+            (Screen::Help, Command::Help) => Action::None,
+            (_, Command::Help) => Action::Navigate(Screen::Help),
+            (_, Command::StationId) => {
+                self.timers.reset_station_id();
+                Action::None
+            }
+            (Screen::QsoEntry, _) => self.qso_entry.handle_command(command),
+            (Screen::QsoList, _) => self
+                .qso_list
+                .handle_command(command, self.current_log.as_ref()),
+            (Screen::LogSelect, _) => self.log_select.handle_command(command),
+            (Screen::Awards, _) => self.awards.handle_command(command),
+            (Screen::LogCreate | Screen::Export | Screen::Search | Screen::Help, _) => Action::None,
+        };
         self.apply_action(action);
     }
 
+    /// Returns `true` if the active screen is waiting for a y/n answer or prompt input.
+    fn awaiting_input(&self) -> bool {
+        match self.screen {
+            Screen::LogSelect => {
+                self.log_select.pending_delete_label().is_some()
+                    || self.log_select.pending_merge()
+                    || self.log_select.pending_read_only().is_some()
+            }
+            Screen::QsoEntry => self.qso_entry.operator_prompt().is_some(),
+            Screen::QsoList => {
+                self.qso_list.pending_delete().is_some()
+                    || self.qso_list.bulk_edit_open()
+                    || self.qso_list.column_picker_open()
+            }
+            Screen::LogCreate | Screen::Export | Screen::Search | Screen::Awards | Screen::Help => {
+                false
+            }
+        }
+    }
+
     /// Applies an [`Action`] returned by a screen handler.
+    ///
+    /// A change to the active log is held back, and the user asked to reload
+    /// or merge, if the log's file was changed by another program.
     fn apply_action(&mut self, action: Action) {
+        if self.writes_active_log(&action) && self.active_log_modified() {
+            self.held_write = Some(action);
+            return;
+        }
         match action {
             Action::None => {}
             Action::Quit => self.should_quit = true,
             Action::Navigate(screen) => self.navigate(screen),
-            Action::SelectLog(log) => {
-                self.qso_entry.set_log_context(&log);
-                self.current_log = Some(log);
-                self.screen = Screen::QsoEntry;
+            Action::SelectLog(log) => self.apply_select_log(log),
+            Action::SelectLogReadOnly(log) => {
+                self.manager.unlock_log();
+                self.open_log(log, true);
+                self.qso_entry
+                    .set_error("Opened read-only: changes will not be saved".into());
+            }
+            Action::AddQso(_)
+            | Action::UpdateQso(..)
+            | Action::DeleteQso(_)
+            | Action::BulkUpdateQsos(..)
+                if self.read_only =>
+            {
+                self.reject_read_only();
             }
             Action::CreateLog(log) => self.apply_create_log(log),
+            Action::EditLog(log) => {
+                self.log_create.start_editing(&log);
+                self.screen = Screen::LogCreate;
+            }
+            Action::CopyLog(log) => {
+                self.log_create.start_from(&log);
+                self.screen = Screen::LogCreate;
+            }
+            Action::UpdateLogDetails(log_id, details) => {
+                self.apply_update_log_details(&log_id, details);
+            }
             Action::ExportLog => self.apply_export_log(),
             Action::EditQso(index) => self.apply_edit_qso(index),
             Action::UpdateQso(index, qso) => self.apply_update_qso(index, qso),
             Action::DeleteLog(log_id) => self.apply_delete_log(log_id),
+            Action::RepairLog(log_id) => self.apply_repair_log(&log_id),
+            Action::MergeLogs(log_ids, archive) => self.apply_merge_logs(&log_ids, archive),
+            Action::BulkUpdateQsos(indices, changes) => self.apply_bulk_update(&indices, &changes),
+            Action::SaveQsoColumns(columns) => {
+                if let Err(e) = self.manager.save_list_columns(&columns) {
+                    self.qso_list
+                        .set_error(format!("Failed to save columns: {e}"));
+                }
+            }
             Action::DeleteQso(index) => self.apply_delete_qso(index),
             Action::AddQso(qso) => self.apply_add_qso(qso),
+            Action::SearchQsos(query) => self.apply_search(query),
+            Action::OpenSearchHit(log_id, qso) => self.apply_open_search_hit(&log_id, &qso),
         }
     }
 
@@ -183,21 +401,228 @@ impl App {
                 self.screen = Screen::LogSelect;
             }
             Ok(()) => {
-                self.qso_entry.set_log_context(&log);
-                self.current_log = Some(log);
-                self.screen = Screen::QsoEntry;
+                self.hooks.fire(&HookEvent::LogCreated { log: &log });
+                self.apply_select_log(log);
+            }
+        }
+    }
+
+    /// Finds the QSOs in every log that match `query` and lists them on the
+    /// search screen.
+    fn apply_search(&mut self, query: QsoQuery) {
+        match self.manager.search_qsos(&query) {
+            Ok(hits) => self.search.set_results(query, hits),
+            Err(e) => self.search.set_error(format!("Failed to search logs: {e}")),
+        }
+    }
+
+    /// Opens the log with `log_id` and shows its QSO list with `qso` selected.
+    ///
+    /// If the log cannot be opened (another duklog holds it, or it fails to
+    /// load), Log Select or the search screen explains why instead.
+    fn apply_open_search_hit(&mut self, log_id: &str, qso: &Qso) {
+        let log = match self.manager.load_log(log_id) {
+            Ok(log) => log,
+            Err(e) => {
+                self.search.set_error(format!("Failed to load log: {e}"));
+                return;
+            }
+        };
+        self.apply_select_log(log);
+        if self.screen != Screen::QsoEntry {
+            return;
+        }
+        self.navigate(Screen::QsoList);
+        let log = self.current_log.as_ref();
+        match log.and_then(|log| log.qsos().iter().position(|q| q == qso)) {
+            Some(index) => self.qso_list.select_qso(index, log),
+            None => self
+                .qso_list
+                .set_error("QSO is no longer in this log".into()),
+        }
+    }
+
+    /// Locks `log` and opens it, or offers to open it read-only if another
+    /// duklog has it open.
+    ///
+    /// The log is opened as its file holds it once locked, since `log` may
+    /// have been read before the file was last changed.
+    fn apply_select_log(&mut self, log: Log) {
+        match self.manager.lock_log(&log.header().log_id) {
+            Ok(current) => self.open_log(current.unwrap_or(log), false),
+            Err(e @ StorageError::LogLocked { .. }) => {
+                self.log_select.offer_read_only(log, e.to_string());
+                self.screen = Screen::LogSelect;
+            }
+            Err(e) => {
+                self.log_select
+                    .set_error(format!("Failed to lock log: {e}"));
+                self.screen = Screen::LogSelect;
+            }
+        }
+    }
+
+    /// Makes `log` the active log and shows QSO entry.
+    fn open_log(&mut self, log: Log, read_only: bool) {
+        self.qso_entry.set_log_context(&log);
+        self.current_log = Some(log);
+        self.read_only = read_only;
+        self.timers.reset_station_id();
+        self.announce_log();
+        self.screen = Screen::QsoEntry;
+    }
+
+    /// Reports a change refused because the active log is open read-only.
+    fn reject_read_only(&mut self) {
+        self.set_status("Log is open read-only".into());
+    }
+
+    /// Shows `msg` on the screen where the user changes the active log.
+    fn set_status(&mut self, msg: String) {
+        match self.screen {
+            Screen::QsoList => self.qso_list.set_error(msg),
+            Screen::LogCreate => self.log_create.set_error(msg),
+            Screen::LogSelect => self.log_select.set_error(msg),
+            _ => self.qso_entry.set_error(msg),
+        }
+    }
+
+    /// Returns `true` if applying `action` writes the active log's file.
+    fn writes_active_log(&self, action: &Action) -> bool {
+        match action {
+            Action::AddQso(_)
+            | Action::UpdateQso(..)
+            | Action::DeleteQso(_)
+            | Action::BulkUpdateQsos(..) => true,
+            Action::UpdateLogDetails(log_id, _) => self
+                .current_log
+                .as_ref()
+                .is_some_and(|log| &log.header().log_id == log_id),
+            _ => false,
+        }
+    }
+
+    /// Returns `true` if the active log's file was changed by another
+    /// program since duklog last read or wrote it.
+    fn active_log_modified(&self) -> bool {
+        self.current_log.as_ref().is_some_and(|log| {
+            matches!(
+                self.manager.check_unmodified(&log.header().log_id),
+                Err(StorageError::LogModified { .. })
+            )
+        })
+    }
+
+    /// Answers the prompt shown while a change is held back by
+    /// [`apply_action`](Self::apply_action).
+    fn handle_disk_change_key(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Char('r') => self.resolve_disk_change(false),
+            KeyCode::Char('m') => self.resolve_disk_change(true),
+            KeyCode::Esc => {
+                self.held_write = None;
+                self.set_status("Change not saved: log changed on disk".into());
+            }
+            _ => {}
+        }
+    }
+
+    /// Replaces the active log with its file's version, or with the file's
+    /// version plus the QSOs added in memory since the file was last read
+    /// when `merge` is set, then applies the held change if it does not refer
+    /// to QSOs by position.
+    fn resolve_disk_change(&mut self, merge: bool) {
+        let (Some(action), Some(current)) = (self.held_write.take(), self.current_log.as_ref())
+        else {
+            return;
+        };
+        let log_id = &current.header().log_id;
+        // Without a record of the file, nothing in memory is known to be new.
+        let last_read = self
+            .manager
+            .last_read_log(log_id)
+            .unwrap_or_else(|| current.clone());
+        let result = self.manager.reload_log(log_id).and_then(|on_disk| {
+            if !merge {
+                return Ok(on_disk);
+            }
+            let merged = merge_disk_changes(&on_disk, current, &last_read);
+            self.manager.save_log(&merged)?;
+            Ok(merged)
+        });
+        let log = match result {
+            Ok(log) => log,
+            Err(e) => {
+                self.set_status(format!("Failed to reload log: {e}"));
+                return;
+            }
+        };
+        self.qso_entry.set_log_context(&log);
+        self.qso_list.clamp_selection(log.header().qsos.len());
+        self.qso_list.clear_marks();
+        self.current_log = Some(log);
+        let done = if merge { "Merged" } else { "Reloaded" };
+        match action {
+            Action::AddQso(_) | Action::UpdateLogDetails(..) => {
+                self.set_status(format!("{done} changes from disk"));
+                self.apply_action(action);
+            }
+            _ => {
+                self.qso_entry.clear_editing();
+                self.set_status(format!("{done} changes from disk; make the change again"));
             }
         }
     }
 
-    /// Exports the active log to ADIF, updating export screen status.
+    /// Applies edited header details to the log with `log_id` and persists it.
+    ///
+    /// The log keeps its ID, file and QSOs (see ADR-0007); the active log is
+    /// used as the base when it is the one being edited so no QSOs are lost.
+    fn apply_update_log_details(&mut self, log_id: &str, details: Log) {
+        let base = match self.current_log.as_ref() {
+            Some(log) if log.header().log_id == log_id => Ok(log.clone()),
+            _ => self.manager.load_log(log_id),
+        };
+        let edited = match base {
+            Ok(base) => base.with_details(details),
+            Err(e) => {
+                self.log_create
+                    .set_error(format!("Failed to load log: {e}"));
+                return;
+            }
+        };
+        if let Err(e) = self.manager.save_log(&edited) {
+            self.log_create
+                .set_error(format!("Failed to save log: {e}"));
+            return;
+        }
+        if self
+            .current_log
+            .as_ref()
+            .is_some_and(|l| l.header().log_id == log_id)
+        {
+            self.qso_entry.set_log_context(&edited);
+            self.current_log = Some(edited);
+        }
+        self.navigate(Screen::LogSelect);
+    }
+
+    /// Exports the active log in the selected format, updating export screen status.
     fn apply_export_log(&mut self) {
         match self.current_log {
             Some(ref log) => {
                 let export_path = Path::new(self.export.path());
                 let internal_path = self.manager.log_path(&log.header().log_id);
-                match storage::export_adif(&internal_path, export_path) {
-                    Ok(()) => self.export.set_success(),
+                let format = self.export.format();
+                match storage::export_log(&internal_path, log, format, export_path) {
+                    Ok(()) => {
+                        self.hooks.fire(&HookEvent::LogExported {
+                            log,
+                            path: export_path,
+                            format,
+                        });
+                        self.export.set_success();
+                    }
                     Err(e) => self.export.set_error(e.to_string()),
                 }
             }
@@ -229,9 +654,29 @@ impl App {
     }
 
     /// Replaces the QSO at `index` with `qso` and persists the log.
-    fn apply_update_qso(&mut self, index: usize, qso: Qso) {
+    ///
+    /// A QSO with a sync ID is located by ID instead, since changes from other
+    /// stations may have moved it while it was being edited.
+    fn apply_update_qso(&mut self, index: usize, mut qso: Qso) {
         match self.current_log {
             Some(ref mut log) => {
+                let index = match qso.id.as_deref() {
+                    Some(id) => match log.qso_index_by_id(id) {
+                        Some(index) => index,
+                        None => {
+                            self.qso_entry
+                                .set_error("QSO was deleted on another station".into());
+                            self.qso_entry.clear_editing();
+                            return;
+                        }
+                    },
+                    None => index,
+                };
+                if let Some(node) = self.sync.as_mut() {
+                    assign_id(log, &mut qso);
+                    node.stamp(&mut qso);
+                }
+                let id = qso.id.clone();
                 if log.replace_qso(index, qso).is_none() {
                     self.qso_entry
                         .set_error(format!("QSO index {index} out of bounds"));
@@ -243,6 +688,14 @@ impl App {
                     self.qso_entry.clear_editing();
                     return;
                 }
+                if let (Some(node), Some(id)) = (self.sync.as_ref(), id) {
+                    node.publish(log, &id);
+                }
+                self.hooks.fire(&HookEvent::QsoUpdated {
+                    log,
+                    qso: &log.header().qsos[index],
+                    index,
+                });
                 self.qso_entry.clear_editing();
                 self.screen = Screen::QsoList;
             }
@@ -265,6 +718,21 @@ impl App {
             .is_some_and(|l| l.header().log_id == log_id)
         {
             self.current_log = None;
+            self.manager.unlock_log();
+        }
+        if let Err(e) = self.log_select.load(&self.manager) {
+            self.log_select
+                .set_error(format!("Failed to load logs: {e}"));
+        }
+    }
+
+    /// Removes the damaged records from the log file identified by `log_id` and
+    /// reloads the log list.
+    fn apply_repair_log(&mut self, log_id: &str) {
+        if let Err(e) = self.manager.repair_log(log_id) {
+            self.log_select
+                .set_error(format!("Failed to repair log: {e}"));
+            return;
         }
         if let Err(e) = self.log_select.load(&self.manager) {
             self.log_select
@@ -272,37 +740,138 @@ impl App {
         }
     }
 
+    /// Merges the given logs into a new log and opens it.
+    fn apply_merge_logs(&mut self, log_ids: &[String], archive: bool) {
+        match self.manager.merge_logs(log_ids, archive) {
+            Ok(merged) => {
+                let summary = format!(
+                    "Merged {} logs: {} QSOs, {} duplicate(s) skipped",
+                    log_ids.len(),
+                    merged.log.qsos().len(),
+                    merged.duplicates
+                );
+                if archive
+                    && self
+                        .current_log
+                        .as_ref()
+                        .is_some_and(|l| log_ids.contains(&l.header().log_id))
+                {
+                    self.current_log = None;
+                }
+                self.hooks.fire(&HookEvent::LogCreated { log: &merged.log });
+                self.apply_action(Action::SelectLog(merged.log));
+                self.qso_entry.set_error(summary);
+            }
+            Err(e) => {
+                self.log_select
+                    .set_error(format!("Failed to merge logs: {e}"));
+            }
+        }
+    }
+
     /// Removes the QSO at `index` from the active log and persists the change.
     fn apply_delete_qso(&mut self, index: usize) {
         if let Some(log) = self.current_log.as_mut()
-            && log.remove_qso(index).is_some()
+            && let Some(removed) = log.remove_qso(index)
         {
             let new_count = log.header().qsos.len();
             self.qso_list.clamp_selection(new_count);
+            self.qso_list.clear_marks();
+            if let Some(node) = self.sync.as_mut() {
+                node.stamp_deletion(log, &removed);
+            }
             if let Err(e) = self.manager.save_log(log) {
                 self.qso_list.set_error(format!("Failed to save log: {e}"));
+                return;
+            }
+            self.hooks.fire(&HookEvent::QsoDeleted {
+                log,
+                qso: &removed,
+                index,
+            });
+            if let (Some(node), Some(id)) = (self.sync.as_ref(), removed.id) {
+                node.publish(log, &id);
+            }
+        }
+    }
+
+    /// Applies `changes` to the QSOs at `indices` and persists them in one write.
+    fn apply_bulk_update(&mut self, indices: &[usize], changes: &QsoChanges) {
+        let Some(log) = self.current_log.as_mut() else {
+            return;
+        };
+        let mut updated = Vec::new();
+        let mut ids = Vec::new();
+        for &index in indices {
+            let Some(mut qso) = log.header().qsos.get(index).cloned() else {
+                continue;
+            };
+            changes.apply(&mut qso);
+            if let Some(node) = self.sync.as_mut() {
+                assign_id(log, &mut qso);
+                node.stamp(&mut qso);
+            }
+            ids.extend(qso.id.clone());
+            log.replace_qso(index, qso);
+            updated.push(index);
+        }
+        if let Err(e) = self.manager.save_log(log) {
+            self.qso_list.set_error(format!("Failed to save log: {e}"));
+            return;
+        }
+        if let Some(node) = self.sync.as_ref() {
+            for id in &ids {
+                node.publish(log, id);
             }
         }
+        for &index in &updated {
+            self.hooks.fire(&HookEvent::QsoUpdated {
+                log,
+                qso: &log.header().qsos[index],
+                index,
+            });
+        }
+        self.qso_list
+            .set_error(format!("Updated {} QSOs", updated.len()));
     }
 
-    /// Appends `qso` to the active log, surfacing any duplicate warning.
-    fn apply_add_qso(&mut self, qso: Qso) {
+    /// Appends `qso` to the active log, surfacing any duplicate warning, or a
+    /// warning that the QSO falls outside the log's contest period.
+    fn apply_add_qso(&mut self, mut qso: Qso) {
         match self.current_log {
             Some(ref mut log) => {
+                if let Some(node) = self.sync.as_mut() {
+                    assign_id(log, &mut qso);
+                    node.stamp(&mut qso);
+                }
                 let duplicate_warning = (!log.find_duplicates(&qso).is_empty()).then(|| {
                     format!(
                         "Warning: duplicate contact — {} {} {} already logged",
                         qso.their_call, qso.band, qso.mode
                     )
                 });
+                let period_warning = log
+                    .operating_period()
+                    .filter(|period| !period.contains(qso.timestamp))
+                    .map(|_| {
+                        format!(
+                            "Warning: QSO at {} is outside the {} period",
+                            qso.timestamp.format("%Y-%m-%d %H:%M UTC"),
+                            log.log_type_name()
+                        )
+                    });
                 if let Err(e) = self.manager.append_qso(log, &qso) {
                     self.qso_entry.set_error(format!("Failed to save QSO: {e}"));
                     return;
                 }
                 log.add_qso(qso.clone());
+                if let (Some(node), Some(id)) = (self.sync.as_ref(), qso.id.as_deref()) {
+                    node.publish(log, id);
+                }
+                self.hooks.fire(&HookEvent::QsoAdded { log, qso: &qso });
                 self.qso_entry.add_recent_qso(qso);
                 self.qso_entry.clear_fast_fields();
-                if let Some(msg) = duplicate_warning {
+                if let Some(msg) = duplicate_warning.or(period_warning) {
                     self.qso_entry.set_error(msg);
                 }
             }
@@ -312,6 +881,50 @@ impl App {
         }
     }
 
+    /// Offers the newly opened log to other stations, saving any sync IDs it needed.
+    fn announce_log(&mut self) {
+        if let (Some(node), Some(log)) = (self.sync.as_mut(), self.current_log.as_mut())
+            && node.announce(log)
+            && !self.read_only
+            && let Err(e) = self.manager.save_log(log)
+        {
+            self.qso_entry.set_error(format!("Failed to save log: {e}"));
+        }
+    }
+
+    /// Applies changes received from other stations and persists them.
+    fn poll_sync(&mut self) {
+        let Some(node) = self.sync.as_mut() else {
+            return;
+        };
+        let update = node.poll(self.current_log.as_mut());
+        if update.changed
+            && let Some(log) = self.current_log.as_ref()
+        {
+            if !self.read_only
+                && let Err(e) = self.manager.save_log(log)
+            {
+                self.qso_entry.set_error(format!("Failed to save log: {e}"));
+            }
+            self.qso_entry.set_log_context(log);
+            self.qso_list.clamp_selection(log.header().qsos.len());
+            self.qso_list.clear_marks();
+        }
+        if update.conflicts > 0 {
+            self.qso_entry.set_error(format!(
+                "Sync: {} conflicting edit(s) resolved — newest kept",
+                update.conflicts
+            ));
+        }
+    }
+
+    /// Shows hooks that failed since the last poll in the status line.
+    fn poll_hooks(&mut self) {
+        for failure in self.hooks.poll() {
+            self.set_status(failure.to_string());
+        }
+    }
+
     /// Handles screen navigation with side effects (resetting forms, reloading logs).
     fn navigate(&mut self, screen: Screen) {
         match screen {
@@ -340,6 +953,20 @@ impl App {
                 self.export.prepare(self.current_log.as_ref());
                 self.screen = Screen::Export;
             }
+            Screen::Search => {
+                if let Some(query) = self.search.query().cloned() {
+                    self.apply_search(query);
+                }
+                self.screen = Screen::Search;
+            }
+            Screen::Awards => {
+                if let Err(e) = self.awards.load(&self.manager) {
+                    self.log_select
+                        .set_error(format!("Failed to load logs: {e}"));
+                    return;
+                }
+                self.screen = Screen::Awards;
+            }
             Screen::Help => {
                 if self.screen != Screen::Help {
                     self.help.set_origin(self.screen);
@@ -371,12 +998,30 @@ impl App {
     }
 }
 
+/// Renders the reload-or-merge prompt over the bottom line of `area`.
+#[cfg_attr(coverage_nightly, coverage(off))]
+#[mutants::skip]
+fn draw_disk_change_prompt(theme: &Theme, frame: &mut Frame, area: Rect) {
+    let line = Rect {
+        y: area.bottom().saturating_sub(1),
+        height: area.height.min(1),
+        ..area
+    };
+    let prompt = Paragraph::new(
+        "Log file changed on disk. r: reload from disk  m: merge with disk  Esc: cancel",
+    )
+    .style(theme.warning)
+    .alignment(Alignment::Center);
+    frame.render_widget(Clear, line);
+    frame.render_widget(prompt, line);
+}
+
 #[cfg(test)]
 mod tests {
     use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyEventState, KeyModifiers};
 
     use super::*;
-    use crate::model::{LogHeader, PotaLog};
+    use crate::model::{ExtraFields, LogHeader, PotaLog};
     use crate::storage::LogManager;
 
     fn make_app() -> (tempfile::TempDir, App) {
@@ -421,6 +1066,7 @@ mod tests {
                 qsos: vec![],
                 created_at: chrono::Utc::now(),
                 log_id: id.into(),
+                extra_fields: ExtraFields::default(),
             },
             park_ref: "K-0001".into(),
         });
@@ -453,71 +1099,233 @@ mod tests {
             assert!(!app.should_quit());
             assert!(app.current_log().is_none());
         }
-    }
-
-    mod accessors {
-        use super::*;
 
         #[test]
-        fn current_log_returns_set_log() {
-            let (_dir, mut app) = make_app();
-            assert!(app.current_log().is_none());
-
-            use crate::model::GeneralLog;
-            let log = Log::General(GeneralLog {
-                header: LogHeader {
-                    station_callsign: "W1AW".into(),
-                    operator: Some("W1AW".into()),
-                    grid_square: "FN31pr".into(),
-                    qsos: vec![],
-                    created_at: chrono::Utc::now(),
-                    log_id: "test".into(),
-                },
-            });
-            app.current_log = Some(log.clone());
-            assert_eq!(app.current_log().unwrap().header().log_id, "test");
+        fn with_config_applies_band_and_mode_cycles() {
+            let dir = tempfile::tempdir().unwrap();
+            let manager = LogManager::with_path(dir.path()).unwrap();
+            let config = Config {
+                band_cycle: vec![crate::model::Band::M6, crate::model::Band::M2],
+                mode_cycle: vec![crate::model::Mode::Usb, crate::model::Mode::Cw],
+                ..Config::default()
+            };
+            let app = App::with_config(manager, config).unwrap();
+            assert_eq!(
+                app.qso_entry.band_cycle(),
+                &[crate::model::Band::M6, crate::model::Band::M2]
+            );
+            assert_eq!(
+                app.qso_entry.mode_cycle(),
+                &[crate::model::Mode::Usb, crate::model::Mode::Cw]
+            );
         }
 
         #[test]
-        fn manager_accessor_returns_manager() {
-            let (_dir, app) = make_app();
-            let _manager = app.manager();
+        fn corrupt_list_columns_fall_back_to_defaults() {
+            let dir = tempfile::tempdir().unwrap();
+            let manager = LogManager::with_path(dir.path()).unwrap();
+            let log = Log::Pota(
+                PotaLog::new("W1AW".into(), None, "K-0001".into(), "FN31".into()).unwrap(),
+            );
+            manager.save_log(&log).unwrap();
+            std::fs::write(dir.path().join("list_columns.json"), "{not json").unwrap();
+
+            let app = App::new(manager).unwrap();
+            assert_eq!(app.screen(), Screen::LogSelect);
+            let error = app.log_select.error().unwrap();
+            assert!(
+                error.starts_with("Failed to read saved QSO list columns, using defaults"),
+                "{error}"
+            );
+            assert_eq!(
+                app.qso_list.columns_for(&log),
+                QsoListState::new().columns_for(&log)
+            );
         }
     }
 
-    mod global_keys {
+    mod theme {
         use super::*;
+        use crate::tui::theme::ThemeName;
 
-        #[test]
-        fn q_on_log_select_is_ignored() {
-            let (_dir, mut app) = make_app();
-            app.handle_key(press(KeyCode::Char('q')));
-            assert!(!app.should_quit());
-            assert_eq!(app.screen(), Screen::LogSelect);
+        fn app_with_theme(theme: Option<&str>) -> Result<App, AppError> {
+            let dir = tempfile::tempdir().unwrap();
+            let manager = LogManager::with_path(dir.path()).unwrap();
+            let config = Config {
+                theme: theme.map(String::from),
+                ..Config::default()
+            };
+            App::with_config(manager, config)
         }
 
         #[test]
-        fn esc_on_log_select_quits() {
-            let (_dir, mut app) = make_app();
-            app.handle_key(press(KeyCode::Esc));
-            assert!(app.should_quit());
+        fn configured_theme_is_applied() {
+            let app = app_with_theme(Some("high-contrast")).unwrap();
+            assert_eq!(app.theme, Theme::new(ThemeName::HighContrast));
         }
 
         #[test]
-        fn f1_navigates_to_help() {
-            let (_dir, mut app) = make_app();
-            app.handle_key(press(KeyCode::F(1)));
-            assert_eq!(app.screen(), Screen::Help);
-            assert!(!app.should_quit());
+        fn unknown_theme_is_rejected() {
+            let result = app_with_theme(Some("solarized"));
+            assert!(matches!(result, Err(AppError::UnknownTheme(_))));
         }
+    }
 
-        #[test]
-        fn q_on_help_is_ignored() {
-            let (_dir, mut app) = make_app();
-            app.handle_key(press(KeyCode::F(1)));
-            assert_eq!(app.screen(), Screen::Help);
+    mod keymap {
+        use super::*;
 
-            app.handle_key(press(KeyCode::Char('q')));
+        fn make_app_with_keys(keys: &[(&str, &[&str])]) -> (tempfile::TempDir, App) {
+            let dir = tempfile::tempdir().unwrap();
+            let manager = LogManager::with_path(dir.path()).unwrap();
+            save_test_log(&manager, "log1");
+            let config = Config {
+                keys: keys
+                    .iter()
+                    .map(|(cmd, keys)| {
+                        (
+                            cmd.to_string(),
+                            keys.iter().map(|k| k.to_string()).collect(),
+                        )
+                    })
+                    .collect(),
+                ..Config::default()
+            };
+            let app = App::with_config(manager, config).unwrap();
+            (dir, app)
+        }
+
+        fn ctrl_press(code: KeyCode) -> KeyEvent {
+            KeyEvent {
+                code,
+                modifiers: KeyModifiers::CONTROL,
+                kind: KeyEventKind::Press,
+                state: KeyEventState::NONE,
+            }
+        }
+
+        #[test]
+        fn rebound_key_dispatches_command() {
+            let (_dir, mut app) = make_app_with_keys(&[("new_log", &["Ctrl+n"])]);
+            app.handle_key(ctrl_press(KeyCode::Char('n')));
+            assert_eq!(app.screen(), Screen::LogCreate);
+        }
+
+        #[test]
+        fn default_key_is_released_when_rebound() {
+            let (_dir, mut app) = make_app_with_keys(&[("new_log", &["Ctrl+n"])]);
+            app.handle_key(press(KeyCode::Char('n')));
+            assert_eq!(app.screen(), Screen::LogSelect);
+        }
+
+        #[test]
+        fn rebound_help_key_opens_help() {
+            let (_dir, mut app) = make_app_with_keys(&[("help", &["F2"])]);
+            app.handle_key(press(KeyCode::F(2)));
+            assert_eq!(app.screen(), Screen::Help);
+        }
+
+        #[test]
+        fn unbound_command_is_ignored() {
+            let (_dir, mut app) = make_app_with_keys(&[("delete_log", &[])]);
+            app.handle_key(press(KeyCode::Char('d')));
+            assert!(app.log_select.pending_delete_label().is_none());
+        }
+
+        #[test]
+        fn invalid_keymap_is_rejected() {
+            let dir = tempfile::tempdir().unwrap();
+            let manager = LogManager::with_path(dir.path()).unwrap();
+            let config = Config {
+                keys: [("warp_drive".to_string(), vec!["F5".to_string()])].into(),
+                ..Config::default()
+            };
+            let result = App::with_config(manager, config);
+            assert!(matches!(result, Err(AppError::Keymap(_))));
+        }
+
+        #[test]
+        fn confirmation_answer_is_not_treated_as_command() {
+            // 'n' is bound to new_log, but answers "no" while a delete is pending.
+            let (_dir, mut app) = make_app_with_keys(&[]);
+            app.handle_key(press(KeyCode::Char('d')));
+            app.handle_key(press(KeyCode::Char('n')));
+            assert_eq!(app.screen(), Screen::LogSelect);
+            assert!(app.log_select.pending_delete_label().is_none());
+        }
+
+        #[test]
+        fn help_works_while_confirmation_pending() {
+            let (_dir, mut app) = make_app_with_keys(&[]);
+            app.handle_key(press(KeyCode::Char('d')));
+            app.handle_key(press(KeyCode::F(1)));
+            assert_eq!(app.screen(), Screen::Help);
+        }
+    }
+
+    mod accessors {
+        use super::*;
+
+        #[test]
+        fn current_log_returns_set_log() {
+            let (_dir, mut app) = make_app();
+            assert!(app.current_log().is_none());
+
+            use crate::model::GeneralLog;
+            let log = Log::General(GeneralLog {
+                header: LogHeader {
+                    station_callsign: "W1AW".into(),
+                    operator: Some("W1AW".into()),
+                    grid_square: "FN31pr".into(),
+                    qsos: vec![],
+                    created_at: chrono::Utc::now(),
+                    log_id: "test".into(),
+                    extra_fields: ExtraFields::default(),
+                },
+            });
+            app.current_log = Some(log.clone());
+            assert_eq!(app.current_log().unwrap().header().log_id, "test");
+        }
+
+        #[test]
+        fn manager_accessor_returns_manager() {
+            let (_dir, app) = make_app();
+            let _manager = app.manager();
+        }
+    }
+
+    mod global_keys {
+        use super::*;
+
+        #[test]
+        fn q_on_log_select_is_ignored() {
+            let (_dir, mut app) = make_app();
+            app.handle_key(press(KeyCode::Char('q')));
+            assert!(!app.should_quit());
+            assert_eq!(app.screen(), Screen::LogSelect);
+        }
+
+        #[test]
+        fn esc_on_log_select_quits() {
+            let (_dir, mut app) = make_app();
+            app.handle_key(press(KeyCode::Esc));
+            assert!(app.should_quit());
+        }
+
+        #[test]
+        fn f1_navigates_to_help() {
+            let (_dir, mut app) = make_app();
+            app.handle_key(press(KeyCode::F(1)));
+            assert_eq!(app.screen(), Screen::Help);
+            assert!(!app.should_quit());
+        }
+
+        #[test]
+        fn q_on_help_is_ignored() {
+            let (_dir, mut app) = make_app();
+            app.handle_key(press(KeyCode::F(1)));
+            assert_eq!(app.screen(), Screen::Help);
+
+            app.handle_key(press(KeyCode::Char('q')));
             assert_eq!(app.screen(), Screen::Help);
             assert!(!app.should_quit());
         }
@@ -905,103 +1713,711 @@ mod tests {
         }
     }
 
-    mod delete_log_integration {
+    mod edit_log_integration {
         use super::*;
 
-        fn make_app_with_logs(ids: &[&str]) -> (tempfile::TempDir, App) {
+        fn make_app_with_log() -> (tempfile::TempDir, App) {
             let dir = tempfile::tempdir().unwrap();
             let manager = LogManager::with_path(dir.path()).unwrap();
-            for id in ids {
-                save_test_log(&manager, id);
-            }
+            save_test_log(&manager, "test-log");
             let app = App::new(manager).unwrap();
             (dir, app)
         }
 
+        /// Replaces the park reference on the open edit form and saves.
+        fn change_park(app: &mut App, park: &str) {
+            app.handle_key(press(KeyCode::Tab)); // → OPERATOR
+            app.handle_key(press(KeyCode::Tab)); // → POTA_PARK_REF
+            for _ in 0..10 {
+                app.handle_key(press(KeyCode::Backspace));
+            }
+            type_string(app, park);
+            app.handle_key(press(KeyCode::Enter));
+        }
+
         #[test]
-        fn d_on_empty_list_is_noop() {
-            let (_dir, mut app) = make_app();
-            app.handle_key(press(KeyCode::Char('d')));
-            assert!(app.log_select.logs().is_empty());
-            assert!(!app.should_quit());
+        fn e_opens_edit_form_for_selected_log() {
+            let (_dir, mut app) = make_app_with_log();
+            app.handle_key(press(KeyCode::Char('e')));
+            assert_eq!(app.screen(), Screen::LogCreate);
+            assert!(app.log_create.is_editing());
+            assert_eq!(app.log_create.form().value(0), "W1AW");
         }
 
         #[test]
-        fn d_sets_pending_confirmation() {
-            let (_dir, mut app) = make_app_with_logs(&["log1"]);
-            app.handle_key(press(KeyCode::Char('d')));
-            assert!(app.log_select.pending_delete_label().is_some());
+        fn edit_saves_details_under_same_id() {
+            let (dir, mut app) = make_app_with_log();
+            app.handle_key(press(KeyCode::Char('e')));
+            change_park(&mut app, "K-0002");
+
             assert_eq!(app.screen(), Screen::LogSelect);
+            let loaded = app.manager().load_log("test-log").unwrap();
+            assert_eq!(loaded.park_ref(), Some("K-0002"));
+            assert!(dir.path().join("test-log.adif").exists());
+            assert_eq!(app.manager().list_logs().unwrap().len(), 1);
         }
 
         #[test]
-        fn d_then_y_deletes_log_and_reloads_list() {
-            let (dir, mut app) = make_app_with_logs(&["log1"]);
-            assert_eq!(app.log_select.logs().len(), 1);
+        fn editing_open_log_keeps_qsos_and_updates_export_path() {
+            let (_dir, mut app) = make_app_with_log();
+            app.handle_key(press(KeyCode::Enter));
+            type_string(&mut app, "KD9XYZ");
+            app.handle_key(press(KeyCode::Enter));
+            app.handle_key(press(KeyCode::Esc));
+            assert_eq!(app.screen(), Screen::LogSelect);
 
-            app.handle_key(press(KeyCode::Char('d')));
-            app.handle_key(press(KeyCode::Char('y')));
+            app.handle_key(press(KeyCode::Char('e')));
+            change_park(&mut app, "K-0002");
 
-            assert!(app.log_select.logs().is_empty());
-            assert!(!dir.path().join("log1.jsonl").exists());
+            let log = app.current_log().unwrap();
+            assert_eq!(log.park_ref(), Some("K-0002"));
+            assert_eq!(log.header().log_id, "test-log");
+            assert_eq!(log.qsos().len(), 1);
+            let loaded = app.manager().load_log("test-log").unwrap();
+            assert_eq!(loaded.qsos().len(), 1);
+
+            app.navigate(Screen::Export);
+            assert!(
+                app.export.path().contains("K-0002"),
+                "{}",
+                app.export.path()
+            );
         }
 
         #[test]
-        fn d_then_n_preserves_list() {
-            let (_dir, mut app) = make_app_with_logs(&["log1"]);
-            app.handle_key(press(KeyCode::Char('d')));
-            app.handle_key(press(KeyCode::Char('n')));
-            assert_eq!(app.log_select.logs().len(), 1);
-            assert!(app.log_select.pending_delete_label().is_none());
+        fn esc_cancels_edit() {
+            let (_dir, mut app) = make_app_with_log();
+            app.handle_key(press(KeyCode::Char('e')));
+            app.handle_key(press(KeyCode::Esc));
+            assert_eq!(app.screen(), Screen::LogSelect);
+            let loaded = app.manager().load_log("test-log").unwrap();
+            assert_eq!(loaded.park_ref(), Some("K-0001"));
+        }
+    }
+
+    mod copy_log_integration {
+        use super::*;
+
+        fn make_app_with_log(
+            created_at: chrono::DateTime<chrono::Utc>,
+        ) -> (tempfile::TempDir, App) {
+            let dir = tempfile::tempdir().unwrap();
+            let manager = LogManager::with_path(dir.path()).unwrap();
+            let mut log = save_test_log(&manager, "old-log");
+            log.header_mut().created_at = created_at;
+            log.add_qso(make_qso());
+            manager.save_log(&log).unwrap();
+            let app = App::new(manager).unwrap();
+            (dir, app)
+        }
+
+        fn make_qso() -> Qso {
+            Qso::new(
+                "KD9XYZ".into(),
+                "59".into(),
+                "59".into(),
+                crate::model::Band::M20,
+                crate::model::Mode::Ssb,
+                chrono::Utc::now(),
+                String::new(),
+                None,
+                None,
+                None,
+                None,
+            )
+            .unwrap()
         }
 
         #[test]
-        fn d_then_esc_preserves_list() {
-            let (_dir, mut app) = make_app_with_logs(&["log1"]);
-            app.handle_key(press(KeyCode::Char('d')));
-            app.handle_key(press(KeyCode::Esc));
-            assert_eq!(app.log_select.logs().len(), 1);
-            assert!(app.log_select.pending_delete_label().is_none());
+        fn c_creates_new_log_from_selected() {
+            let yesterday = chrono::Utc::now() - chrono::Duration::days(1);
+            let (_dir, mut app) = make_app_with_log(yesterday);
+            app.handle_key(press(KeyCode::Char('c')));
+            assert_eq!(app.screen(), Screen::LogCreate);
+            assert!(!app.log_create.is_editing());
+            app.handle_key(press(KeyCode::Enter));
+
+            assert_eq!(app.screen(), Screen::QsoEntry);
+            let log = app.current_log().unwrap();
+            assert_ne!(log.header().log_id, "old-log");
+            assert_eq!(log.park_ref(), Some("K-0001"));
+            assert_eq!(log.header().operator.as_deref(), Some("W1AW"));
+            assert!(log.qsos().is_empty());
+            assert_eq!(app.manager().list_logs().unwrap().len(), 2);
         }
 
         #[test]
-        fn deleting_one_of_two_logs_leaves_one() {
+        fn copy_on_same_day_hits_duplicate_check() {
+            let (_dir, mut app) = make_app_with_log(chrono::Utc::now());
+            app.handle_key(press(KeyCode::Char('c')));
+            app.handle_key(press(KeyCode::Enter));
+
+            assert_eq!(app.screen(), Screen::LogCreate);
+            assert!(app.log_create.general_error().is_some());
+            assert_eq!(app.manager().list_logs().unwrap().len(), 1);
+        }
+    }
+
+    mod merge_logs_integration {
+        use super::*;
+
+        fn make_app_with_logs(ids: &[&str]) -> (tempfile::TempDir, App) {
+            let dir = tempfile::tempdir().unwrap();
+            let manager = LogManager::with_path(dir.path()).unwrap();
+            for id in ids {
+                save_test_log(&manager, id);
+            }
+            let app = App::new(manager).unwrap();
+            (dir, app)
+        }
+
+        fn mark_all_and_merge(app: &mut App, answer: char) {
+            for _ in 0..app.log_select.logs().len() {
+                app.handle_key(press(KeyCode::Char(' ')));
+            }
+            app.handle_key(press(KeyCode::Char('m')));
+            app.handle_key(press(KeyCode::Char(answer)));
+        }
+
+        #[test]
+        fn merge_and_archive_opens_merged_log() {
             let (_dir, mut app) = make_app_with_logs(&["log1", "log2"]);
-            assert_eq!(app.log_select.logs().len(), 2);
+            mark_all_and_merge(&mut app, 'a');
 
-            app.handle_key(press(KeyCode::Char('d')));
-            app.handle_key(press(KeyCode::Char('y')));
+            assert_eq!(app.screen(), Screen::QsoEntry);
+            let merged_id = app.current_log().unwrap().header().log_id.clone();
+            assert!(merged_id.contains("-merged-"), "{merged_id}");
+            assert!(
+                app.qso_entry.error().unwrap().starts_with("Merged 2 logs"),
+                "{:?}",
+                app.qso_entry.error()
+            );
+            let remaining = app.manager().list_logs().unwrap();
+            assert_eq!(remaining.len(), 1);
+            assert_eq!(remaining[0].header().log_id, merged_id);
+        }
 
-            assert_eq!(app.log_select.logs().len(), 1);
+        #[test]
+        fn merge_and_keep_leaves_sources() {
+            let (_dir, mut app) = make_app_with_logs(&["log1", "log2"]);
+            mark_all_and_merge(&mut app, 'k');
+            assert_eq!(app.screen(), Screen::QsoEntry);
+            assert_eq!(app.manager().list_logs().unwrap().len(), 3);
         }
 
         #[test]
-        fn deleting_only_log_leaves_empty_list_with_no_selection() {
-            let (_dir, mut app) = make_app_with_logs(&["log1"]);
-            app.handle_key(press(KeyCode::Char('d')));
-            app.handle_key(press(KeyCode::Char('y')));
+        fn bound_keys_wait_while_merge_prompt_is_open() {
+            let (_dir, mut app) = make_app_with_logs(&["log1", "log2"]);
+            mark_all_and_merge(&mut app, 'n');
+            assert_eq!(app.screen(), Screen::LogSelect, "n cancels, not new log");
+            assert!(!app.log_select.pending_merge());
+        }
 
-            assert!(app.log_select.logs().is_empty());
-            assert_eq!(app.log_select.selected(), None);
+        #[test]
+        fn merge_failure_shows_error_on_log_select() {
+            let (dir, mut app) = make_app_with_logs(&["log1", "log2"]);
+            std::fs::remove_file(dir.path().join("log2.adif")).unwrap();
+            mark_all_and_merge(&mut app, 'a');
+            assert_eq!(app.screen(), Screen::LogSelect);
+            assert!(
+                app.log_select
+                    .error()
+                    .unwrap()
+                    .starts_with("Failed to merge logs"),
+                "{:?}",
+                app.log_select.error()
+            );
         }
+    }
+
+    mod repair_log_integration {
+        use super::*;
 
         #[test]
-        fn deleting_current_log_clears_current_log() {
+        fn damaged_log_opens_after_repair() {
             let dir = tempfile::tempdir().unwrap();
             let manager = LogManager::with_path(dir.path()).unwrap();
             save_test_log(&manager, "log1");
+            let path = dir.path().join("log1.adif");
+            let mut content = std::fs::read_to_string(&path).unwrap();
+            content.push_str("<CALL:6>KD9");
+            std::fs::write(&path, content).unwrap();
             let mut app = App::new(manager).unwrap();
+            assert!(app.log_select.damage("log1").is_some());
 
-            // Open the log (sets current_log)
             app.handle_key(press(KeyCode::Enter));
-            assert_eq!(app.screen(), Screen::QsoEntry);
-            assert!(app.current_log().is_some());
-
-            // Return to log select, then delete it
-            app.handle_key(press(KeyCode::Esc));
             assert_eq!(app.screen(), Screen::LogSelect);
-            app.handle_key(press(KeyCode::Char('d')));
-            app.handle_key(press(KeyCode::Char('y')));
+
+            app.handle_key(press(KeyCode::Char('r')));
+            assert_eq!(app.log_select.damage("log1"), None);
+            assert!(dir.path().join("log1.adif.damaged").exists());
+
+            app.handle_key(press(KeyCode::Enter));
+            assert_eq!(app.screen(), Screen::QsoEntry);
+        }
+
+        #[test]
+        fn repair_failure_sets_error() {
+            let (_dir, mut app) = make_app();
+            app.apply_action(Action::RepairLog("missing".into()));
+            assert!(
+                app.log_select
+                    .error()
+                    .is_some_and(|e| e.starts_with("Failed to repair log")),
+            );
+        }
+    }
+
+    mod log_locking {
+        use super::*;
+
+        /// Returns an app on a directory where another duklog, stood in for
+        /// by the returned manager, has `log1` open.
+        fn make_locked_app() -> (tempfile::TempDir, LogManager, App) {
+            let dir = tempfile::tempdir().unwrap();
+            let mut other = LogManager::with_path(dir.path()).unwrap();
+            save_test_log(&other, "log1");
+            other.lock_log("log1").unwrap();
+            let app = App::new(LogManager::with_path(dir.path()).unwrap()).unwrap();
+            (dir, other, app)
+        }
+
+        #[test]
+        fn selecting_log_locks_it() {
+            let dir = tempfile::tempdir().unwrap();
+            let manager = LogManager::with_path(dir.path()).unwrap();
+            save_test_log(&manager, "log1");
+            let mut app = App::new(manager).unwrap();
+            app.handle_key(press(KeyCode::Enter));
+            assert_eq!(app.screen(), Screen::QsoEntry);
+
+            let mut other = LogManager::with_path(dir.path()).unwrap();
+            assert!(matches!(
+                other.lock_log("log1"),
+                Err(StorageError::LogLocked { .. })
+            ));
+            drop(app);
+            other.lock_log("log1").unwrap();
+        }
+
+        #[test]
+        fn locked_log_offers_read_only() {
+            let (_dir, _other, mut app) = make_locked_app();
+            app.handle_key(press(KeyCode::Enter));
+            assert_eq!(app.screen(), Screen::LogSelect);
+            let prompt = app.log_select.pending_read_only().unwrap();
+            assert_eq!(
+                prompt,
+                format!("log is open in another duklog (pid {})", std::process::id())
+            );
+            // Commands wait for the answer.
+            app.handle_key(press(KeyCode::Char('n')));
+            assert_eq!(app.screen(), Screen::LogSelect);
+            assert_eq!(app.log_select.pending_read_only(), None);
+        }
+
+        #[test]
+        fn read_only_log_refuses_changes() {
+            let (dir, _other, mut app) = make_locked_app();
+            app.handle_key(press(KeyCode::Enter));
+            app.handle_key(press(KeyCode::Char('y')));
+            assert_eq!(app.screen(), Screen::QsoEntry);
+            assert_eq!(
+                app.qso_entry.error(),
+                Some("Opened read-only: changes will not be saved")
+            );
+
+            type_string(&mut app, "KD9XYZ");
+            app.handle_key(press(KeyCode::Enter));
+            assert_eq!(app.qso_entry.error(), Some("Log is open read-only"));
+            assert!(app.current_log().unwrap().header().qsos.is_empty());
+            let manager = LogManager::with_path(dir.path()).unwrap();
+            assert!(manager.load_log("log1").unwrap().header().qsos.is_empty());
+        }
+
+        #[test]
+        fn selecting_another_log_clears_read_only() {
+            let (dir, other, mut app) = make_locked_app();
+            save_test_log(&other, "log2");
+            app.navigate(Screen::LogSelect);
+            let index = app
+                .log_select
+                .logs()
+                .iter()
+                .position(|l| l.header().log_id == "log1")
+                .unwrap();
+            for _ in 0..index {
+                app.handle_key(press(KeyCode::Down));
+            }
+            app.handle_key(press(KeyCode::Enter));
+            app.handle_key(press(KeyCode::Char('y')));
+            assert!(app.read_only);
+
+            let log2 = LogManager::with_path(dir.path())
+                .unwrap()
+                .load_log("log2")
+                .unwrap();
+            app.apply_action(Action::SelectLog(log2));
+            assert!(!app.read_only);
+            type_string(&mut app, "KD9XYZ");
+            app.handle_key(press(KeyCode::Enter));
+            assert_eq!(app.current_log().unwrap().header().qsos.len(), 1);
+        }
+    }
+
+    mod disk_changes {
+        use super::*;
+
+        /// Returns an app with `log1` open and one QSO (KD9XYZ) logged.
+        fn make_open_app() -> (tempfile::TempDir, App) {
+            let dir = tempfile::tempdir().unwrap();
+            let manager = LogManager::with_path(dir.path()).unwrap();
+            save_test_log(&manager, "log1");
+            let mut app = App::new(manager).unwrap();
+            app.handle_key(press(KeyCode::Enter));
+            type_string(&mut app, "KD9XYZ");
+            app.handle_key(press(KeyCode::Enter));
+            (dir, app)
+        }
+
+        /// Rewrites `log1`'s file as an editor would, with only `calls` in it.
+        fn rewrite_externally(dir: &tempfile::TempDir, app: &App, calls: &[&str]) {
+            let mut log = app.current_log().unwrap().clone();
+            let template = log.header().qsos[0].clone();
+            log.header_mut().qsos = calls
+                .iter()
+                .map(|call| Qso {
+                    their_call: call.to_string(),
+                    ..template.clone()
+                })
+                .collect();
+            let content = crate::adif::format_adif(&log).unwrap();
+            std::fs::write(dir.path().join("log1.adif"), content).unwrap();
+        }
+
+        fn calls(app: &App) -> Vec<String> {
+            app.current_log()
+                .unwrap()
+                .header()
+                .qsos
+                .iter()
+                .map(|q| q.their_call.clone())
+                .collect()
+        }
+
+        fn saved_calls(dir: &tempfile::TempDir) -> Vec<String> {
+            LogManager::with_path(dir.path())
+                .unwrap()
+                .load_log("log1")
+                .unwrap()
+                .header()
+                .qsos
+                .iter()
+                .map(|q| q.their_call.clone())
+                .collect()
+        }
+
+        #[test]
+        fn write_after_external_change_is_held() {
+            let (dir, mut app) = make_open_app();
+            rewrite_externally(&dir, &app, &["KD9XYZ", "N0CALL"]);
+            type_string(&mut app, "W1AW");
+            app.handle_key(press(KeyCode::Enter));
+            assert!(matches!(app.held_write, Some(Action::AddQso(_))));
+            assert_eq!(calls(&app), vec!["KD9XYZ"]);
+
+            // Other keys wait for the answer.
+            app.handle_key(press(KeyCode::Char('x')));
+            assert!(app.held_write.is_some());
+            app.handle_key(press(KeyCode::Esc));
+            assert!(app.held_write.is_none());
+            assert_eq!(
+                app.qso_entry.error(),
+                Some("Change not saved: log changed on disk")
+            );
+            assert_eq!(saved_calls(&dir), vec!["KD9XYZ", "N0CALL"]);
+        }
+
+        #[test]
+        fn reload_takes_disk_version_and_adds_held_qso() {
+            let (dir, mut app) = make_open_app();
+            rewrite_externally(&dir, &app, &["N0CALL"]);
+            type_string(&mut app, "W1AW");
+            app.handle_key(press(KeyCode::Enter));
+            app.handle_key(press(KeyCode::Char('r')));
+            assert!(app.held_write.is_none());
+            assert_eq!(calls(&app), vec!["N0CALL", "W1AW"]);
+            assert_eq!(saved_calls(&dir), vec!["N0CALL", "W1AW"]);
+        }
+
+        #[test]
+        fn merge_keeps_qsos_added_in_memory_since_last_read() {
+            let (dir, mut app) = make_open_app();
+            // A QSO received over sync whose save was refused.
+            let log = app.current_log.as_mut().unwrap();
+            let mut unsaved = log.header().qsos[0].clone();
+            unsaved.their_call = "W2XYZ".into();
+            unsaved.timestamp += chrono::Duration::minutes(1);
+            log.add_qso(unsaved);
+            // The editor corrects KD9XYZ to N0CALL.
+            rewrite_externally(&dir, &app, &["N0CALL"]);
+            type_string(&mut app, "W1AW");
+            app.handle_key(press(KeyCode::Enter));
+            app.handle_key(press(KeyCode::Char('m')));
+            let mut merged = calls(&app);
+            merged.sort();
+            assert_eq!(merged, vec!["N0CALL", "W1AW", "W2XYZ"]);
+            let mut saved = saved_calls(&dir);
+            saved.sort();
+            assert_eq!(saved, merged);
+        }
+
+        #[test]
+        fn selecting_log_opens_file_changed_after_listing() {
+            let (dir, mut app) = make_open_app();
+            app.navigate(Screen::LogSelect);
+            rewrite_externally(&dir, &app, &["KD9XYZ", "N0CALL"]);
+            app.handle_key(press(KeyCode::Enter));
+            assert_eq!(app.screen(), Screen::QsoEntry);
+            assert_eq!(calls(&app), vec!["KD9XYZ", "N0CALL"]);
+
+            app.apply_action(Action::DeleteQso(0));
+            assert!(app.held_write.is_none());
+            assert_eq!(saved_calls(&dir), vec!["N0CALL"]);
+        }
+
+        #[test]
+        fn positional_change_is_dropped_after_reload() {
+            let (dir, mut app) = make_open_app();
+            rewrite_externally(&dir, &app, &["N0CALL", "KD9XYZ"]);
+            app.apply_action(Action::DeleteQso(0));
+            app.handle_key(press(KeyCode::Char('r')));
+            assert_eq!(calls(&app), vec!["N0CALL", "KD9XYZ"]);
+            assert_eq!(
+                app.qso_entry.error(),
+                Some("Reloaded changes from disk; make the change again")
+            );
+            assert_eq!(saved_calls(&dir), vec!["N0CALL", "KD9XYZ"]);
+        }
+    }
+
+    mod hooks {
+        use std::time::{Duration, Instant};
+
+        use super::*;
+        use crate::config::HooksConfig;
+
+        /// Returns an app with `log1` open whose unset hooks append each
+        /// event to `events.txt` in the returned directory, one per line.
+        fn make_hooked_app(mut hooks: HooksConfig) -> (tempfile::TempDir, App) {
+            let dir = tempfile::tempdir().unwrap();
+            let record = vec![
+                "sh".to_string(),
+                "-c".to_string(),
+                format!(
+                    "cat >> '{0}'; echo >> '{0}'",
+                    dir.path().join("events.txt").display()
+                ),
+            ];
+            for command in [
+                &mut hooks.qso_added,
+                &mut hooks.qso_updated,
+                &mut hooks.qso_deleted,
+                &mut hooks.log_exported,
+            ] {
+                command.get_or_insert_with(|| record.clone());
+            }
+            let manager = LogManager::with_path(dir.path().join("logs")).unwrap();
+            save_test_log(&manager, "log1");
+            let config = Config {
+                hooks,
+                ..Config::default()
+            };
+            let mut app = App::with_config(manager, config).unwrap();
+            app.handle_key(press(KeyCode::Enter));
+            (dir, app)
+        }
+
+        fn wait_for_hooks(app: &mut App) {
+            let deadline = Instant::now() + Duration::from_secs(10);
+            while app.hooks.is_running() {
+                assert!(Instant::now() < deadline, "hooks did not finish");
+                app.poll_hooks();
+                std::thread::sleep(Duration::from_millis(10));
+            }
+        }
+
+        fn events(dir: &tempfile::TempDir) -> Vec<String> {
+            std::fs::read_to_string(dir.path().join("events.txt"))
+                .unwrap_or_default()
+                .lines()
+                .map(|line| {
+                    let event: serde_json::Value = serde_json::from_str(line).unwrap();
+                    event["event"].as_str().unwrap().to_string()
+                })
+                .collect()
+        }
+
+        #[test]
+        fn qso_changes_fire_hooks() {
+            let (dir, mut app) = make_hooked_app(HooksConfig::default());
+            type_string(&mut app, "KD9XYZ");
+            app.handle_key(press(KeyCode::Enter));
+            wait_for_hooks(&mut app);
+            let mut qso = app.current_log().unwrap().header().qsos[0].clone();
+            qso.comments = "fixed".into();
+            app.apply_action(Action::UpdateQso(0, qso));
+            wait_for_hooks(&mut app);
+            app.apply_action(Action::DeleteQso(0));
+            wait_for_hooks(&mut app);
+            assert_eq!(
+                events(&dir),
+                vec!["qso_added", "qso_updated", "qso_deleted"]
+            );
+        }
+
+        #[test]
+        fn export_fires_hook() {
+            let (dir, mut app) = make_hooked_app(HooksConfig::default());
+            app.navigate(Screen::Export);
+            app.export
+                .set_path(dir.path().join("out.adif").display().to_string());
+            app.apply_action(Action::ExportLog);
+            wait_for_hooks(&mut app);
+            assert_eq!(events(&dir), vec!["log_exported"]);
+        }
+
+        #[test]
+        fn failed_hook_is_reported_without_blocking() {
+            let (_dir, mut app) = make_hooked_app(HooksConfig {
+                qso_added: Some(vec!["sh".into(), "-c".into(), "exit 7".into()]),
+                ..HooksConfig::default()
+            });
+            type_string(&mut app, "KD9XYZ");
+            app.handle_key(press(KeyCode::Enter));
+            assert_eq!(app.current_log().unwrap().header().qsos.len(), 1);
+            wait_for_hooks(&mut app);
+            assert_eq!(
+                app.qso_entry.error(),
+                Some("Hook qso_added failed: exit status: 7")
+            );
+        }
+
+        #[test]
+        fn empty_hook_command_is_rejected() {
+            let dir = tempfile::tempdir().unwrap();
+            let manager = LogManager::with_path(dir.path()).unwrap();
+            let config = Config {
+                hooks: HooksConfig {
+                    log_created: Some(vec![]),
+                    ..HooksConfig::default()
+                },
+                ..Config::default()
+            };
+            assert!(matches!(
+                App::with_config(manager, config),
+                Err(AppError::Hook(_))
+            ));
+        }
+    }
+
+    mod delete_log_integration {
+        use super::*;
+
+        fn make_app_with_logs(ids: &[&str]) -> (tempfile::TempDir, App) {
+            let dir = tempfile::tempdir().unwrap();
+            let manager = LogManager::with_path(dir.path()).unwrap();
+            for id in ids {
+                save_test_log(&manager, id);
+            }
+            let app = App::new(manager).unwrap();
+            (dir, app)
+        }
+
+        #[test]
+        fn d_on_empty_list_is_noop() {
+            let (_dir, mut app) = make_app();
+            app.handle_key(press(KeyCode::Char('d')));
+            assert!(app.log_select.logs().is_empty());
+            assert!(!app.should_quit());
+        }
+
+        #[test]
+        fn d_sets_pending_confirmation() {
+            let (_dir, mut app) = make_app_with_logs(&["log1"]);
+            app.handle_key(press(KeyCode::Char('d')));
+            assert!(app.log_select.pending_delete_label().is_some());
+            assert_eq!(app.screen(), Screen::LogSelect);
+        }
+
+        #[test]
+        fn d_then_y_deletes_log_and_reloads_list() {
+            let (dir, mut app) = make_app_with_logs(&["log1"]);
+            assert_eq!(app.log_select.logs().len(), 1);
+
+            app.handle_key(press(KeyCode::Char('d')));
+            app.handle_key(press(KeyCode::Char('y')));
+
+            assert!(app.log_select.logs().is_empty());
+            assert!(!dir.path().join("log1.jsonl").exists());
+        }
+
+        #[test]
+        fn d_then_n_preserves_list() {
+            let (_dir, mut app) = make_app_with_logs(&["log1"]);
+            app.handle_key(press(KeyCode::Char('d')));
+            app.handle_key(press(KeyCode::Char('n')));
+            assert_eq!(app.log_select.logs().len(), 1);
+            assert!(app.log_select.pending_delete_label().is_none());
+        }
+
+        #[test]
+        fn d_then_esc_preserves_list() {
+            let (_dir, mut app) = make_app_with_logs(&["log1"]);
+            app.handle_key(press(KeyCode::Char('d')));
+            app.handle_key(press(KeyCode::Esc));
+            assert_eq!(app.log_select.logs().len(), 1);
+            assert!(app.log_select.pending_delete_label().is_none());
+        }
+
+        #[test]
+        fn deleting_one_of_two_logs_leaves_one() {
+            let (_dir, mut app) = make_app_with_logs(&["log1", "log2"]);
+            assert_eq!(app.log_select.logs().len(), 2);
+
+            app.handle_key(press(KeyCode::Char('d')));
+            app.handle_key(press(KeyCode::Char('y')));
+
+            assert_eq!(app.log_select.logs().len(), 1);
+        }
+
+        #[test]
+        fn deleting_only_log_leaves_empty_list_with_no_selection() {
+            let (_dir, mut app) = make_app_with_logs(&["log1"]);
+            app.handle_key(press(KeyCode::Char('d')));
+            app.handle_key(press(KeyCode::Char('y')));
+
+            assert!(app.log_select.logs().is_empty());
+            assert_eq!(app.log_select.selected(), None);
+        }
+
+        #[test]
+        fn deleting_current_log_clears_current_log() {
+            let dir = tempfile::tempdir().unwrap();
+            let manager = LogManager::with_path(dir.path()).unwrap();
+            save_test_log(&manager, "log1");
+            let mut app = App::new(manager).unwrap();
+
+            // Open the log (sets current_log)
+            app.handle_key(press(KeyCode::Enter));
+            assert_eq!(app.screen(), Screen::QsoEntry);
+            assert!(app.current_log().is_some());
+
+            // Return to log select, then delete it
+            app.handle_key(press(KeyCode::Esc));
+            assert_eq!(app.screen(), Screen::LogSelect);
+            app.handle_key(press(KeyCode::Char('d')));
+            app.handle_key(press(KeyCode::Char('y')));
 
             assert!(app.current_log().is_none());
         }
@@ -1063,6 +2479,8 @@ mod tests {
     }
 
     mod qso_entry_integration {
+        use chrono::TimeZone;
+
         use super::*;
 
         fn make_app_with_log() -> (tempfile::TempDir, App) {
@@ -1110,6 +2528,57 @@ mod tests {
             assert_eq!(app.qso_entry.form().value(1), "59");
         }
 
+        #[test]
+        fn switched_operator_persists_per_qso() {
+            let (_dir, mut app) = make_app_with_log();
+            let alt = |ch| KeyEvent {
+                code: KeyCode::Char(ch),
+                modifiers: KeyModifiers::ALT,
+                kind: KeyEventKind::Press,
+                state: KeyEventState::NONE,
+            };
+            app.handle_key(alt('o'));
+            type_string(&mut app, "K1ABC");
+            // Bound keys are held back while the prompt is open
+            let band = app.qso_entry.band();
+            app.handle_key(alt('b'));
+            assert_eq!(app.qso_entry.band(), band);
+            app.handle_key(press(KeyCode::Enter));
+            submit_qso(&mut app);
+
+            let loaded = app.manager().load_log("test-log").unwrap();
+            assert_eq!(loaded.header().qsos[0].operator.as_deref(), Some("K1ABC"));
+        }
+
+        #[test]
+        fn backfill_qso_persists_entered_timestamp() {
+            let (_dir, mut app) = make_app_with_log();
+            app.handle_key(KeyEvent {
+                code: KeyCode::Char('t'),
+                modifiers: KeyModifiers::ALT,
+                kind: KeyEventKind::Press,
+                state: KeyEventState::NONE,
+            });
+            assert!(app.qso_entry.is_backfill());
+
+            type_string(&mut app, "KD9XYZ");
+            for _ in 0..6 {
+                app.handle_key(press(KeyCode::Tab)); // → Date
+            }
+            for _ in 0..10 {
+                app.handle_key(press(KeyCode::Backspace));
+            }
+            type_string(&mut app, "2025-07-04");
+            app.handle_key(press(KeyCode::Tab)); // → Time
+            type_string(&mut app, "1432");
+            app.handle_key(press(KeyCode::Enter));
+
+            let expected = chrono::Utc.with_ymd_and_hms(2025, 7, 4, 14, 32, 0).unwrap();
+            let loaded = app.manager().load_log("test-log").unwrap();
+            assert_eq!(loaded.header().qsos[0].timestamp, expected);
+            assert_eq!(app.qso_entry.form().value(7), "1433");
+        }
+
         #[test]
         fn submit_qso_adds_to_recent() {
             let (_dir, mut app) = make_app_with_log();
@@ -1169,6 +2638,7 @@ mod tests {
                 None,
                 None,
                 None,
+                None,
             )
             .unwrap();
             manager.append_qso(&log, &qso).unwrap();
@@ -1390,17 +2860,38 @@ mod tests {
             app.handle_key(alt_press(KeyCode::Char('x')));
             assert_eq!(app.screen(), Screen::Export);
 
-            // Override path to a temp directory so we don't pollute ~/
+            // Override path to a temp directory so we don't pollute ~/
+            let export_dir = tempfile::tempdir().unwrap();
+            let export_path = export_dir.path().join("test.adif");
+            app.export.set_path(export_path.display().to_string());
+
+            app.apply_action(Action::ExportLog);
+            assert_eq!(app.export.status(), &ExportStatus::Success);
+
+            let content = std::fs::read_to_string(&export_path).unwrap();
+            assert!(content.contains("<eoh>"));
+            assert!(content.contains("KD9XYZ"));
+        }
+
+        #[test]
+        fn export_log_writes_csv_when_selected() {
+            let (_dir, mut app) = make_app_with_log();
+            type_string(&mut app, "KD9XYZ");
+            app.handle_key(press(KeyCode::Enter));
+            app.handle_key(alt_press(KeyCode::Char('x')));
+
             let export_dir = tempfile::tempdir().unwrap();
-            let export_path = export_dir.path().join("test.adif");
-            app.export.set_path(export_path.display().to_string());
-
-            app.apply_action(Action::ExportLog);
+            app.export
+                .set_path(export_dir.path().join("test.adif").display().to_string());
+            app.handle_key(press(KeyCode::Tab)); // → ADX
+            app.handle_key(press(KeyCode::Tab)); // → CSV
+            assert!(app.export.path().ends_with("test.csv"));
+            app.handle_key(press(KeyCode::Enter));
             assert_eq!(app.export.status(), &ExportStatus::Success);
 
-            let content = std::fs::read_to_string(&export_path).unwrap();
-            assert!(content.contains("<eoh>"));
-            assert!(content.contains("KD9XYZ"));
+            let content = std::fs::read_to_string(export_dir.path().join("test.csv")).unwrap();
+            assert!(content.starts_with("log_id,log_type,"));
+            assert!(content.contains(",KD9XYZ,"));
         }
 
         #[test]
@@ -1707,6 +3198,103 @@ mod tests {
         }
     }
 
+    mod bulk_edit_integration {
+        use super::*;
+        use crate::model::Band;
+        use crate::tui::screens::qso_list::QsoColumn;
+
+        fn shift_down() -> KeyEvent {
+            KeyEvent {
+                modifiers: KeyModifiers::SHIFT,
+                ..press(KeyCode::Down)
+            }
+        }
+
+        /// Opens a log, logs `calls`, and opens the QSO list.
+        fn make_app_with_qsos(calls: &[&str]) -> (tempfile::TempDir, App) {
+            let dir = tempfile::tempdir().unwrap();
+            let manager = LogManager::with_path(dir.path()).unwrap();
+            save_test_log(&manager, "test-log");
+            let mut app = App::new(manager).unwrap();
+            app.handle_key(press(KeyCode::Enter));
+            for call in calls {
+                type_string(&mut app, call);
+                app.handle_key(press(KeyCode::Enter));
+            }
+            app.navigate(Screen::QsoList);
+            (dir, app)
+        }
+
+        #[test]
+        fn bulk_edit_updates_marked_qsos_and_saves() {
+            let (dir, mut app) = make_app_with_qsos(&["W1AB", "W2CD", "W3EF"]);
+            app.handle_key(shift_down());
+            app.handle_key(press(KeyCode::Char('b')));
+            type_string(&mut app, "40m");
+            app.handle_key(press(KeyCode::Enter));
+
+            let bands: Vec<Band> = app
+                .current_log()
+                .unwrap()
+                .qsos()
+                .iter()
+                .map(|q| q.band)
+                .collect();
+            assert_eq!(bands, vec![Band::M40, Band::M40, Band::M20]);
+            assert_eq!(app.qso_list.error(), Some("Updated 2 QSOs"));
+
+            let manager = LogManager::with_path(dir.path()).unwrap();
+            let saved = manager.load_log("test-log").unwrap();
+            assert_eq!(saved.qsos()[1].band, Band::M40);
+            assert_eq!(saved.qsos()[2].band, Band::M20);
+        }
+
+        #[test]
+        fn space_in_dialog_is_typed_not_a_mark() {
+            let (_dir, mut app) = make_app_with_qsos(&["W1AB", "W2CD"]);
+            app.handle_key(press(KeyCode::Char(' ')));
+            app.handle_key(press(KeyCode::Char('b')));
+            for _ in 0..5 {
+                app.handle_key(press(KeyCode::Tab));
+            }
+            type_string(&mut app, "big gun");
+            app.handle_key(press(KeyCode::Enter));
+            let log = app.current_log().unwrap();
+            assert_eq!(log.qsos()[0].comments, "big gun");
+            assert_eq!(log.qsos()[1].comments, "");
+        }
+
+        #[test]
+        fn edit_after_sorting_opens_the_right_qso() {
+            let (_dir, mut app) = make_app_with_qsos(&["W2CD", "W1AB"]);
+            app.handle_key(press(KeyCode::Char('s')));
+            app.handle_key(press(KeyCode::Enter));
+            assert_eq!(app.screen(), Screen::QsoEntry);
+            assert_eq!(app.qso_entry.form().value(0), "W1AB", "W1AB sorts first");
+        }
+
+        #[test]
+        fn column_choice_is_saved_and_restored() {
+            let (dir, mut app) = make_app_with_qsos(&["W1AB"]);
+            app.handle_key(press(KeyCode::Char('c')));
+            app.handle_key(press(KeyCode::Char(' ')));
+            app.handle_key(press(KeyCode::Enter));
+            assert!(!app.qso_list.column_picker_open());
+
+            let manager = LogManager::with_path(dir.path()).unwrap();
+            let app = App::new(manager).unwrap();
+            let log = app.log_select.logs()[0].clone();
+            assert!(!app.qso_list.columns_for(&log).contains(&QsoColumn::Time));
+        }
+
+        #[test]
+        fn deleting_a_qso_clears_marks() {
+            let (_dir, mut app) = make_app_with_qsos(&["W1AB", "W2CD"]);
+            app.handle_key(press(KeyCode::Char(' ')));
+            app.apply_action(Action::DeleteQso(1));
+            assert!(app.qso_list.marked_indices().is_empty());
+        }
+    }
     mod delete_qso_integration {
         use super::*;
 
@@ -1815,4 +3403,517 @@ mod tests {
             );
         }
     }
+
+    mod lan_sync {
+        use std::time::{Duration, Instant};
+
+        use super::*;
+        use crate::config::SyncConfig;
+
+        fn make_sync_app(sync: SyncConfig) -> (tempfile::TempDir, App) {
+            let dir = tempfile::tempdir().unwrap();
+            let manager = LogManager::with_path(dir.path()).unwrap();
+            save_test_log(&manager, "field-day");
+            let config = Config {
+                sync,
+                ..Config::default()
+            };
+            let mut app = App::with_config(manager, config).unwrap();
+            app.handle_key(press(KeyCode::Enter));
+            (dir, app)
+        }
+
+        /// Starts a listening station and a second station connected to it.
+        fn make_pair() -> ((tempfile::TempDir, App), (tempfile::TempDir, App)) {
+            let first = make_sync_app(SyncConfig {
+                listen: Some("127.0.0.1:0".into()),
+                peers: vec![],
+            });
+            let addr = first.1.sync.as_ref().unwrap().local_addr().unwrap();
+            let second = make_sync_app(SyncConfig {
+                listen: None,
+                peers: vec![addr.to_string()],
+            });
+            (first, second)
+        }
+
+        fn poll_until(a: &mut App, b: &mut App, done: impl Fn(&App, &App) -> bool) {
+            let deadline = Instant::now() + Duration::from_secs(10);
+            while !done(a, b) {
+                assert!(Instant::now() < deadline, "stations did not converge");
+                a.poll_sync();
+                b.poll_sync();
+                std::thread::sleep(Duration::from_millis(10));
+            }
+        }
+
+        fn qso_count(app: &App) -> usize {
+            app.current_log().unwrap().header().qsos.len()
+        }
+
+        #[test]
+        fn invalid_sync_address_is_rejected() {
+            let dir = tempfile::tempdir().unwrap();
+            let manager = LogManager::with_path(dir.path()).unwrap();
+            let config = Config {
+                sync: SyncConfig {
+                    listen: Some("not an address".into()),
+                    peers: vec![],
+                },
+                ..Config::default()
+            };
+            let result = App::with_config(manager, config);
+            assert!(matches!(result, Err(AppError::Sync(_))));
+        }
+
+        #[test]
+        fn qsos_have_no_id_without_sync() {
+            let (_dir, mut app) = make_sync_app(SyncConfig::default());
+            type_string(&mut app, "KD9XYZ");
+            app.handle_key(press(KeyCode::Enter));
+            assert_eq!(app.current_log().unwrap().header().qsos[0].id, None);
+        }
+
+        #[test]
+        fn added_qso_reaches_other_station_and_is_saved() {
+            let ((_dir_a, mut a), (dir_b, mut b)) = make_pair();
+            type_string(&mut a, "KD9XYZ");
+            a.handle_key(press(KeyCode::Enter));
+            assert!(a.current_log().unwrap().header().qsos[0].id.is_some());
+
+            poll_until(&mut a, &mut b, |_, b| qso_count(b) == 1);
+            assert_eq!(
+                b.current_log().unwrap().header().qsos[0].their_call,
+                "KD9XYZ"
+            );
+            let manager = LogManager::with_path(dir_b.path()).unwrap();
+            assert_eq!(
+                manager.load_log("field-day").unwrap().header().qsos.len(),
+                1
+            );
+        }
+
+        #[test]
+        fn edit_and_delete_reach_other_station() {
+            let ((_dir_a, mut a), (_dir_b, mut b)) = make_pair();
+            type_string(&mut a, "KD9XYZ");
+            a.handle_key(press(KeyCode::Enter));
+            poll_until(&mut a, &mut b, |_, b| qso_count(b) == 1);
+
+            let mut edited = b.current_log().unwrap().header().qsos[0].clone();
+            edited.comments = "fixed".into();
+            b.apply_action(Action::UpdateQso(0, edited));
+            poll_until(&mut a, &mut b, |a, _| {
+                a.current_log().unwrap().header().qsos[0].comments == "fixed"
+            });
+
+            a.apply_action(Action::DeleteQso(0));
+            poll_until(&mut a, &mut b, |_, b| qso_count(b) == 0);
+        }
+
+        #[test]
+        fn bulk_update_reaches_other_station() {
+            let ((_dir_a, mut a), (_dir_b, mut b)) = make_pair();
+            for call in ["KD9XYZ", "N0CALL"] {
+                type_string(&mut a, call);
+                a.handle_key(press(KeyCode::Enter));
+            }
+            poll_until(&mut a, &mut b, |_, b| qso_count(b) == 2);
+
+            let changes = QsoChanges {
+                comments: Some("bulk".into()),
+                ..QsoChanges::default()
+            };
+            a.apply_action(Action::BulkUpdateQsos(vec![0, 1], changes));
+            poll_until(&mut a, &mut b, |_, b| {
+                b.current_log()
+                    .unwrap()
+                    .qsos()
+                    .iter()
+                    .all(|q| q.comments == "bulk")
+            });
+        }
+
+        #[test]
+        fn local_changes_save_their_versions() {
+            let ((dir_a, mut a), (_dir_b, mut b)) = make_pair();
+            type_string(&mut a, "KD9XYZ");
+            a.handle_key(press(KeyCode::Enter));
+            let manager = LogManager::with_path(dir_a.path()).unwrap();
+            let saved = manager.load_log("field-day").unwrap();
+            assert!(
+                saved.qsos()[0]
+                    .extra_fields
+                    .get("APP_DUKLOG_QSO_VERSION")
+                    .is_some()
+            );
+
+            poll_until(&mut a, &mut b, |_, b| qso_count(b) == 1);
+            a.apply_action(Action::DeleteQso(0));
+            let saved = manager.load_log("field-day").unwrap();
+            let tombstones = saved.header().extra_fields.get("APP_DUKLOG_TOMBSTONES");
+            assert!(tombstones.is_some_and(|t| t.contains("KD9XYZ")));
+        }
+
+        #[test]
+        fn remote_change_cancels_pending_delete() {
+            let ((_dir_a, mut a), (_dir_b, mut b)) = make_pair();
+            type_string(&mut a, "KD9XYZ");
+            a.handle_key(press(KeyCode::Enter));
+            poll_until(&mut a, &mut b, |_, b| qso_count(b) == 1);
+
+            b.navigate(Screen::QsoList);
+            b.handle_key(press(KeyCode::Char('d')));
+            assert_eq!(b.qso_list.pending_delete(), Some(0));
+
+            type_string(&mut a, "N0CALL");
+            a.handle_key(press(KeyCode::Enter));
+            poll_until(&mut a, &mut b, |_, b| qso_count(b) == 2);
+            assert_eq!(b.qso_list.pending_delete(), None);
+        }
+
+        #[test]
+        fn update_of_qso_deleted_elsewhere_shows_error() {
+            let ((_dir_a, mut a), (_dir_b, mut b)) = make_pair();
+            type_string(&mut a, "KD9XYZ");
+            a.handle_key(press(KeyCode::Enter));
+            poll_until(&mut a, &mut b, |_, b| qso_count(b) == 1);
+
+            let stale = b.current_log().unwrap().header().qsos[0].clone();
+            a.apply_action(Action::DeleteQso(0));
+            poll_until(&mut a, &mut b, |_, b| qso_count(b) == 0);
+
+            b.apply_action(Action::UpdateQso(0, stale));
+            assert_eq!(qso_count(&b), 0);
+            assert_eq!(
+                b.qso_entry.error(),
+                Some("QSO was deleted on another station")
+            );
+        }
+    }
+    mod timers {
+        use chrono::{TimeDelta, TimeZone};
+
+        use super::*;
+        use crate::model::{FdClass, FdPowerCategory, FieldDayLog, OperatingPeriod};
+
+        /// Returns an app with a Field Day log for the 2025 event open.
+        fn make_app_with_field_day() -> (tempfile::TempDir, App) {
+            let dir = tempfile::tempdir().unwrap();
+            let manager = LogManager::with_path(dir.path()).unwrap();
+            let mut fd = FieldDayLog::new(
+                "W1AW".into(),
+                None,
+                1,
+                FdClass::B,
+                "EPA".into(),
+                FdPowerCategory::Low,
+                "FN31".into(),
+            )
+            .unwrap();
+            fd.header.created_at = chrono::Utc.with_ymd_and_hms(2025, 6, 1, 0, 0, 0).unwrap();
+            manager.save_log(&Log::FieldDay(fd)).unwrap();
+            let mut app = App::new(manager).unwrap();
+            app.handle_key(press(KeyCode::Enter));
+            (dir, app)
+        }
+
+        fn fd_qso(timestamp: chrono::DateTime<chrono::Utc>) -> Qso {
+            Qso::new(
+                "KD9XYZ".into(),
+                "59".into(),
+                "59".into(),
+                crate::model::Band::M20,
+                crate::model::Mode::Ssb,
+                timestamp,
+                String::new(),
+                None,
+                Some("3A CT".into()),
+                None,
+                None,
+            )
+            .unwrap()
+        }
+
+        #[test]
+        fn qso_outside_contest_period_is_logged_with_warning() {
+            let (_dir, mut app) = make_app_with_field_day();
+            let period = OperatingPeriod::field_day(2025);
+            app.apply_action(Action::AddQso(fd_qso(period.end)));
+            assert_eq!(app.current_log().unwrap().header().qsos.len(), 1);
+            assert_eq!(
+                app.qso_entry.error(),
+                Some("Warning: QSO at 2025-06-29 21:00 UTC is outside the FD period")
+            );
+        }
+
+        #[test]
+        fn qso_inside_contest_period_has_no_warning() {
+            let (_dir, mut app) = make_app_with_field_day();
+            let period = OperatingPeriod::field_day(2025);
+            app.apply_action(Action::AddQso(fd_qso(period.start)));
+            assert_eq!(app.qso_entry.error(), None);
+        }
+
+        #[test]
+        fn station_id_key_restarts_reminder() {
+            let (_dir, mut app) = make_app_with_field_day();
+            let log = app.current_log().unwrap().clone();
+            app.timers.tick(chrono::Utc::now() + TimeDelta::minutes(11));
+            assert!(app.timers.readings(&log).iter().any(|r| r.text == "ID now"));
+            app.handle_key(KeyEvent {
+                code: KeyCode::Char('i'),
+                modifiers: KeyModifiers::ALT,
+                kind: KeyEventKind::Press,
+                state: KeyEventState::NONE,
+            });
+            let readings = app.timers.readings(&log);
+            assert_eq!(readings.last().unwrap().text, "ID in 10m 00s");
+        }
+    }
+    mod worked_before {
+        use super::*;
+
+        /// Returns an app with `current` open and an older log, `old`, that
+        /// has one QSO with KD9XYZ.
+        fn make_app_with_history() -> (tempfile::TempDir, App) {
+            let dir = tempfile::tempdir().unwrap();
+            let manager = LogManager::with_path(dir.path()).unwrap();
+            let mut old = save_test_log(&manager, "old");
+            old.header_mut().created_at = chrono::Utc::now() - chrono::Duration::days(30);
+            old.add_qso(
+                Qso::new(
+                    "KD9XYZ".into(),
+                    "59".into(),
+                    "59".into(),
+                    crate::model::Band::M40,
+                    crate::model::Mode::Cw,
+                    chrono::Utc::now() - chrono::Duration::days(30),
+                    "first contact".into(),
+                    None,
+                    None,
+                    None,
+                    None,
+                )
+                .unwrap(),
+            );
+            manager.save_log(&old).unwrap();
+            save_test_log(&manager, "current");
+            let mut app = App::new(manager).unwrap();
+            app.handle_key(press(KeyCode::Enter));
+            assert_eq!(app.current_log().unwrap().header().log_id, "current");
+            (dir, app)
+        }
+
+        #[test]
+        fn typing_callsign_shows_qsos_from_other_logs() {
+            let (_dir, mut app) = make_app_with_history();
+            type_string(&mut app, "KD9XY");
+            assert!(app.qso_entry.worked_before().is_empty());
+            type_string(&mut app, "Z");
+            let entries = app.qso_entry.worked_before();
+            assert_eq!(entries.len(), 1);
+            assert_eq!(entries[0].log_id, "old");
+            assert_eq!(entries[0].qso.comments, "first contact");
+        }
+
+        #[test]
+        fn logged_qso_is_found_next_time() {
+            let (_dir, mut app) = make_app_with_history();
+            type_string(&mut app, "KD9XYZ");
+            app.handle_key(press(KeyCode::Enter));
+            assert_eq!(app.qso_entry.worked_before_call(), None);
+            type_string(&mut app, "KD9XYZ");
+            let log_ids: Vec<_> = app
+                .qso_entry
+                .worked_before()
+                .iter()
+                .map(|e| e.log_id.as_str())
+                .collect();
+            assert_eq!(log_ids, vec!["current", "old"]);
+        }
+
+        #[test]
+        fn clearing_callsign_hides_panel() {
+            let (_dir, mut app) = make_app_with_history();
+            type_string(&mut app, "KD9XYZ");
+            for _ in 0..6 {
+                app.handle_key(press(KeyCode::Backspace));
+            }
+            assert_eq!(app.qso_entry.worked_before_call(), None);
+        }
+    }
+
+    mod search {
+        use super::*;
+
+        /// Returns an app on Log Select with two logs: `first`, which has
+        /// QSOs with K4ABC, KD9XYZ and W1ABC an hour apart, and `second`,
+        /// which has none.
+        fn make_app_with_qsos() -> (tempfile::TempDir, App) {
+            let dir = tempfile::tempdir().unwrap();
+            let manager = LogManager::with_path(dir.path()).unwrap();
+            let mut first = save_test_log(&manager, "first");
+            for (hours_ago, call) in [(3, "K4ABC"), (2, "KD9XYZ"), (1, "W1ABC")] {
+                first.add_qso(
+                    Qso::new(
+                        call.into(),
+                        "59".into(),
+                        "59".into(),
+                        crate::model::Band::M20,
+                        crate::model::Mode::Ssb,
+                        chrono::Utc::now() - chrono::Duration::hours(hours_ago),
+                        String::new(),
+                        None,
+                        None,
+                        None,
+                        None,
+                    )
+                    .unwrap(),
+                );
+            }
+            manager.save_log(&first).unwrap();
+            save_test_log(&manager, "second");
+            (dir, App::new(manager).unwrap())
+        }
+
+        fn search_for(app: &mut App, call: &str) {
+            app.handle_key(press(KeyCode::Char('/')));
+            assert_eq!(app.screen(), Screen::Search);
+            type_string(app, call);
+            app.handle_key(press(KeyCode::Enter));
+        }
+
+        #[test]
+        fn slash_opens_search_and_esc_returns() {
+            let (_dir, mut app) = make_app_with_qsos();
+            app.handle_key(press(KeyCode::Char('/')));
+            assert_eq!(app.screen(), Screen::Search);
+            app.handle_key(press(KeyCode::Esc));
+            assert_eq!(app.screen(), Screen::LogSelect);
+        }
+
+        #[test]
+        fn search_lists_matching_qsos_newest_first() {
+            let (_dir, mut app) = make_app_with_qsos();
+            search_for(&mut app, "abc");
+            let calls: Vec<_> = app
+                .search
+                .results()
+                .iter()
+                .map(|h| h.qso.their_call.as_str())
+                .collect();
+            assert_eq!(calls, vec!["W1ABC", "K4ABC"]);
+            assert!(app.search.in_results());
+        }
+
+        #[test]
+        fn enter_opens_hit_in_qso_list() {
+            let (_dir, mut app) = make_app_with_qsos();
+            search_for(&mut app, "KD9XYZ");
+            app.handle_key(press(KeyCode::Enter));
+            assert_eq!(app.screen(), Screen::QsoList);
+            let log = app.current_log().unwrap();
+            assert_eq!(log.header().log_id, "first");
+            let row = app.qso_list.selected();
+            let index = app.qso_list.display_order(Some(log))[row];
+            assert_eq!(log.qsos()[index].their_call, "KD9XYZ");
+        }
+
+        #[test]
+        fn returning_to_search_reruns_last_query() {
+            let (_dir, mut app) = make_app_with_qsos();
+            search_for(&mut app, "KD9XYZ");
+            app.handle_key(press(KeyCode::Enter));
+            app.handle_key(press(KeyCode::Esc));
+            assert_eq!(app.screen(), Screen::QsoEntry);
+            type_string(&mut app, "KD9XYZ");
+            app.handle_key(press(KeyCode::Enter));
+            app.handle_key(press(KeyCode::Esc));
+            assert_eq!(app.screen(), Screen::LogSelect);
+            app.handle_key(press(KeyCode::Char('/')));
+            assert_eq!(app.search.results().len(), 2);
+        }
+    }
+
+    mod awards {
+        use super::*;
+        use crate::awards::Award;
+
+        /// Returns an app on Log Select with a log holding one QSO with a
+        /// park on 20m and one with a state on 40m.
+        fn make_app_with_awards() -> (tempfile::TempDir, App) {
+            let dir = tempfile::tempdir().unwrap();
+            let manager = LogManager::with_path(dir.path()).unwrap();
+            let mut log = save_test_log(&manager, "hunted");
+            let qso = |band, park: Option<&str>| {
+                Qso::new(
+                    "K4ABC".into(),
+                    "59".into(),
+                    "59".into(),
+                    band,
+                    crate::model::Mode::Ssb,
+                    chrono::Utc::now(),
+                    String::new(),
+                    park.map(String::from),
+                    None,
+                    None,
+                    None,
+                )
+                .unwrap()
+            };
+            log.add_qso(qso(crate::model::Band::M20, Some("K-1234")));
+            let mut with_state = qso(crate::model::Band::M40, None);
+            with_state.extra_fields.insert("STATE", "GA");
+            log.add_qso(with_state);
+            manager.save_log(&log).unwrap();
+            (dir, App::new(manager).unwrap())
+        }
+
+        fn worked(app: &App, award: Award) -> Vec<String> {
+            let progress = app.awards.progress().iter();
+            progress
+                .filter(|p| p.award == award)
+                .flat_map(|p| p.worked.clone())
+                .collect()
+        }
+
+        #[test]
+        fn a_opens_awards_from_every_log() {
+            let (_dir, mut app) = make_app_with_awards();
+            app.handle_key(press(KeyCode::Char('a')));
+            assert_eq!(app.screen(), Screen::Awards);
+            assert_eq!(worked(&app, Award::Parks), vec!["K-1234"]);
+            assert_eq!(worked(&app, Award::States), vec!["GA"]);
+            app.handle_key(press(KeyCode::Esc));
+            assert_eq!(app.screen(), Screen::LogSelect);
+        }
+
+        #[test]
+        fn b_filters_by_band() {
+            let (_dir, mut app) = make_app_with_awards();
+            app.handle_key(press(KeyCode::Char('a')));
+            app.handle_key(press(KeyCode::Char('b')));
+            assert_eq!(app.awards.filter().band, Some(crate::model::Band::M40));
+            assert!(worked(&app, Award::Parks).is_empty());
+            assert_eq!(worked(&app, Award::States), vec!["GA"]);
+        }
+
+        #[test]
+        fn reopening_picks_up_new_qsos() {
+            let (_dir, mut app) = make_app_with_awards();
+            app.handle_key(press(KeyCode::Char('a')));
+            app.handle_key(press(KeyCode::Esc));
+            app.handle_key(press(KeyCode::Enter));
+            type_string(&mut app, "W1XYZ");
+            app.handle_key(press(KeyCode::Tab));
+            app.handle_key(press(KeyCode::Tab));
+            app.handle_key(press(KeyCode::Tab));
+            type_string(&mut app, "K-0002");
+            app.handle_key(press(KeyCode::Enter));
+            app.handle_key(press(KeyCode::Esc));
+            app.handle_key(press(KeyCode::Char('a')));
+            assert_eq!(worked(&app, Award::Parks), vec!["K-0002", "K-1234"]);
+        }
+    }
 }
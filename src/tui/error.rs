@@ -1,4 +1,6 @@
+use crate::hooks::HookError;
 use crate::storage::StorageError;
+use crate::sync::SyncError;
 
 /// Errors that can occur in the TUI layer.
 #[derive(Debug, thiserror::Error)]
@@ -10,4 +12,59 @@ pub enum AppError {
     /// A storage error occurred while persisting data.
     #[error("Storage error: {0}")]
     Storage(#[from] StorageError),
+
+    /// The configured key bindings are invalid.
+    #[error("Keymap error: {0}")]
+    Keymap(#[from] KeymapError),
+
+    /// The configured theme name is not a built-in theme.
+    #[error("unknown theme {0:?}")]
+    UnknownTheme(String),
+
+    /// LAN sync is configured but could not start.
+    #[error("Sync error: {0}")]
+    Sync(#[from] SyncError),
+
+    /// The configured hooks are invalid.
+    #[error("Hook error: {0}")]
+    Hook(#[from] HookError),
+}
+
+/// Errors in user key binding overrides.
+#[derive(Debug, thiserror::Error)]
+pub enum KeymapError {
+    /// The config names a command that does not exist.
+    #[error("unknown command {0:?}")]
+    UnknownCommand(String),
+
+    /// A key string could not be parsed.
+    #[error("invalid key {key:?} for command {command:?}")]
+    InvalidKey {
+        /// The command the key was bound to.
+        command: String,
+        /// The unparseable key string.
+        key: String,
+    },
+
+    /// The same key is bound to two commands available on the same screen.
+    #[error("key {key} is bound to both {first} and {second}")]
+    Conflict {
+        /// The conflicting key, as displayed on the help screen.
+        key: String,
+        /// The first command bound to the key.
+        first: &'static str,
+        /// The second command bound to the key.
+        second: &'static str,
+    },
+
+    /// A plain character key is bound to a command available where text is typed.
+    #[error(
+        "key {key} cannot be bound to {command}: it is typed as text on screens where {command} applies; add Ctrl or Alt"
+    )]
+    TextEntryKey {
+        /// The character key, as displayed on the help screen.
+        key: String,
+        /// The command the key was bound to.
+        command: &'static str,
+    },
 }
@@ -0,0 +1,867 @@
+//! Keymap — translates key events into semantic [`Command`]s.
+//!
+//! [`App`](super::App) resolves every key press against the active [`Keymap`]
+//! before delegating to a screen. Bound keys reach the screen as a [`Command`];
+//! everything else (text entry, Tab, Esc, arrows, y/n confirmations) is passed
+//! through as the raw [`KeyEvent`].
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use super::app::Screen;
+use super::error::KeymapError;
+
+/// A remappable user command.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Command {
+    /// Open context-sensitive help (every screen).
+    Help,
+    /// Select the next band in the cycle (QSO Entry).
+    NextBand,
+    /// Select the previous band in the cycle (QSO Entry).
+    PrevBand,
+    /// Select the next favourite mode (QSO Entry).
+    NextMode,
+    /// Select the previous favourite mode (QSO Entry).
+    PrevMode,
+    /// Open the QSO list (QSO Entry).
+    OpenQsoList,
+    /// Open the export screen (QSO Entry).
+    OpenExport,
+    /// Toggle backfill mode for entering QSOs with explicit date/time (QSO Entry).
+    ToggleBackfill,
+    /// Switch the current operator for multi-op logs (QSO Entry).
+    SetOperator,
+    /// Restart the station ID reminder (QSO Entry).
+    StationId,
+    /// Start creating a new log (Log Select).
+    NewLog,
+    /// Delete the selected log, pending confirmation (Log Select).
+    DeleteLog,
+    /// Mark or unmark the selected log for merging (Log Select).
+    MarkLog,
+    /// Merge the marked logs into a new log, pending confirmation (Log Select).
+    MergeLogs,
+    /// Edit the selected log's details (Log Select).
+    EditLog,
+    /// Start a new log pre-filled from the selected one (Log Select).
+    CopyLog,
+    /// Remove the damaged records from the selected log's file (Log Select).
+    RepairLog,
+    /// Search the QSOs in every log (Log Select).
+    Search,
+    /// Show award progress across every log (Log Select).
+    Awards,
+    /// Edit the selected QSO (QSO List).
+    EditQso,
+    /// Delete the selected QSO, pending confirmation (QSO List).
+    DeleteQso,
+    /// Mark or unmark the selected QSO for bulk editing (QSO List).
+    MarkQso,
+    /// Open the bulk-edit dialog for the marked QSOs (QSO List).
+    BulkEdit,
+    /// Sort by the next field in the cycle (QSO List).
+    SortQsos,
+    /// Reverse the sort order (QSO List).
+    ReverseSort,
+    /// Choose which columns are shown (QSO List).
+    ChooseColumns,
+    /// Filter award progress by the next band in use (Awards).
+    FilterBand,
+    /// Filter award progress by the next mode in use (Awards).
+    FilterMode,
+}
+
+static ALL_COMMANDS: &[Command] = &[
+    Command::Help,
+    Command::NextBand,
+    Command::PrevBand,
+    Command::NextMode,
+    Command::PrevMode,
+    Command::OpenQsoList,
+    Command::OpenExport,
+    Command::ToggleBackfill,
+    Command::SetOperator,
+    Command::StationId,
+    Command::NewLog,
+    Command::DeleteLog,
+    Command::MarkLog,
+    Command::MergeLogs,
+    Command::EditLog,
+    Command::CopyLog,
+    Command::RepairLog,
+    Command::Search,
+    Command::Awards,
+    Command::EditQso,
+    Command::DeleteQso,
+    Command::MarkQso,
+    Command::BulkEdit,
+    Command::SortQsos,
+    Command::ReverseSort,
+    Command::ChooseColumns,
+    Command::FilterBand,
+    Command::FilterMode,
+];
+
+static ALL_SCREENS: &[Screen] = &[
+    Screen::LogSelect,
+    Screen::LogCreate,
+    Screen::QsoEntry,
+    Screen::QsoList,
+    Screen::Export,
+    Screen::Search,
+    Screen::Awards,
+    Screen::Help,
+];
+
+/// Screens with a text field that takes unmodified character keys.
+static TEXT_ENTRY_SCREENS: &[Screen] = &[
+    Screen::LogCreate,
+    Screen::QsoEntry,
+    Screen::Export,
+    Screen::Search,
+];
+
+impl Command {
+    /// Returns every command.
+    pub fn all() -> &'static [Command] {
+        ALL_COMMANDS
+    }
+
+    /// Returns the name used for this command in the config file.
+    pub fn name(self) -> &'static str {
+        match self {
+            Command::Help => "help",
+            Command::NextBand => "next_band",
+            Command::PrevBand => "prev_band",
+            Command::NextMode => "next_mode",
+            Command::PrevMode => "prev_mode",
+            Command::OpenQsoList => "qso_list",
+            Command::OpenExport => "export",
+            Command::ToggleBackfill => "backfill",
+            Command::SetOperator => "operator",
+            Command::StationId => "station_id",
+            Command::NewLog => "new_log",
+            Command::DeleteLog => "delete_log",
+            Command::MarkLog => "mark_log",
+            Command::MergeLogs => "merge_logs",
+            Command::EditLog => "edit_log",
+            Command::CopyLog => "copy_log",
+            Command::RepairLog => "repair_log",
+            Command::Search => "search",
+            Command::Awards => "awards",
+            Command::EditQso => "edit_qso",
+            Command::DeleteQso => "delete_qso",
+            Command::MarkQso => "mark_qso",
+            Command::BulkEdit => "bulk_edit",
+            Command::SortQsos => "sort_qsos",
+            Command::ReverseSort => "reverse_sort",
+            Command::ChooseColumns => "choose_columns",
+            Command::FilterBand => "filter_band",
+            Command::FilterMode => "filter_mode",
+        }
+    }
+
+    /// Parses a command from its config-file [`name`](Self::name).
+    pub fn from_name(s: &str) -> Option<Command> {
+        ALL_COMMANDS.iter().find(|c| c.name() == s).copied()
+    }
+
+    /// Returns the description shown on the help screen.
+    pub fn description(self) -> &'static str {
+        match self {
+            Command::Help => "help",
+            Command::NextBand => "next band",
+            Command::PrevBand => "prev band",
+            Command::NextMode => "next mode",
+            Command::PrevMode => "prev mode",
+            Command::OpenQsoList => "open QSO list",
+            Command::OpenExport => "export log",
+            Command::ToggleBackfill => "toggle backfill (paper log) mode",
+            Command::SetOperator => "switch operator",
+            Command::StationId => "restart station ID reminder",
+            Command::NewLog => "new log",
+            Command::DeleteLog => "delete log (y/n to confirm)",
+            Command::MarkLog => "mark log for merge",
+            Command::MergeLogs => "merge marked logs",
+            Command::EditLog => "edit log details",
+            Command::CopyLog => "new log from selected",
+            Command::RepairLog => "repair damaged log",
+            Command::Search => "search QSOs in all logs",
+            Command::Awards => "award progress",
+            Command::EditQso => "edit QSO",
+            Command::DeleteQso => "delete QSO (y/n to confirm)",
+            Command::MarkQso => "mark QSO for bulk edit",
+            Command::BulkEdit => "bulk edit marked QSOs",
+            Command::SortQsos => "cycle sort (time, call, band, mode, park)",
+            Command::ReverseSort => "reverse sort order",
+            Command::ChooseColumns => "choose columns",
+            Command::FilterBand => "cycle band filter",
+            Command::FilterMode => "cycle mode filter",
+        }
+    }
+
+    /// Returns `true` if this command is available on `screen`.
+    pub fn applies_to(self, screen: Screen) -> bool {
+        match self {
+            Command::Help => true,
+            Command::NextBand
+            | Command::PrevBand
+            | Command::NextMode
+            | Command::PrevMode
+            | Command::OpenQsoList
+            | Command::OpenExport
+            | Command::ToggleBackfill
+            | Command::SetOperator
+            | Command::StationId => screen == Screen::QsoEntry,
+            Command::NewLog
+            | Command::DeleteLog
+            | Command::MarkLog
+            | Command::MergeLogs
+            | Command::EditLog
+            | Command::CopyLog
+            | Command::RepairLog
+            | Command::Search
+            | Command::Awards => screen == Screen::LogSelect,
+            Command::EditQso
+            | Command::DeleteQso
+            | Command::MarkQso
+            | Command::BulkEdit
+            | Command::SortQsos
+            | Command::ReverseSort
+            | Command::ChooseColumns => screen == Screen::QsoList,
+            Command::FilterBand | Command::FilterMode => screen == Screen::Awards,
+        }
+    }
+
+    /// Returns the built-in bindings for this command.
+    fn default_keys(self) -> Vec<KeyBinding> {
+        let alt_shift = KeyModifiers::ALT.union(KeyModifiers::SHIFT);
+        let key = |code, modifiers| vec![KeyBinding::new(code, modifiers)];
+        match self {
+            Command::Help => key(KeyCode::F(1), KeyModifiers::NONE),
+            Command::NextBand => key(KeyCode::Char('b'), KeyModifiers::ALT),
+            Command::PrevBand => key(KeyCode::Char('B'), alt_shift),
+            Command::NextMode => key(KeyCode::Char('m'), KeyModifiers::ALT),
+            Command::PrevMode => key(KeyCode::Char('M'), alt_shift),
+            Command::OpenQsoList => key(KeyCode::Char('e'), KeyModifiers::ALT),
+            Command::OpenExport => key(KeyCode::Char('x'), KeyModifiers::ALT),
+            Command::ToggleBackfill => key(KeyCode::Char('t'), KeyModifiers::ALT),
+            Command::SetOperator => key(KeyCode::Char('o'), KeyModifiers::ALT),
+            Command::StationId => key(KeyCode::Char('i'), KeyModifiers::ALT),
+            Command::NewLog => key(KeyCode::Char('n'), KeyModifiers::NONE),
+            Command::DeleteLog | Command::DeleteQso => key(KeyCode::Char('d'), KeyModifiers::NONE),
+            Command::MarkLog | Command::MarkQso => key(KeyCode::Char(' '), KeyModifiers::NONE),
+            Command::MergeLogs | Command::FilterMode => key(KeyCode::Char('m'), KeyModifiers::NONE),
+            Command::EditLog => key(KeyCode::Char('e'), KeyModifiers::NONE),
+            Command::CopyLog => key(KeyCode::Char('c'), KeyModifiers::NONE),
+            Command::Search => key(KeyCode::Char('/'), KeyModifiers::NONE),
+            Command::Awards => key(KeyCode::Char('a'), KeyModifiers::NONE),
+            Command::EditQso => key(KeyCode::Enter, KeyModifiers::NONE),
+            Command::BulkEdit | Command::FilterBand => key(KeyCode::Char('b'), KeyModifiers::NONE),
+            Command::SortQsos => key(KeyCode::Char('s'), KeyModifiers::NONE),
+            Command::ReverseSort | Command::RepairLog => {
+                key(KeyCode::Char('r'), KeyModifiers::NONE)
+            }
+            Command::ChooseColumns => key(KeyCode::Char('c'), KeyModifiers::NONE),
+        }
+    }
+}
+
+/// A key plus modifiers, normalised so that terminal quirks compare equal.
+///
+/// Letters carry Shift explicitly (`Shift+Alt+B` is `'B'` with `SHIFT|ALT`,
+/// whether or not the terminal reported the Shift bit), and Shift is dropped
+/// from other characters since it is already reflected in the character itself.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyBinding {
+    code: KeyCode,
+    modifiers: KeyModifiers,
+}
+
+impl KeyBinding {
+    /// Creates a normalised binding.
+    pub fn new(code: KeyCode, modifiers: KeyModifiers) -> Self {
+        let modifiers =
+            modifiers & (KeyModifiers::CONTROL | KeyModifiers::ALT | KeyModifiers::SHIFT);
+        match code {
+            KeyCode::Char(c) if c.is_ascii_alphabetic() => {
+                let shifted = c.is_ascii_uppercase() || modifiers.contains(KeyModifiers::SHIFT);
+                if shifted {
+                    Self {
+                        code: KeyCode::Char(c.to_ascii_uppercase()),
+                        modifiers: modifiers | KeyModifiers::SHIFT,
+                    }
+                } else {
+                    Self { code, modifiers }
+                }
+            }
+            KeyCode::Char(_) => Self {
+                code,
+                modifiers: modifiers - KeyModifiers::SHIFT,
+            },
+            _ => Self { code, modifiers },
+        }
+    }
+
+    /// Creates a binding matching a key event.
+    pub fn from_event(key: &KeyEvent) -> Self {
+        Self::new(key.code, key.modifiers)
+    }
+
+    /// Returns `true` for a character typed without Ctrl or Alt, which a
+    /// text field would take as input.
+    fn types_text(self) -> bool {
+        matches!(self.code, KeyCode::Char(_))
+            && !self
+                .modifiers
+                .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT)
+    }
+
+    /// Parses a binding such as `"Alt+b"`, `"Shift+Alt+B"`, `"Ctrl+e"`, `"F5"` or `"d"`.
+    ///
+    /// Modifier and key names are case-insensitive; a single character is taken
+    /// literally. Returns `None` for unrecognised names.
+    pub fn parse(s: &str) -> Option<Self> {
+        let (mods, key) = if s == "+" {
+            ("", s)
+        } else if let Some(mods) = s.strip_suffix("++") {
+            (mods, "+")
+        } else {
+            s.rsplit_once('+').unwrap_or(("", s))
+        };
+        let modifiers =
+            mods.split('+')
+                .filter(|m| !m.is_empty())
+                .try_fold(KeyModifiers::NONE, |acc, m| {
+                    let modifier = match m.to_ascii_lowercase().as_str() {
+                        "ctrl" | "control" => KeyModifiers::CONTROL,
+                        "alt" | "meta" => KeyModifiers::ALT,
+                        "shift" => KeyModifiers::SHIFT,
+                        _ => return None,
+                    };
+                    Some(acc | modifier)
+                })?;
+        let mut chars = key.chars();
+        let code = match (chars.next(), chars.next()) {
+            (Some(c), None) => KeyCode::Char(c),
+            _ => parse_named_key(key)?,
+        };
+        Some(Self::new(code, modifiers))
+    }
+}
+
+/// Parses a named (multi-character) key such as `Enter` or `F5`.
+fn parse_named_key(name: &str) -> Option<KeyCode> {
+    let lower = name.to_ascii_lowercase();
+    let code = match lower.as_str() {
+        "enter" | "return" => KeyCode::Enter,
+        "esc" | "escape" => KeyCode::Esc,
+        "tab" => KeyCode::Tab,
+        "backspace" => KeyCode::Backspace,
+        "delete" | "del" => KeyCode::Delete,
+        "insert" | "ins" => KeyCode::Insert,
+        "space" => KeyCode::Char(' '),
+        "up" => KeyCode::Up,
+        "down" => KeyCode::Down,
+        "left" => KeyCode::Left,
+        "right" => KeyCode::Right,
+        "home" => KeyCode::Home,
+        "end" => KeyCode::End,
+        "pageup" => KeyCode::PageUp,
+        "pagedown" => KeyCode::PageDown,
+        _ => {
+            let n = lower.strip_prefix('f')?.parse::<u8>().ok()?;
+            if !(1..=24).contains(&n) {
+                return None;
+            }
+            KeyCode::F(n)
+        }
+    };
+    Some(code)
+}
+
+#[mutants::skip]
+impl fmt::Display for KeyBinding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.modifiers.contains(KeyModifiers::CONTROL) {
+            f.write_str("Ctrl+")?;
+        }
+        if self.modifiers.contains(KeyModifiers::SHIFT) {
+            f.write_str("Shift+")?;
+        }
+        if self.modifiers.contains(KeyModifiers::ALT) {
+            f.write_str("Alt+")?;
+        }
+        match self.code {
+            KeyCode::Char(' ') => f.write_str("Space"),
+            KeyCode::Char(c) => write!(f, "{c}"),
+            KeyCode::F(n) => write!(f, "F{n}"),
+            KeyCode::Enter => f.write_str("Enter"),
+            KeyCode::Esc => f.write_str("Esc"),
+            KeyCode::Tab => f.write_str("Tab"),
+            KeyCode::Backspace => f.write_str("Backspace"),
+            KeyCode::Delete => f.write_str("Delete"),
+            KeyCode::Insert => f.write_str("Insert"),
+            KeyCode::Up => f.write_str("Up"),
+            KeyCode::Down => f.write_str("Down"),
+            KeyCode::Left => f.write_str("Left"),
+            KeyCode::Right => f.write_str("Right"),
+            KeyCode::Home => f.write_str("Home"),
+            KeyCode::End => f.write_str("End"),
+            KeyCode::PageUp => f.write_str("PageUp"),
+            KeyCode::PageDown => f.write_str("PageDown"),
+            other => write!(f, "{other:?}"),
+        }
+    }
+}
+
+/// The active key bindings: each [`Command`] maps to zero or more keys.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Keymap {
+    bindings: BTreeMap<Command, Vec<KeyBinding>>,
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Keymap {
+    /// Creates the built-in keymap.
+    pub fn new() -> Self {
+        let bindings = ALL_COMMANDS
+            .iter()
+            .map(|&command| (command, command.default_keys()))
+            .collect();
+        Self { bindings }
+    }
+
+    /// Creates a keymap from the built-in bindings with user overrides applied.
+    ///
+    /// `overrides` maps command names to key lists, as in the config file's
+    /// `keys` table. A listed command's defaults are replaced entirely (an empty
+    /// list unbinds it); unlisted commands keep their defaults.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`KeymapError`] for an unknown command name, an unparseable key,
+    /// a key bound to two commands that are available on the same screen, or a
+    /// plain character bound to a command available on a text-entry screen.
+    pub fn with_overrides(overrides: &BTreeMap<String, Vec<String>>) -> Result<Self, KeymapError> {
+        let mut keymap = Self::new();
+        for (name, keys) in overrides {
+            let command = Command::from_name(name)
+                .ok_or_else(|| KeymapError::UnknownCommand(name.clone()))?;
+            let bindings = keys
+                .iter()
+                .map(|key| {
+                    KeyBinding::parse(key).ok_or_else(|| KeymapError::InvalidKey {
+                        command: name.clone(),
+                        key: key.clone(),
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            keymap.bindings.insert(command, bindings);
+        }
+        keymap.check_conflicts()?;
+        Ok(keymap)
+    }
+
+    /// Returns an error if any key resolves to two commands on the same screen,
+    /// or if a plain character is bound to a command available where text is
+    /// typed, since it could then never be typed there.
+    fn check_conflicts(&self) -> Result<(), KeymapError> {
+        let pairs = self
+            .bindings
+            .iter()
+            .flat_map(|(&command, keys)| keys.iter().map(move |&key| (command, key)));
+        pairs
+            .clone()
+            .enumerate()
+            .flat_map(|(i, a)| pairs.clone().skip(i + 1).map(move |b| (a, b)))
+            .find(|&((cmd_a, key_a), (cmd_b, key_b))| {
+                cmd_a != cmd_b
+                    && key_a == key_b
+                    && ALL_SCREENS
+                        .iter()
+                        .any(|&s| cmd_a.applies_to(s) && cmd_b.applies_to(s))
+            })
+            .map_or(Ok(()), |((first, key), (second, _))| {
+                Err(KeymapError::Conflict {
+                    key: key.to_string(),
+                    first: first.name(),
+                    second: second.name(),
+                })
+            })?;
+        pairs
+            .clone()
+            .find(|&(command, key)| {
+                key.types_text() && TEXT_ENTRY_SCREENS.iter().any(|&s| command.applies_to(s))
+            })
+            .map_or(Ok(()), |(command, key)| {
+                Err(KeymapError::TextEntryKey {
+                    key: key.to_string(),
+                    command: command.name(),
+                })
+            })
+    }
+
+    /// Returns the command bound to `key` on `screen`, if any.
+    pub fn resolve(&self, screen: Screen, key: &KeyEvent) -> Option<Command> {
+        let binding = KeyBinding::from_event(key);
+        self.bindings
+            .iter()
+            .find(|(command, keys)| command.applies_to(screen) && keys.contains(&binding))
+            .map(|(&command, _)| command)
+    }
+
+    /// Returns the keys bound to `command`.
+    pub fn keys(&self, command: Command) -> &[KeyBinding] {
+        self.bindings.get(&command).map_or(&[], Vec::as_slice)
+    }
+
+    /// Returns the keys bound to `command` for display, e.g. `"Alt+b / F5"`.
+    ///
+    /// Returns `"(unbound)"` when the command has no keys.
+    pub fn describe(&self, command: Command) -> String {
+        match self.keys(command) {
+            [] => "(unbound)".to_string(),
+            keys => keys
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(" / "),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crossterm::event::{KeyEventKind, KeyEventState};
+
+    use super::*;
+
+    fn event(code: KeyCode, modifiers: KeyModifiers) -> KeyEvent {
+        KeyEvent {
+            code,
+            modifiers,
+            kind: KeyEventKind::Press,
+            state: KeyEventState::NONE,
+        }
+    }
+
+    fn overrides(pairs: &[(&str, &[&str])]) -> BTreeMap<String, Vec<String>> {
+        pairs
+            .iter()
+            .map(|(name, keys)| {
+                (
+                    name.to_string(),
+                    keys.iter().map(|k| k.to_string()).collect(),
+                )
+            })
+            .collect()
+    }
+
+    mod command {
+        use super::*;
+
+        #[test]
+        fn names_round_trip() {
+            for &command in Command::all() {
+                assert_eq!(Command::from_name(command.name()), Some(command));
+            }
+        }
+
+        #[test]
+        fn unknown_name_is_none() {
+            assert_eq!(Command::from_name("next-band"), None);
+        }
+
+        #[test]
+        fn help_applies_everywhere() {
+            assert!(
+                ALL_SCREENS
+                    .iter()
+                    .all(|&screen| Command::Help.applies_to(screen))
+            );
+        }
+
+        #[test]
+        fn screen_commands_apply_to_one_screen() {
+            assert!(Command::NextBand.applies_to(Screen::QsoEntry));
+            assert!(!Command::NextBand.applies_to(Screen::QsoList));
+            assert!(Command::DeleteQso.applies_to(Screen::QsoList));
+            assert!(!Command::DeleteQso.applies_to(Screen::LogSelect));
+            assert!(Command::NewLog.applies_to(Screen::LogSelect));
+            assert!(!Command::NewLog.applies_to(Screen::QsoEntry));
+        }
+    }
+
+    mod key_binding {
+        use super::*;
+
+        #[test]
+        fn parses_plain_char() {
+            assert_eq!(
+                KeyBinding::parse("d"),
+                Some(KeyBinding::new(KeyCode::Char('d'), KeyModifiers::NONE))
+            );
+        }
+
+        #[test]
+        fn parses_modifiers_case_insensitively() {
+            assert_eq!(
+                KeyBinding::parse("ALT+b"),
+                Some(KeyBinding::new(KeyCode::Char('b'), KeyModifiers::ALT))
+            );
+            assert_eq!(
+                KeyBinding::parse("ctrl+e"),
+                Some(KeyBinding::new(KeyCode::Char('e'), KeyModifiers::CONTROL))
+            );
+        }
+
+        #[test]
+        fn shift_letter_and_uppercase_letter_are_equal() {
+            assert_eq!(KeyBinding::parse("Shift+Alt+b"), KeyBinding::parse("Alt+B"));
+            assert_eq!(
+                KeyBinding::parse("Alt+B"),
+                Some(KeyBinding::new(
+                    KeyCode::Char('B'),
+                    KeyModifiers::ALT | KeyModifiers::SHIFT
+                ))
+            );
+        }
+
+        #[test]
+        fn shift_is_dropped_for_symbols() {
+            assert_eq!(
+                KeyBinding::new(KeyCode::Char('?'), KeyModifiers::SHIFT),
+                KeyBinding::new(KeyCode::Char('?'), KeyModifiers::NONE)
+            );
+        }
+
+        #[test]
+        fn parses_named_keys() {
+            assert_eq!(
+                KeyBinding::parse("F5"),
+                Some(KeyBinding::new(KeyCode::F(5), KeyModifiers::NONE))
+            );
+            assert_eq!(
+                KeyBinding::parse("enter"),
+                Some(KeyBinding::new(KeyCode::Enter, KeyModifiers::NONE))
+            );
+            assert_eq!(
+                KeyBinding::parse("Ctrl+PageDown"),
+                Some(KeyBinding::new(KeyCode::PageDown, KeyModifiers::CONTROL))
+            );
+            assert_eq!(
+                KeyBinding::parse("Space"),
+                Some(KeyBinding::new(KeyCode::Char(' '), KeyModifiers::NONE))
+            );
+        }
+
+        #[test]
+        fn parses_plus_key() {
+            assert_eq!(
+                KeyBinding::parse("Alt++"),
+                Some(KeyBinding::new(KeyCode::Char('+'), KeyModifiers::ALT))
+            );
+            assert_eq!(
+                KeyBinding::parse("+"),
+                Some(KeyBinding::new(KeyCode::Char('+'), KeyModifiers::NONE))
+            );
+        }
+
+        #[test]
+        fn rejects_unknown_names() {
+            assert_eq!(KeyBinding::parse("Hyper+b"), None);
+            assert_eq!(KeyBinding::parse("Alt+bogus"), None);
+            assert_eq!(KeyBinding::parse("F0"), None);
+            assert_eq!(KeyBinding::parse("F25"), None);
+            assert_eq!(KeyBinding::parse(""), None);
+            assert_eq!(KeyBinding::parse("Alt+"), None);
+        }
+
+        #[test]
+        fn display_matches_help_conventions() {
+            assert_eq!(KeyBinding::parse("Alt+b").unwrap().to_string(), "Alt+b");
+            assert_eq!(
+                KeyBinding::parse("Alt+Shift+b").unwrap().to_string(),
+                "Shift+Alt+B"
+            );
+            assert_eq!(KeyBinding::parse("ctrl+f2").unwrap().to_string(), "Ctrl+F2");
+            assert_eq!(KeyBinding::parse("space").unwrap().to_string(), "Space");
+        }
+
+        #[test]
+        fn from_event_matches_terminal_without_shift_bit() {
+            // Some terminals report Shift+Alt+B as 'B' with only ALT set
+            let reported = event(KeyCode::Char('B'), KeyModifiers::ALT);
+            assert_eq!(
+                KeyBinding::from_event(&reported),
+                KeyBinding::parse("Shift+Alt+B").unwrap()
+            );
+        }
+    }
+
+    mod keymap {
+        use super::*;
+
+        #[test]
+        fn defaults_match_documented_keys() {
+            let keymap = Keymap::new();
+            assert_eq!(keymap.describe(Command::Help), "F1");
+            assert_eq!(keymap.describe(Command::NextBand), "Alt+b");
+            assert_eq!(keymap.describe(Command::PrevBand), "Shift+Alt+B");
+            assert_eq!(keymap.describe(Command::NextMode), "Alt+m");
+            assert_eq!(keymap.describe(Command::PrevMode), "Shift+Alt+M");
+            assert_eq!(keymap.describe(Command::OpenQsoList), "Alt+e");
+            assert_eq!(keymap.describe(Command::OpenExport), "Alt+x");
+            assert_eq!(keymap.describe(Command::StationId), "Alt+i");
+            assert_eq!(keymap.describe(Command::NewLog), "n");
+            assert_eq!(keymap.describe(Command::DeleteLog), "d");
+            assert_eq!(keymap.describe(Command::MarkLog), "Space");
+            assert_eq!(keymap.describe(Command::MergeLogs), "m");
+            assert_eq!(keymap.describe(Command::EditLog), "e");
+            assert_eq!(keymap.describe(Command::CopyLog), "c");
+            assert_eq!(keymap.describe(Command::RepairLog), "r");
+            assert_eq!(keymap.describe(Command::Search), "/");
+            assert_eq!(keymap.describe(Command::Awards), "a");
+            assert_eq!(keymap.describe(Command::FilterBand), "b");
+            assert_eq!(keymap.describe(Command::FilterMode), "m");
+            assert_eq!(keymap.describe(Command::MarkQso), "Space");
+            assert_eq!(keymap.describe(Command::BulkEdit), "b");
+            assert_eq!(keymap.describe(Command::SortQsos), "s");
+            assert_eq!(keymap.describe(Command::ReverseSort), "r");
+            assert_eq!(keymap.describe(Command::ChooseColumns), "c");
+            assert_eq!(keymap.describe(Command::EditQso), "Enter");
+            assert_eq!(keymap.describe(Command::DeleteQso), "d");
+        }
+
+        #[test]
+        fn resolve_is_scoped_to_screen() {
+            let keymap = Keymap::new();
+            let d = event(KeyCode::Char('d'), KeyModifiers::NONE);
+            assert_eq!(
+                keymap.resolve(Screen::LogSelect, &d),
+                Some(Command::DeleteLog)
+            );
+            assert_eq!(
+                keymap.resolve(Screen::QsoList, &d),
+                Some(Command::DeleteQso)
+            );
+            assert_eq!(keymap.resolve(Screen::QsoEntry, &d), None);
+        }
+
+        #[test]
+        fn resolve_help_on_every_screen() {
+            let keymap = Keymap::new();
+            let f1 = event(KeyCode::F(1), KeyModifiers::NONE);
+            for &screen in ALL_SCREENS {
+                assert_eq!(keymap.resolve(screen, &f1), Some(Command::Help));
+            }
+        }
+
+        #[test]
+        fn override_replaces_defaults() {
+            let keymap =
+                Keymap::with_overrides(&overrides(&[("next_band", &["F5", "Ctrl+b"])])).unwrap();
+            assert_eq!(keymap.describe(Command::NextBand), "F5 / Ctrl+b");
+            let alt_b = event(KeyCode::Char('b'), KeyModifiers::ALT);
+            assert_eq!(keymap.resolve(Screen::QsoEntry, &alt_b), None);
+            let f5 = event(KeyCode::F(5), KeyModifiers::NONE);
+            assert_eq!(
+                keymap.resolve(Screen::QsoEntry, &f5),
+                Some(Command::NextBand)
+            );
+        }
+
+        #[test]
+        fn empty_override_unbinds() {
+            let keymap = Keymap::with_overrides(&overrides(&[("delete_qso", &[])])).unwrap();
+            assert_eq!(keymap.describe(Command::DeleteQso), "(unbound)");
+            let d = event(KeyCode::Char('d'), KeyModifiers::NONE);
+            assert_eq!(keymap.resolve(Screen::QsoList, &d), None);
+        }
+
+        #[test]
+        fn unknown_command_is_error() {
+            let err = Keymap::with_overrides(&overrides(&[("fly", &["F5"])])).unwrap_err();
+            assert!(matches!(err, KeymapError::UnknownCommand(ref name) if name == "fly"));
+        }
+
+        #[test]
+        fn invalid_key_is_error() {
+            let err = Keymap::with_overrides(&overrides(&[("help", &["Hyper+h"])])).unwrap_err();
+            assert!(matches!(err, KeymapError::InvalidKey { .. }));
+            assert!(err.to_string().contains("Hyper+h"), "{err}");
+        }
+
+        #[test]
+        fn conflict_on_same_screen_is_error() {
+            let err = Keymap::with_overrides(&overrides(&[("next_mode", &["Alt+b"])])).unwrap_err();
+            let KeymapError::Conflict { key, first, second } = err else {
+                panic!("expected Conflict, got {err:?}");
+            };
+            assert_eq!(key, "Alt+b");
+            assert_eq!((first, second), ("next_band", "next_mode"));
+        }
+
+        #[test]
+        fn help_key_conflicts_with_any_screen_command() {
+            let err = Keymap::with_overrides(&overrides(&[("help", &["d"])])).unwrap_err();
+            assert!(matches!(err, KeymapError::Conflict { .. }));
+        }
+
+        #[test]
+        fn plain_character_for_text_entry_command_is_error() {
+            let err =
+                Keymap::with_overrides(&overrides(&[("next_band", &["F5", "b"])])).unwrap_err();
+            let KeymapError::TextEntryKey { key, command } = &err else {
+                panic!("expected TextEntryKey, got {err:?}");
+            };
+            assert_eq!((key.as_str(), *command), ("b", "next_band"));
+            assert!(err.to_string().contains("add Ctrl or Alt"), "{err}");
+
+            for key in ["?", "H", "1"] {
+                let err = Keymap::with_overrides(&overrides(&[("help", &[key])])).unwrap_err();
+                assert!(
+                    matches!(err, KeymapError::TextEntryKey { .. }),
+                    "{key}: {err:?}"
+                );
+            }
+        }
+
+        #[test]
+        fn modified_or_non_character_keys_for_text_entry_commands_are_allowed() {
+            let keymap = Keymap::with_overrides(&overrides(&[
+                ("next_band", &["Ctrl+b"]),
+                ("prev_band", &["Shift+Alt+B"]),
+                ("help", &["F2"]),
+            ]));
+            assert!(keymap.is_ok(), "{keymap:?}");
+        }
+
+        #[test]
+        fn plain_character_for_list_screen_command_is_allowed() {
+            let keymap = Keymap::with_overrides(&overrides(&[("sort_qsos", &["o"])]));
+            assert!(keymap.is_ok(), "{keymap:?}");
+        }
+
+        #[test]
+        fn same_key_on_different_screens_is_allowed() {
+            let keymap = Keymap::with_overrides(&overrides(&[
+                ("delete_log", &["x"]),
+                ("delete_qso", &["x"]),
+            ]));
+            assert!(
+                keymap.is_ok(),
+                "delete_log and delete_qso never share a screen"
+            );
+        }
+    }
+}
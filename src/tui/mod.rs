@@ -3,7 +3,10 @@
 pub mod action;
 pub mod app;
 pub mod error;
+pub mod keymap;
 pub mod screens;
+pub mod theme;
+pub mod timers;
 pub mod widgets;
 
 #[cfg(test)]
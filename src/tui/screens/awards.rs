@@ -0,0 +1,447 @@
+//! Awards screen — progress toward common awards across every log.
+
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::Frame;
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::Style;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Paragraph, Row, Table, Wrap};
+
+use crate::awards::{AwardFilter, AwardProgress, award_progress, bands_and_modes};
+use crate::model::{Band, Log, Mode};
+use crate::storage::{LogManager, StorageError};
+use crate::tui::action::Action;
+use crate::tui::app::Screen;
+use crate::tui::keymap::Command;
+use crate::tui::theme::Theme;
+
+/// Lines scrolled by PageUp / PageDown in the detail panel.
+const PAGE_LINES: u16 = 10;
+
+/// State for the awards screen.
+#[derive(Debug, Clone, Default)]
+pub struct AwardsState {
+    logs: Vec<Log>,
+    /// Bands and modes used in the logs, offered as filters.
+    bands: Vec<Band>,
+    modes: Vec<Mode>,
+    filter: AwardFilter,
+    progress: Vec<AwardProgress>,
+    selected: usize,
+    scroll: u16,
+}
+
+impl AwardsState {
+    /// Creates an empty awards screen.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reads every log from `manager` and recomputes award progress.
+    ///
+    /// A band or mode filter no longer used in any log is cleared.
+    pub fn load(&mut self, manager: &LogManager) -> Result<(), StorageError> {
+        self.logs = manager.list_logs()?;
+        (self.bands, self.modes) = bands_and_modes(&self.logs);
+        if self.filter.band.is_some_and(|b| !self.bands.contains(&b)) {
+            self.filter.band = None;
+        }
+        if self.filter.mode.is_some_and(|m| !self.modes.contains(&m)) {
+            self.filter.mode = None;
+        }
+        self.recompute();
+        Ok(())
+    }
+
+    /// Handles a key event, returning an [`Action`] for the app to apply.
+    pub fn handle_key(&mut self, key: KeyEvent) -> Action {
+        match key.code {
+            KeyCode::Up => self.select(self.selected.saturating_sub(1)),
+            KeyCode::Down => {
+                self.select((self.selected + 1).min(self.progress.len().saturating_sub(1)));
+            }
+            KeyCode::PageUp => self.scroll = self.scroll.saturating_sub(PAGE_LINES),
+            KeyCode::PageDown => self.scroll = self.scroll.saturating_add(PAGE_LINES),
+            KeyCode::Esc => return Action::Navigate(Screen::LogSelect),
+            _ => {}
+        }
+        Action::None
+    }
+
+    /// Handles a keymap [`Command`], returning an [`Action`] for the app to apply.
+    pub fn handle_command(&mut self, command: Command) -> Action {
+        match command {
+            Command::FilterBand => {
+                self.filter.band = next_filter(&self.bands, self.filter.band);
+                self.recompute();
+            }
+            Command::FilterMode => {
+                self.filter.mode = next_filter(&self.modes, self.filter.mode);
+                self.recompute();
+            }
+            _ => {}
+        }
+        Action::None
+    }
+
+    fn select(&mut self, index: usize) {
+        if index != self.selected {
+            self.selected = index;
+            self.scroll = 0;
+        }
+    }
+
+    fn recompute(&mut self) {
+        self.progress = award_progress(&self.logs, &self.filter);
+        self.scroll = 0;
+    }
+
+    /// Returns the progress toward each award, in display order.
+    pub fn progress(&self) -> &[AwardProgress] {
+        &self.progress
+    }
+
+    /// Returns the selected award's row.
+    pub fn selected(&self) -> usize {
+        self.selected
+    }
+
+    /// Returns the band and mode filter.
+    pub fn filter(&self) -> AwardFilter {
+        self.filter
+    }
+
+    /// Returns the detail panel scroll offset.
+    pub fn scroll(&self) -> u16 {
+        self.scroll
+    }
+}
+
+/// Returns the filter after `current`: no filter, then each of `options` in
+/// turn, then no filter again.
+fn next_filter<T: PartialEq + Copy>(options: &[T], current: Option<T>) -> Option<T> {
+    match current.and_then(|c| options.iter().position(|&o| o == c)) {
+        None => options.first().copied(),
+        Some(i) => options.get(i + 1).copied(),
+    }
+}
+
+/// Renders the awards screen: the filter line, a table of awards with counts,
+/// and the worked and needed lists of the selected award.
+#[mutants::skip]
+pub fn draw_awards(state: &AwardsState, theme: &Theme, frame: &mut Frame, area: Rect) {
+    let block = Block::default()
+        .title(" Awards ")
+        .borders(Borders::ALL)
+        .border_style(theme.accent);
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let [filter_area, body_area, footer_area] = Layout::vertical([
+        Constraint::Length(2),
+        Constraint::Min(0),
+        Constraint::Length(1),
+    ])
+    .areas(inner);
+
+    let filter = state.filter();
+    let band = filter.band.map_or("All".to_string(), |b| b.to_string());
+    let mode = filter.mode.map_or("All".to_string(), |m| m.to_string());
+    let filter_line = Line::from(vec![
+        Span::styled("Band: ", theme.dim),
+        Span::styled(band, theme.accent),
+        Span::styled("  Mode: ", theme.dim),
+        Span::styled(mode, theme.accent),
+    ]);
+    frame.render_widget(Paragraph::new(filter_line), filter_area);
+
+    let [table_area, detail_area] =
+        Layout::horizontal([Constraint::Length(40), Constraint::Min(0)]).areas(body_area);
+    draw_award_table(state, theme, frame, table_area);
+    if let Some(progress) = state.progress().get(state.selected()) {
+        draw_award_detail(progress, state.scroll(), theme, frame, detail_area);
+    }
+
+    let footer = "↑/↓: select  PgUp/PgDn: scroll  b: band  m: mode  Esc: back  F1: help";
+    frame.render_widget(Paragraph::new(footer).style(theme.dim), footer_area);
+}
+
+/// Renders one row per award: name, number worked, and number still needed.
+#[mutants::skip]
+fn draw_award_table(state: &AwardsState, theme: &Theme, frame: &mut Frame, area: Rect) {
+    let header = Row::new(vec!["Award", "Worked", "Needed"])
+        .style(theme.header)
+        .bottom_margin(1);
+    let rows: Vec<Row> = state
+        .progress()
+        .iter()
+        .enumerate()
+        .map(|(i, p)| {
+            let needed = p.remaining().map_or("—".to_string(), |n| n.to_string());
+            let style = if i == state.selected() {
+                theme.selected
+            } else {
+                Style::default()
+            };
+            Row::new(vec![
+                p.award.name().to_string(),
+                p.worked.len().to_string(),
+                needed,
+            ])
+            .style(style)
+        })
+        .collect();
+    let widths = [
+        Constraint::Length(20),
+        Constraint::Length(7),
+        Constraint::Length(7),
+    ];
+    frame.render_widget(Table::new(rows, widths).header(header), area);
+}
+
+/// Renders what has been worked and what is still needed for one award.
+#[mutants::skip]
+fn draw_award_detail(
+    progress: &AwardProgress,
+    scroll: u16,
+    theme: &Theme,
+    frame: &mut Frame,
+    area: Rect,
+) {
+    let mut lines = vec![Line::from(Span::styled(
+        format!("Worked ({})", progress.worked.len()),
+        theme.header,
+    ))];
+    lines.push(Line::from(if progress.worked.is_empty() {
+        "None yet".to_string()
+    } else {
+        progress.worked.join(", ")
+    }));
+    if !progress.needed.is_empty() {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            format!("Needed ({})", progress.needed.len()),
+            theme.header,
+        )));
+        lines.push(Line::from(progress.needed.join(", ")));
+    } else if let (Some(target), Some(remaining)) = (progress.target, progress.remaining()) {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            format!("{remaining} more needed for {target}"),
+            theme.header,
+        )));
+    }
+    if progress.uncredited > 0 {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            format!(
+                "{} QSOs not counted (no {})",
+                progress.uncredited,
+                progress.award.source()
+            ),
+            theme.warning,
+        )));
+    }
+    let detail = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .title(format!(" {} ", progress.award.name()))
+                .borders(Borders::LEFT),
+        )
+        .wrap(Wrap { trim: false })
+        .scroll((scroll, 0));
+    frame.render_widget(detail, area);
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use crossterm::event::{KeyEventKind, KeyEventState, KeyModifiers};
+
+    use super::*;
+    use crate::awards::Award;
+    use crate::model::{FdClass, FdPowerCategory, FieldDayLog, Qso};
+
+    fn press(code: KeyCode) -> KeyEvent {
+        KeyEvent {
+            code,
+            modifiers: KeyModifiers::NONE,
+            kind: KeyEventKind::Press,
+            state: KeyEventState::NONE,
+        }
+    }
+
+    fn make_qso(band: Band, mode: Mode, exchange: &str) -> Qso {
+        Qso::new(
+            "K4ABC".into(),
+            "59".into(),
+            "59".into(),
+            band,
+            mode,
+            Utc::now(),
+            String::new(),
+            None,
+            Some(exchange.into()),
+            None,
+            None,
+        )
+        .unwrap()
+    }
+
+    /// Returns a state over a Field Day log with CT on 20m SSB and EPA on
+    /// 40m CW.
+    fn make_state() -> AwardsState {
+        let mut log = FieldDayLog::new(
+            "W1AW".into(),
+            None,
+            1,
+            FdClass::B,
+            "EPA".into(),
+            FdPowerCategory::Low,
+            "FN31".into(),
+        )
+        .unwrap();
+        log.header.qsos = vec![
+            make_qso(Band::M20, Mode::Ssb, "3A CT"),
+            make_qso(Band::M40, Mode::Cw, "1D EPA"),
+        ];
+        let logs = vec![Log::FieldDay(log)];
+        let (bands, modes) = bands_and_modes(&logs);
+        let mut state = AwardsState {
+            logs,
+            bands,
+            modes,
+            ..AwardsState::default()
+        };
+        state.recompute();
+        state
+    }
+
+    fn sections(state: &AwardsState) -> &[String] {
+        let progress = state.progress().iter();
+        let sections = progress.last().unwrap();
+        assert_eq!(sections.award, Award::Sections);
+        &sections.worked
+    }
+
+    mod navigation {
+        use super::*;
+
+        #[test]
+        fn up_down_select_awards_within_bounds() {
+            let mut state = make_state();
+            state.handle_key(press(KeyCode::Up));
+            assert_eq!(state.selected(), 0);
+            for _ in 0..10 {
+                state.handle_key(press(KeyCode::Down));
+            }
+            assert_eq!(state.selected(), Award::all().len() - 1);
+        }
+
+        #[test]
+        fn selecting_award_resets_scroll() {
+            let mut state = make_state();
+            state.handle_key(press(KeyCode::PageDown));
+            assert_eq!(state.scroll(), PAGE_LINES);
+            state.handle_key(press(KeyCode::Down));
+            assert_eq!(state.scroll(), 0);
+        }
+
+        #[test]
+        fn esc_returns_to_log_select() {
+            let mut state = make_state();
+            assert_eq!(
+                state.handle_key(press(KeyCode::Esc)),
+                Action::Navigate(Screen::LogSelect)
+            );
+        }
+    }
+
+    mod filters {
+        use super::*;
+
+        #[test]
+        fn band_filter_cycles_through_used_bands() {
+            let mut state = make_state();
+            assert_eq!(sections(&state), ["CT", "EPA"]);
+            state.handle_command(Command::FilterBand);
+            assert_eq!(state.filter().band, Some(Band::M40));
+            assert_eq!(sections(&state), ["EPA"]);
+            state.handle_command(Command::FilterBand);
+            assert_eq!(state.filter().band, Some(Band::M20));
+            assert_eq!(sections(&state), ["CT"]);
+            state.handle_command(Command::FilterBand);
+            assert_eq!(state.filter().band, None);
+        }
+
+        #[test]
+        fn band_and_mode_filters_combine() {
+            let mut state = make_state();
+            state.handle_command(Command::FilterBand);
+            state.handle_command(Command::FilterMode);
+            assert_eq!(state.filter().mode, Some(Mode::Ssb));
+            assert!(sections(&state).is_empty());
+        }
+
+        #[test]
+        fn next_filter_with_no_options_stays_off() {
+            assert_eq!(next_filter::<Band>(&[], None), None);
+        }
+    }
+
+    mod rendering {
+        use ratatui::Terminal;
+        use ratatui::backend::TestBackend;
+
+        use super::*;
+        use crate::tui::test_utils::buffer_to_string;
+
+        fn render(state: &AwardsState) -> String {
+            let backend = TestBackend::new(100, 20);
+            let mut terminal = Terminal::new(backend).unwrap();
+            terminal
+                .draw(|frame| draw_awards(state, &Theme::default(), frame, frame.area()))
+                .unwrap();
+            buffer_to_string(terminal.backend().buffer())
+        }
+
+        #[test]
+        fn renders_awards_with_counts() {
+            let output = render(&make_state());
+            for text in [
+                "POTA Parks Hunted",
+                "Worked All States",
+                "DXCC",
+                "VUCC",
+                "ARRL Sections",
+                "Band: All",
+                "Mode: All",
+            ] {
+                assert!(output.contains(text), "should show {text}: {output}");
+            }
+        }
+
+        #[test]
+        fn renders_worked_and_needed_lists() {
+            let mut state = make_state();
+            state.select(1);
+            let output = render(&state);
+            assert!(output.contains("Worked (2)"), "{output}");
+            assert!(output.contains("CT, PA"), "{output}");
+            assert!(output.contains("Needed (48)"), "{output}");
+            assert!(output.contains("AK, AL"), "{output}");
+        }
+
+        #[test]
+        fn renders_uncredited_qsos() {
+            let mut state = make_state();
+            state.select(2);
+            let output = render(&state);
+            assert!(output.contains("100 more needed for 100"), "{output}");
+            assert!(
+                output.contains("2 QSOs not counted (no DXCC or COUNTRY field)"),
+                "{output}"
+            );
+        }
+    }
+}
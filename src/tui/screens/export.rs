@@ -1,17 +1,24 @@
-//! Export confirmation screen — review path and QSO count, then write ADIF.
+//! Export confirmation screen — choose a format, review path, QSO count,
+//! ADIF conformance findings and the POTA upload preflight, then write the
+//! file.
+
+use std::path::Path;
 
 use crossterm::event::{KeyCode, KeyEvent};
 use ratatui::Frame;
 use ratatui::layout::{Constraint, Layout, Rect};
-use ratatui::style::{Color, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, Paragraph};
 use tui_textarea::TextArea;
 
+use crate::adif::{Finding, Severity, format_adif, validate};
+use crate::export::{ExportFormat, PotaPreflight, pota_preflight};
 use crate::model::Log;
 use crate::storage::default_export_path;
 use crate::tui::action::Action;
 use crate::tui::app::Screen;
+use crate::tui::theme::Theme;
+use crate::tui::timers::TimerReading;
 use crate::tui::widgets::{StatusBarContext, draw_status_bar};
 
 /// Current status of the export operation.
@@ -31,6 +38,17 @@ pub struct ExportState {
     textarea: TextArea<'static>,
     status: ExportStatus,
     qso_count: usize,
+    format: ExportFormat,
+    /// ADIF conformance findings for the log, computed by [`prepare`](Self::prepare).
+    findings: Vec<Finding>,
+    /// The POTA log being exported, kept to re-run the preflight when the
+    /// path changes. `None` for other log types.
+    pota_log: Option<Log>,
+    /// POTA upload preflight for the current path.
+    preflight: Option<PotaPreflight>,
+    /// Set after `Enter` was pressed once despite errors in `findings` or
+    /// `preflight`.
+    confirming: bool,
 }
 
 impl Default for ExportState {
@@ -46,25 +64,41 @@ impl ExportState {
             textarea: TextArea::default(),
             status: ExportStatus::Ready,
             qso_count: 0,
+            format: ExportFormat::default(),
+            findings: Vec::new(),
+            pota_log: None,
+            preflight: None,
+            confirming: false,
         }
     }
 
     /// Prepares the export screen for the given log, computing the default
-    /// export path and QSO count. Resets status to [`ExportStatus::Ready`].
-    /// Cursor is placed at the end of the path.
+    /// export path for the selected format, the QSO count, the ADIF
+    /// conformance findings and, for POTA logs, the upload preflight. Resets
+    /// status to [`ExportStatus::Ready`]. Cursor
+    /// is placed at the end of the path.
     pub fn prepare(&mut self, log: Option<&Log>) {
         self.status = ExportStatus::Ready;
+        self.confirming = false;
         match log {
             Some(log) => {
                 self.qso_count = log.header().qsos.len();
-                let path = default_export_path(log)
+                // A formatting failure is reported by the export itself.
+                self.findings = format_adif(log)
+                    .map(|adif| validate(&adif))
+                    .unwrap_or_default();
+                let path = default_export_path(log, self.format)
                     .map(|p| p.display().to_string())
                     .unwrap_or_else(|e| format!("<error: {e}>"));
+                self.pota_log = matches!(log, Log::Pota(_)).then(|| log.clone());
                 self.set_path(path);
             }
             None => {
                 self.qso_count = 0;
+                self.findings.clear();
+                self.pota_log = None;
                 self.textarea = TextArea::default();
+                self.refresh_preflight();
             }
         }
     }
@@ -77,21 +111,104 @@ impl ExportState {
     /// - `Delete` removes the character at the cursor.
     /// - `Left` / `Right` move the cursor one character.
     /// - `Home` / `End` jump to the start or end of the path.
-    /// - `Enter` exports to the current path; `Esc` cancels.
+    /// - `Tab` selects the next format, updating the path's extension.
+    /// - `Enter` exports to the current path; `Esc` cancels. When the ADIF
+    ///   check found errors, the first `Enter` asks for confirmation and a
+    ///   second one exports anyway.
     pub fn handle_key(&mut self, key: KeyEvent) -> Action {
         match self.status {
-            ExportStatus::Ready => match key.code {
-                KeyCode::Enter => Action::ExportLog,
-                KeyCode::Esc => Action::Navigate(Screen::QsoEntry),
-                _ => {
-                    self.textarea.input(key);
-                    Action::None
+            ExportStatus::Ready => {
+                if key.code == KeyCode::Enter {
+                    if self.error_count() > 0 && !self.confirming {
+                        self.confirming = true;
+                        return Action::None;
+                    }
+                    return Action::ExportLog;
+                }
+                self.confirming = false;
+                match key.code {
+                    KeyCode::Esc => Action::Navigate(Screen::QsoEntry),
+                    KeyCode::Tab => {
+                        self.cycle_format();
+                        Action::None
+                    }
+                    _ => {
+                        if self.textarea.input(key) {
+                            self.refresh_preflight();
+                        }
+                        Action::None
+                    }
                 }
-            },
+            }
             ExportStatus::Success | ExportStatus::Error(_) => Action::Navigate(Screen::QsoEntry),
         }
     }
 
+    /// Selects the next format, swapping the path's extension when it still
+    /// matches the previous format.
+    fn cycle_format(&mut self) {
+        let next = self.format.next();
+        let old_suffix = format!(".{}", self.format.extension());
+        if let Some(stem) = self.path().strip_suffix(&old_suffix) {
+            let path = format!("{stem}.{}", next.extension());
+            self.set_path(path);
+        }
+        self.format = next;
+    }
+
+    /// Returns the selected export format.
+    pub fn format(&self) -> ExportFormat {
+        self.format
+    }
+
+    /// Returns `true` if the ADIF check applies to the selected format.
+    ///
+    /// ADX carries the same fields as ADIF, so it is checked too; CSV and
+    /// JSON have their own schemas.
+    pub fn is_checked(&self) -> bool {
+        matches!(self.format, ExportFormat::Adif | ExportFormat::Adx)
+    }
+
+    /// Returns the ADIF conformance findings for the selected format.
+    pub fn findings(&self) -> &[Finding] {
+        if self.is_checked() {
+            &self.findings
+        } else {
+            &[]
+        }
+    }
+
+    /// Returns the POTA upload preflight for the current path, if the log is
+    /// a POTA log and the selected format is checked.
+    pub fn preflight(&self) -> Option<&PotaPreflight> {
+        self.preflight.as_ref().filter(|_| self.is_checked())
+    }
+
+    /// Returns the number of ADIF findings and preflight findings that are
+    /// errors.
+    pub fn error_count(&self) -> usize {
+        let preflight = self.preflight().map_or(&[][..], |p| &p.findings);
+        self.findings()
+            .iter()
+            .chain(preflight)
+            .filter(|f| f.is_error())
+            .count()
+    }
+
+    /// Re-runs the POTA preflight against the current path, whose name
+    /// determines the UTC day the file is for.
+    fn refresh_preflight(&mut self) {
+        self.preflight = self
+            .pota_log
+            .as_ref()
+            .and_then(|log| pota_preflight(log, Path::new(self.path())));
+    }
+
+    /// Returns `true` while waiting for a second `Enter` to export despite errors.
+    pub fn is_confirming(&self) -> bool {
+        self.confirming
+    }
+
     /// Marks the export as successful.
     pub fn set_success(&mut self) {
         self.status = ExportStatus::Success;
@@ -112,6 +229,7 @@ impl ExportState {
         use tui_textarea::CursorMove;
         self.textarea = TextArea::new(vec![path]);
         self.textarea.move_cursor(CursorMove::End);
+        self.refresh_preflight();
     }
 
     /// Returns the cursor column position within the path (character-based).
@@ -132,25 +250,42 @@ impl ExportState {
 
 /// Renders the export confirmation screen.
 #[mutants::skip]
-pub fn draw_export(state: &ExportState, log: Option<&Log>, frame: &mut Frame, area: Rect) {
+pub fn draw_export(
+    state: &ExportState,
+    log: Option<&Log>,
+    timers: &[TimerReading],
+    theme: &Theme,
+    frame: &mut Frame,
+    area: Rect,
+) {
     let [status_area, content_area] =
         Layout::vertical([Constraint::Length(1), Constraint::Min(0)]).areas(area);
 
-    let ctx = log.map(StatusBarContext::from_log).unwrap_or_default();
-    draw_status_bar(&ctx, frame, status_area);
+    let ctx = log
+        .map(|log| StatusBarContext::from_log(log).with_timers(timers))
+        .unwrap_or_default();
+    draw_status_bar(&ctx, theme, frame, status_area);
 
+    let title = format!(" Export {} ", state.format().name());
     let block = Block::default()
-        .title(" Export ADIF ")
+        .title(title)
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::Cyan));
+        .border_style(theme.accent);
 
     let inner = block.inner(content_area);
     frame.render_widget(block, content_area);
 
-    let [info_area, path_area, export_status_area, footer_area] = Layout::vertical([
-        Constraint::Length(3),
+    let [
+        info_area,
+        path_area,
+        export_status_area,
+        findings_area,
+        footer_area,
+    ] = Layout::vertical([
+        Constraint::Length(4),
         Constraint::Length(1),
         Constraint::Length(2),
+        Constraint::Min(0),
         Constraint::Length(1),
     ])
     .areas(inner);
@@ -165,13 +300,17 @@ pub fn draw_export(state: &ExportState, log: Option<&Log>, frame: &mut Frame, ar
             .unwrap_or_default();
         lines.push(Line::from(Span::styled(
             format!("Station: {callsign}{park}"),
-            Style::default().fg(Color::White),
+            theme.text,
         )));
     }
     lines.push(Line::from(""));
     lines.push(Line::from(Span::styled(
         format!("QSOs: {}", state.qso_count()),
-        Style::default().fg(Color::White),
+        theme.text,
+    )));
+    lines.push(Line::from(Span::styled(
+        format!("Format: < {} >", state.format().name()),
+        theme.text,
     )));
     frame.render_widget(Paragraph::new(lines), info_area);
 
@@ -179,46 +318,140 @@ pub fn draw_export(state: &ExportState, log: Option<&Log>, frame: &mut Frame, ar
     let [label_area, edit_area] =
         Layout::horizontal([Constraint::Length(6), Constraint::Min(0)]).areas(path_area);
     frame.render_widget(
-        Paragraph::new(Line::from("Path: ")).style(Style::default().fg(Color::White)),
+        Paragraph::new(Line::from("Path: ")).style(theme.text),
         label_area,
     );
     if matches!(state.status(), ExportStatus::Ready) {
         let mut ta = state.textarea.clone();
-        ta.set_style(Style::default().fg(Color::Yellow));
-        ta.set_cursor_line_style(Style::default().fg(Color::Yellow));
+        ta.set_style(theme.focus);
+        ta.set_cursor_line_style(theme.focus);
         frame.render_widget(&ta, edit_area);
     } else {
         frame.render_widget(
-            Paragraph::new(Line::from(Span::styled(
-                state.path(),
-                Style::default().fg(Color::Yellow),
-            ))),
+            Paragraph::new(Line::from(Span::styled(state.path(), theme.focus))),
             edit_area,
         );
     }
 
     // Status message
-    let (status_text, status_color) = match state.status() {
-        ExportStatus::Ready => ("Press Enter to export.", Color::White),
-        ExportStatus::Success => ("Export complete!", Color::Green),
-        ExportStatus::Error(msg) => (msg.as_str(), Color::Red),
+    let confirm_text;
+    let (status_text, status_style) = match state.status() {
+        ExportStatus::Ready if state.is_confirming() => {
+            confirm_text = format!(
+                "{} error(s) found. Press Enter again to export anyway.",
+                state.error_count()
+            );
+            (confirm_text.as_str(), theme.warning)
+        }
+        ExportStatus::Ready => ("Press Enter to export.", theme.text),
+        ExportStatus::Success => ("Export complete!", theme.activated),
+        ExportStatus::Error(msg) => (msg.as_str(), theme.error),
     };
-    let status_line = Line::from(Span::styled(status_text, Style::default().fg(status_color)));
+    let status_line = Line::from(Span::styled(status_text, status_style));
     frame.render_widget(
         Paragraph::new(vec![Line::from(""), status_line]),
         export_status_area,
     );
 
+    if log.is_some() && state.is_checked() {
+        draw_findings(
+            state.findings(),
+            state.preflight(),
+            theme,
+            frame,
+            findings_area,
+        );
+    }
+
     // Footer
     let footer_text = match state.status() {
-        ExportStatus::Ready => "Enter: export  Esc: back  (edit path above)",
+        ExportStatus::Ready => "Enter: export  Tab: format  Esc: back  (edit path above)",
         ExportStatus::Success | ExportStatus::Error(_) => "Press any key to return",
     };
-    let footer =
-        Paragraph::new(Line::from(footer_text)).style(Style::default().fg(Color::DarkGray));
+    let footer = Paragraph::new(Line::from(footer_text)).style(theme.dim);
     frame.render_widget(footer, footer_area);
 }
 
+/// Renders the ADIF check summary followed by one line per finding, then the
+/// POTA preflight's per-day activation summary and findings.
+#[mutants::skip]
+fn draw_findings(
+    findings: &[Finding],
+    preflight: Option<&PotaPreflight>,
+    theme: &Theme,
+    frame: &mut Frame,
+    area: Rect,
+) {
+    let errors = findings.iter().filter(|f| f.is_error()).count();
+    let warnings = findings.len() - errors;
+    let summary = if findings.is_empty() {
+        Span::styled("ADIF check: no problems found", theme.activated)
+    } else {
+        Span::styled(
+            format!("ADIF check: {errors} error(s), {warnings} warning(s)"),
+            theme.header,
+        )
+    };
+    let mut lines = vec![Line::from(summary)];
+    lines.extend(findings.iter().map(|finding| finding_line(finding, theme)));
+
+    if let Some(preflight) = preflight {
+        let summary = if preflight.findings.is_empty() {
+            Span::styled("POTA preflight: no problems found", theme.activated)
+        } else {
+            Span::styled(
+                format!("POTA preflight: {} error(s)", preflight.findings.len()),
+                theme.header,
+            )
+        };
+        lines.push(Line::from(summary));
+        let file_day = preflight.file_day();
+        let days = if preflight.days.contains(&file_day) {
+            preflight.days.clone()
+        } else {
+            let mut days = preflight.days.clone();
+            days.push(file_day);
+            days.sort_by_key(|d| d.date);
+            days
+        };
+        lines.extend(days.iter().map(|day| {
+            let (status, style) = if day.is_activated() {
+                ("activated".to_string(), theme.activated)
+            } else {
+                (
+                    format!("{} short of activation", day.needed()),
+                    theme.warning,
+                )
+            };
+            Line::from(Span::styled(
+                format!(
+                    "  {}: {} unique QSO(s) — {status}",
+                    day.date.format("%Y-%m-%d"),
+                    day.unique_qsos
+                ),
+                style,
+            ))
+        }));
+        lines.extend(
+            preflight
+                .findings
+                .iter()
+                .map(|finding| finding_line(finding, theme)),
+        );
+    }
+    frame.render_widget(Paragraph::new(lines), area);
+}
+
+/// Formats one finding, styled by severity.
+#[mutants::skip]
+fn finding_line<'a>(finding: &Finding, theme: &Theme) -> Line<'a> {
+    let style = match finding.severity {
+        Severity::Error => theme.error,
+        Severity::Warning => theme.warning,
+    };
+    Line::from(Span::styled(format!("  {finding}"), style))
+}
+
 #[cfg(test)]
 mod tests {
     use chrono::{TimeZone, Utc};
@@ -260,6 +493,7 @@ mod tests {
             None,
             None,
             None,
+            None,
         )
         .unwrap()
     }
@@ -473,6 +707,190 @@ mod tests {
         }
     }
 
+    mod format {
+        use super::*;
+
+        #[test]
+        fn tab_cycles_format_and_extension() {
+            let mut state = ExportState::new();
+            state.set_path("/tmp/log.adif".into());
+            assert_eq!(state.format(), ExportFormat::Adif);
+
+            assert_eq!(state.handle_key(press(KeyCode::Tab)), Action::None);
+            assert_eq!(state.format(), ExportFormat::Adx);
+            assert_eq!(state.path(), "/tmp/log.adx");
+
+            state.handle_key(press(KeyCode::Tab));
+            assert_eq!(state.format(), ExportFormat::Csv);
+            assert_eq!(state.path(), "/tmp/log.csv");
+
+            state.handle_key(press(KeyCode::Tab));
+            assert_eq!(state.format(), ExportFormat::Json);
+            assert_eq!(state.path(), "/tmp/log.json");
+
+            state.handle_key(press(KeyCode::Tab));
+            assert_eq!(state.path(), "/tmp/log.adif");
+        }
+
+        #[test]
+        fn tab_keeps_custom_extension() {
+            let mut state = ExportState::new();
+            state.set_path("/tmp/log.txt".into());
+            state.handle_key(press(KeyCode::Tab));
+            assert_eq!(state.format(), ExportFormat::Adx);
+            assert_eq!(state.path(), "/tmp/log.txt");
+        }
+
+        #[test]
+        fn prepare_uses_selected_format() {
+            let mut state = ExportState::new();
+            state.handle_key(press(KeyCode::Tab));
+            state.prepare(Some(&make_log()));
+            assert_eq!(state.format(), ExportFormat::Adx);
+            assert!(state.path().ends_with("W1AW@K-0001-20260216.adx"));
+        }
+    }
+
+    mod validation {
+        use super::*;
+
+        fn make_invalid_log() -> Log {
+            let mut log = make_log();
+            let mut qso = make_qso();
+            qso.mode = Mode::Digi;
+            log.add_qso(qso);
+            log
+        }
+
+        #[test]
+        fn conforming_log_has_no_findings() {
+            let mut state = ExportState::new();
+            let mut log = make_log();
+            log.add_qso(make_qso());
+            state.prepare(Some(&log));
+            assert!(state.findings().is_empty());
+            assert_eq!(state.handle_key(press(KeyCode::Enter)), Action::ExportLog);
+        }
+
+        #[test]
+        fn prepare_lists_findings() {
+            let mut state = ExportState::new();
+            state.prepare(Some(&make_invalid_log()));
+            assert_eq!(state.error_count(), 1);
+            assert_eq!(state.findings()[0].field.as_deref(), Some("MODE"));
+
+            state.prepare(None);
+            assert!(state.findings().is_empty());
+        }
+
+        #[test]
+        fn errors_need_a_second_enter() {
+            let mut state = ExportState::new();
+            state.prepare(Some(&make_invalid_log()));
+            assert_eq!(state.handle_key(press(KeyCode::Enter)), Action::None);
+            assert!(state.is_confirming());
+            assert_eq!(state.handle_key(press(KeyCode::Enter)), Action::ExportLog);
+        }
+
+        #[test]
+        fn other_key_cancels_confirmation() {
+            let mut state = ExportState::new();
+            state.prepare(Some(&make_invalid_log()));
+            state.handle_key(press(KeyCode::Enter));
+            state.handle_key(press(KeyCode::Left));
+            assert!(!state.is_confirming());
+            assert_eq!(state.handle_key(press(KeyCode::Enter)), Action::None);
+        }
+
+        #[test]
+        fn adx_is_checked_but_csv_is_not() {
+            let mut state = ExportState::new();
+            state.prepare(Some(&make_invalid_log()));
+            state.handle_key(press(KeyCode::Tab));
+            assert_eq!(state.format(), ExportFormat::Adx);
+            assert_eq!(state.error_count(), 1);
+
+            state.handle_key(press(KeyCode::Tab));
+            assert_eq!(state.format(), ExportFormat::Csv);
+            assert!(!state.is_checked());
+            assert!(state.findings().is_empty());
+            assert_eq!(state.handle_key(press(KeyCode::Enter)), Action::ExportLog);
+        }
+    }
+
+    mod preflight {
+        use super::*;
+        use crate::model::GeneralLog;
+
+        #[test]
+        fn prepare_runs_preflight_for_pota_logs() {
+            let mut state = ExportState::new();
+            let mut log = make_log();
+            log.add_qso(make_qso());
+            state.prepare(Some(&log));
+            let preflight = state.preflight().unwrap();
+            assert!(preflight.findings.is_empty());
+            assert_eq!(preflight.file_day().unique_qsos, 1);
+        }
+
+        #[test]
+        fn other_logs_have_no_preflight() {
+            let mut state = ExportState::new();
+            let log = Log::General(
+                GeneralLog::new("W1AW".to_string(), None, "FN31".to_string()).unwrap(),
+            );
+            state.prepare(Some(&log));
+            assert_eq!(state.preflight(), None);
+        }
+
+        #[test]
+        fn editing_path_date_rechecks_qso_dates() {
+            let mut state = ExportState::new();
+            let mut log = make_log();
+            log.add_qso(make_qso());
+            state.prepare(Some(&log));
+            let path = state.path().replace("20260216", "20260217");
+            state.set_path(path);
+            assert_eq!(state.error_count(), 1);
+            assert_eq!(
+                state.preflight().unwrap().findings[0].field.as_deref(),
+                Some("QSO_DATE")
+            );
+
+            // Typing restores the original date one keystroke at a time.
+            for _ in 0..".adif".len() + 1 {
+                state.handle_key(press(KeyCode::Left));
+            }
+            state.handle_key(press(KeyCode::Backspace));
+            state.handle_key(press(KeyCode::Char('6')));
+            assert_eq!(state.error_count(), 0);
+        }
+
+        #[test]
+        fn preflight_errors_need_a_second_enter() {
+            let mut state = ExportState::new();
+            let mut log = make_log();
+            let mut qso = make_qso();
+            qso.their_park = Some("K-0001".to_string());
+            log.add_qso(qso);
+            state.prepare(Some(&log));
+            assert_eq!(state.error_count(), 1);
+            assert_eq!(state.handle_key(press(KeyCode::Enter)), Action::None);
+            assert_eq!(state.handle_key(press(KeyCode::Enter)), Action::ExportLog);
+        }
+
+        #[test]
+        fn csv_is_not_preflighted() {
+            let mut state = ExportState::new();
+            state.prepare(Some(&make_log()));
+            state.handle_key(press(KeyCode::Tab));
+            assert!(state.preflight().is_some());
+            state.handle_key(press(KeyCode::Tab));
+            assert_eq!(state.format(), ExportFormat::Csv);
+            assert_eq!(state.preflight(), None);
+        }
+    }
+
     mod status_setters {
         use super::*;
 
@@ -509,7 +927,7 @@ mod tests {
             let mut terminal = Terminal::new(backend).unwrap();
             terminal
                 .draw(|frame| {
-                    draw_export(state, log, frame, frame.area());
+                    draw_export(state, log, &[], &Theme::default(), frame, frame.area());
                 })
                 .unwrap();
             buffer_to_string(terminal.backend().buffer())
@@ -522,6 +940,18 @@ mod tests {
             assert!(output.contains("Export ADIF"), "should show title");
         }
 
+        #[test]
+        fn renders_selected_format() {
+            let mut state = ExportState::new();
+            for _ in 0..3 {
+                state.handle_key(press(KeyCode::Tab));
+            }
+            let output = render_export(&state, None, 80, 15);
+            assert!(output.contains("Export JSON"), "title names format");
+            assert!(output.contains("Format: < JSON >"), "{output}");
+            assert!(output.contains("Tab: format"), "{output}");
+        }
+
         #[test]
         fn renders_station_info() {
             let mut state = ExportState::new();
@@ -596,6 +1026,56 @@ mod tests {
             );
         }
 
+        #[test]
+        fn renders_check_passed() {
+            let mut state = ExportState::new();
+            let log = make_log();
+            state.prepare(Some(&log));
+            let output = render_export(&state, Some(&log), 80, 20);
+            assert!(output.contains("ADIF check: no problems found"), "{output}");
+        }
+
+        #[test]
+        fn renders_findings_and_confirmation() {
+            let mut state = ExportState::new();
+            let mut log = make_log();
+            let mut qso = make_qso();
+            qso.mode = Mode::Digi;
+            log.add_qso(qso);
+            state.prepare(Some(&log));
+            state.handle_key(press(KeyCode::Enter));
+            let output = render_export(&state, Some(&log), 80, 20);
+            assert!(
+                output.contains("ADIF check: 1 error(s), 0 warning(s)"),
+                "{output}"
+            );
+            assert!(
+                output.contains("QSO 1 MODE: unknown mode \"DIGI\""),
+                "{output}"
+            );
+            assert!(output.contains("Press Enter again"), "{output}");
+        }
+
+        #[test]
+        fn renders_preflight_summary() {
+            let mut state = ExportState::new();
+            let mut log = make_log();
+            let mut qso = make_qso();
+            qso.their_park = Some("K-0001".to_string());
+            log.add_qso(qso);
+            state.prepare(Some(&log));
+            let output = render_export(&state, Some(&log), 80, 20);
+            assert!(output.contains("POTA preflight: 1 error(s)"), "{output}");
+            assert!(
+                output.contains("2026-02-16: 1 unique QSO(s) — 9 short of activation"),
+                "{output}"
+            );
+            assert!(
+                output.contains("QSO 1 SIG_INFO: park-to-park with our own park K-0001"),
+                "{output}"
+            );
+        }
+
         #[test]
         fn renders_footer_after_completion() {
             let mut state = ExportState::new();
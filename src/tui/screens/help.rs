@@ -3,55 +3,106 @@
 use crossterm::event::{KeyCode, KeyEvent};
 use ratatui::Frame;
 use ratatui::layout::{Constraint, Layout, Rect};
-use ratatui::style::{Color, Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, Paragraph};
 
 use crate::tui::action::Action;
 use crate::tui::app::Screen;
+use crate::tui::keymap::{Command, Keymap};
+use crate::tui::theme::Theme;
+
+/// A single row of the help table.
+///
+/// Rebindable commands are looked up in the active [`Keymap`] so the help
+/// screen always reflects the user's configuration.
+#[derive(Debug, Clone, Copy)]
+enum HelpEntry {
+    Fixed(&'static str, &'static str),
+    Bound(Command),
+}
+
+use HelpEntry::{Bound, Fixed};
+
+static LOG_SELECT_KEYS: &[HelpEntry] = &[
+    Fixed("↑/↓", "navigate"),
+    Fixed("Enter", "open log"),
+    Bound(Command::NewLog),
+    Bound(Command::DeleteLog),
+    Bound(Command::MarkLog),
+    Bound(Command::MergeLogs),
+    Bound(Command::EditLog),
+    Bound(Command::CopyLog),
+    Bound(Command::RepairLog),
+    Bound(Command::Search),
+    Bound(Command::Awards),
+    Fixed("Esc", "quit"),
+    Bound(Command::Help),
+];
+
+static LOG_CREATE_KEYS: &[HelpEntry] = &[
+    Fixed("Tab / Shift-Tab", "next / prev field"),
+    Fixed("Enter", "create log"),
+    Fixed("Esc", "cancel"),
+    Bound(Command::Help),
+];
 
-static LOG_SELECT_KEYS: &[(&str, &str)] = &[
-    ("↑/↓", "navigate"),
-    ("Enter", "open log"),
-    ("n", "new log"),
-    ("d", "delete log (y/n to confirm)"),
-    ("Esc", "quit"),
-    ("F1", "help"),
+static QSO_ENTRY_KEYS: &[HelpEntry] = &[
+    Fixed("Tab / Shift-Tab", "next / prev field"),
+    Fixed("Enter", "log QSO"),
+    Fixed("Esc", "back to Log Select; in edit mode: cancel"),
+    Bound(Command::NextBand),
+    Bound(Command::PrevBand),
+    Bound(Command::NextMode),
+    Bound(Command::PrevMode),
+    Bound(Command::OpenQsoList),
+    Bound(Command::OpenExport),
+    Bound(Command::ToggleBackfill),
+    Bound(Command::SetOperator),
+    Bound(Command::StationId),
+    Bound(Command::Help),
 ];
 
-static LOG_CREATE_KEYS: &[(&str, &str)] = &[
-    ("Tab / Shift-Tab", "next / prev field"),
-    ("Enter", "create log"),
-    ("Esc", "cancel"),
-    ("F1", "help"),
+static QSO_LIST_KEYS: &[HelpEntry] = &[
+    Fixed("↑/↓", "navigate"),
+    Fixed("Home / End", "first / last"),
+    Bound(Command::EditQso),
+    Bound(Command::DeleteQso),
+    Bound(Command::MarkQso),
+    Fixed("Shift-↑/↓", "extend marked range"),
+    Bound(Command::BulkEdit),
+    Bound(Command::SortQsos),
+    Bound(Command::ReverseSort),
+    Bound(Command::ChooseColumns),
+    Fixed("Esc", "clear marks, then back"),
+    Bound(Command::Help),
 ];
 
-static QSO_ENTRY_KEYS: &[(&str, &str)] = &[
-    ("Tab / Shift-Tab", "next / prev field"),
-    ("Enter", "log QSO"),
-    ("Esc", "back to Log Select; in edit mode: cancel"),
-    ("Alt+b", "next band"),
-    ("Shift+Alt+B", "prev band"),
-    ("Alt+m", "next mode"),
-    ("Shift+Alt+M", "prev mode"),
-    ("Alt+e", "open QSO list"),
-    ("Alt+x", "export log"),
-    ("F1", "help"),
+static EXPORT_KEYS: &[HelpEntry] = &[
+    Fixed("Enter", "export to ADIF"),
+    Fixed("Enter Enter", "export despite check errors"),
+    Fixed("Esc", "back"),
+    Bound(Command::Help),
 ];
 
-static QSO_LIST_KEYS: &[(&str, &str)] = &[
-    ("↑/↓", "navigate"),
-    ("Home / End", "first / last"),
-    ("Enter", "edit QSO"),
-    ("d", "delete QSO (y/n to confirm)"),
-    ("Esc", "back"),
-    ("F1", "help"),
+static SEARCH_KEYS: &[HelpEntry] = &[
+    Fixed("Tab / Shift-Tab", "next / prev field"),
+    Fixed("Enter", "search; in results: open QSO in its log"),
+    Fixed("↓ / ↑", "into / out of results"),
+    Fixed("Home / End", "first / last result"),
+    Fixed("Esc", "back to form, then to Log Select"),
+    Bound(Command::Help),
 ];
 
-static EXPORT_KEYS: &[(&str, &str)] =
-    &[("Enter", "export to ADIF"), ("Esc", "back"), ("F1", "help")];
+static AWARDS_KEYS: &[HelpEntry] = &[
+    Fixed("↑/↓", "select award"),
+    Fixed("PgUp / PgDn", "scroll worked / needed lists"),
+    Bound(Command::FilterBand),
+    Bound(Command::FilterMode),
+    Fixed("Esc", "back"),
+    Bound(Command::Help),
+];
 
-static HELP_KEYS: &[(&str, &str)] = &[("↑/↓", "scroll"), ("Esc", "back")];
+static HELP_KEYS: &[HelpEntry] = &[Fixed("↑/↓", "scroll"), Fixed("Esc", "back")];
 
 /// State for the help screen.
 #[derive(Debug, Clone)]
@@ -119,49 +170,56 @@ fn screen_name(screen: Screen) -> &'static str {
         Screen::QsoEntry => "QSO Entry",
         Screen::QsoList => "QSO List",
         Screen::Export => "Export",
+        Screen::Search => "Search",
+        Screen::Awards => "Awards",
         Screen::Help => "Help",
     }
 }
 
-fn build_section(title: &'static str, keys: &[(&'static str, &'static str)]) -> Vec<Line<'static>> {
-    let header_style = Style::default()
-        .fg(Color::Cyan)
-        .add_modifier(Modifier::BOLD);
-    let key_style = Style::default().fg(Color::Yellow);
-    let dim_style = Style::default().fg(Color::DarkGray);
-
+fn build_section(
+    title: &'static str,
+    entries: &[HelpEntry],
+    keymap: &Keymap,
+    theme: &Theme,
+) -> Vec<Line<'static>> {
     let mut lines = vec![
         Line::from(""),
-        Line::from(Span::styled(title, header_style)),
+        Line::from(Span::styled(title, theme.header)),
     ];
-    for (key, desc) in keys {
+    for entry in entries {
+        let (key, desc) = match *entry {
+            Fixed(key, desc) => (key.to_string(), desc),
+            Bound(command) => (keymap.describe(command), command.description()),
+        };
         lines.push(Line::from(vec![
-            Span::styled(format!("  {key:<20}"), key_style),
-            Span::styled(*desc, dim_style),
+            Span::styled(format!("  {key:<20}"), theme.focus),
+            Span::styled(desc, theme.dim),
         ]));
     }
     lines
 }
 
-fn help_content(origin: Screen) -> Vec<Line<'static>> {
+fn help_content(origin: Screen, keymap: &Keymap, theme: &Theme) -> Vec<Line<'static>> {
     match origin {
-        Screen::LogSelect => build_section("Log Select", LOG_SELECT_KEYS),
-        Screen::LogCreate => build_section("Log Create", LOG_CREATE_KEYS),
-        Screen::QsoEntry => build_section("QSO Entry", QSO_ENTRY_KEYS),
-        Screen::QsoList => build_section("QSO List", QSO_LIST_KEYS),
-        Screen::Export => build_section("Export", EXPORT_KEYS),
-        Screen::Help => build_section("Help", HELP_KEYS),
+        Screen::LogSelect => build_section("Log Select", LOG_SELECT_KEYS, keymap, theme),
+        Screen::LogCreate => build_section("Log Create", LOG_CREATE_KEYS, keymap, theme),
+        Screen::QsoEntry => build_section("QSO Entry", QSO_ENTRY_KEYS, keymap, theme),
+        Screen::QsoList => build_section("QSO List", QSO_LIST_KEYS, keymap, theme),
+        Screen::Export => build_section("Export", EXPORT_KEYS, keymap, theme),
+        Screen::Search => build_section("Search", SEARCH_KEYS, keymap, theme),
+        Screen::Awards => build_section("Awards", AWARDS_KEYS, keymap, theme),
+        Screen::Help => build_section("Help", HELP_KEYS, keymap, theme),
     }
 }
 
 /// Renders the help screen.
 #[mutants::skip]
-pub fn draw_help(state: &HelpState, frame: &mut Frame, area: Rect) {
+pub fn draw_help(state: &HelpState, keymap: &Keymap, theme: &Theme, frame: &mut Frame, area: Rect) {
     let title = format!(" Help – {} ", screen_name(state.origin()));
     let block = Block::default()
         .title(title)
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::Cyan));
+        .border_style(theme.accent);
 
     let inner = block.inner(area);
     frame.render_widget(block, area);
@@ -169,7 +227,7 @@ pub fn draw_help(state: &HelpState, frame: &mut Frame, area: Rect) {
     let [content_area, footer_area] =
         Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).areas(inner);
 
-    let content_lines = help_content(state.origin());
+    let content_lines = help_content(state.origin(), keymap, theme);
     let total = content_lines.len() as u16;
     let height = content_area.height;
     let capped_scroll = state.scroll().min(total.saturating_sub(height));
@@ -177,8 +235,7 @@ pub fn draw_help(state: &HelpState, frame: &mut Frame, area: Rect) {
     let paragraph = Paragraph::new(content_lines).scroll((capped_scroll, 0));
     frame.render_widget(paragraph, content_area);
 
-    let footer =
-        Paragraph::new("↑/↓: scroll  Esc: back").style(Style::default().fg(Color::DarkGray));
+    let footer = Paragraph::new("↑/↓: scroll  Esc: back").style(theme.dim);
     frame.render_widget(footer, footer_area);
 }
 
@@ -329,7 +386,11 @@ mod tests {
         use super::*;
 
         fn content_text(screen: Screen) -> String {
-            help_content(screen)
+            content_text_with(screen, &Keymap::default())
+        }
+
+        fn content_text_with(screen: Screen, keymap: &Keymap) -> String {
+            help_content(screen, keymap, &Theme::default())
                 .into_iter()
                 .flat_map(|l| l.spans.into_iter())
                 .map(|s| s.content.into_owned())
@@ -348,7 +409,7 @@ mod tests {
             ];
             for screen in screens {
                 assert!(
-                    !help_content(screen).is_empty(),
+                    !help_content(screen, &Keymap::default(), &Theme::default()).is_empty(),
                     "{screen:?} should have content"
                 );
             }
@@ -360,6 +421,31 @@ mod tests {
             assert!(content_text(Screen::QsoEntry).contains("QSO Entry"));
         }
 
+        #[test]
+        fn default_bindings_are_listed() {
+            let text = content_text(Screen::QsoEntry);
+            assert!(text.contains("Alt+b"), "{text}");
+            assert!(text.contains("Shift+Alt+B"), "{text}");
+            assert!(text.contains("F1"), "{text}");
+        }
+
+        #[test]
+        fn rebound_key_is_listed() {
+            let overrides = [("next_band".to_string(), vec!["F5".to_string()])].into();
+            let keymap = Keymap::with_overrides(&overrides).unwrap();
+            let text = content_text_with(Screen::QsoEntry, &keymap);
+            assert!(text.contains("F5"), "{text}");
+            assert!(!text.contains("Alt+b"), "{text}");
+        }
+
+        #[test]
+        fn unbound_command_is_marked() {
+            let overrides = [("delete_qso".to_string(), vec![])].into();
+            let keymap = Keymap::with_overrides(&overrides).unwrap();
+            let text = content_text_with(Screen::QsoList, &keymap);
+            assert!(text.contains("(unbound)"), "{text}");
+        }
+
         #[test]
         fn qso_entry_content_excludes_other_sections() {
             let text = content_text(Screen::QsoEntry);
@@ -383,7 +469,13 @@ mod tests {
             let mut terminal = Terminal::new(backend).unwrap();
             terminal
                 .draw(|frame| {
-                    draw_help(state, frame, frame.area());
+                    draw_help(
+                        state,
+                        &Keymap::default(),
+                        &Theme::default(),
+                        frame,
+                        frame.area(),
+                    );
                 })
                 .unwrap();
             buffer_to_string(terminal.backend().buffer())
@@ -1,9 +1,11 @@
 //! Log creation screen — form for entering new log session details.
+//!
+//! The same screen edits an existing log's details, using the form for that
+//! log's type, and starts a new log pre-filled from an existing one.
 
 use crossterm::event::{KeyCode, KeyEvent};
 use ratatui::Frame;
 use ratatui::layout::{Constraint, Layout, Rect};
-use ratatui::style::{Color, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, Paragraph};
 
@@ -14,6 +16,7 @@ use crate::model::{
 };
 use crate::tui::action::Action;
 use crate::tui::app::Screen;
+use crate::tui::theme::Theme;
 use crate::tui::widgets::form::{Form, FormField, draw_form};
 
 // --- Field index constants ---
@@ -40,6 +43,9 @@ const CONTEST_TX_COUNT: usize = 2;
 const CONTEST_CLASS: usize = 3;
 /// Field index for ARRL section in the contest log form.
 const CONTEST_SECTION: usize = 4;
+/// Field index for the power category, shown only on Field Day forms pre-filled
+/// from an existing log.
+const FD_POWER: usize = 5;
 
 // --- Local enums ---
 
@@ -107,6 +113,11 @@ pub struct LogCreateState {
     tx_count_buf: String,
     class_buf: String,
     section_buf: String,
+    power_buf: String,
+    /// The existing log the form was pre-filled from, if any.
+    source: Option<Log>,
+    /// `true` when saving updates `source` instead of creating a new log.
+    editing: bool,
 }
 
 impl Default for LogCreateState {
@@ -134,9 +145,71 @@ impl LogCreateState {
             tx_count_buf: String::new(),
             class_buf: String::new(),
             section_buf: String::new(),
+            power_buf: String::new(),
+            source: None,
+            editing: false,
         }
     }
 
+    /// Switches the screen to editing `log`'s details, pre-filled from its header.
+    ///
+    /// The log type cannot be changed while editing.
+    pub fn start_editing(&mut self, log: &Log) {
+        self.prefill(log);
+        self.editing = true;
+    }
+
+    /// Starts a new log pre-filled with `log`'s type and details, but no QSOs.
+    ///
+    /// The new log still goes through the usual duplicate-log check on create.
+    pub fn start_from(&mut self, log: &Log) {
+        self.prefill(log);
+    }
+
+    /// Resets the form and fills it from `log`'s type and header details.
+    fn prefill(&mut self, log: &Log) {
+        *self = Self::new();
+        self.power_buf = FdPowerCategory::Low.adif_str().to_uppercase();
+        let header = log.header();
+        self.callsign_buf.clone_from(&header.station_callsign);
+        self.operator_buf = header.operator.clone().unwrap_or_default();
+        self.grid_square_buf.clone_from(&header.grid_square);
+        match log {
+            Log::General(_) => self.log_type = LogType::General,
+            Log::Pota(p) => {
+                self.log_type = LogType::Pota;
+                self.park_ref_buf.clone_from(&p.park_ref);
+            }
+            Log::FieldDay(f) => {
+                self.log_type = LogType::FieldDay;
+                self.tx_count_buf = f.tx_count.to_string();
+                self.class_buf = f.class.to_string();
+                self.section_buf.clone_from(&f.section);
+                self.power_buf = f.power.adif_str().to_uppercase();
+            }
+            Log::WinterFieldDay(w) => {
+                self.log_type = LogType::WinterFieldDay;
+                self.tx_count_buf = w.tx_count.to_string();
+                self.class_buf = w.class.to_string();
+                self.section_buf.clone_from(&w.section);
+            }
+        }
+        self.source = Some(log.clone());
+        self.form = self.build_form_for_type();
+        self.focus_area = FocusArea::Fields;
+        self.form.set_focus(0);
+    }
+
+    /// Returns `true` when editing an existing log rather than creating one.
+    pub fn is_editing(&self) -> bool {
+        self.editing
+    }
+
+    /// Returns `true` when the form shows the Field Day power category.
+    fn has_power_field(&self) -> bool {
+        self.source.is_some() && self.log_type == LogType::FieldDay
+    }
+
     /// Handles a key event, returning an [`Action`] for the app to apply.
     pub fn handle_key(&mut self, key: KeyEvent) -> Action {
         match key.code {
@@ -174,7 +247,7 @@ impl LogCreateState {
                 Action::None
             }
             KeyCode::Left => {
-                if self.focus_area == FocusArea::TypeSelector {
+                if self.focus_area == FocusArea::TypeSelector && !self.editing {
                     self.sync_buffers_from_form();
                     self.log_type = self.log_type.prev();
                     let form = self.build_form_for_type();
@@ -183,7 +256,7 @@ impl LogCreateState {
                 Action::None
             }
             KeyCode::Right => {
-                if self.focus_area == FocusArea::TypeSelector {
+                if self.focus_area == FocusArea::TypeSelector && !self.editing {
                     self.sync_buffers_from_form();
                     self.log_type = self.log_type.next();
                     let form = self.build_form_for_type();
@@ -202,6 +275,7 @@ impl LogCreateState {
                 let focus = self.form.focus();
                 let should_uppercase = focus == CALLSIGN
                     || focus == OPERATOR
+                    || (self.has_power_field() && focus == FD_POWER)
                     || (self.log_type == LogType::Pota && focus == POTA_PARK_REF)
                     || (matches!(self.log_type, LogType::FieldDay | LogType::WinterFieldDay)
                         && (focus == CONTEST_CLASS || focus == CONTEST_SECTION));
@@ -267,6 +341,19 @@ impl LogCreateState {
                 self.section_buf = self.form.value(CONTEST_SECTION).to_string();
             }
         }
+        if self.has_power_field() {
+            self.power_buf = self.form.value(FD_POWER).to_string();
+        }
+    }
+
+    /// Grid square for contest logs, whose forms have no grid field.
+    ///
+    /// Blank forms leave it empty; forms pre-filled from a log keep its grid.
+    fn contest_grid_square(&self) -> String {
+        self.source
+            .as_ref()
+            .map(|log| log.header().grid_square.clone())
+            .unwrap_or_default()
     }
 
     /// Builds a new form for the current log type, pre-populated from buffers.
@@ -317,22 +404,37 @@ impl LogCreateState {
             }
         }
 
+        if self.has_power_field() {
+            form.push_field(Box::new(FormField::new("Power (QRP/LOW/HIGH)", true)));
+            form.set_value(FD_POWER, &self.power_buf);
+        }
+
         form
     }
 
     /// Validates all fields and attempts to create a [`Log`].
+    ///
+    /// When editing, the validated log carries the new details and the app
+    /// applies them to the edited log.
     fn submit(&mut self) -> Action {
         self.form.clear_errors();
         self.general_error = None;
-        match self.log_type {
+        let log = match self.log_type {
             LogType::General => self.submit_general(),
             LogType::Pota => self.submit_pota(),
             LogType::FieldDay => self.submit_field_day(),
             LogType::WinterFieldDay => self.submit_wfd(),
+        };
+        match (log, &self.source) {
+            (Some(details), Some(original)) if self.editing => {
+                Action::UpdateLogDetails(original.header().log_id.clone(), details)
+            }
+            (Some(log), _) => Action::CreateLog(log),
+            (None, _) => Action::None,
         }
     }
 
-    fn submit_general(&mut self) -> Action {
+    fn submit_general(&mut self) -> Option<Log> {
         let callsign = self.form.value(CALLSIGN).to_string();
         let operator_str = self.form.value(OPERATOR).to_string();
         let operator = (!operator_str.is_empty()).then_some(operator_str);
@@ -351,19 +453,19 @@ impl LogCreateState {
         }
 
         if self.form.has_errors() {
-            return Action::None;
+            return None;
         }
 
         match GeneralLog::new(callsign, operator, grid_square) {
-            Ok(log) => Action::CreateLog(Log::General(log)),
+            Ok(log) => Some(Log::General(log)),
             Err(e) => {
                 self.form.set_error(CALLSIGN, e.to_string());
-                Action::None
+                None
             }
         }
     }
 
-    fn submit_pota(&mut self) -> Action {
+    fn submit_pota(&mut self) -> Option<Log> {
         let callsign = self.form.value(CALLSIGN).to_string();
         let operator_str = self.form.value(OPERATOR).to_string();
         let operator = (!operator_str.is_empty()).then_some(operator_str);
@@ -387,19 +489,19 @@ impl LogCreateState {
         }
 
         if self.form.has_errors() {
-            return Action::None;
+            return None;
         }
 
         match PotaLog::new(callsign, operator, park_ref, grid_square) {
-            Ok(log) => Action::CreateLog(Log::Pota(log)),
+            Ok(log) => Some(Log::Pota(log)),
             Err(e) => {
                 self.form.set_error(CALLSIGN, e.to_string());
-                Action::None
+                None
             }
         }
     }
 
-    fn submit_field_day(&mut self) -> Action {
+    fn submit_field_day(&mut self) -> Option<Log> {
         let callsign = self.form.value(CALLSIGN).to_string();
         let operator_str = self.form.value(OPERATOR).to_string();
         let operator = (!operator_str.is_empty()).then_some(operator_str);
@@ -442,14 +544,24 @@ impl LogCreateState {
             self.form.set_error(CONTEST_SECTION, e.to_string());
         }
 
+        // Blank forms start at Low; the power category is shown once pre-filled from a log.
+        let power = if self.has_power_field() {
+            let power = FdPowerCategory::from_adif_str(self.form.value(FD_POWER));
+            if power.is_none() {
+                self.form
+                    .set_error(FD_POWER, "must be QRP, LOW or HIGH".to_string());
+            }
+            power.unwrap_or(FdPowerCategory::Low)
+        } else {
+            FdPowerCategory::Low
+        };
+
         if self.form.has_errors() {
-            return Action::None;
+            return None;
         }
 
-        // Phase 4.3 will add a power category selector; default to Low for now.
-        let power = FdPowerCategory::Low;
         let Ok(class) = class_result else {
-            return Action::None; // unreachable: class error already set above
+            return None; // unreachable: class error already set above
         };
         match FieldDayLog::new(
             callsign,
@@ -458,17 +570,17 @@ impl LogCreateState {
             class,
             section,
             power,
-            String::new(),
+            self.contest_grid_square(),
         ) {
-            Ok(log) => Action::CreateLog(Log::FieldDay(log)),
+            Ok(log) => Some(Log::FieldDay(log)),
             Err(e) => {
                 self.form.set_error(CALLSIGN, e.to_string());
-                Action::None
+                None
             }
         }
     }
 
-    fn submit_wfd(&mut self) -> Action {
+    fn submit_wfd(&mut self) -> Option<Log> {
         let callsign = self.form.value(CALLSIGN).to_string();
         let operator_str = self.form.value(OPERATOR).to_string();
         let operator = (!operator_str.is_empty()).then_some(operator_str);
@@ -512,17 +624,24 @@ impl LogCreateState {
         }
 
         if self.form.has_errors() {
-            return Action::None;
+            return None;
         }
 
         let Ok(class) = class_result else {
-            return Action::None; // unreachable: class error already set above
+            return None; // unreachable: class error already set above
         };
-        match WfdLog::new(callsign, operator, tx_count, class, section, String::new()) {
-            Ok(log) => Action::CreateLog(Log::WinterFieldDay(log)),
+        match WfdLog::new(
+            callsign,
+            operator,
+            tx_count,
+            class,
+            section,
+            self.contest_grid_square(),
+        ) {
+            Ok(log) => Some(Log::WinterFieldDay(log)),
             Err(e) => {
                 self.form.set_error(CALLSIGN, e.to_string());
-                Action::None
+                None
             }
         }
     }
@@ -530,11 +649,16 @@ impl LogCreateState {
 
 /// Renders the log creation screen.
 #[mutants::skip]
-pub fn draw_log_create(state: &LogCreateState, frame: &mut Frame, area: Rect) {
+pub fn draw_log_create(state: &LogCreateState, theme: &Theme, frame: &mut Frame, area: Rect) {
+    let title = if state.is_editing() {
+        " Edit Log Details "
+    } else {
+        " Create New Log "
+    };
     let block = Block::default()
-        .title(" Create New Log ")
+        .title(title)
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::Cyan));
+        .border_style(theme.accent);
 
     let inner = block.inner(area);
     frame.render_widget(block, area);
@@ -558,34 +682,37 @@ pub fn draw_log_create(state: &LogCreateState, frame: &mut Frame, area: Rect) {
     .areas(centered);
 
     // Type selector
-    let type_text = format!("< {} >", state.log_type.display_name());
-    let selector_border_color = if state.focus_area == FocusArea::TypeSelector {
-        Color::Yellow
+    let type_text = if state.is_editing() {
+        state.log_type.display_name().to_string()
+    } else {
+        format!("< {} >", state.log_type.display_name())
+    };
+    let selector_border_style = if state.focus_area == FocusArea::TypeSelector {
+        theme.focus
     } else {
-        Color::DarkGray
+        theme.dim
     };
     let type_selector = Paragraph::new(type_text).block(
         Block::default()
             .title("Log Type")
             .borders(Borders::ALL)
-            .border_style(Style::default().fg(selector_border_color)),
+            .border_style(selector_border_style),
     );
     frame.render_widget(type_selector, type_row);
 
-    draw_form(state.form(), frame, form_area);
+    draw_form(state.form(), theme, frame, form_area);
 
     if let Some(err) = state.general_error() {
-        let error = Paragraph::new(Line::from(Span::styled(
-            err,
-            Style::default().fg(Color::Red),
-        )));
+        let error = Paragraph::new(Line::from(Span::styled(err, theme.error)));
         frame.render_widget(error, error_area);
     }
 
-    let footer = Paragraph::new(Line::from(
-        "Tab/Shift+Tab: next/prev  \u{2190}/\u{2192}: log type  Enter: create  Esc: cancel",
-    ))
-    .style(Style::default().fg(Color::DarkGray));
+    let footer_text = if state.is_editing() {
+        "Tab/Shift+Tab: next/prev  Enter: save  Esc: cancel"
+    } else {
+        "Tab/Shift+Tab: next/prev  \u{2190}/\u{2192}: log type  Enter: create  Esc: cancel"
+    };
+    let footer = Paragraph::new(Line::from(footer_text)).style(theme.dim);
     frame.render_widget(footer, footer_area);
 }
 
@@ -1363,12 +1490,23 @@ mod tests {
             let mut terminal = Terminal::new(backend).unwrap();
             terminal
                 .draw(|frame| {
-                    draw_log_create(state, frame, frame.area());
+                    draw_log_create(state, &Theme::default(), frame, frame.area());
                 })
                 .unwrap();
             buffer_to_string(terminal.backend().buffer())
         }
 
+        #[test]
+        fn renders_edit_title_and_footer() {
+            let mut state = LogCreateState::new();
+            state.start_editing(&editing::make_fd_log());
+            let output = render_log_create(&state, 80, 24);
+            assert!(output.contains("Edit Log Details"), "{output}");
+            assert!(output.contains("Enter: save"), "{output}");
+            assert!(output.contains("Power (QRP/LOW/HIGH)"), "{output}");
+            assert!(!output.contains("log type"), "{output}");
+        }
+
         #[test]
         fn renders_title_and_fields() {
             let state = LogCreateState::new();
@@ -1480,6 +1618,188 @@ mod tests {
         }
     }
 
+    mod editing {
+        use super::*;
+
+        use crate::model::FdClass;
+
+        pub(super) fn make_fd_log() -> Log {
+            let mut log = FieldDayLog::new(
+                "W1AW".to_string(),
+                None,
+                3,
+                FdClass::B,
+                "EPA".to_string(),
+                FdPowerCategory::Low,
+                "FN31".to_string(),
+            )
+            .unwrap();
+            log.header.log_id = "FD-W1AW-original".to_string();
+            Log::FieldDay(log)
+        }
+
+        fn make_pota_log() -> Log {
+            let mut log = PotaLog::new(
+                "W1AW".to_string(),
+                Some("KD9XYZ".to_string()),
+                "K-0001".to_string(),
+                "FN31".to_string(),
+            )
+            .unwrap();
+            log.header.log_id = "K-0001-original".to_string();
+            Log::Pota(log)
+        }
+
+        fn clear_field(state: &mut LogCreateState) {
+            for _ in 0..20 {
+                state.handle_key(press(KeyCode::Backspace));
+            }
+        }
+
+        #[test]
+        fn start_editing_prefills_fields() {
+            let mut state = LogCreateState::new();
+            state.start_editing(&make_pota_log());
+            assert!(state.is_editing());
+            assert_eq!(state.log_type, LogType::Pota);
+            assert_eq!(state.focus_area, FocusArea::Fields);
+            assert_eq!(state.form().value(CALLSIGN), "W1AW");
+            assert_eq!(state.form().value(OPERATOR), "KD9XYZ");
+            assert_eq!(state.form().value(POTA_PARK_REF), "K-0001");
+            assert_eq!(state.form().value(POTA_GRID), "FN31");
+        }
+
+        #[test]
+        fn log_type_cannot_change() {
+            let mut state = LogCreateState::new();
+            state.start_editing(&make_pota_log());
+            state.handle_key(shift_press(KeyCode::BackTab));
+            assert_eq!(state.focus_area, FocusArea::TypeSelector);
+            state.handle_key(press(KeyCode::Right));
+            assert_eq!(state.log_type, LogType::Pota);
+        }
+
+        #[test]
+        fn submit_returns_update_with_original_id() {
+            let mut state = LogCreateState::new();
+            state.start_editing(&make_pota_log());
+            state.form.set_focus(POTA_PARK_REF);
+            clear_field(&mut state);
+            type_string(&mut state, "K-0002");
+            match state.handle_key(press(KeyCode::Enter)) {
+                Action::UpdateLogDetails(log_id, details) => {
+                    assert_eq!(log_id, "K-0001-original");
+                    assert_eq!(details.park_ref(), Some("K-0002"));
+                }
+                other => panic!("expected UpdateLogDetails, got {other:?}"),
+            }
+        }
+
+        #[test]
+        fn invalid_edit_is_rejected() {
+            let mut state = LogCreateState::new();
+            state.start_editing(&make_pota_log());
+            state.form.set_focus(POTA_PARK_REF);
+            clear_field(&mut state);
+            type_string(&mut state, "bogus");
+            assert_eq!(state.handle_key(press(KeyCode::Enter)), Action::None);
+            assert!(state.form().has_errors());
+        }
+
+        #[test]
+        fn field_day_power_is_editable() {
+            let mut state = LogCreateState::new();
+            state.start_editing(&make_fd_log());
+            assert_eq!(state.form().fields().len(), 6);
+            assert_eq!(state.form().value(FD_POWER), "LOW");
+            state.form.set_focus(FD_POWER);
+            clear_field(&mut state);
+            type_string(&mut state, "qrp");
+            assert_eq!(state.form().value(FD_POWER), "QRP");
+            match state.handle_key(press(KeyCode::Enter)) {
+                Action::UpdateLogDetails(_, Log::FieldDay(log)) => {
+                    assert_eq!(log.power, FdPowerCategory::Qrp);
+                    assert_eq!(log.header.grid_square, "FN31", "grid is kept");
+                }
+                other => panic!("expected UpdateLogDetails(FieldDay), got {other:?}"),
+            }
+        }
+
+        #[test]
+        fn invalid_power_is_rejected() {
+            let mut state = LogCreateState::new();
+            state.start_editing(&make_fd_log());
+            state.form.set_focus(FD_POWER);
+            clear_field(&mut state);
+            type_string(&mut state, "MAX");
+            assert_eq!(state.handle_key(press(KeyCode::Enter)), Action::None);
+            assert_eq!(
+                state.form().fields()[FD_POWER].error(),
+                Some("must be QRP, LOW or HIGH")
+            );
+        }
+
+        #[test]
+        fn reset_leaves_edit_mode() {
+            let mut state = LogCreateState::new();
+            state.start_editing(&make_fd_log());
+            state.reset();
+            assert!(!state.is_editing());
+            switch_to_field_day(&mut state);
+            assert_eq!(state.form().fields().len(), 5, "no power field");
+        }
+    }
+
+    mod copying {
+        use super::*;
+
+        use super::editing::make_fd_log;
+
+        #[test]
+        fn start_from_prefills_fields_for_a_new_log() {
+            let mut state = LogCreateState::new();
+            state.start_from(&make_fd_log());
+            assert!(!state.is_editing());
+            assert_eq!(state.log_type, LogType::FieldDay);
+            assert_eq!(
+                state.form().values(),
+                vec!["W1AW", "", "3", "B", "EPA", "LOW"]
+            );
+        }
+
+        #[test]
+        fn submit_creates_log_with_copied_details() {
+            let mut state = LogCreateState::new();
+            let Log::FieldDay(mut source) = make_fd_log() else {
+                unreachable!()
+            };
+            source.power = FdPowerCategory::High;
+            state.start_from(&Log::FieldDay(source));
+            match state.handle_key(press(KeyCode::Enter)) {
+                Action::CreateLog(Log::FieldDay(log)) => {
+                    assert_ne!(log.header.log_id, "FD-W1AW-original");
+                    assert_eq!(log.power, FdPowerCategory::High);
+                    assert_eq!(log.section, "EPA");
+                    assert_eq!(log.header.grid_square, "FN31");
+                    assert!(log.header.qsos.is_empty());
+                }
+                other => panic!("expected CreateLog(FieldDay), got {other:?}"),
+            }
+        }
+
+        #[test]
+        fn log_type_can_still_change() {
+            let mut state = LogCreateState::new();
+            state.start_from(&make_fd_log());
+            state.handle_key(shift_press(KeyCode::BackTab));
+            state.handle_key(press(KeyCode::Right));
+            assert_eq!(state.log_type, LogType::WinterFieldDay);
+            assert_eq!(state.form().fields().len(), 5, "power is Field Day only");
+            state.handle_key(press(KeyCode::Left));
+            assert_eq!(state.form().value(FD_POWER), "LOW");
+        }
+    }
+
     mod reset {
         use super::*;
 
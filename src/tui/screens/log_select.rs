@@ -3,14 +3,17 @@
 use crossterm::event::{KeyCode, KeyEvent};
 use ratatui::Frame;
 use ratatui::layout::{Alignment, Constraint, Layout, Rect};
-use ratatui::style::{Color, Modifier, Style};
+use ratatui::style::Style;
 use ratatui::text::Line;
 use ratatui::widgets::{Block, Borders, Paragraph, Row, Table};
 
+use crate::adif::Damage;
 use crate::model::Log;
-use crate::storage::{LogManager, StorageError};
+use crate::storage::{LogFileProblem, LogManager, StorageError};
 use crate::tui::action::Action;
 use crate::tui::app::Screen;
+use crate::tui::keymap::Command;
+use crate::tui::theme::Theme;
 
 /// State for the log selection screen.
 #[derive(Debug, Clone)]
@@ -23,6 +26,15 @@ pub struct LogSelectState {
     error: Option<String>,
     /// When `Some`, a delete confirmation is pending for `(log_id, display_label)`.
     pending_delete: Option<(String, String)>,
+    /// IDs of the logs marked for merging, in the order they were marked.
+    marked: Vec<String>,
+    /// `true` while asking whether to archive the marked logs after merging.
+    pending_merge: bool,
+    /// Damaged and unreadable log files found by the last load.
+    problems: Vec<LogFileProblem>,
+    /// When `Some`, asking whether to open `(log, lock_error)` read-only
+    /// because another duklog has it open.
+    pending_read_only: Option<(Log, String)>,
 }
 
 impl Default for LogSelectState {
@@ -39,20 +51,47 @@ impl LogSelectState {
             selected: None,
             error: None,
             pending_delete: None,
+            marked: Vec::new(),
+            pending_merge: false,
+            problems: Vec::new(),
+            pending_read_only: None,
         }
     }
 
     /// Loads the log list from the given manager, updating selection state.
+    ///
+    /// Files that could not be read are skipped and named in the error line.
     pub fn load(&mut self, manager: &LogManager) -> Result<(), StorageError> {
-        self.logs = manager.list_logs()?;
+        let listing = manager.scan_logs()?;
+        self.logs = listing.logs;
+        self.problems = listing.problems;
         self.selected = if self.logs.is_empty() { None } else { Some(0) };
-        self.error = None;
+        self.error = self.unreadable_message();
         self.pending_delete = None;
+        self.marked.clear();
+        self.pending_merge = false;
+        self.pending_read_only = None;
         Ok(())
     }
 
     /// Handles a key event, returning an [`Action`] for the app to apply.
+    ///
+    /// Rebindable commands (new, delete) are resolved by the app's keymap
+    /// and dispatched through [`handle_command`](Self::handle_command).
     pub fn handle_key(&mut self, key: KeyEvent) -> Action {
+        if self.pending_merge {
+            return self.handle_merge_key(key);
+        }
+        if let Some((log, reason)) = self.pending_read_only.take() {
+            return match key.code {
+                KeyCode::Char('y') => Action::SelectLogReadOnly(log),
+                KeyCode::Char('n') | KeyCode::Esc => Action::None,
+                _ => {
+                    self.pending_read_only = Some((log, reason));
+                    Action::None
+                }
+            };
+        }
         match self.pending_delete.take() {
             Some((log_id, label)) => match key.code {
                 KeyCode::Char('y') => Action::DeleteLog(log_id),
@@ -72,14 +111,34 @@ impl LogSelectState {
                     Action::None
                 }
                 KeyCode::Enter => self.select_current(),
-                KeyCode::Char('n') => Action::Navigate(Screen::LogCreate),
-                KeyCode::Char('d') => self.start_delete(),
                 KeyCode::Esc => Action::Quit,
                 _ => Action::None,
             },
         }
     }
 
+    /// Applies a keymap [`Command`], returning an [`Action`] for the app to apply.
+    pub fn handle_command(&mut self, command: Command) -> Action {
+        match command {
+            Command::NewLog => Action::Navigate(Screen::LogCreate),
+            Command::Search => Action::Navigate(Screen::Search),
+            Command::Awards => Action::Navigate(Screen::Awards),
+            Command::DeleteLog => self.start_delete(),
+            Command::MarkLog => self.toggle_mark(),
+            Command::MergeLogs => self.start_merge(),
+            Command::EditLog => self
+                .selected
+                .and_then(|i| self.logs.get(i))
+                .map_or(Action::None, |log| Action::EditLog(log.clone())),
+            Command::CopyLog => self
+                .selected
+                .and_then(|i| self.logs.get(i))
+                .map_or(Action::None, |log| Action::CopyLog(log.clone())),
+            Command::RepairLog => self.repair_current(),
+            _ => Action::None,
+        }
+    }
+
     /// Returns the cached log list.
     pub fn logs(&self) -> &[Log] {
         &self.logs
@@ -107,8 +166,72 @@ impl LogSelectState {
             .map(|(_, label)| label.as_str())
     }
 
+    /// Returns `true` if the log with the given ID is marked for merging.
+    pub fn is_marked(&self, log_id: &str) -> bool {
+        self.marked.iter().any(|id| id == log_id)
+    }
+
+    /// Returns the number of logs marked for merging.
+    pub fn marked_count(&self) -> usize {
+        self.marked.len()
+    }
+
+    /// Returns `true` while the merge confirmation is pending.
+    pub fn pending_merge(&self) -> bool {
+        self.pending_merge
+    }
+
+    /// Asks whether to open `log` read-only, explaining with `reason` why it
+    /// cannot be opened normally.
+    pub fn offer_read_only(&mut self, log: Log, reason: String) {
+        self.pending_read_only = Some((log, reason));
+    }
+
+    /// Returns the reason shown with the pending read-only prompt, if any.
+    pub fn pending_read_only(&self) -> Option<&str> {
+        self.pending_read_only
+            .as_ref()
+            .map(|(_, reason)| reason.as_str())
+    }
+
+    /// Returns the damaged records of the log's file, if it has any.
+    pub fn damage(&self, log_id: &str) -> Option<&Damage> {
+        self.problems.iter().find_map(|problem| match problem {
+            LogFileProblem::Damaged { log_id: id, damage } if id == log_id => Some(damage),
+            _ => None,
+        })
+    }
+
+    /// Returns the damaged records of the selected log's file, if it has any.
+    pub fn selected_damage(&self) -> Option<&Damage> {
+        let log = self.selected.and_then(|i| self.logs.get(i))?;
+        self.damage(&log.header().log_id)
+    }
+
+    /// Names the files skipped because they could not be read, if any.
+    fn unreadable_message(&self) -> Option<String> {
+        let names: Vec<String> = self
+            .problems
+            .iter()
+            .filter_map(|problem| match problem {
+                LogFileProblem::Unreadable { path, .. } => path
+                    .file_name()
+                    .map(|name| name.to_string_lossy().into_owned()),
+                LogFileProblem::Damaged { .. } => None,
+            })
+            .collect();
+        (!names.is_empty()).then(|| format!("Skipped unreadable log file(s): {}", names.join(", ")))
+    }
+
     /// Returns an action to open the currently selected log.
-    fn select_current(&self) -> Action {
+    ///
+    /// A damaged log is not opened, since QSOs appended after the damage
+    /// could not be read back.
+    fn select_current(&mut self) -> Action {
+        if self.selected_damage().is_some() {
+            self.error = Some("This log is damaged. Press 'r' to repair it first.".into());
+            return Action::None;
+        }
         match self.selected {
             Some(i) => self
                 .logs
@@ -118,6 +241,16 @@ impl LogSelectState {
         }
     }
 
+    /// Returns an action to repair the selected log if its file is damaged.
+    fn repair_current(&self) -> Action {
+        match self.selected.and_then(|i| self.logs.get(i)) {
+            Some(log) if self.selected_damage().is_some() => {
+                Action::RepairLog(log.header().log_id.clone())
+            }
+            _ => Action::None,
+        }
+    }
+
     /// Moves the selection up by one (no wrap).
     fn select_prev(&mut self) {
         self.selected = match self.selected {
@@ -134,6 +267,48 @@ impl LogSelectState {
         };
     }
 
+    /// Marks the selected log for merging, or unmarks it if already marked.
+    fn toggle_mark(&mut self) -> Action {
+        let Some(log) = self.selected.and_then(|i| self.logs.get(i)) else {
+            return Action::None;
+        };
+        let log_id = &log.header().log_id;
+        match self.marked.iter().position(|id| id == log_id) {
+            Some(pos) => {
+                self.marked.remove(pos);
+            }
+            None => self.marked.push(log_id.clone()),
+        }
+        self.select_next();
+        Action::None
+    }
+
+    /// Starts the merge confirmation, or explains why the marked logs cannot be merged.
+    fn start_merge(&mut self) -> Action {
+        if self.marked.len() < 2 {
+            self.error = Some("Mark at least two logs to merge".into());
+        } else {
+            self.error = None;
+            self.pending_merge = true;
+        }
+        Action::None
+    }
+
+    /// Answers the merge confirmation: archive the originals, keep them, or cancel.
+    fn handle_merge_key(&mut self, key: KeyEvent) -> Action {
+        let archive = match key.code {
+            KeyCode::Char('a') => true,
+            KeyCode::Char('k') => false,
+            KeyCode::Char('n') | KeyCode::Esc => {
+                self.pending_merge = false;
+                return Action::None;
+            }
+            _ => return Action::None,
+        };
+        self.pending_merge = false;
+        Action::MergeLogs(self.marked.clone(), archive)
+    }
+
     /// Starts a delete confirmation for the currently selected log.
     ///
     /// Returns `Action::None` always — the confirmation is tracked in state.
@@ -156,11 +331,11 @@ impl LogSelectState {
 
 /// Renders the log selection screen.
 #[mutants::skip]
-pub fn draw_log_select(state: &LogSelectState, frame: &mut Frame, area: Rect) {
+pub fn draw_log_select(state: &LogSelectState, theme: &Theme, frame: &mut Frame, area: Rect) {
     let block = Block::default()
         .title(" Select Log ")
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::Cyan));
+        .border_style(theme.accent);
 
     if state.logs().is_empty() {
         let lines = vec![
@@ -178,8 +353,8 @@ pub fn draw_log_select(state: &LogSelectState, frame: &mut Frame, area: Rect) {
     let inner = block.inner(area);
     frame.render_widget(block, area);
 
-    let header = Row::new(vec!["Callsign", "Date", "Type", "Grid", "QSOs"])
-        .style(Style::default().add_modifier(Modifier::BOLD))
+    let header = Row::new(vec!["", "Callsign", "Date", "Type", "Grid", "QSOs"])
+        .style(theme.header)
         .bottom_margin(1);
 
     let rows: Vec<Row> = state
@@ -188,11 +363,20 @@ pub fn draw_log_select(state: &LogSelectState, frame: &mut Frame, area: Rect) {
         .enumerate()
         .map(|(i, log)| {
             let style = if state.selected() == Some(i) {
-                Style::default().fg(Color::Black).bg(Color::Yellow)
+                theme.selected
             } else {
                 Style::default()
             };
+            let log_id = &log.header().log_id;
+            let mark = if state.is_marked(log_id) {
+                "*"
+            } else if state.damage(log_id).is_some() {
+                "!"
+            } else {
+                ""
+            };
             Row::new(vec![
+                mark.to_string(),
                 log.header().station_callsign.clone(),
                 log.header().created_at.format("%Y-%m-%d").to_string(),
                 log.log_type_name().to_string(),
@@ -204,6 +388,7 @@ pub fn draw_log_select(state: &LogSelectState, frame: &mut Frame, area: Rect) {
         .collect();
 
     let widths = [
+        Constraint::Length(1),
         Constraint::Length(12),
         Constraint::Length(12),
         Constraint::Length(12),
@@ -218,20 +403,45 @@ pub fn draw_log_select(state: &LogSelectState, frame: &mut Frame, area: Rect) {
 
     frame.render_widget(table, table_area);
 
-    let footer = Paragraph::new("n: new  Enter: open  d: delete  Esc: quit  F1: help")
-        .style(Style::default().fg(Color::DarkGray));
+    let footer_text = if state.marked_count() == 0 {
+        "n: new  c: copy  e: edit  d: delete  Space: mark  /: search  F1: help".to_string()
+    } else {
+        format!(
+            "{} marked  Space: mark  m: merge  F1: help",
+            state.marked_count()
+        )
+    };
+    let footer = Paragraph::new(footer_text).style(theme.dim);
     frame.render_widget(footer, footer_area);
 
-    if let Some(label) = state.pending_delete_label() {
+    if state.pending_merge() {
+        let prompt = Paragraph::new(format!(
+            "Merge {} logs? a: archive originals  k: keep originals  Esc: cancel",
+            state.marked_count()
+        ))
+        .style(theme.warning)
+        .alignment(Alignment::Center);
+        frame.render_widget(prompt, footer_area);
+    } else if let Some(reason) = state.pending_read_only() {
+        let prompt = Paragraph::new(format!("{reason}. Open read-only? y/n"))
+            .style(theme.warning)
+            .alignment(Alignment::Center);
+        frame.render_widget(prompt, footer_area);
+    } else if let Some(label) = state.pending_delete_label() {
         let prompt = Paragraph::new(format!("Delete {label}? y/n"))
-            .style(Style::default().fg(Color::Yellow))
+            .style(theme.warning)
             .alignment(Alignment::Center);
         frame.render_widget(prompt, footer_area);
     } else if let Some(err) = state.error() {
         let err_line = Paragraph::new(err)
-            .style(Style::default().fg(Color::Red))
+            .style(theme.error)
             .alignment(Alignment::Center);
         frame.render_widget(err_line, footer_area);
+    } else if let Some(damage) = state.selected_damage() {
+        let warning = Paragraph::new(format!("Damaged: {damage}  r: repair"))
+            .style(theme.warning)
+            .alignment(Alignment::Center);
+        frame.render_widget(warning, footer_area);
     }
 }
 
@@ -241,7 +451,7 @@ mod tests {
     use crossterm::event::{KeyEventKind, KeyEventState, KeyModifiers};
 
     use super::*;
-    use crate::model::{GeneralLog, LogHeader, PotaLog};
+    use crate::model::{ExtraFields, GeneralLog, LogHeader, PotaLog};
 
     fn press(code: KeyCode) -> KeyEvent {
         KeyEvent {
@@ -261,6 +471,7 @@ mod tests {
                 qsos: vec![],
                 created_at: Utc.with_ymd_and_hms(2026, 2, 16, 12, 0, 0).unwrap(),
                 log_id: id.into(),
+                extra_fields: ExtraFields::default(),
             },
             park_ref: park_ref.into(),
         })
@@ -275,6 +486,7 @@ mod tests {
                 qsos: vec![],
                 created_at: Utc.with_ymd_and_hms(2026, 2, 16, 12, 0, 0).unwrap(),
                 log_id: id.into(),
+                extra_fields: ExtraFields::default(),
             },
         })
     }
@@ -289,6 +501,10 @@ mod tests {
             selected: Some(0),
             error: None,
             pending_delete: None,
+            marked: Vec::new(),
+            pending_merge: false,
+            problems: Vec::new(),
+            pending_read_only: None,
         }
     }
 
@@ -402,11 +618,26 @@ mod tests {
         }
 
         #[test]
-        fn n_navigates_to_log_create() {
+        fn new_log_navigates_to_log_create() {
             let mut state = make_populated_state();
-            let action = state.handle_key(press(KeyCode::Char('n')));
+            let action = state.handle_command(Command::NewLog);
             assert_eq!(action, Action::Navigate(Screen::LogCreate));
         }
+
+        #[test]
+        fn search_navigates_to_search() {
+            let mut state = make_populated_state();
+            let action = state.handle_command(Command::Search);
+            assert_eq!(action, Action::Navigate(Screen::Search));
+        }
+
+        #[test]
+        fn unrelated_command_returns_none() {
+            let mut state = make_populated_state();
+            let action = state.handle_command(Command::EditQso);
+            assert_eq!(action, Action::None);
+            assert!(state.pending_delete_label().is_none());
+        }
     }
 
     mod quit {
@@ -435,17 +666,17 @@ mod tests {
         use super::*;
 
         #[test]
-        fn d_on_empty_list_is_noop() {
+        fn delete_log_on_empty_list_is_noop() {
             let mut state = LogSelectState::new();
-            let action = state.handle_key(press(KeyCode::Char('d')));
+            let action = state.handle_command(Command::DeleteLog);
             assert_eq!(action, Action::None);
             assert!(state.pending_delete_label().is_none());
         }
 
         #[test]
-        fn d_with_selected_log_sets_pending_delete() {
+        fn delete_log_with_selected_log_sets_pending_delete() {
             let mut state = make_populated_state();
-            let action = state.handle_key(press(KeyCode::Char('d')));
+            let action = state.handle_command(Command::DeleteLog);
             assert_eq!(action, Action::None);
             assert!(state.pending_delete_label().is_some());
         }
@@ -453,7 +684,7 @@ mod tests {
         #[test]
         fn pending_delete_label_includes_park_ref_and_date() {
             let mut state = make_populated_state();
-            state.handle_key(press(KeyCode::Char('d')));
+            state.handle_command(Command::DeleteLog);
             let label = state.pending_delete_label().unwrap();
             assert!(label.contains("K-0001"), "should include park ref: {label}");
             assert!(label.contains("2026-02-16"), "should include date: {label}");
@@ -463,7 +694,7 @@ mod tests {
         fn pending_delete_label_falls_back_to_callsign_when_no_park_ref() {
             let mut state = make_populated_state();
             state.selected = Some(1); // log2 has no park_ref
-            state.handle_key(press(KeyCode::Char('d')));
+            state.handle_command(Command::DeleteLog);
             let label = state.pending_delete_label().unwrap();
             assert!(label.contains("N0CALL"), "should use callsign: {label}");
         }
@@ -471,7 +702,7 @@ mod tests {
         #[test]
         fn y_while_pending_returns_delete_action() {
             let mut state = make_populated_state();
-            state.handle_key(press(KeyCode::Char('d')));
+            state.handle_command(Command::DeleteLog);
             let action = state.handle_key(press(KeyCode::Char('y')));
             match action {
                 Action::DeleteLog(log_id) => assert_eq!(log_id, "log1"),
@@ -483,7 +714,7 @@ mod tests {
         #[test]
         fn n_while_pending_cancels() {
             let mut state = make_populated_state();
-            state.handle_key(press(KeyCode::Char('d')));
+            state.handle_command(Command::DeleteLog);
             let action = state.handle_key(press(KeyCode::Char('n')));
             assert_eq!(action, Action::None);
             assert!(state.pending_delete_label().is_none());
@@ -492,7 +723,7 @@ mod tests {
         #[test]
         fn esc_while_pending_cancels_not_quits() {
             let mut state = make_populated_state();
-            state.handle_key(press(KeyCode::Char('d')));
+            state.handle_command(Command::DeleteLog);
             let action = state.handle_key(press(KeyCode::Esc));
             assert_eq!(action, Action::None);
             assert!(state.pending_delete_label().is_none());
@@ -501,7 +732,7 @@ mod tests {
         #[test]
         fn other_keys_while_pending_are_noop() {
             let mut state = make_populated_state();
-            state.handle_key(press(KeyCode::Char('d')));
+            state.handle_command(Command::DeleteLog);
             assert!(state.pending_delete_label().is_some());
             // Enter should not select the log
             let action = state.handle_key(press(KeyCode::Enter));
@@ -510,6 +741,99 @@ mod tests {
         }
     }
 
+    mod merge {
+        use super::*;
+
+        fn mark_first_two(state: &mut LogSelectState) {
+            state.handle_command(Command::MarkLog);
+            state.handle_command(Command::MarkLog);
+        }
+
+        #[test]
+        fn mark_toggles_and_advances_selection() {
+            let mut state = make_populated_state();
+            state.handle_command(Command::MarkLog);
+            assert!(state.is_marked("log1"));
+            assert_eq!(state.selected(), Some(1));
+
+            state.selected = Some(0);
+            state.handle_command(Command::MarkLog);
+            assert!(!state.is_marked("log1"));
+            assert_eq!(state.marked_count(), 0);
+        }
+
+        #[test]
+        fn mark_on_empty_list_is_noop() {
+            let mut state = LogSelectState::new();
+            assert_eq!(state.handle_command(Command::MarkLog), Action::None);
+            assert_eq!(state.marked_count(), 0);
+        }
+
+        #[test]
+        fn merge_with_fewer_than_two_marked_shows_error() {
+            let mut state = make_populated_state();
+            state.handle_command(Command::MarkLog);
+            state.handle_command(Command::MergeLogs);
+            assert!(!state.pending_merge());
+            assert_eq!(state.error(), Some("Mark at least two logs to merge"));
+        }
+
+        #[test]
+        fn a_merges_and_archives_marked_logs() {
+            let mut state = make_populated_state();
+            mark_first_two(&mut state);
+            state.handle_command(Command::MergeLogs);
+            assert!(state.pending_merge());
+            let action = state.handle_key(press(KeyCode::Char('a')));
+            assert_eq!(
+                action,
+                Action::MergeLogs(vec!["log1".into(), "log2".into()], true)
+            );
+            assert!(!state.pending_merge());
+        }
+
+        #[test]
+        fn k_merges_and_keeps_marked_logs() {
+            let mut state = make_populated_state();
+            mark_first_two(&mut state);
+            state.handle_command(Command::MergeLogs);
+            let action = state.handle_key(press(KeyCode::Char('k')));
+            assert_eq!(
+                action,
+                Action::MergeLogs(vec!["log1".into(), "log2".into()], false)
+            );
+        }
+
+        #[test]
+        fn esc_cancels_merge_without_quitting() {
+            let mut state = make_populated_state();
+            mark_first_two(&mut state);
+            state.handle_command(Command::MergeLogs);
+            assert_eq!(state.handle_key(press(KeyCode::Esc)), Action::None);
+            assert!(!state.pending_merge());
+            assert_eq!(state.marked_count(), 2, "marks survive a cancel");
+        }
+
+        #[test]
+        fn other_keys_while_merge_pending_are_noop() {
+            let mut state = make_populated_state();
+            mark_first_two(&mut state);
+            state.handle_command(Command::MergeLogs);
+            assert_eq!(state.handle_key(press(KeyCode::Enter)), Action::None);
+            assert!(state.pending_merge());
+        }
+
+        #[test]
+        fn load_clears_marks() {
+            let dir = tempfile::tempdir().unwrap();
+            let manager = LogManager::with_path(dir.path()).unwrap();
+            let mut state = make_populated_state();
+            mark_first_two(&mut state);
+            state.load(&manager).unwrap();
+            assert_eq!(state.marked_count(), 0);
+        }
+    }
+
     mod rendering {
         use ratatui::Terminal;
         use ratatui::backend::TestBackend;
@@ -523,7 +847,7 @@ mod tests {
             let mut terminal = Terminal::new(backend).unwrap();
             terminal
                 .draw(|frame| {
-                    draw_log_select(state, frame, frame.area());
+                    draw_log_select(state, &Theme::default(), frame, frame.area());
                 })
                 .unwrap();
             buffer_to_string(terminal.backend().buffer())
@@ -559,7 +883,42 @@ mod tests {
             let output = render_log_select(&state, 80, 12);
             assert!(output.contains("n: new"), "should show n: new hint");
             assert!(output.contains("d: delete"), "should show d: delete hint");
+            assert!(output.contains("e: edit"), "should show e: edit hint");
+            assert!(output.contains("c: copy"), "should show c: copy hint");
             assert!(output.contains("F1: help"), "should show F1: help hint");
+            assert!(output.contains("/: search"), "should show /: search hint");
+        }
+
+        #[test]
+        fn renders_merge_hint_once_logs_are_marked() {
+            let mut state = make_populated_state();
+            state.handle_command(Command::MarkLog);
+            let output = render_log_select(&state, 80, 12);
+            assert!(output.contains("1 marked"), "should show mark count");
+            assert!(output.contains("m: merge"), "should show m: merge hint");
+        }
+
+        #[test]
+        fn renders_marked_logs() {
+            let mut state = make_populated_state();
+            state.selected = Some(1);
+            state.handle_command(Command::MarkLog);
+            let output = render_log_select(&state, 80, 12);
+            let row = output.lines().find(|l| l.contains("N0CALL")).unwrap();
+            assert!(row.contains('*'), "marked row shows *: {row}");
+            let row = output.lines().find(|l| l.contains("KD9XYZ")).unwrap();
+            assert!(!row.contains('*'), "unmarked row has no *: {row}");
+        }
+
+        #[test]
+        fn renders_merge_prompt() {
+            let mut state = make_populated_state();
+            state.handle_command(Command::MarkLog);
+            state.handle_command(Command::MarkLog);
+            state.handle_command(Command::MergeLogs);
+            let output = render_log_select(&state, 80, 12);
+            assert!(output.contains("Merge 2 logs?"), "{output}");
+            assert!(output.contains("a: archive originals"), "{output}");
         }
 
         #[test]
@@ -573,7 +932,7 @@ mod tests {
         #[test]
         fn renders_confirmation_prompt_when_pending_delete() {
             let mut state = make_populated_state();
-            state.handle_key(press(KeyCode::Char('d')));
+            state.handle_command(Command::DeleteLog);
             let output = render_log_select(&state, 60, 12);
             assert!(
                 output.contains("Delete"),
@@ -586,13 +945,204 @@ mod tests {
         fn confirmation_prompt_replaces_error_when_both_set() {
             let mut state = make_populated_state();
             state.set_error("old error".into());
-            state.handle_key(press(KeyCode::Char('d')));
+            state.handle_command(Command::DeleteLog);
             let output = render_log_select(&state, 60, 12);
             assert!(output.contains("Delete"), "should show confirmation");
             assert!(!output.contains("old error"), "should not show stale error");
         }
     }
 
+    mod edit {
+        use super::*;
+
+        #[test]
+        fn edit_opens_selected_log() {
+            let mut state = make_populated_state();
+            state.selected = Some(1);
+            let expected = state.logs()[1].clone();
+            assert_eq!(
+                state.handle_command(Command::EditLog),
+                Action::EditLog(expected)
+            );
+        }
+
+        #[test]
+        fn edit_on_empty_list_is_noop() {
+            let mut state = LogSelectState::new();
+            assert_eq!(state.handle_command(Command::EditLog), Action::None);
+        }
+
+        #[test]
+        fn copy_starts_from_selected_log() {
+            let mut state = make_populated_state();
+            let expected = state.logs()[0].clone();
+            assert_eq!(
+                state.handle_command(Command::CopyLog),
+                Action::CopyLog(expected)
+            );
+        }
+
+        #[test]
+        fn copy_on_empty_list_is_noop() {
+            let mut state = LogSelectState::new();
+            assert_eq!(state.handle_command(Command::CopyLog), Action::None);
+        }
+    }
+
+    mod damaged {
+        use super::*;
+        use crate::adif::DamagedRecord;
+
+        fn make_damaged_state() -> LogSelectState {
+            let mut state = make_populated_state();
+            state.problems = vec![LogFileProblem::Damaged {
+                log_id: "log1".into(),
+                damage: Damage {
+                    records: vec![DamagedRecord {
+                        offset: 512,
+                        len: 40,
+                        reason: "record not terminated by <EOR>".into(),
+                    }],
+                },
+            }];
+            state
+        }
+
+        #[test]
+        fn load_flags_damaged_and_unreadable_files() {
+            let dir = tempfile::tempdir().unwrap();
+            let manager = LogManager::with_path(dir.path()).unwrap();
+            let log = make_pota_log("test", "W1AW", "K-0001");
+            manager.save_log(&log).unwrap();
+            let path = dir.path().join("test.adif");
+            let mut content = std::fs::read_to_string(&path).unwrap();
+            content.push_str("<CALL:4>W1");
+            std::fs::write(&path, content).unwrap();
+            std::fs::write(dir.path().join("junk.adif"), "not adif").unwrap();
+
+            let mut state = LogSelectState::new();
+            state.load(&manager).unwrap();
+            assert_eq!(state.logs().len(), 1);
+            assert!(state.damage("test").is_some());
+            assert_eq!(
+                state.error(),
+                Some("Skipped unreadable log file(s): junk.adif")
+            );
+        }
+
+        #[test]
+        fn enter_on_damaged_log_asks_for_repair() {
+            let mut state = make_damaged_state();
+            assert_eq!(state.handle_key(press(KeyCode::Enter)), Action::None);
+            assert!(state.error().unwrap().contains("repair"));
+        }
+
+        #[test]
+        fn repair_on_damaged_log() {
+            let mut state = make_damaged_state();
+            assert_eq!(
+                state.handle_command(Command::RepairLog),
+                Action::RepairLog("log1".into())
+            );
+        }
+
+        #[test]
+        fn repair_on_intact_log_is_noop() {
+            let mut state = make_damaged_state();
+            state.selected = Some(1);
+            assert_eq!(state.handle_command(Command::RepairLog), Action::None);
+            assert_eq!(
+                state.handle_key(press(KeyCode::Enter)),
+                Action::SelectLog(make_general_log("log2", "N0CALL"))
+            );
+        }
+
+        #[test]
+        fn renders_damage_mark_and_hint() {
+            use ratatui::Terminal;
+            use ratatui::backend::TestBackend;
+
+            use crate::tui::test_utils::buffer_to_string;
+
+            let state = make_damaged_state();
+            let backend = TestBackend::new(90, 12);
+            let mut terminal = Terminal::new(backend).unwrap();
+            terminal
+                .draw(|frame| draw_log_select(&state, &Theme::default(), frame, frame.area()))
+                .unwrap();
+            let output = buffer_to_string(terminal.backend().buffer());
+            let row = output.lines().find(|l| l.contains("W1AW")).unwrap();
+            assert!(row.contains('!'), "damaged row shows !: {row}");
+            assert!(
+                output.contains("Damaged: 40 damaged bytes at byte 512"),
+                "{output}"
+            );
+            assert!(output.contains("r: repair"), "{output}");
+        }
+    }
+
+    mod read_only {
+        use super::*;
+
+        fn make_prompting_state() -> LogSelectState {
+            let mut state = make_populated_state();
+            state.offer_read_only(
+                make_pota_log("log1", "W1AW", "K-0001"),
+                "log is open in another duklog (pid 4242)".into(),
+            );
+            state
+        }
+
+        #[test]
+        fn y_opens_read_only() {
+            let mut state = make_prompting_state();
+            assert_eq!(
+                state.handle_key(press(KeyCode::Char('y'))),
+                Action::SelectLogReadOnly(make_pota_log("log1", "W1AW", "K-0001"))
+            );
+            assert_eq!(state.pending_read_only(), None);
+        }
+
+        #[test]
+        fn n_and_esc_cancel() {
+            for code in [KeyCode::Char('n'), KeyCode::Esc] {
+                let mut state = make_prompting_state();
+                assert_eq!(state.handle_key(press(code)), Action::None);
+                assert_eq!(state.pending_read_only(), None);
+            }
+        }
+
+        #[test]
+        fn other_keys_keep_prompt() {
+            let mut state = make_prompting_state();
+            assert_eq!(state.handle_key(press(KeyCode::Enter)), Action::None);
+            assert_eq!(
+                state.pending_read_only(),
+                Some("log is open in another duklog (pid 4242)")
+            );
+        }
+
+        #[test]
+        fn renders_prompt() {
+            use ratatui::Terminal;
+            use ratatui::backend::TestBackend;
+
+            use crate::tui::test_utils::buffer_to_string;
+
+            let state = make_prompting_state();
+            let backend = TestBackend::new(90, 12);
+            let mut terminal = Terminal::new(backend).unwrap();
+            terminal
+                .draw(|frame| draw_log_select(&state, &Theme::default(), frame, frame.area()))
+                .unwrap();
+            let output = buffer_to_string(terminal.backend().buffer());
+            assert!(
+                output.contains("log is open in another duklog (pid 4242). Open read-only? y/n"),
+                "{output}"
+            );
+        }
+    }
+
     mod error {
         use super::*;
 
@@ -1,15 +1,19 @@
 //! TUI screen implementations.
 
+pub mod awards;
 pub mod export;
 pub mod help;
 pub mod log_create;
 pub mod log_select;
 pub mod qso_entry;
 pub mod qso_list;
+pub mod search;
 
+pub use awards::{AwardsState, draw_awards};
 pub use export::{ExportState, ExportStatus, draw_export};
 pub use help::{HelpState, draw_help};
 pub use log_create::{LogCreateState, draw_log_create};
 pub use log_select::{LogSelectState, draw_log_select};
 pub use qso_entry::{QsoEntryState, draw_qso_entry};
 pub use qso_list::{QsoListState, draw_qso_list};
+pub use search::{SearchState, draw_search};
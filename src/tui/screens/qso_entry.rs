@@ -1,19 +1,25 @@
 //! QSO entry screen — the core data entry form for logging contacts.
 
-use chrono::{DateTime, Utc};
+use std::collections::HashSet;
+
+use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveTime, Utc};
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use ratatui::Frame;
 use ratatui::layout::{Constraint, Layout, Rect};
-use ratatui::style::{Color, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, Paragraph, Row, Table};
 
 use crate::model::{
-    Band, Log, Mode, Qso, normalize_park_ref, validate_callsign, validate_fd_exchange,
-    validate_park_ref, validate_section, validate_wfd_exchange,
+    Band, ExtraFields, Log, Mode, Qso, format_khz, normalize_park_ref, parse_khz,
+    validate_callsign, validate_fd_exchange, validate_park_ref, validate_section,
+    validate_wfd_exchange,
 };
+use crate::storage::WorkedEntry;
 use crate::tui::action::Action;
 use crate::tui::app::Screen;
+use crate::tui::keymap::Command;
+use crate::tui::theme::Theme;
+use crate::tui::timers::TimerReading;
 use crate::tui::widgets::form::{Form, FormField, RstField, draw_form_field};
 use crate::tui::widgets::{StatusBarContext, draw_status_bar};
 
@@ -73,6 +79,16 @@ impl QsoFormType {
         }
     }
 
+    /// Index of the Date field; present only in backfill or edit mode.
+    fn date_idx(self) -> usize {
+        self.comments_idx() + 1
+    }
+
+    /// Index of the Time (HHMM) field; present only in backfill or edit mode.
+    fn time_idx(self) -> usize {
+        self.comments_idx() + 2
+    }
+
     /// Index of the optional/required frequency field for this form type.
     fn frequency_field_idx(self) -> usize {
         match self {
@@ -83,6 +99,20 @@ impl QsoFormType {
     }
 }
 
+/// The QSO being edited: its position in the log and the values the form cannot show.
+#[derive(Debug, Clone, PartialEq)]
+struct EditTarget {
+    index: usize,
+    /// Original timestamp, kept when the Date/Time fields are left unchanged.
+    timestamp: DateTime<Utc>,
+    /// Operator of the edited QSO; switchable with the operator command.
+    operator: Option<String>,
+    /// Sync ID of the edited QSO, kept so the edit replaces it on every station.
+    id: Option<String>,
+    /// ADIF fields of the edited QSO that duklog does not interpret.
+    extra_fields: ExtraFields,
+}
+
 /// State for the QSO entry screen.
 #[derive(Debug)]
 pub struct QsoEntryState {
@@ -90,10 +120,30 @@ pub struct QsoEntryState {
     form_type: QsoFormType,
     band: Band,
     mode: Mode,
+    /// Bands offered by Alt+B / Alt+Shift+B, in cycle order.
+    band_cycle: Vec<Band>,
+    /// Favourite modes offered by Alt+M / Alt+Shift+M, in cycle order.
+    mode_cycle: Vec<Mode>,
     recent_qsos: Vec<Qso>,
     error: Option<String>,
-    /// When editing an existing QSO: `(index, original_timestamp)`.
-    editing: Option<(usize, DateTime<Utc>)>,
+    /// The existing QSO being edited, if any.
+    editing: Option<EditTarget>,
+    /// Operator stamped on new QSOs; `None` means the log's operator.
+    operator: Option<String>,
+    /// Callsign being typed while switching operator.
+    operator_prompt: Option<String>,
+    /// ID of the active log; backfill mode is toggled per log.
+    log_id: String,
+    /// IDs of logs with backfill mode switched on.
+    backfill_logs: HashSet<String>,
+    /// Timestamp of the previous QSO logged in backfill mode, for the monotonic check.
+    last_backfill: Option<DateTime<Utc>>,
+    /// An out-of-order backfill timestamp the operator has already been warned
+    /// about; submitting it again logs it anyway.
+    out_of_order_ack: Option<DateTime<Utc>>,
+    /// The callsign last looked up in the callsign index and the earlier QSOs
+    /// found for it, across all logs.
+    worked_before: Option<(String, Vec<WorkedEntry>)>,
 }
 
 impl Default for QsoEntryState {
@@ -113,9 +163,18 @@ impl QsoEntryState {
             form_type,
             band: Band::default(),
             mode,
+            band_cycle: Band::default_cycle().to_vec(),
+            mode_cycle: Mode::default_cycle().to_vec(),
             recent_qsos: Vec::new(),
             error: None,
             editing: None,
+            operator: None,
+            operator_prompt: None,
+            log_id: String::new(),
+            backfill_logs: HashSet::new(),
+            last_backfill: None,
+            out_of_order_ack: None,
+            worked_before: None,
         }
     }
 
@@ -159,43 +218,68 @@ impl QsoEntryState {
         }
     }
 
-    /// Handles a key event, returning an [`Action`] for the app to apply.
-    pub fn handle_key(&mut self, key: KeyEvent) -> Action {
-        // Alt+B/M cycle band/mode forward; Shift+Alt+B/M cycle backward
-        if key.modifiers == KeyModifiers::ALT {
-            match key.code {
-                KeyCode::Char('b') => {
-                    self.cycle_band(true);
-                    return Action::None;
-                }
-                KeyCode::Char('m') => {
-                    self.cycle_mode(true);
-                    return Action::None;
-                }
-                KeyCode::Char('x') => {
-                    return Action::Navigate(Screen::Export);
-                }
-                KeyCode::Char('e') => {
-                    return Action::Navigate(Screen::QsoList);
-                }
-                _ => {}
+    /// Handles a keymap [`Command`], returning an [`Action`] for the app to apply.
+    pub fn handle_command(&mut self, command: Command) -> Action {
+        match command {
+            Command::NextBand => {
+                self.cycle_band(true);
+                Action::None
             }
-        }
-        const ALT_SHIFT: KeyModifiers = KeyModifiers::ALT.union(KeyModifiers::SHIFT);
-        if key.modifiers == ALT_SHIFT {
-            match key.code {
-                KeyCode::Char('B') => {
-                    self.cycle_band(false);
-                    return Action::None;
-                }
-                KeyCode::Char('M') => {
-                    self.cycle_mode(false);
-                    return Action::None;
-                }
-                _ => {}
+            Command::PrevBand => {
+                self.cycle_band(false);
+                Action::None
+            }
+            Command::NextMode => {
+                self.cycle_mode(true);
+                Action::None
+            }
+            Command::PrevMode => {
+                self.cycle_mode(false);
+                Action::None
+            }
+            Command::OpenQsoList => Action::Navigate(Screen::QsoList),
+            Command::OpenExport => Action::Navigate(Screen::Export),
+            Command::ToggleBackfill => {
+                self.toggle_backfill();
+                Action::None
             }
+            Command::SetOperator => {
+                self.operator_prompt = Some(String::new());
+                self.error = None;
+                Action::None
+            }
+            Command::Help
+            | Command::StationId
+            | Command::Search
+            | Command::Awards
+            | Command::FilterBand
+            | Command::FilterMode
+            | Command::NewLog
+            | Command::DeleteLog
+            | Command::MarkLog
+            | Command::MergeLogs
+            | Command::EditLog
+            | Command::CopyLog
+            | Command::RepairLog
+            | Command::EditQso
+            | Command::DeleteQso
+            | Command::MarkQso
+            | Command::BulkEdit
+            | Command::SortQsos
+            | Command::ReverseSort
+            | Command::ChooseColumns => Action::None,
         }
+    }
 
+    /// Handles a key event, returning an [`Action`] for the app to apply.
+    ///
+    /// Band/mode cycling and navigation shortcuts arrive as commands via
+    /// [`handle_command`](Self::handle_command); this handles form input.
+    pub fn handle_key(&mut self, key: KeyEvent) -> Action {
+        if self.operator_prompt.is_some() {
+            self.handle_operator_key(key);
+            return Action::None;
+        }
         match key.code {
             KeyCode::Tab => {
                 if self.form.focus() == self.form_type.frequency_field_idx() {
@@ -217,7 +301,7 @@ impl QsoEntryState {
             }
             KeyCode::Esc => {
                 if self.editing.is_some() {
-                    self.editing = None;
+                    self.clear_editing();
                     Action::Navigate(Screen::QsoList)
                 } else {
                     Action::Navigate(Screen::LogSelect)
@@ -244,6 +328,88 @@ impl QsoEntryState {
         self.mode
     }
 
+    /// Returns the bands offered by Alt+B, in cycle order.
+    pub fn band_cycle(&self) -> &[Band] {
+        &self.band_cycle
+    }
+
+    /// Replaces the Alt+B cycle list. An empty list restores [`Band::default_cycle`].
+    pub fn set_band_cycle(&mut self, bands: Vec<Band>) {
+        self.band_cycle = if bands.is_empty() {
+            Band::default_cycle().to_vec()
+        } else {
+            bands
+        };
+    }
+
+    /// Returns the favourite modes offered by Alt+M, in cycle order.
+    pub fn mode_cycle(&self) -> &[Mode] {
+        &self.mode_cycle
+    }
+
+    /// Replaces the Alt+M favourites list. An empty list restores [`Mode::default_cycle`].
+    pub fn set_mode_cycle(&mut self, modes: Vec<Mode>) {
+        self.mode_cycle = if modes.is_empty() {
+            Mode::default_cycle().to_vec()
+        } else {
+            modes
+        };
+    }
+
+    /// Returns the operator for the QSO in the form: the edited QSO's operator
+    /// in edit mode, otherwise the current operator. `None` means the log's operator.
+    pub fn operator(&self) -> Option<&str> {
+        match &self.editing {
+            Some(target) => target.operator.as_deref(),
+            None => self.operator.as_deref(),
+        }
+    }
+
+    /// Returns the callsign typed so far while switching operator, if switching.
+    pub fn operator_prompt(&self) -> Option<&str> {
+        self.operator_prompt.as_deref()
+    }
+
+    /// Handles a key while the operator prompt is open.
+    ///
+    /// Enter applies the typed callsign (blank reverts to the log's operator),
+    /// Esc cancels. In edit mode only the edited QSO's operator changes.
+    fn handle_operator_key(&mut self, key: KeyEvent) {
+        let Some(buf) = self.operator_prompt.as_mut() else {
+            return;
+        };
+        match key.code {
+            KeyCode::Char(ch)
+                if !key
+                    .modifiers
+                    .intersects(KeyModifiers::ALT | KeyModifiers::CONTROL) =>
+            {
+                buf.push(ch.to_ascii_uppercase());
+            }
+            KeyCode::Backspace => {
+                buf.pop();
+            }
+            KeyCode::Esc => self.operator_prompt = None,
+            KeyCode::Enter => {
+                let call = buf.trim().to_string();
+                if !call.is_empty()
+                    && let Err(e) = validate_callsign(&call)
+                {
+                    self.error = Some(format!("operator: {e}"));
+                    return;
+                }
+                let operator = (!call.is_empty()).then_some(call);
+                match self.editing.as_mut() {
+                    Some(target) => target.operator = operator,
+                    None => self.operator = operator,
+                }
+                self.operator_prompt = None;
+                self.error = None;
+            }
+            _ => {}
+        }
+    }
+
     /// Returns the recent QSOs list.
     pub fn recent_qsos(&self) -> &[Qso] {
         &self.recent_qsos
@@ -259,12 +425,53 @@ impl QsoEntryState {
         self.error = Some(msg);
     }
 
+    /// Returns the callsign in the form, trimmed.
+    pub fn their_call(&self) -> &str {
+        self.form.value(THEIR_CALL).trim()
+    }
+
+    /// Returns the callsign the worked-before panel was last filled for.
+    pub fn worked_before_call(&self) -> Option<&str> {
+        self.worked_before.as_ref().map(|(call, _)| call.as_str())
+    }
+
+    /// Returns the earlier QSOs with the callsign in the form, newest first.
+    pub fn worked_before(&self) -> &[WorkedEntry] {
+        self.worked_before
+            .as_ref()
+            .map_or(&[], |(_, entries)| entries.as_slice())
+    }
+
+    /// Fills the worked-before panel with the earlier QSOs found for `call`.
+    ///
+    /// In edit mode the QSO being edited is left out, so the panel only shows
+    /// other contacts.
+    pub fn set_worked_before(&mut self, call: String, mut entries: Vec<WorkedEntry>) {
+        if let Some(target) = &self.editing {
+            entries.retain(|e| !(e.log_id == self.log_id && e.qso.timestamp == target.timestamp));
+        }
+        self.worked_before = Some((call, entries));
+    }
+
+    /// Empties the worked-before panel.
+    pub fn clear_worked_before(&mut self) {
+        self.worked_before = None;
+    }
+
     /// Populates recent QSOs from a log (newest first) and rebuilds the form for the log type.
     ///
     /// Stores up to 20 QSOs; `draw_recent_qsos` limits display to what fits in the
     /// available `Rect` height so the row count adapts to the terminal size.
     pub fn set_log_context(&mut self, log: &Log) {
         self.recent_qsos = log.header().qsos.iter().rev().take(20).cloned().collect();
+        if log.header().log_id != self.log_id {
+            self.log_id = log.header().log_id.clone();
+            self.last_backfill = None;
+            self.out_of_order_ack = None;
+            // The operator is sticky: resume with whoever logged the latest QSO.
+            self.operator = log.header().qsos.last().and_then(|q| q.operator.clone());
+            self.operator_prompt = None;
+        }
         let new_type = match log {
             Log::General(_) => QsoFormType::General,
             Log::Pota(_) => QsoFormType::Pota,
@@ -275,6 +482,7 @@ impl QsoEntryState {
             self.form_type = new_type;
             self.form = Self::build_form_for_type(new_type, self.mode);
         }
+        self.sync_timestamp_fields();
     }
 
     /// Adds a QSO to the recent list (newest first), capped at 20.
@@ -293,6 +501,69 @@ impl QsoEntryState {
     /// Clears edit mode without resetting the rest of the form.
     pub fn clear_editing(&mut self) {
         self.editing = None;
+        self.worked_before = None;
+        self.sync_timestamp_fields();
+    }
+
+    /// Returns `true` if backfill mode is on for the active log.
+    pub fn is_backfill(&self) -> bool {
+        self.backfill_logs.contains(&self.log_id)
+    }
+
+    /// Switches backfill mode on or off for the active log.
+    ///
+    /// Backfill mode adds Date and Time fields so QSOs from a paper log can be
+    /// entered with their real timestamps instead of the current time.
+    pub fn toggle_backfill(&mut self) {
+        if !self.backfill_logs.remove(&self.log_id) {
+            self.backfill_logs.insert(self.log_id.clone());
+        }
+        self.last_backfill = None;
+        self.out_of_order_ack = None;
+        self.sync_timestamp_fields();
+    }
+
+    /// Returns `true` if the form currently contains the Date and Time fields.
+    fn has_timestamp_fields(&self) -> bool {
+        self.form.fields().len() > self.form_type.date_idx()
+    }
+
+    /// Adds or removes the Date and Time fields to match backfill/edit mode.
+    ///
+    /// Newly added fields are pre-filled with the date of the most recent QSO
+    /// (or today) so only the time needs typing.
+    fn sync_timestamp_fields(&mut self) {
+        let wanted = self.is_backfill() || self.is_editing();
+        match (wanted, self.has_timestamp_fields()) {
+            (true, false) => {
+                self.form
+                    .push_field(Box::new(FormField::new("Date (YYYY-MM-DD)", true)));
+                self.form
+                    .push_field(Box::new(FormField::new("Time (HHMM UTC)", true)));
+                let date = self
+                    .recent_qsos
+                    .first()
+                    .map_or_else(Utc::now, |qso| qso.timestamp);
+                self.form.set_value(
+                    self.form_type.date_idx(),
+                    date.format("%Y-%m-%d").to_string(),
+                );
+            }
+            (false, true) => self.form.truncate(self.form_type.date_idx()),
+            _ => {}
+        }
+    }
+
+    /// Fills the Date and Time fields from `timestamp`.
+    fn set_timestamp_fields(&mut self, timestamp: DateTime<Utc>) {
+        self.form.set_value(
+            self.form_type.date_idx(),
+            timestamp.format("%Y-%m-%d").to_string(),
+        );
+        self.form.set_value(
+            self.form_type.time_idx(),
+            timestamp.format("%H%M").to_string(),
+        );
     }
 
     /// Enters edit mode: populates the form from an existing QSO.
@@ -304,10 +575,7 @@ impl QsoEntryState {
                 self.form.set_value(RST_RCVD, &qso.rst_rcvd);
                 self.form.set_value(
                     GENERAL_FREQUENCY,
-                    qso.frequency
-                        .map(|f| f.to_string())
-                        .unwrap_or_default()
-                        .as_str(),
+                    qso.frequency.map(format_khz).unwrap_or_default().as_str(),
                 );
             }
             QsoFormType::Pota => {
@@ -317,10 +585,7 @@ impl QsoEntryState {
                     .set_value(3, qso.their_park.as_deref().unwrap_or(""));
                 self.form.set_value(
                     POTA_FREQUENCY,
-                    qso.frequency
-                        .map(|f| f.to_string())
-                        .unwrap_or_default()
-                        .as_str(),
+                    qso.frequency.map(format_khz).unwrap_or_default().as_str(),
                 );
             }
             QsoFormType::FieldDay | QsoFormType::WinterFieldDay => {
@@ -335,10 +600,7 @@ impl QsoEntryState {
                 }
                 self.form.set_value(
                     CONTEST_FREQUENCY,
-                    qso.frequency
-                        .map(|f| f.to_string())
-                        .unwrap_or_default()
-                        .as_str(),
+                    qso.frequency.map(format_khz).unwrap_or_default().as_str(),
                 );
             }
         }
@@ -349,13 +611,25 @@ impl QsoEntryState {
         self.form.clear_errors();
         self.error = None;
         self.form.set_focus(THEIR_CALL);
-        self.editing = Some((index, qso.timestamp));
+        self.editing = Some(EditTarget {
+            index,
+            timestamp: qso.timestamp,
+            operator: qso.operator.clone(),
+            id: qso.id.clone(),
+            extra_fields: qso.extra_fields.clone(),
+        });
+        self.worked_before = None;
+        self.sync_timestamp_fields();
+        self.set_timestamp_fields(qso.timestamp);
     }
 
     /// Clears fast-moving fields and repopulates RST defaults for the current mode.
     ///
     /// For General/POTA: resets Their Callsign, RST fields, type-specific field, and Comments.
     /// For FD/WFD: resets Their Callsign, Their Class, Their Section, Frequency, and Comments.
+    ///
+    /// In backfill mode the date is kept and the time advances one minute past
+    /// the QSO just logged.
     pub fn clear_fast_fields(&mut self) {
         self.form.clear_value(THEIR_CALL);
         if self.form_type.has_rst() {
@@ -380,6 +654,14 @@ impl QsoEntryState {
         self.form.clear_errors();
         self.error = None;
         self.editing = None;
+        self.out_of_order_ack = None;
+        self.worked_before = None;
+        self.sync_timestamp_fields();
+        if self.is_backfill()
+            && let Some(last) = self.last_backfill
+        {
+            self.set_timestamp_fields(last + Duration::minutes(1));
+        }
         self.form.set_focus(THEIR_CALL);
     }
 
@@ -406,29 +688,43 @@ impl QsoEntryState {
         Action::None
     }
 
-    /// Cycles the band forward or backward, wrapping around.
+    /// Cycles the band through the configured cycle list, wrapping around.
+    ///
+    /// The current band may lie outside the list (set from a frequency or an
+    /// edited QSO); in that case the nearest listed band in that direction is chosen.
     fn cycle_band(&mut self, forward: bool) {
-        self.band = cycle(Band::all(), self.band, forward);
+        self.band = if self.band_cycle.contains(&self.band) {
+            cycle(&self.band_cycle, self.band, forward)
+        } else {
+            nearest_band(&self.band_cycle, self.band, forward)
+        };
     }
 
     /// If the frequency field contains a parseable kHz value that maps to a known band,
     /// auto-selects that band. Called when leaving the frequency field.
     fn try_auto_set_band_from_frequency(&mut self) {
         let idx = self.form_type.frequency_field_idx();
-        if let Ok(freq) = self.form.value(idx).parse::<u32>()
-            && let Some(band) = Band::from_frequency_khz(freq)
+        if let Some(freq) = parse_khz(self.form.value(idx))
+            && let Some(band) = Band::from_frequency_hz(freq)
         {
             self.band = band;
         }
     }
 
-    /// Cycles the mode forward or backward, wrapping around.
+    /// Cycles the mode through the favourites list, wrapping around.
+    ///
+    /// If the current mode is not a favourite (e.g. loaded from an edited QSO),
+    /// forward jumps to the first favourite and backward to the last.
     ///
     /// When the mode changes, RST fields are updated to the new mode's default
     /// only if they still contain the previous mode's default.
     /// FD/WFD forms have no RST fields, so RST updates are skipped for those.
     fn cycle_mode(&mut self, forward: bool) {
-        self.mode = cycle(Mode::all(), self.mode, forward);
+        self.mode = match (self.mode_cycle.contains(&self.mode), forward) {
+            (true, _) => cycle(&self.mode_cycle, self.mode, forward),
+            (false, true) => self.mode_cycle[0],
+            (false, false) => self.mode_cycle[self.mode_cycle.len() - 1],
+        };
         let new_rst = self.mode.default_rst();
 
         if self.form_type.has_rst() {
@@ -437,6 +733,39 @@ impl QsoEntryState {
         }
     }
 
+    /// Parses the Date and Time fields, if present, setting field errors on failure.
+    ///
+    /// Returns `None` when the fields are absent or invalid. Rejects timestamps in
+    /// the future and dates before 1930 (the earliest ADIF `QSO_DATE`).
+    fn parse_timestamp_fields(&mut self) -> Option<DateTime<Utc>> {
+        if !self.has_timestamp_fields() {
+            return None;
+        }
+        let (date_idx, time_idx) = (self.form_type.date_idx(), self.form_type.time_idx());
+        let date = parse_date(self.form.value(date_idx));
+        let time = parse_time(self.form.value(time_idx));
+        if date.is_none() {
+            self.form
+                .set_error(date_idx, "date must be YYYY-MM-DD".into());
+        }
+        if time.is_none() {
+            self.form
+                .set_error(time_idx, "time must be HHMM (UTC)".into());
+        }
+        let timestamp = date?.and_time(time?).and_utc();
+        if timestamp.year() < 1930 {
+            self.form
+                .set_error(date_idx, "date must be 1930 or later".into());
+            return None;
+        }
+        if timestamp > Utc::now() {
+            self.form
+                .set_error(time_idx, "time is in the future".into());
+            return None;
+        }
+        Some(timestamp)
+    }
+
     /// Validates the form and constructs a QSO.
     fn submit(&mut self) -> Action {
         self.form.clear_errors();
@@ -454,7 +783,7 @@ impl QsoEntryState {
         let rst_rcvd: String;
         let mut their_park: Option<String> = None;
         let mut exchange_rcvd: Option<String> = None;
-        let mut frequency: Option<u32> = None;
+        let mut frequency: Option<u64> = None;
 
         match self.form_type {
             QsoFormType::General => {
@@ -469,14 +798,14 @@ impl QsoEntryState {
                 }
                 let freq_str = self.form.value(GENERAL_FREQUENCY).to_string();
                 if !freq_str.is_empty() {
-                    match freq_str.parse::<u32>() {
-                        Ok(f) if f > 0 => {
+                    match parse_khz(&freq_str) {
+                        Some(f) => {
                             self.try_auto_set_band_from_frequency();
                             frequency = Some(f);
                         }
                         _ => self.form.set_error(
                             GENERAL_FREQUENCY,
-                            "frequency must be a positive integer (kHz)".into(),
+                            "frequency must be a positive number (kHz)".into(),
                         ),
                     }
                 }
@@ -504,14 +833,14 @@ impl QsoEntryState {
                 }
                 let freq_str = self.form.value(POTA_FREQUENCY).to_string();
                 if !freq_str.is_empty() {
-                    match freq_str.parse::<u32>() {
-                        Ok(f) if f > 0 => {
+                    match parse_khz(&freq_str) {
+                        Some(f) => {
                             self.try_auto_set_band_from_frequency();
                             frequency = Some(f);
                         }
                         _ => self.form.set_error(
                             POTA_FREQUENCY,
-                            "frequency must be a positive integer (kHz)".into(),
+                            "frequency must be a positive number (kHz)".into(),
                         ),
                     }
                 }
@@ -537,15 +866,15 @@ impl QsoEntryState {
                     }
                 }
                 let freq_str = self.form.value(CONTEST_FREQUENCY).to_string();
-                match freq_str.parse::<u32>() {
-                    Ok(f) if f > 0 => {
+                match parse_khz(&freq_str) {
+                    Some(f) => {
                         // Auto-select band; no-op if frequency is between amateur allocations
                         self.try_auto_set_band_from_frequency();
                         frequency = Some(f);
                     }
                     _ => self.form.set_error(
                         CONTEST_FREQUENCY,
-                        "frequency must be a positive integer (kHz)".into(),
+                        "frequency must be a positive number (kHz)".into(),
                     ),
                 }
             }
@@ -570,25 +899,52 @@ impl QsoEntryState {
                     }
                 }
                 let freq_str = self.form.value(CONTEST_FREQUENCY).to_string();
-                match freq_str.parse::<u32>() {
-                    Ok(f) if f > 0 => {
+                match parse_khz(&freq_str) {
+                    Some(f) => {
                         // Auto-select band; no-op if frequency is between amateur allocations
                         self.try_auto_set_band_from_frequency();
                         frequency = Some(f);
                     }
                     _ => self.form.set_error(
                         CONTEST_FREQUENCY,
-                        "frequency must be a positive integer (kHz)".into(),
+                        "frequency must be a positive number (kHz)".into(),
                     ),
                 }
             }
         }
 
+        let entered_timestamp = self.parse_timestamp_fields();
+
         if self.form.has_errors() {
             return Action::None;
         }
 
-        let timestamp = self.editing.map(|(_, ts)| ts).unwrap_or_else(Utc::now);
+        // An edited QSO whose Date/Time fields are unchanged keeps its original
+        // timestamp, including the seconds the HHMM field cannot show.
+        let original = self.editing.as_ref().map(|target| target.timestamp);
+        let timestamp = match (entered_timestamp, original) {
+            (Some(ts), Some(original)) if (ts..ts + Duration::minutes(1)).contains(&original) => {
+                original
+            }
+            (Some(ts), _) => ts,
+            (None, Some(ts)) => ts,
+            (None, None) => Utc::now(),
+        };
+
+        if self.editing.is_none()
+            && entered_timestamp.is_some()
+            && let Some(prev) = self.last_backfill
+            && timestamp < prev
+            && self.out_of_order_ack != Some(timestamp)
+        {
+            self.out_of_order_ack = Some(timestamp);
+            self.error = Some(format!(
+                "{} is before the previous QSO ({}) — press Enter again to log it anyway",
+                timestamp.format("%Y-%m-%d %H%M"),
+                prev.format("%Y-%m-%d %H%M"),
+            ));
+            return Action::None;
+        }
 
         match Qso::new(
             their_call,
@@ -601,10 +957,20 @@ impl QsoEntryState {
             their_park,
             exchange_rcvd,
             frequency,
+            self.operator().map(str::to_string),
         ) {
-            Ok(qso) => match self.editing {
-                Some((idx, _)) => Action::UpdateQso(idx, qso),
-                None => Action::AddQso(qso),
+            Ok(mut qso) => match &self.editing {
+                Some(target) => {
+                    qso.id = target.id.clone();
+                    qso.extra_fields = target.extra_fields.clone();
+                    Action::UpdateQso(target.index, qso)
+                }
+                None => {
+                    if entered_timestamp.is_some() {
+                        self.last_backfill = Some(timestamp);
+                    }
+                    Action::AddQso(qso)
+                }
             },
             Err(e) => {
                 self.form.set_error(THEIR_CALL, e.to_string());
@@ -614,6 +980,24 @@ impl QsoEntryState {
     }
 }
 
+/// Parses a date typed as `YYYY-MM-DD` or `YYYYMMDD`.
+fn parse_date(s: &str) -> Option<NaiveDate> {
+    let s = s.trim();
+    NaiveDate::parse_from_str(s, "%Y-%m-%d")
+        .or_else(|_| NaiveDate::parse_from_str(s, "%Y%m%d"))
+        .ok()
+}
+
+/// Parses a UTC time typed as `HHMM` or `HH:MM`.
+fn parse_time(s: &str) -> Option<NaiveTime> {
+    let s = s.trim();
+    if s.len() == 4 && s.bytes().all(|b| b.is_ascii_digit()) {
+        NaiveTime::parse_from_str(s, "%H%M").ok()
+    } else {
+        NaiveTime::parse_from_str(s, "%H:%M").ok()
+    }
+}
+
 /// Cycles through a slice to find the next or previous element.
 ///
 /// # Panics
@@ -632,18 +1016,59 @@ fn cycle<T: PartialEq + Copy>(items: &[T], current: T, forward: bool) -> T {
     items[next]
 }
 
+/// Returns the band in `bands` adjacent to `current` in wavelength order.
+///
+/// Steps to the next shorter (forward) or longer (backward) wavelength listed
+/// in `bands`, wrapping to the opposite end when none remains.
+///
+/// # Panics
+///
+/// Panics if `bands` is empty.
+fn nearest_band(bands: &[Band], current: Band, forward: bool) -> Band {
+    let rank = |band: &Band| Band::all().iter().position(|b| b == band);
+    let here = rank(&current);
+    let (ahead, wrap) = if forward {
+        (
+            bands
+                .iter()
+                .filter(|b| rank(b) > here)
+                .min_by_key(|b| rank(b)),
+            bands.iter().min_by_key(|b| rank(b)),
+        )
+    } else {
+        (
+            bands
+                .iter()
+                .filter(|b| rank(b) < here)
+                .max_by_key(|b| rank(b)),
+            bands.iter().max_by_key(|b| rank(b)),
+        )
+    };
+    *ahead.or(wrap).expect("band cycle must not be empty")
+}
+
 /// Renders the QSO entry screen.
 #[mutants::skip]
-pub fn draw_qso_entry(state: &QsoEntryState, log: Option<&Log>, frame: &mut Frame, area: Rect) {
+pub fn draw_qso_entry(
+    state: &QsoEntryState,
+    log: Option<&Log>,
+    timers: &[TimerReading],
+    theme: &Theme,
+    frame: &mut Frame,
+    area: Rect,
+) {
     let title = if state.is_editing() {
         " Edit QSO "
+    } else if state.is_backfill() {
+        " QSO Entry (backfill) "
     } else {
         " QSO Entry "
     };
+    let form_height = if state.has_timestamp_fields() { 9 } else { 6 };
     let block = Block::default()
         .title(title)
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::Cyan));
+        .border_style(theme.accent);
 
     let inner = block.inner(area);
     frame.render_widget(block, area);
@@ -657,34 +1082,50 @@ pub fn draw_qso_entry(state: &QsoEntryState, log: Option<&Log>, frame: &mut Fram
     ] = Layout::vertical([
         Constraint::Length(1),
         Constraint::Length(2),
-        Constraint::Length(6),
+        Constraint::Length(form_height),
         Constraint::Min(3),
         Constraint::Length(1),
     ])
     .areas(inner);
 
-    let ctx = log.map(StatusBarContext::from_log).unwrap_or_default();
-    draw_status_bar(&ctx, frame, status_area);
+    let ctx = log
+        .map(|log| StatusBarContext::from_log(log).with_timers(timers))
+        .unwrap_or_default();
+    draw_status_bar(&ctx, theme, frame, status_area);
 
-    draw_header(state, log, frame, header_area);
+    draw_header(state, log, theme, frame, header_area);
 
     // Form fields
-    draw_qso_entry_form(state, frame, form_area);
-
-    // Error message
-    if let Some(err) = state.error() {
-        let err_paragraph = Paragraph::new(Span::styled(err, Style::default().fg(Color::Red)));
-        // Render at bottom of form area
-        let err_area = Rect {
-            x: form_area.x,
-            y: form_area.y + form_area.height.saturating_sub(1),
-            width: form_area.width,
-            height: 1,
-        };
-        frame.render_widget(err_paragraph, err_area);
+    draw_qso_entry_form(state, theme, frame, form_area);
+
+    // Operator prompt or error message, at the bottom of the form area
+    let msg_area = Rect {
+        x: form_area.x,
+        y: form_area.y + form_area.height.saturating_sub(1),
+        width: form_area.width,
+        height: 1,
+    };
+    if let Some(call) = state.operator_prompt() {
+        let mut spans = vec![
+            Span::styled(format!("Operator: {call}_"), theme.focus),
+            Span::styled(
+                "  Enter: switch (blank = log operator)  Esc: cancel",
+                theme.dim,
+            ),
+        ];
+        if let Some(err) = state.error() {
+            spans.push(Span::styled(format!("  {err}"), theme.error));
+        }
+        frame.render_widget(Paragraph::new(Line::from(spans)), msg_area);
+    } else if let Some(err) = state.error() {
+        frame.render_widget(Paragraph::new(Span::styled(err, theme.error)), msg_area);
     }
 
-    draw_recent_qsos(state, frame, recent_area);
+    if state.worked_before().is_empty() {
+        draw_recent_qsos(state, theme, frame, recent_area);
+    } else {
+        draw_worked_before(state, theme, frame, recent_area);
+    }
 
     // Footer
     let footer_text = if state.is_editing() {
@@ -692,8 +1133,7 @@ pub fn draw_qso_entry(state: &QsoEntryState, log: Option<&Log>, frame: &mut Fram
     } else {
         "Tab/Shift+Tab: next/prev  Alt+b/m: band/mode (Shift: reverse)  Alt+e: edit  Alt+x: export  Enter: log  Esc: back"
     };
-    let footer =
-        Paragraph::new(Line::from(footer_text)).style(Style::default().fg(Color::DarkGray));
+    let footer = Paragraph::new(Line::from(footer_text)).style(theme.dim);
     frame.render_widget(footer, footer_area);
 }
 
@@ -707,22 +1147,29 @@ pub fn draw_qso_entry(state: &QsoEntryState, log: Option<&Log>, frame: &mut Fram
 ///   - General:        Frequency (3)  | Comments (4) — two halves
 ///   - POTA:           Their Park (3) | Frequency (4) | Comments (5) — three thirds
 ///   - FD / WFD:       Frequency (3)  | Comments (4) — two halves
+///
+/// Row 3 (backfill and edit mode only): Date | Time — two halves
 #[mutants::skip]
-fn draw_qso_entry_form(state: &QsoEntryState, frame: &mut Frame, area: Rect) {
+fn draw_qso_entry_form(state: &QsoEntryState, theme: &Theme, frame: &mut Frame, area: Rect) {
     use ratatui::layout::Constraint::Ratio;
     let form = state.form();
     let form_type = state.form_type;
 
-    // Split into two rows of 3 lines each
-    let [row1_area, row2_area] =
-        Layout::vertical([Constraint::Length(3), Constraint::Length(3)]).areas(area);
+    // Split into rows of 3 lines each; the third row is empty unless the
+    // timestamp fields are shown
+    let [row1_area, row2_area, row3_area] = Layout::vertical([
+        Constraint::Length(3),
+        Constraint::Length(3),
+        Constraint::Min(0),
+    ])
+    .areas(area);
 
     // Row 1: always three equal columns (indices 0, 1, 2)
     let [col0, col1, col2] =
         Layout::horizontal([Ratio(1, 3), Ratio(1, 3), Ratio(1, 3)]).areas(row1_area);
-    draw_form_field(form, 0, frame, col0);
-    draw_form_field(form, 1, frame, col1);
-    draw_form_field(form, 2, frame, col2);
+    draw_form_field(form, 0, theme, frame, col0);
+    draw_form_field(form, 1, theme, frame, col1);
+    draw_form_field(form, 2, theme, frame, col2);
 
     // Row 2: layout depends on form type
     match form_type {
@@ -730,23 +1177,37 @@ fn draw_qso_entry_form(state: &QsoEntryState, frame: &mut Frame, area: Rect) {
             // Frequency on left half, Comments on right half
             let [freq_area, comments_area] =
                 Layout::horizontal([Ratio(1, 2), Ratio(1, 2)]).areas(row2_area);
-            draw_form_field(form, 3, frame, freq_area);
-            draw_form_field(form, 4, frame, comments_area);
+            draw_form_field(form, 3, theme, frame, freq_area);
+            draw_form_field(form, 4, theme, frame, comments_area);
         }
         QsoFormType::Pota => {
             // Their Park | Frequency | Comments — three equal columns
             let [park_area, freq_area, comments_area] =
                 Layout::horizontal([Ratio(1, 3), Ratio(1, 3), Ratio(1, 3)]).areas(row2_area);
-            draw_form_field(form, 3, frame, park_area);
-            draw_form_field(form, POTA_FREQUENCY, frame, freq_area);
-            draw_form_field(form, 5, frame, comments_area);
+            draw_form_field(form, 3, theme, frame, park_area);
+            draw_form_field(form, POTA_FREQUENCY, theme, frame, freq_area);
+            draw_form_field(form, 5, theme, frame, comments_area);
         }
     }
+
+    // Row 3: Date | Time, only present in backfill and edit mode
+    if state.has_timestamp_fields() {
+        let [date_area, time_area] =
+            Layout::horizontal([Ratio(1, 2), Ratio(1, 2)]).areas(row3_area);
+        draw_form_field(form, form_type.date_idx(), theme, frame, date_area);
+        draw_form_field(form, form_type.time_idx(), theme, frame, time_area);
+    }
 }
 
 /// Renders the station info, band/mode, and activation progress header.
 #[mutants::skip]
-fn draw_header(state: &QsoEntryState, log: Option<&Log>, frame: &mut Frame, area: Rect) {
+fn draw_header(
+    state: &QsoEntryState,
+    log: Option<&Log>,
+    theme: &Theme,
+    frame: &mut Frame,
+    area: Rect,
+) {
     if let Some(log) = log {
         let callsign = &log.header().station_callsign;
         let park = log.park_ref().unwrap_or("-");
@@ -754,21 +1215,19 @@ fn draw_header(state: &QsoEntryState, log: Option<&Log>, frame: &mut Frame, area
         let today = log.qso_count_today();
         let needed = log.needs_for_activation();
 
+        let operator = state
+            .operator()
+            .or(log.header().operator.as_deref())
+            .unwrap_or(callsign);
+
         let header_line1 = Line::from(vec![
-            Span::styled(
-                format!("{callsign} @ {park} ({grid})"),
-                Style::default().fg(Color::White),
-            ),
+            Span::styled(format!("{callsign} @ {park} ({grid})"), theme.text),
             Span::raw("    "),
-            Span::styled(
-                format!("Band: {}", state.band()),
-                Style::default().fg(Color::Yellow),
-            ),
+            Span::styled(format!("Band: {}", state.band()), theme.focus),
             Span::raw("  "),
-            Span::styled(
-                format!("Mode: {}", state.mode()),
-                Style::default().fg(Color::Yellow),
-            ),
+            Span::styled(format!("Mode: {}", state.mode()), theme.focus),
+            Span::raw("  "),
+            Span::styled(format!("Op: {operator}"), theme.focus),
         ]);
 
         let activation_info = if needed > 0 {
@@ -776,10 +1235,7 @@ fn draw_header(state: &QsoEntryState, log: Option<&Log>, frame: &mut Frame, area
         } else {
             format!("QSOs today: {today} / 10  [Activated!]")
         };
-        let header_line2 = Line::from(Span::styled(
-            activation_info,
-            Style::default().fg(Color::DarkGray),
-        ));
+        let header_line2 = Line::from(Span::styled(activation_info, theme.dim));
 
         frame.render_widget(Paragraph::new(vec![header_line1, header_line2]), area);
     }
@@ -794,7 +1250,7 @@ fn format_rst(qso: &Qso) -> String {
 }
 
 fn format_frequency(qso: &Qso) -> String {
-    qso.frequency.map(|f| f.to_string()).unwrap_or_default()
+    qso.frequency.map(format_khz).unwrap_or_default()
 }
 
 fn recent_qso_row_general(qso: &Qso) -> Row<'static> {
@@ -803,7 +1259,7 @@ fn recent_qso_row_general(qso: &Qso) -> Row<'static> {
         format_timestamp(qso),
         qso.their_call.clone(),
         qso.band.to_string(),
-        qso.mode.to_string(),
+        qso.mode_name().to_string(),
         format_rst(qso),
         format_frequency(qso),
     ])
@@ -816,7 +1272,7 @@ fn recent_qso_row_pota(qso: &Qso) -> Row<'static> {
         format_timestamp(qso),
         qso.their_call.clone(),
         qso.band.to_string(),
-        qso.mode.to_string(),
+        qso.mode_name().to_string(),
         format_rst(qso),
         qso.their_park.clone().unwrap_or_default(),
         format_frequency(qso),
@@ -829,7 +1285,7 @@ fn recent_qso_row_contest(qso: &Qso) -> Row<'static> {
         format_timestamp(qso),
         qso.their_call.clone(),
         qso.band.to_string(),
-        qso.mode.to_string(),
+        qso.mode_name().to_string(),
         qso.exchange_rcvd.clone().unwrap_or_default(),
         format_frequency(qso),
     ])
@@ -854,11 +1310,11 @@ fn build_recent_rows<F: Fn(&Qso) -> Row<'static>>(
 /// Column sets are fully branched on log type; park and frequency are always
 /// separate columns so there is never any ambiguity about which value is shown.
 #[mutants::skip]
-fn draw_recent_qsos(state: &QsoEntryState, frame: &mut Frame, area: Rect) {
+fn draw_recent_qsos(state: &QsoEntryState, theme: &Theme, frame: &mut Frame, area: Rect) {
     let recent_block = Block::default()
         .title(" Recent QSOs ")
         .borders(Borders::TOP)
-        .border_style(Style::default().fg(Color::DarkGray));
+        .border_style(theme.dim);
 
     let recent_inner = recent_block.inner(area);
     frame.render_widget(recent_block, area);
@@ -926,6 +1382,53 @@ fn draw_recent_qsos(state: &QsoEntryState, frame: &mut Frame, area: Rect) {
     }
 }
 
+/// Renders earlier QSOs with the callsign being entered, from every log, in
+/// place of the recent QSOs.
+///
+/// Columns: Date | Time | Log | Band | Mode | Park or exchange received | Comments.
+#[mutants::skip]
+fn draw_worked_before(state: &QsoEntryState, theme: &Theme, frame: &mut Frame, area: Rect) {
+    let entries = state.worked_before();
+    let call = state.worked_before_call().unwrap_or_default();
+    let logs: HashSet<&str> = entries.iter().map(|e| e.log_id.as_str()).collect();
+    let block = Block::default()
+        .title(format!(
+            " Worked Before: {call} ({} QSOs in {} logs) ",
+            entries.len(),
+            logs.len()
+        ))
+        .borders(Borders::TOP)
+        .border_style(theme.warning);
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let rows = entries.iter().take(inner.height as usize).map(|e| {
+        let qso = &e.qso;
+        Row::new(vec![
+            qso.timestamp.format("%Y-%m-%d").to_string(),
+            format_timestamp(qso),
+            e.log_label.clone(),
+            qso.band.to_string(),
+            qso.mode_name().to_string(),
+            qso.their_park
+                .clone()
+                .or_else(|| qso.exchange_rcvd.clone())
+                .unwrap_or_default(),
+            qso.comments.clone(),
+        ])
+    });
+    let widths = [
+        Constraint::Length(10),
+        Constraint::Length(5),
+        Constraint::Length(10),
+        Constraint::Length(5),
+        Constraint::Length(6),
+        Constraint::Length(10),
+        Constraint::Min(10),
+    ];
+    frame.render_widget(Table::new(rows, widths).style(theme.text), inner);
+}
+
 #[cfg(test)]
 mod tests {
     use chrono::{TimeZone, Utc};
@@ -961,15 +1464,6 @@ mod tests {
         }
     }
 
-    fn shift_alt_press(code: KeyCode) -> KeyEvent {
-        KeyEvent {
-            code,
-            modifiers: KeyModifiers::ALT | KeyModifiers::SHIFT,
-            kind: KeyEventKind::Press,
-            state: KeyEventState::NONE,
-        }
-    }
-
     fn type_string(state: &mut QsoEntryState, s: &str) {
         for ch in s.chars() {
             state.handle_key(press(KeyCode::Char(ch)));
@@ -988,6 +1482,7 @@ mod tests {
             None,
             None,
             None,
+            None,
         )
         .unwrap()
     }
@@ -1265,26 +1760,26 @@ mod tests {
         use super::*;
 
         #[test]
-        fn alt_b_cycles_forward() {
+        fn next_band_cycles_forward() {
             let mut state = QsoEntryState::new();
             assert_eq!(state.band(), Band::M20);
-            state.handle_key(alt_press(KeyCode::Char('b')));
+            state.handle_command(Command::NextBand);
             assert_eq!(state.band(), Band::M17);
         }
 
         #[test]
-        fn shift_alt_b_cycles_backward() {
+        fn prev_band_cycles_backward() {
             let mut state = QsoEntryState::new();
             assert_eq!(state.band(), Band::M20);
-            state.handle_key(shift_alt_press(KeyCode::Char('B')));
+            state.handle_command(Command::PrevBand);
             assert_eq!(state.band(), Band::M30);
         }
 
         #[test]
         fn wraps_forward() {
             let mut state = QsoEntryState::new();
-            for _ in 0..Band::all().len() {
-                state.handle_key(alt_press(KeyCode::Char('b')));
+            for _ in 0..Band::default_cycle().len() {
+                state.handle_command(Command::NextBand);
             }
             assert_eq!(state.band(), Band::M20);
         }
@@ -1292,12 +1787,65 @@ mod tests {
         #[test]
         fn wraps_backward() {
             let mut state = QsoEntryState::new();
-            for _ in 0..Band::all().len() {
-                state.handle_key(shift_alt_press(KeyCode::Char('B')));
+            for _ in 0..Band::default_cycle().len() {
+                state.handle_command(Command::PrevBand);
             }
             assert_eq!(state.band(), Band::M20);
         }
 
+        #[test]
+        fn default_cycle_skips_bands_outside_the_list() {
+            let mut state = QsoEntryState::new();
+            state.band = Band::M2;
+            state.handle_command(Command::NextBand);
+            assert_eq!(
+                state.band(),
+                Band::Cm70,
+                "1.25m is not in the default cycle"
+            );
+        }
+
+        #[test]
+        fn configured_cycle_is_used() {
+            let mut state = QsoEntryState::new();
+            state.set_band_cycle(vec![Band::M6, Band::M2, Band::M1_25, Band::Cm70]);
+            state.band = Band::M6;
+            state.handle_command(Command::NextBand);
+            assert_eq!(state.band(), Band::M2);
+            state.handle_command(Command::NextBand);
+            assert_eq!(state.band(), Band::M1_25);
+            state.handle_command(Command::PrevBand);
+            assert_eq!(state.band(), Band::M2);
+        }
+
+        #[test]
+        fn empty_configured_cycle_restores_default() {
+            let mut state = QsoEntryState::new();
+            state.set_band_cycle(Vec::new());
+            assert_eq!(state.band_cycle(), Band::default_cycle());
+        }
+
+        #[test]
+        fn band_outside_cycle_steps_to_nearest_forward() {
+            // 20M (default) is not in the VHF list; forward goes to the next shorter band
+            let mut state = QsoEntryState::new();
+            state.set_band_cycle(vec![Band::M6, Band::M2, Band::Cm70]);
+            state.handle_command(Command::NextBand);
+            assert_eq!(state.band(), Band::M6);
+        }
+
+        #[test]
+        fn band_outside_cycle_steps_to_nearest_backward_with_wrap() {
+            let mut state = QsoEntryState::new();
+            state.set_band_cycle(vec![Band::M6, Band::M2, Band::Cm70]);
+            state.handle_command(Command::PrevBand);
+            assert_eq!(
+                state.band(),
+                Band::Cm70,
+                "nothing longer than 20M; wraps to the end"
+            );
+        }
+
         #[test]
         fn b_types_in_callsign() {
             let mut state = QsoEntryState::new();
@@ -1318,26 +1866,26 @@ mod tests {
         use super::*;
 
         #[test]
-        fn alt_m_cycles_forward() {
+        fn next_mode_cycles_forward() {
             let mut state = QsoEntryState::new();
             assert_eq!(state.mode(), Mode::Ssb);
-            state.handle_key(alt_press(KeyCode::Char('m')));
+            state.handle_command(Command::NextMode);
             assert_eq!(state.mode(), Mode::Cw);
         }
 
         #[test]
-        fn shift_alt_m_cycles_backward() {
+        fn prev_mode_cycles_backward() {
             let mut state = QsoEntryState::new();
             assert_eq!(state.mode(), Mode::Ssb);
-            state.handle_key(shift_alt_press(KeyCode::Char('M')));
+            state.handle_command(Command::PrevMode);
             assert_eq!(state.mode(), Mode::Digi);
         }
 
         #[test]
         fn wraps_forward() {
             let mut state = QsoEntryState::new();
-            for _ in 0..Mode::all().len() {
-                state.handle_key(alt_press(KeyCode::Char('m')));
+            for _ in 0..Mode::default_cycle().len() {
+                state.handle_command(Command::NextMode);
             }
             assert_eq!(state.mode(), Mode::Ssb);
         }
@@ -1345,12 +1893,45 @@ mod tests {
         #[test]
         fn wraps_backward() {
             let mut state = QsoEntryState::new();
-            for _ in 0..Mode::all().len() {
-                state.handle_key(shift_alt_press(KeyCode::Char('M')));
+            for _ in 0..Mode::default_cycle().len() {
+                state.handle_command(Command::PrevMode);
             }
             assert_eq!(state.mode(), Mode::Ssb);
         }
 
+        #[test]
+        fn configured_favourites_are_used() {
+            let mut state = QsoEntryState::new();
+            state.set_mode_cycle(vec![Mode::Usb, Mode::Cw, Mode::Ft4]);
+            state.handle_command(Command::NextMode);
+            assert_eq!(
+                state.mode(),
+                Mode::Usb,
+                "SSB is not a favourite; jumps to first"
+            );
+            state.handle_command(Command::NextMode);
+            assert_eq!(state.mode(), Mode::Cw);
+            state.handle_command(Command::PrevMode);
+            assert_eq!(state.mode(), Mode::Usb);
+            state.handle_command(Command::PrevMode);
+            assert_eq!(state.mode(), Mode::Ft4);
+        }
+
+        #[test]
+        fn mode_outside_favourites_backward_jumps_to_last() {
+            let mut state = QsoEntryState::new();
+            state.set_mode_cycle(vec![Mode::Cw, Mode::Olivia]);
+            state.handle_command(Command::PrevMode);
+            assert_eq!(state.mode(), Mode::Olivia);
+        }
+
+        #[test]
+        fn empty_favourites_restore_default() {
+            let mut state = QsoEntryState::new();
+            state.set_mode_cycle(Vec::new());
+            assert_eq!(state.mode_cycle(), Mode::default_cycle());
+        }
+
         #[test]
         fn m_types_in_callsign() {
             let mut state = QsoEntryState::new();
@@ -1383,7 +1964,7 @@ mod tests {
             assert_eq!(state.form().value(RST_RCVD), "59");
 
             // Switch to CW
-            state.handle_key(alt_press(KeyCode::Char('m')));
+            state.handle_command(Command::NextMode);
             assert_eq!(state.mode(), Mode::Cw);
             assert_eq!(state.form().value(RST_SENT), "599");
             assert_eq!(state.form().value(RST_RCVD), "599");
@@ -1399,7 +1980,7 @@ mod tests {
             type_string(&mut state, "57");
 
             // Switch to CW — RST Sent should keep "57", RST Rcvd should update
-            state.handle_key(alt_press(KeyCode::Char('m')));
+            state.handle_command(Command::NextMode);
             assert_eq!(state.form().value(RST_SENT), "57");
             assert_eq!(state.form().value(RST_RCVD), "599");
         }
@@ -1419,7 +2000,7 @@ mod tests {
             state.handle_key(press(KeyCode::Backspace));
             type_string(&mut state, "55");
 
-            state.handle_key(alt_press(KeyCode::Char('m')));
+            state.handle_command(Command::NextMode);
             assert_eq!(state.form().value(RST_SENT), "57");
             assert_eq!(state.form().value(RST_RCVD), "55");
         }
@@ -1429,7 +2010,7 @@ mod tests {
             // When a mode change updates an unedited RST field, the new default
             // should also be replaceable on first keystroke.
             let mut state = QsoEntryState::new();
-            state.handle_key(alt_press(KeyCode::Char('m'))); // SSB → CW, value "59" → "599"
+            state.handle_command(Command::NextMode); // SSB → CW, value "59" → "599"
             assert_eq!(state.form().value(RST_SENT), "599");
             // First keystroke should replace "599", not append to it
             state.handle_key(press(KeyCode::Tab)); // → RST Sent
@@ -1449,7 +2030,7 @@ mod tests {
             state.handle_key(press(KeyCode::Tab)); // → RST Sent
             state.handle_key(press(KeyCode::Char('5'))); // first char replaces default → "5"
             assert_eq!(state.form().value(RST_SENT), "5");
-            state.handle_key(alt_press(KeyCode::Char('m'))); // SSB → CW
+            state.handle_command(Command::NextMode); // SSB → CW
             assert_eq!(
                 state.form().value(RST_SENT),
                 "5",
@@ -1636,7 +2217,7 @@ mod tests {
             match action {
                 Action::AddQso(qso) => {
                     assert_eq!(qso.exchange_rcvd, Some("3A CT".to_string()));
-                    assert_eq!(qso.frequency, Some(14225));
+                    assert_eq!(qso.frequency, Some(14_225_000));
                     assert_eq!(qso.their_park, None);
                 }
                 other => panic!("expected AddQso, got {other:?}"),
@@ -1759,7 +2340,7 @@ mod tests {
             match action {
                 Action::AddQso(qso) => {
                     assert_eq!(qso.exchange_rcvd, Some("2H EPA".to_string()));
-                    assert_eq!(qso.frequency, Some(14225));
+                    assert_eq!(qso.frequency, Some(14_225_000));
                 }
                 other => panic!("expected AddQso, got {other:?}"),
             }
@@ -1837,6 +2418,30 @@ mod tests {
             assert_eq!(state.band(), Band::M80, "band should auto-select to 80M");
         }
 
+        #[test]
+        fn fractional_khz_frequency_reaches_lf_band_edges() {
+            for (freq, band, hz) in [
+                ("135.7", Band::M2190, 135_700),
+                ("137.8", Band::M2190, 137_800),
+                ("472", Band::M630, 472_000),
+                ("475.5", Band::M630, 475_500),
+            ] {
+                let mut state = QsoEntryState::new();
+                state.set_log_context(&make_general_log());
+                fill_valid_callsign(&mut state);
+                state.handle_key(press(KeyCode::Tab)); // → rst_sent
+                state.handle_key(press(KeyCode::Tab)); // → rst_rcvd
+                state.handle_key(press(KeyCode::Tab)); // → frequency
+                type_string(&mut state, freq);
+                state.handle_key(press(KeyCode::Tab)); // → comments (triggers auto-band)
+                assert_eq!(state.band(), band, "{freq}");
+                match state.handle_key(press(KeyCode::Enter)) {
+                    Action::AddQso(qso) => assert_eq!(qso.frequency, Some(hz)),
+                    other => panic!("expected AddQso, got {other:?}"),
+                }
+            }
+        }
+
         #[test]
         fn general_form_tab_with_empty_frequency_does_not_change_band() {
             // Tabbing off an empty frequency field must not change the band.
@@ -1878,7 +2483,7 @@ mod tests {
             state.handle_key(press(KeyCode::Tab)); // → comments (4): auto-band → M40
             assert_eq!(state.band(), Band::M40);
             // Manually step one band forward: M40 → M30
-            state.handle_key(alt_press(KeyCode::Char('b')));
+            state.handle_command(Command::NextBand);
             assert_eq!(state.band(), Band::M30);
             // Tab from comments (4) to callsign (0) wrapping: must NOT fire auto-band
             state.handle_key(press(KeyCode::Tab));
@@ -1904,7 +2509,7 @@ mod tests {
             state.handle_key(press(KeyCode::BackTab));
             assert_eq!(state.band(), Band::M40);
             // Manually step one band forward: M40 → M30
-            state.handle_key(alt_press(KeyCode::Char('b')));
+            state.handle_command(Command::NextBand);
             assert_eq!(state.band(), Band::M30);
             // BackTab from section (2) to class (1): must NOT fire auto-band
             state.handle_key(press(KeyCode::BackTab));
@@ -2040,8 +2645,8 @@ mod tests {
         fn submit_with_different_band_and_mode() {
             let mut state = QsoEntryState::new();
             fill_valid_callsign(&mut state);
-            state.handle_key(alt_press(KeyCode::Char('b'))); // 20M -> 17M
-            state.handle_key(alt_press(KeyCode::Char('m'))); // SSB -> CW
+            state.handle_command(Command::NextBand); // 20M -> 17M
+            state.handle_command(Command::NextMode); // SSB -> CW
             let action = state.handle_key(press(KeyCode::Enter));
             match action {
                 Action::AddQso(qso) => {
@@ -2079,7 +2684,7 @@ mod tests {
             type_string(&mut state, "14225");
             let action = state.handle_key(press(KeyCode::Enter));
             match action {
-                Action::AddQso(qso) => assert_eq!(qso.frequency, Some(14225)),
+                Action::AddQso(qso) => assert_eq!(qso.frequency, Some(14_225_000)),
                 other => panic!("expected AddQso, got {other:?}"),
             }
         }
@@ -2176,7 +2781,7 @@ mod tests {
             type_string(&mut state, "14225");
             let action = state.handle_key(press(KeyCode::Enter));
             match action {
-                Action::AddQso(qso) => assert_eq!(qso.frequency, Some(14225)),
+                Action::AddQso(qso) => assert_eq!(qso.frequency, Some(14_225_000)),
                 other => panic!("expected AddQso, got {other:?}"),
             }
         }
@@ -2300,8 +2905,8 @@ mod tests {
         #[test]
         fn band_mode_persist() {
             let mut state = QsoEntryState::new();
-            state.handle_key(alt_press(KeyCode::Char('b'))); // cycle band
-            state.handle_key(alt_press(KeyCode::Char('m'))); // cycle mode
+            state.handle_command(Command::NextBand); // cycle band
+            state.handle_command(Command::NextMode); // cycle mode
             let band = state.band();
             let mode = state.mode();
 
@@ -2313,7 +2918,7 @@ mod tests {
         #[test]
         fn rst_matches_current_mode_after_clear() {
             let mut state = QsoEntryState::new();
-            state.handle_key(alt_press(KeyCode::Char('m'))); // SSB -> CW
+            state.handle_command(Command::NextMode); // SSB -> CW
             assert_eq!(state.mode(), Mode::Cw);
 
             state.clear_fast_fields();
@@ -2511,6 +3116,64 @@ mod tests {
         }
     }
 
+    mod worked_before {
+        use super::*;
+
+        fn entry(log_id: &str, qso: Qso) -> WorkedEntry {
+            WorkedEntry {
+                log_id: log_id.into(),
+                log_label: "K-0001".into(),
+                qso,
+            }
+        }
+
+        #[test]
+        fn their_call_is_trimmed() {
+            let mut state = QsoEntryState::new();
+            type_string(&mut state, "KD9XYZ ");
+            assert_eq!(state.their_call(), "KD9XYZ");
+        }
+
+        #[test]
+        fn set_stores_call_and_entries() {
+            let mut state = QsoEntryState::new();
+            let qso = make_qso("KD9XYZ", Band::M20, Mode::Ssb);
+            state.set_worked_before("KD9XYZ".into(), vec![entry("other", qso)]);
+            assert_eq!(state.worked_before_call(), Some("KD9XYZ"));
+            assert_eq!(state.worked_before().len(), 1);
+            state.clear_worked_before();
+            assert_eq!(state.worked_before_call(), None);
+            assert!(state.worked_before().is_empty());
+        }
+
+        #[test]
+        fn editing_leaves_out_edited_qso() {
+            let mut state = QsoEntryState::new();
+            let log = make_pota_log();
+            state.set_log_context(&log);
+            let edited = make_qso("KD9XYZ", Band::M20, Mode::Ssb);
+            let mut earlier = edited.clone();
+            earlier.timestamp -= Duration::days(1);
+            state.start_editing(0, &edited);
+            let log_id = log.header().log_id.clone();
+            state.set_worked_before(
+                "KD9XYZ".into(),
+                vec![entry(&log_id, edited), entry(&log_id, earlier.clone())],
+            );
+            assert_eq!(state.worked_before().len(), 1);
+            assert_eq!(state.worked_before()[0].qso, earlier);
+        }
+
+        #[test]
+        fn logging_clears_panel() {
+            let mut state = QsoEntryState::new();
+            let qso = make_qso("KD9XYZ", Band::M20, Mode::Ssb);
+            state.set_worked_before("KD9XYZ".into(), vec![entry("other", qso)]);
+            state.clear_fast_fields();
+            assert_eq!(state.worked_before_call(), None);
+        }
+    }
+
     mod navigation {
         use super::*;
 
@@ -2522,16 +3185,16 @@ mod tests {
         }
 
         #[test]
-        fn alt_x_navigates_to_export() {
+        fn open_export_navigates_to_export() {
             let mut state = QsoEntryState::new();
-            let action = state.handle_key(alt_press(KeyCode::Char('x')));
+            let action = state.handle_command(Command::OpenExport);
             assert_eq!(action, Action::Navigate(Screen::Export));
         }
 
         #[test]
-        fn alt_e_navigates_to_qso_list() {
+        fn open_qso_list_navigates_to_qso_list() {
             let mut state = QsoEntryState::new();
-            let action = state.handle_key(alt_press(KeyCode::Char('e')));
+            let action = state.handle_command(Command::OpenQsoList);
             assert_eq!(action, Action::Navigate(Screen::QsoList));
         }
     }
@@ -2570,8 +3233,8 @@ mod tests {
         fn resets_to_defaults() {
             let mut state = QsoEntryState::new();
             fill_valid_callsign(&mut state);
-            state.handle_key(alt_press(KeyCode::Char('b')));
-            state.handle_key(alt_press(KeyCode::Char('m')));
+            state.handle_command(Command::NextBand);
+            state.handle_command(Command::NextMode);
             state.add_recent_qso(make_qso("W1AW", Band::M20, Mode::Ssb));
             state.set_error("some error".into());
 
@@ -2600,6 +3263,7 @@ mod tests {
                 Some("K-5678".to_string()),
                 None,
                 None,
+                None,
             )
             .unwrap()
         }
@@ -2660,23 +3324,53 @@ mod tests {
         }
 
         #[test]
-        fn esc_in_edit_mode_navigates_to_qso_list() {
+        fn submit_in_edit_mode_keeps_sync_id() {
             let mut state = QsoEntryState::new();
             state.set_log_context(&make_pota_log());
-            state.start_editing(0, &make_test_qso());
-            let action = state.handle_key(press(KeyCode::Esc));
-            assert_eq!(action, Action::Navigate(Screen::QsoList));
-            assert!(!state.is_editing());
+            let mut qso = make_test_qso();
+            qso.id = Some("qso-1".to_string());
+            state.start_editing(0, &qso);
+
+            match state.handle_key(press(KeyCode::Enter)) {
+                Action::UpdateQso(_, updated) => assert_eq!(updated.id.as_deref(), Some("qso-1")),
+                other => panic!("expected UpdateQso, got {other:?}"),
+            }
         }
 
         #[test]
-        fn reset_clears_editing_state() {
+        fn submit_in_edit_mode_keeps_extra_fields() {
             let mut state = QsoEntryState::new();
             state.set_log_context(&make_pota_log());
-            state.start_editing(0, &make_test_qso());
-            state.reset();
-            assert!(!state.is_editing());
-        }
+            let mut qso = make_test_qso();
+            qso.extra_fields = [("NAME", "Hiram"), ("STATE", "CT")].into_iter().collect();
+            state.start_editing(0, &qso);
+
+            match state.handle_key(press(KeyCode::Enter)) {
+                Action::UpdateQso(_, updated) => {
+                    assert_eq!(updated.extra_fields, qso.extra_fields);
+                }
+                other => panic!("expected UpdateQso, got {other:?}"),
+            }
+        }
+
+        #[test]
+        fn esc_in_edit_mode_navigates_to_qso_list() {
+            let mut state = QsoEntryState::new();
+            state.set_log_context(&make_pota_log());
+            state.start_editing(0, &make_test_qso());
+            let action = state.handle_key(press(KeyCode::Esc));
+            assert_eq!(action, Action::Navigate(Screen::QsoList));
+            assert!(!state.is_editing());
+        }
+
+        #[test]
+        fn reset_clears_editing_state() {
+            let mut state = QsoEntryState::new();
+            state.set_log_context(&make_pota_log());
+            state.start_editing(0, &make_test_qso());
+            state.reset();
+            assert!(!state.is_editing());
+        }
 
         #[test]
         fn clear_fast_fields_clears_editing_state() {
@@ -2730,7 +3424,8 @@ mod tests {
                 String::new(),
                 None,
                 Some("3A CT".to_string()),
-                Some(14225),
+                Some(14_225_000),
+                None,
             )
             .unwrap();
             state.start_editing(0, &qso);
@@ -2754,7 +3449,8 @@ mod tests {
                 String::new(),
                 None,
                 Some("2H EPA".to_string()),
-                Some(14225),
+                Some(14_225_000),
+                None,
             )
             .unwrap();
             state.start_editing(0, &qso);
@@ -2799,6 +3495,477 @@ mod tests {
         }
     }
 
+    mod backfill {
+        use super::*;
+
+        fn backfill_state() -> QsoEntryState {
+            let mut state = QsoEntryState::new();
+            state.set_log_context(&make_pota_log());
+            state.toggle_backfill();
+            state
+        }
+
+        fn set_timestamp(state: &mut QsoEntryState, date: &str, time: &str) {
+            let (date_idx, time_idx) = (state.form_type.date_idx(), state.form_type.time_idx());
+            state.form.set_value(date_idx, date);
+            state.form.set_value(time_idx, time);
+        }
+
+        fn date_value(state: &QsoEntryState) -> &str {
+            state.form().value(state.form_type.date_idx())
+        }
+
+        fn time_value(state: &QsoEntryState) -> &str {
+            state.form().value(state.form_type.time_idx())
+        }
+
+        fn submit_timestamp(state: &mut QsoEntryState) -> DateTime<Utc> {
+            match state.handle_key(press(KeyCode::Enter)) {
+                Action::AddQso(qso) => qso.timestamp,
+                other => panic!("expected AddQso, got {other:?}"),
+            }
+        }
+
+        #[test]
+        fn off_by_default() {
+            let mut state = QsoEntryState::new();
+            state.set_log_context(&make_pota_log());
+            assert!(!state.is_backfill());
+            assert_eq!(state.form().fields().len(), 6);
+        }
+
+        #[test]
+        fn toggle_adds_date_and_time_fields() {
+            let state = backfill_state();
+            assert!(state.is_backfill());
+            let labels: Vec<&str> = state.form().fields().iter().map(|f| f.label()).collect();
+            assert_eq!(labels[6..], ["Date (YYYY-MM-DD)", "Time (HHMM UTC)"]);
+        }
+
+        #[test]
+        fn toggle_twice_removes_fields() {
+            let mut state = backfill_state();
+            state.toggle_backfill();
+            assert!(!state.is_backfill());
+            assert_eq!(state.form().fields().len(), 6);
+        }
+
+        #[test]
+        fn toggle_command_switches_mode() {
+            let mut state = QsoEntryState::new();
+            state.set_log_context(&make_pota_log());
+            let action = state.handle_command(Command::ToggleBackfill);
+            assert_eq!(action, Action::None);
+            assert!(state.is_backfill());
+        }
+
+        #[test]
+        fn date_prefilled_from_latest_qso() {
+            let mut log = make_pota_log();
+            log.add_qso(make_qso("W3ABC", Band::M20, Mode::Ssb));
+            let mut state = QsoEntryState::new();
+            state.set_log_context(&log);
+            state.toggle_backfill();
+            assert_eq!(date_value(&state), "2026-02-16");
+            assert_eq!(time_value(&state), "");
+        }
+
+        #[test]
+        fn date_prefilled_with_today_for_empty_log() {
+            let state = backfill_state();
+            assert_eq!(
+                date_value(&state),
+                Utc::now().format("%Y-%m-%d").to_string()
+            );
+        }
+
+        #[test]
+        fn submit_uses_entered_timestamp() {
+            let mut state = backfill_state();
+            fill_valid_callsign(&mut state);
+            set_timestamp(&mut state, "2025-07-04", "1432");
+            assert_eq!(
+                submit_timestamp(&mut state),
+                Utc.with_ymd_and_hms(2025, 7, 4, 14, 32, 0).unwrap()
+            );
+        }
+
+        #[test]
+        fn accepts_compact_date_and_colon_time() {
+            let mut state = backfill_state();
+            fill_valid_callsign(&mut state);
+            set_timestamp(&mut state, "20250704", "09:05");
+            assert_eq!(
+                submit_timestamp(&mut state),
+                Utc.with_ymd_and_hms(2025, 7, 4, 9, 5, 0).unwrap()
+            );
+        }
+
+        #[test]
+        fn invalid_time_sets_field_error() {
+            let mut state = backfill_state();
+            fill_valid_callsign(&mut state);
+            set_timestamp(&mut state, "2025-07-04", "2460");
+            assert_eq!(state.handle_key(press(KeyCode::Enter)), Action::None);
+            let time_idx = state.form_type.time_idx();
+            assert_eq!(
+                state.form().fields()[time_idx].error(),
+                Some("time must be HHMM (UTC)")
+            );
+        }
+
+        #[test]
+        fn invalid_date_sets_field_error() {
+            let mut state = backfill_state();
+            fill_valid_callsign(&mut state);
+            set_timestamp(&mut state, "2025-02-30", "1200");
+            assert_eq!(state.handle_key(press(KeyCode::Enter)), Action::None);
+            let date_idx = state.form_type.date_idx();
+            assert_eq!(
+                state.form().fields()[date_idx].error(),
+                Some("date must be YYYY-MM-DD")
+            );
+        }
+
+        #[test]
+        fn future_timestamp_rejected() {
+            let mut state = backfill_state();
+            fill_valid_callsign(&mut state);
+            let tomorrow = Utc::now() + Duration::days(1);
+            set_timestamp(&mut state, &tomorrow.format("%Y-%m-%d").to_string(), "0000");
+            assert_eq!(state.handle_key(press(KeyCode::Enter)), Action::None);
+            let time_idx = state.form_type.time_idx();
+            assert_eq!(
+                state.form().fields()[time_idx].error(),
+                Some("time is in the future")
+            );
+        }
+
+        #[test]
+        fn date_before_1930_rejected() {
+            let mut state = backfill_state();
+            fill_valid_callsign(&mut state);
+            set_timestamp(&mut state, "1929-12-31", "2359");
+            assert_eq!(state.handle_key(press(KeyCode::Enter)), Action::None);
+            let date_idx = state.form_type.date_idx();
+            assert_eq!(
+                state.form().fields()[date_idx].error(),
+                Some("date must be 1930 or later")
+            );
+        }
+
+        #[test]
+        fn clear_fast_fields_keeps_date_and_advances_time() {
+            let mut state = backfill_state();
+            fill_valid_callsign(&mut state);
+            set_timestamp(&mut state, "2025-07-04", "1432");
+            submit_timestamp(&mut state);
+            state.clear_fast_fields();
+            assert_eq!(date_value(&state), "2025-07-04");
+            assert_eq!(time_value(&state), "1433");
+            assert_eq!(state.form().value(THEIR_CALL), "");
+            assert_eq!(state.form().focus(), THEIR_CALL);
+        }
+
+        #[test]
+        fn auto_increment_rolls_over_midnight() {
+            let mut state = backfill_state();
+            fill_valid_callsign(&mut state);
+            set_timestamp(&mut state, "2025-07-04", "2359");
+            submit_timestamp(&mut state);
+            state.clear_fast_fields();
+            assert_eq!(date_value(&state), "2025-07-05");
+            assert_eq!(time_value(&state), "0000");
+        }
+
+        #[test]
+        fn out_of_order_time_warns_then_logs_on_second_enter() {
+            let mut state = backfill_state();
+            fill_valid_callsign(&mut state);
+            set_timestamp(&mut state, "2025-07-04", "1432");
+            submit_timestamp(&mut state);
+            state.clear_fast_fields();
+
+            fill_valid_callsign(&mut state);
+            set_timestamp(&mut state, "2025-07-04", "1400");
+            assert_eq!(state.handle_key(press(KeyCode::Enter)), Action::None);
+            assert!(
+                state
+                    .error()
+                    .is_some_and(|e| e.contains("before the previous QSO")),
+                "{:?}",
+                state.error()
+            );
+            assert_eq!(
+                submit_timestamp(&mut state),
+                Utc.with_ymd_and_hms(2025, 7, 4, 14, 0, 0).unwrap()
+            );
+        }
+
+        #[test]
+        fn changed_out_of_order_time_warns_again() {
+            let mut state = backfill_state();
+            fill_valid_callsign(&mut state);
+            set_timestamp(&mut state, "2025-07-04", "1432");
+            submit_timestamp(&mut state);
+            state.clear_fast_fields();
+
+            fill_valid_callsign(&mut state);
+            set_timestamp(&mut state, "2025-07-04", "1400");
+            state.handle_key(press(KeyCode::Enter));
+            set_timestamp(&mut state, "2025-07-04", "1401");
+            assert_eq!(state.handle_key(press(KeyCode::Enter)), Action::None);
+            assert!(state.error().is_some());
+        }
+
+        #[test]
+        fn is_per_log() {
+            let pota = make_pota_log();
+            let fd = make_fd_log();
+            let mut state = QsoEntryState::new();
+            state.set_log_context(&pota);
+            state.toggle_backfill();
+
+            state.set_log_context(&fd);
+            assert!(!state.is_backfill());
+            assert_eq!(state.form().fields().len(), 5);
+
+            state.set_log_context(&pota);
+            assert!(state.is_backfill());
+            assert_eq!(state.form().fields().len(), 8);
+        }
+
+        #[test]
+        fn sticky_date_survives_returning_to_screen() {
+            let log = make_pota_log();
+            let mut state = QsoEntryState::new();
+            state.set_log_context(&log);
+            state.toggle_backfill();
+            set_timestamp(&mut state, "2025-07-04", "1432");
+            state.set_log_context(&log);
+            assert_eq!(date_value(&state), "2025-07-04");
+        }
+
+        #[test]
+        fn normal_mode_ignores_timestamp_fields() {
+            let mut state = QsoEntryState::new();
+            state.set_log_context(&make_pota_log());
+            fill_valid_callsign(&mut state);
+            let before = Utc::now();
+            let ts = submit_timestamp(&mut state);
+            assert!(ts >= before);
+        }
+    }
+
+    mod editing_timestamp {
+        use super::*;
+
+        fn editing_state(qso: &Qso) -> QsoEntryState {
+            let mut state = QsoEntryState::new();
+            state.set_log_context(&make_pota_log());
+            state.start_editing(1, qso);
+            state
+        }
+
+        fn submit_update(state: &mut QsoEntryState) -> Qso {
+            match state.handle_key(press(KeyCode::Enter)) {
+                Action::UpdateQso(_, qso) => qso,
+                other => panic!("expected UpdateQso, got {other:?}"),
+            }
+        }
+
+        #[test]
+        fn start_editing_shows_timestamp_fields() {
+            let state = editing_state(&make_qso("W3ABC", Band::M20, Mode::Ssb));
+            assert_eq!(state.form().value(state.form_type.date_idx()), "2026-02-16");
+            assert_eq!(state.form().value(state.form_type.time_idx()), "1430");
+        }
+
+        #[test]
+        fn changed_time_updates_timestamp() {
+            let mut state = editing_state(&make_qso("W3ABC", Band::M20, Mode::Ssb));
+            state.form.set_value(state.form_type.time_idx(), "1515");
+            let updated = submit_update(&mut state);
+            assert_eq!(
+                updated.timestamp,
+                Utc.with_ymd_and_hms(2026, 2, 16, 15, 15, 0).unwrap()
+            );
+        }
+
+        #[test]
+        fn unchanged_fields_keep_original_seconds() {
+            let mut qso = make_qso("W3ABC", Band::M20, Mode::Ssb);
+            qso.timestamp = Utc.with_ymd_and_hms(2026, 2, 16, 14, 30, 42).unwrap();
+            let mut state = editing_state(&qso);
+            let updated = submit_update(&mut state);
+            assert_eq!(updated.timestamp, qso.timestamp);
+        }
+
+        #[test]
+        fn edit_does_not_warn_about_order() {
+            let mut state = editing_state(&make_qso("W3ABC", Band::M20, Mode::Ssb));
+            state.last_backfill = Some(Utc.with_ymd_and_hms(2026, 3, 1, 0, 0, 0).unwrap());
+            submit_update(&mut state);
+        }
+
+        #[test]
+        fn esc_removes_timestamp_fields() {
+            let mut state = editing_state(&make_qso("W3ABC", Band::M20, Mode::Ssb));
+            state.handle_key(press(KeyCode::Esc));
+            assert_eq!(state.form().fields().len(), 6);
+        }
+
+        #[test]
+        fn clear_editing_keeps_fields_in_backfill_mode() {
+            let mut state = QsoEntryState::new();
+            state.set_log_context(&make_pota_log());
+            state.toggle_backfill();
+            state.start_editing(0, &make_qso("W3ABC", Band::M20, Mode::Ssb));
+            state.clear_editing();
+            assert_eq!(state.form().fields().len(), 8);
+        }
+    }
+
+    mod operator {
+        use super::*;
+
+        fn switch_operator(state: &mut QsoEntryState, call: &str) {
+            state.handle_command(Command::SetOperator);
+            type_string(state, call);
+            state.handle_key(press(KeyCode::Enter));
+        }
+
+        fn submit_new(state: &mut QsoEntryState) -> Qso {
+            fill_valid_callsign(state);
+            match state.handle_key(press(KeyCode::Enter)) {
+                Action::AddQso(qso) => qso,
+                other => panic!("expected AddQso, got {other:?}"),
+            }
+        }
+
+        #[test]
+        fn defaults_to_log_operator() {
+            let mut state = QsoEntryState::new();
+            state.set_log_context(&make_pota_log());
+            assert_eq!(state.operator(), None);
+            assert_eq!(submit_new(&mut state).operator, None);
+        }
+
+        #[test]
+        fn command_opens_prompt() {
+            let mut state = QsoEntryState::new();
+            let action = state.handle_command(Command::SetOperator);
+            assert_eq!(action, Action::None);
+            assert_eq!(state.operator_prompt(), Some(""));
+        }
+
+        #[test]
+        fn prompt_uppercases_and_edits() {
+            let mut state = QsoEntryState::new();
+            state.handle_command(Command::SetOperator);
+            type_string(&mut state, "k1abx");
+            state.handle_key(press(KeyCode::Backspace));
+            type_string(&mut state, "c");
+            assert_eq!(state.operator_prompt(), Some("K1ABC"));
+            assert_eq!(state.form().value(THEIR_CALL), "", "form is untouched");
+        }
+
+        #[test]
+        fn enter_switches_operator_and_is_sticky() {
+            let mut state = QsoEntryState::new();
+            state.set_log_context(&make_pota_log());
+            switch_operator(&mut state, "K1ABC");
+            assert_eq!(state.operator_prompt(), None);
+            assert_eq!(state.operator(), Some("K1ABC"));
+            assert_eq!(submit_new(&mut state).operator.as_deref(), Some("K1ABC"));
+            state.clear_fast_fields();
+            assert_eq!(submit_new(&mut state).operator.as_deref(), Some("K1ABC"));
+        }
+
+        #[test]
+        fn blank_reverts_to_log_operator() {
+            let mut state = QsoEntryState::new();
+            switch_operator(&mut state, "K1ABC");
+            switch_operator(&mut state, "");
+            assert_eq!(state.operator(), None);
+        }
+
+        #[test]
+        fn esc_cancels_without_switching() {
+            let mut state = QsoEntryState::new();
+            switch_operator(&mut state, "K1ABC");
+            state.handle_command(Command::SetOperator);
+            type_string(&mut state, "N0CALL");
+            let action = state.handle_key(press(KeyCode::Esc));
+            assert_eq!(
+                action,
+                Action::None,
+                "Esc closes the prompt, not the screen"
+            );
+            assert_eq!(state.operator_prompt(), None);
+            assert_eq!(state.operator(), Some("K1ABC"));
+        }
+
+        #[test]
+        fn invalid_callsign_keeps_prompt_open() {
+            let mut state = QsoEntryState::new();
+            switch_operator(&mut state, "K1-ABC");
+            assert_eq!(state.operator_prompt(), Some("K1-ABC"));
+            assert!(state.error().unwrap().starts_with("operator:"));
+            assert_eq!(state.operator(), None);
+        }
+
+        #[test]
+        fn resumes_with_latest_qso_operator() {
+            let mut log = make_pota_log();
+            let mut qso = make_qso("W3ABC", Band::M20, Mode::Ssb);
+            qso.operator = Some("K1ABC".to_string());
+            log.add_qso(qso);
+            let mut state = QsoEntryState::new();
+            state.set_log_context(&log);
+            assert_eq!(state.operator(), Some("K1ABC"));
+        }
+
+        #[test]
+        fn same_log_context_keeps_operator() {
+            let log = make_pota_log();
+            let mut state = QsoEntryState::new();
+            state.set_log_context(&log);
+            switch_operator(&mut state, "K1ABC");
+            state.set_log_context(&log);
+            assert_eq!(state.operator(), Some("K1ABC"));
+        }
+
+        #[test]
+        fn editing_preserves_qso_operator() {
+            let mut state = QsoEntryState::new();
+            state.set_log_context(&make_pota_log());
+            switch_operator(&mut state, "N0CALL");
+            let mut qso = make_qso("W3ABC", Band::M20, Mode::Ssb);
+            qso.operator = Some("K1ABC".to_string());
+            state.start_editing(0, &qso);
+            assert_eq!(state.operator(), Some("K1ABC"));
+            match state.handle_key(press(KeyCode::Enter)) {
+                Action::UpdateQso(0, updated) => {
+                    assert_eq!(updated.operator.as_deref(), Some("K1ABC"));
+                }
+                other => panic!("expected UpdateQso, got {other:?}"),
+            }
+        }
+
+        #[test]
+        fn switching_while_editing_changes_only_the_edited_qso() {
+            let mut state = QsoEntryState::new();
+            state.set_log_context(&make_pota_log());
+            switch_operator(&mut state, "N0CALL");
+            state.start_editing(0, &make_qso("W3ABC", Band::M20, Mode::Ssb));
+            switch_operator(&mut state, "K1ABC");
+            assert_eq!(state.operator(), Some("K1ABC"));
+            state.clear_editing();
+            assert_eq!(state.operator(), Some("N0CALL"));
+        }
+    }
+
     mod rendering {
         use ratatui::Terminal;
         use ratatui::backend::TestBackend;
@@ -2817,7 +3984,7 @@ mod tests {
             let mut terminal = Terminal::new(backend).unwrap();
             terminal
                 .draw(|frame| {
-                    draw_qso_entry(state, log, frame, frame.area());
+                    draw_qso_entry(state, log, &[], &Theme::default(), frame, frame.area());
                 })
                 .unwrap();
             terminal
@@ -2848,6 +4015,25 @@ mod tests {
             )
         }
 
+        #[test]
+        fn renders_current_operator() {
+            let mut state = QsoEntryState::new();
+            let log = make_log();
+            state.set_log_context(&log);
+            let output = render_qso_entry(&state, Some(&log), 100, 30);
+            assert!(output.contains("Op: W1AW"), "defaults to station: {output}");
+            state.handle_command(Command::SetOperator);
+            type_string(&mut state, "K1ABC");
+            let output = render_qso_entry(&state, Some(&log), 100, 30);
+            assert!(
+                output.contains("Operator: K1ABC_"),
+                "shows prompt: {output}"
+            );
+            state.handle_key(press(KeyCode::Enter));
+            let output = render_qso_entry(&state, Some(&log), 100, 30);
+            assert!(output.contains("Op: K1ABC"), "shows switched op: {output}");
+        }
+
         #[test]
         fn renders_title_and_form_fields() {
             let state = QsoEntryState::new();
@@ -2920,6 +4106,32 @@ mod tests {
             assert!(output.contains("KD9XYZ"), "should show second recent QSO");
         }
 
+        #[test]
+        fn renders_worked_before_in_place_of_recent_qsos() {
+            let mut state = QsoEntryState::new();
+            state.add_recent_qso(make_qso("W3ABC", Band::M20, Mode::Ssb));
+            let mut qso = make_qso("KD9XYZ", Band::M40, Mode::Cw);
+            qso.their_park = Some("K-4567".into());
+            qso.comments = "nice signal".into();
+            state.set_worked_before(
+                "KD9XYZ".into(),
+                vec![WorkedEntry {
+                    log_id: "other".into(),
+                    log_label: "K-0001".into(),
+                    qso,
+                }],
+            );
+            let output = render_qso_entry(&state, None, 100, 30);
+            assert!(
+                output.contains("Worked Before: KD9XYZ (1 QSOs in 1 logs)"),
+                "{output}"
+            );
+            for text in ["K-0001", "40M", "CW", "K-4567", "nice signal"] {
+                assert!(output.contains(text), "should show {text}: {output}");
+            }
+            assert!(!output.contains("Recent QSOs"), "{output}");
+        }
+
         #[test]
         fn renders_error_message() {
             let mut state = QsoEntryState::new();
@@ -2962,6 +4174,7 @@ mod tests {
                 Some("K-5678".to_string()),
                 None,
                 None,
+                None,
             )
             .unwrap();
             state.add_recent_qso(qso);
@@ -2985,7 +4198,8 @@ mod tests {
                 String::new(),
                 Some("K-5678".to_string()),
                 None,
-                Some(14_225),
+                Some(14_225_000),
+                None,
             )
             .unwrap();
             state.add_recent_qso(qso);
@@ -3010,7 +4224,8 @@ mod tests {
                 String::new(),
                 None,
                 None,
-                Some(14_225),
+                Some(14_225_000),
+                None,
             )
             .unwrap();
             state.add_recent_qso(qso);
@@ -3037,6 +4252,7 @@ mod tests {
                 None,
                 Some("3A CT".to_string()),
                 None,
+                None,
             )
             .unwrap();
             state.add_recent_qso(qso);
@@ -3063,6 +4279,7 @@ mod tests {
                 None,
                 Some("2H EPA".to_string()),
                 None,
+                None,
             )
             .unwrap();
             state.add_recent_qso(qso);
@@ -3315,13 +4532,13 @@ mod tests {
                 let mut terminal = Terminal::new(backend).unwrap();
                 terminal
                     .draw(|frame| {
-                        draw_recent_qsos(state, frame, frame.area());
+                        draw_recent_qsos(state, &Theme::default(), frame, frame.area());
                     })
                     .unwrap();
                 terminal
             }
 
-            fn pota_qso_with_park(call: &str, park: &str, freq: Option<u32>) -> Qso {
+            fn pota_qso_with_park(call: &str, park: &str, freq: Option<u64>) -> Qso {
                 Qso::new(
                     call.to_string(),
                     "59".to_string(),
@@ -3333,11 +4550,12 @@ mod tests {
                     Some(park.to_string()),
                     None,
                     freq,
+                    None,
                 )
                 .unwrap()
             }
 
-            fn pota_qso_no_park(call: &str, freq: Option<u32>) -> Qso {
+            fn pota_qso_no_park(call: &str, freq: Option<u64>) -> Qso {
                 Qso::new(
                     call.to_string(),
                     "59".to_string(),
@@ -3349,11 +4567,12 @@ mod tests {
                     None,
                     None,
                     freq,
+                    None,
                 )
                 .unwrap()
             }
 
-            fn general_qso(call: &str, freq: Option<u32>) -> Qso {
+            fn general_qso(call: &str, freq: Option<u64>) -> Qso {
                 Qso::new(
                     call.to_string(),
                     "59".to_string(),
@@ -3365,11 +4584,12 @@ mod tests {
                     None,
                     None,
                     freq,
+                    None,
                 )
                 .unwrap()
             }
 
-            fn fd_qso(call: &str, exchange: &str, freq: Option<u32>) -> Qso {
+            fn fd_qso(call: &str, exchange: &str, freq: Option<u64>) -> Qso {
                 Qso::new(
                     call.to_string(),
                     "59".to_string(),
@@ -3381,6 +4601,7 @@ mod tests {
                     None,
                     Some(exchange.to_string()),
                     freq,
+                    None,
                 )
                 .unwrap()
             }
@@ -3390,7 +4611,7 @@ mod tests {
             #[test]
             fn snap_general_with_freq() {
                 let mut state = QsoEntryState::new();
-                state.add_recent_qso(general_qso("W3ABC", Some(14_225)));
+                state.add_recent_qso(general_qso("W3ABC", Some(14_225_000)));
                 assert_snapshot!(render_recent(&state, 80, 4).backend());
             }
 
@@ -3414,7 +4635,7 @@ mod tests {
                     .unwrap(),
                 );
                 state.set_log_context(&log);
-                state.add_recent_qso(pota_qso_with_park("W3ABC", "K-5678", Some(14_225)));
+                state.add_recent_qso(pota_qso_with_park("W3ABC", "K-5678", Some(14_225_000)));
                 assert_snapshot!(render_recent(&state, 80, 4).backend());
             }
 
@@ -3450,7 +4671,7 @@ mod tests {
                     .unwrap(),
                 );
                 state.set_log_context(&log);
-                state.add_recent_qso(pota_qso_no_park("W3ABC", Some(14_225)));
+                state.add_recent_qso(pota_qso_no_park("W3ABC", Some(14_225_000)));
                 assert_snapshot!(render_recent(&state, 80, 4).backend());
             }
 
@@ -3476,7 +4697,7 @@ mod tests {
                 let mut state = QsoEntryState::new();
                 let log = make_fd_log();
                 state.set_log_context(&log);
-                state.add_recent_qso(fd_qso("W3ABC", "3A CT", Some(14_225)));
+                state.add_recent_qso(fd_qso("W3ABC", "3A CT", Some(14_225_000)));
                 assert_snapshot!(render_recent(&state, 80, 4).backend());
             }
 
@@ -3494,7 +4715,7 @@ mod tests {
                 let mut state = QsoEntryState::new();
                 let log = make_wfd_log();
                 state.set_log_context(&log);
-                state.add_recent_qso(fd_qso("W3ABC", "2H EPA", Some(14_225)));
+                state.add_recent_qso(fd_qso("W3ABC", "2H EPA", Some(14_225_000)));
                 assert_snapshot!(render_recent(&state, 80, 4).backend());
             }
 
@@ -3515,7 +4736,7 @@ mod tests {
                 for i in 0..10 {
                     state.add_recent_qso(general_qso(
                         &format!("W{i}AW"),
-                        Some(14_000 + i as u32 * 25),
+                        Some(14_000_000 + i as u64 * 25_000),
                     ));
                 }
                 assert_snapshot!(render_recent(&state, 80, 10).backend());
@@ -3537,7 +4758,7 @@ mod tests {
                     .unwrap(),
                 );
                 state.set_log_context(&log);
-                state.add_recent_qso(pota_qso_with_park("W3ABC", "K-5678", Some(14_225)));
+                state.add_recent_qso(pota_qso_with_park("W3ABC", "K-5678", Some(14_225_000)));
                 let output = buffer_to_string(render_recent(&state, 80, 4).backend().buffer());
                 assert!(output.contains("K-5678"), "park should appear");
                 assert!(
@@ -3560,7 +4781,7 @@ mod tests {
                     .unwrap(),
                 );
                 state.set_log_context(&log);
-                state.add_recent_qso(pota_qso_no_park("W3ABC", Some(14_225)));
+                state.add_recent_qso(pota_qso_no_park("W3ABC", Some(14_225_000)));
                 let output = buffer_to_string(render_recent(&state, 80, 4).backend().buffer());
                 assert!(
                     output.contains("14225"),
@@ -3604,22 +4825,36 @@ mod tests {
 
         #[test]
         fn cycle_forward_wraps_at_end() {
-            assert_eq!(cycle(Band::all(), Band::Cm70, true), Band::M160);
+            assert_eq!(cycle(Band::default_cycle(), Band::Cm70, true), Band::M160);
         }
 
         #[test]
         fn cycle_backward_wraps_at_start() {
-            assert_eq!(cycle(Band::all(), Band::M160, false), Band::Cm70);
+            assert_eq!(cycle(Band::default_cycle(), Band::M160, false), Band::Cm70);
+        }
+
+        #[test]
+        fn nearest_band_forward_and_backward() {
+            let bands = [Band::M160, Band::M40, Band::M20];
+            assert_eq!(nearest_band(&bands, Band::M80, true), Band::M40);
+            assert_eq!(nearest_band(&bands, Band::M80, false), Band::M160);
+        }
+
+        #[test]
+        fn nearest_band_wraps() {
+            let bands = [Band::M160, Band::M40, Band::M20];
+            assert_eq!(nearest_band(&bands, Band::M10, true), Band::M160);
+            assert_eq!(nearest_band(&bands, Band::M630, false), Band::M20);
         }
 
         #[test]
         fn cycle_modes_forward() {
-            assert_eq!(cycle(Mode::all(), Mode::Ssb, true), Mode::Cw);
+            assert_eq!(cycle(Mode::default_cycle(), Mode::Ssb, true), Mode::Cw);
         }
 
         #[test]
         fn cycle_modes_backward_wraps() {
-            assert_eq!(cycle(Mode::all(), Mode::Ssb, false), Mode::Digi);
+            assert_eq!(cycle(Mode::default_cycle(), Mode::Ssb, false), Mode::Digi);
         }
     }
 }
@@ -1,26 +1,351 @@
 //! QSO list screen — scrollable table of all QSOs in the active log.
 
-use crossterm::event::{KeyCode, KeyEvent};
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, BTreeSet};
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use ratatui::Frame;
 use ratatui::layout::{Alignment, Constraint, Layout, Rect};
-use ratatui::style::{Color, Modifier, Style};
+use ratatui::style::Style;
 use ratatui::text::Line;
-use ratatui::widgets::{Paragraph, Row, Table};
+use ratatui::widgets::{Block, Borders, Paragraph, Row, Table};
 
-use crate::model::Log;
+use crate::model::{Band, Log, Mode, Qso, QsoChanges, format_khz, parse_khz};
 use crate::tui::action::Action;
 use crate::tui::app::Screen;
-use crate::tui::widgets::{StatusBarContext, draw_status_bar};
+use crate::tui::keymap::Command;
+use crate::tui::theme::Theme;
+use crate::tui::timers::TimerReading;
+use crate::tui::widgets::{Form, FormField, StatusBarContext, draw_form, draw_status_bar};
+
+/// Bulk-edit field index for the band.
+const BULK_BAND: usize = 0;
+/// Bulk-edit field index for the mode.
+const BULK_MODE: usize = 1;
+/// Bulk-edit field index for the frequency in kHz.
+const BULK_FREQUENCY: usize = 2;
+/// Bulk-edit field index for the RST sent.
+const BULK_RST_SENT: usize = 3;
+/// Bulk-edit field index for the RST received.
+const BULK_RST_RCVD: usize = 4;
+/// Bulk-edit field index for the comments.
+const BULK_COMMENTS: usize = 5;
+
+/// The bulk-edit dialog: a form whose blank fields leave QSOs unchanged.
+#[derive(Debug)]
+struct BulkEditDialog {
+    /// Indices of the QSOs being edited, captured when the dialog opened.
+    indices: Vec<usize>,
+    /// Whether the log requires a frequency on every QSO (FD and WFD), so a
+    /// band change must come with one.
+    frequency_required: bool,
+    form: Form,
+}
 
-/// State for the QSO list screen.
+impl BulkEditDialog {
+    fn new(indices: Vec<usize>, frequency_required: bool) -> Self {
+        let form = Form::new(vec![
+            Box::new(FormField::new("Band", false)),
+            Box::new(FormField::new("Mode", false)),
+            Box::new(FormField::new("Frequency (kHz)", false)),
+            Box::new(FormField::new("RST Sent", false)),
+            Box::new(FormField::new("RST Rcvd", false)),
+            Box::new(FormField::new("Comments", false)),
+        ]);
+        Self {
+            indices,
+            frequency_required,
+            form,
+        }
+    }
+
+    /// Parses the form into a change set, marking invalid fields.
+    ///
+    /// A frequency with no band given also selects the band it falls in; with
+    /// a band it must lie inside it. A band change needs a frequency when the
+    /// log requires one, since the QSOs' old frequencies would be cleared.
+    fn changes(&mut self) -> Option<QsoChanges> {
+        self.form.clear_errors();
+        let text = |idx| Some(self.form.value(idx).trim().to_string()).filter(|v| !v.is_empty());
+
+        let band = text(BULK_BAND);
+        let mode = text(BULK_MODE);
+        let frequency = text(BULK_FREQUENCY);
+        let mut changes = QsoChanges {
+            rst_sent: text(BULK_RST_SENT),
+            rst_rcvd: text(BULK_RST_RCVD),
+            comments: text(BULK_COMMENTS),
+            ..QsoChanges::default()
+        };
+
+        if let Some(band) = band {
+            match Band::from_adif_str(&band) {
+                Some(b) => changes.band = Some(b),
+                None => self
+                    .form
+                    .set_error(BULK_BAND, format!("unknown band {band:?}")),
+            }
+        }
+        if let Some(mode) = mode {
+            match Mode::from_name(&mode) {
+                Some(m) => changes.mode = Some(m),
+                None => self
+                    .form
+                    .set_error(BULK_MODE, format!("unknown mode {mode:?}")),
+            }
+        }
+        if let Some(frequency) = frequency {
+            match parse_khz(&frequency) {
+                Some(f) => match changes.band {
+                    Some(band) if Band::from_frequency_hz(f) != Some(band) => {
+                        self.form.set_error(
+                            BULK_FREQUENCY,
+                            format!("{} kHz is not in the {band} band", format_khz(f)),
+                        );
+                    }
+                    Some(_) => changes.frequency = Some(f),
+                    None => {
+                        changes.frequency = Some(f);
+                        if !self.form.has_errors() {
+                            changes.band = Band::from_frequency_hz(f);
+                        }
+                    }
+                },
+                None => self.form.set_error(
+                    BULK_FREQUENCY,
+                    "frequency must be a positive number (kHz)".into(),
+                ),
+            }
+        } else if self.frequency_required && changes.band.is_some() {
+            self.form.set_error(
+                BULK_FREQUENCY,
+                "a band change needs a frequency in this log".into(),
+            );
+        }
+
+        (!self.form.has_errors()).then_some(changes)
+    }
+}
+
+/// A column the QSO list can show.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum QsoColumn {
+    Time,
+    Date,
+    Call,
+    Band,
+    Mode,
+    Frequency,
+    /// RST sent and received, as `sent/rcvd`.
+    Rst,
+    Park,
+    /// The received contest exchange.
+    Exchange,
+    Operator,
+    Comments,
+}
+
+static ALL_COLUMNS: &[QsoColumn] = &[
+    QsoColumn::Time,
+    QsoColumn::Date,
+    QsoColumn::Call,
+    QsoColumn::Band,
+    QsoColumn::Mode,
+    QsoColumn::Frequency,
+    QsoColumn::Rst,
+    QsoColumn::Park,
+    QsoColumn::Exchange,
+    QsoColumn::Operator,
+    QsoColumn::Comments,
+];
+
+impl QsoColumn {
+    /// Returns every column, in display order.
+    pub fn all() -> &'static [QsoColumn] {
+        ALL_COLUMNS
+    }
+
+    /// Returns the name used when saving the column choice.
+    pub fn name(self) -> &'static str {
+        match self {
+            QsoColumn::Time => "time",
+            QsoColumn::Date => "date",
+            QsoColumn::Call => "call",
+            QsoColumn::Band => "band",
+            QsoColumn::Mode => "mode",
+            QsoColumn::Frequency => "frequency",
+            QsoColumn::Rst => "rst",
+            QsoColumn::Park => "park",
+            QsoColumn::Exchange => "exchange",
+            QsoColumn::Operator => "operator",
+            QsoColumn::Comments => "comments",
+        }
+    }
+
+    /// Parses a column from its saved [`name`](Self::name).
+    pub fn from_name(s: &str) -> Option<QsoColumn> {
+        ALL_COLUMNS.iter().find(|c| c.name() == s).copied()
+    }
+
+    /// Returns the column heading.
+    fn title(self) -> &'static str {
+        match self {
+            QsoColumn::Time => "Time",
+            QsoColumn::Date => "Date",
+            QsoColumn::Call => "Call",
+            QsoColumn::Band => "Band",
+            QsoColumn::Mode => "Mode",
+            QsoColumn::Frequency => "Freq",
+            QsoColumn::Rst => "RST S/R",
+            QsoColumn::Park => "Park",
+            QsoColumn::Exchange => "Exch",
+            QsoColumn::Operator => "Op",
+            QsoColumn::Comments => "Comments",
+        }
+    }
+
+    /// Returns the column width; comments take the remaining space.
+    fn width(self) -> Constraint {
+        match self {
+            QsoColumn::Time => Constraint::Length(6),
+            QsoColumn::Date => Constraint::Length(11),
+            QsoColumn::Call | QsoColumn::Operator => Constraint::Length(12),
+            QsoColumn::Band | QsoColumn::Mode => Constraint::Length(5),
+            QsoColumn::Frequency | QsoColumn::Rst | QsoColumn::Park => Constraint::Length(8),
+            QsoColumn::Exchange => Constraint::Length(10),
+            QsoColumn::Comments => Constraint::Min(0),
+        }
+    }
+
+    /// Returns the cell text for `qso`.
+    fn cell(self, qso: &Qso) -> String {
+        match self {
+            QsoColumn::Time => qso.timestamp.format("%H:%M").to_string(),
+            QsoColumn::Date => qso.timestamp.format("%Y-%m-%d").to_string(),
+            QsoColumn::Call => qso.their_call.clone(),
+            QsoColumn::Band => qso.band.to_string(),
+            QsoColumn::Mode => qso.mode_name().to_string(),
+            QsoColumn::Frequency => qso.frequency.map(format_khz).unwrap_or_default(),
+            QsoColumn::Rst => format!("{}/{}", qso.rst_sent, qso.rst_rcvd),
+            QsoColumn::Park => qso.their_park.clone().unwrap_or_default(),
+            QsoColumn::Exchange => qso.exchange_rcvd.clone().unwrap_or_default(),
+            QsoColumn::Operator => qso.operator.clone().unwrap_or_default(),
+            QsoColumn::Comments => qso.comments.clone(),
+        }
+    }
+
+    /// Returns the columns shown for `log` until the user chooses otherwise.
+    pub fn defaults(log: &Log) -> Vec<QsoColumn> {
+        let common = [
+            QsoColumn::Time,
+            QsoColumn::Date,
+            QsoColumn::Call,
+            QsoColumn::Band,
+            QsoColumn::Mode,
+        ];
+        let specific: &[QsoColumn] = match log {
+            Log::Pota(_) => &[QsoColumn::Rst, QsoColumn::Park],
+            Log::General(_) => &[QsoColumn::Frequency, QsoColumn::Rst],
+            Log::FieldDay(_) | Log::WinterFieldDay(_) => &[QsoColumn::Exchange],
+        };
+        [&common[..], specific, &[QsoColumn::Comments]].concat()
+    }
+}
+
+/// The field the QSO list is sorted by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SortKey {
+    #[default]
+    Time,
+    Call,
+    Band,
+    Mode,
+    Park,
+}
+
+impl SortKey {
+    /// Returns the next key in the sort cycle.
+    fn next(self) -> SortKey {
+        match self {
+            SortKey::Time => SortKey::Call,
+            SortKey::Call => SortKey::Band,
+            SortKey::Band => SortKey::Mode,
+            SortKey::Mode => SortKey::Park,
+            SortKey::Park => SortKey::Time,
+        }
+    }
+
+    /// Returns the label shown in the title.
+    fn label(self) -> &'static str {
+        match self {
+            SortKey::Time => "time",
+            SortKey::Call => "call",
+            SortKey::Band => "band",
+            SortKey::Mode => "mode",
+            SortKey::Park => "park",
+        }
+    }
+
+    /// Compares two QSOs by this key, falling back to time.
+    ///
+    /// Bands sort by frequency; QSOs without a park sort after those with one.
+    fn compare(self, a: &Qso, b: &Qso) -> Ordering {
+        let band_pos = |band| Band::all().iter().position(|&b| b == band);
+        let by_key = match self {
+            SortKey::Time => Ordering::Equal,
+            SortKey::Call => a.their_call.cmp(&b.their_call),
+            SortKey::Band => band_pos(a.band).cmp(&band_pos(b.band)),
+            SortKey::Mode => a.mode_name().cmp(b.mode_name()),
+            SortKey::Park => match (&a.their_park, &b.their_park) {
+                (Some(a), Some(b)) => a.cmp(b),
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (None, None) => Ordering::Equal,
+            },
+        };
+        by_key.then(a.timestamp.cmp(&b.timestamp))
+    }
+}
+
+/// The column picker: every column with a checkbox.
 #[derive(Debug, Clone)]
+struct ColumnPicker {
+    /// Log type name the choice is saved under.
+    log_type: &'static str,
+    /// Index into [`QsoColumn::all`] of the highlighted column.
+    cursor: usize,
+    /// Columns currently ticked.
+    shown: BTreeSet<QsoColumn>,
+}
+
+/// State for the QSO list screen.
+///
+/// Rows are shown in sorted order, but every index this state hands out —
+/// [`Action::EditQso`], [`Action::DeleteQso`], marks — is a position in
+/// [`LogHeader::qsos`](crate::model::LogHeader). `selected` alone is a display row.
+#[derive(Debug)]
 pub struct QsoListState {
-    /// Index of the currently highlighted row (0-based).
+    /// Display row of the highlighted QSO (0-based).
     selected: usize,
     /// When `Some`, a delete confirmation is pending for the stored QSO index.
     pending_delete: Option<usize>,
     /// Error message from the last failed operation.
     error: Option<String>,
+    /// QSO indices marked for bulk editing.
+    marked: BTreeSet<usize>,
+    /// While extending a range with Shift-arrows: the display row it started
+    /// from and the marks that existed before it.
+    range: Option<(usize, BTreeSet<usize>)>,
+    /// The bulk-edit dialog, while open.
+    bulk_edit: Option<BulkEditDialog>,
+    /// Field the rows are sorted by.
+    sort: SortKey,
+    /// `true` to sort newest / Z / highest band first.
+    descending: bool,
+    /// Columns chosen per log type name; types not listed use [`QsoColumn::defaults`].
+    columns: BTreeMap<String, Vec<QsoColumn>>,
+    /// The column picker, while open.
+    column_picker: Option<ColumnPicker>,
 }
 
 impl Default for QsoListState {
@@ -36,11 +361,30 @@ impl QsoListState {
             selected: 0,
             pending_delete: None,
             error: None,
+            marked: BTreeSet::new(),
+            range: None,
+            bulk_edit: None,
+            sort: SortKey::default(),
+            descending: false,
+            columns: BTreeMap::new(),
+            column_picker: None,
         }
     }
 
     /// Handles a key event, returning an [`Action`] for the app to apply.
-    pub fn handle_key(&mut self, key: KeyEvent, qso_count: usize) -> Action {
+    ///
+    /// Rebindable commands (edit, delete, mark, bulk edit, sort, columns) are
+    /// resolved by the app's keymap and dispatched through
+    /// [`handle_command`](Self::handle_command).
+    pub fn handle_key(&mut self, key: KeyEvent, log: Option<&Log>) -> Action {
+        if self.bulk_edit.is_some() {
+            return self.handle_bulk_edit_key(key);
+        }
+        if self.column_picker.is_some() {
+            return self.handle_column_picker_key(key);
+        }
+        let order = self.display_order(log);
+        let qso_count = order.len();
         match self.pending_delete.take() {
             Some(index) => match key.code {
                 KeyCode::Char('y') => Action::DeleteQso(index),
@@ -50,44 +394,257 @@ impl QsoListState {
                     Action::None
                 }
             },
-            None => match key.code {
-                KeyCode::Up => {
-                    self.selected = self.selected.saturating_sub(1);
-                    Action::None
+            None => {
+                let extending = key.modifiers.contains(KeyModifiers::SHIFT)
+                    && matches!(key.code, KeyCode::Up | KeyCode::Down);
+                if extending {
+                    self.range
+                        .get_or_insert_with(|| (self.selected, self.marked.clone()));
+                } else {
+                    self.range = None;
                 }
-                KeyCode::Down => {
-                    if qso_count > 0 {
-                        self.selected = (self.selected + 1).min(qso_count - 1);
+                let action = match key.code {
+                    KeyCode::Up => {
+                        self.selected = self.selected.saturating_sub(1);
+                        Action::None
                     }
-                    Action::None
+                    KeyCode::Down => {
+                        if qso_count > 0 {
+                            self.selected = (self.selected + 1).min(qso_count - 1);
+                        }
+                        Action::None
+                    }
+                    KeyCode::Home => {
+                        self.selected = 0;
+                        Action::None
+                    }
+                    KeyCode::End => {
+                        self.selected = qso_count.saturating_sub(1);
+                        Action::None
+                    }
+                    KeyCode::Esc if !self.marked.is_empty() => {
+                        self.marked.clear();
+                        Action::None
+                    }
+                    KeyCode::Esc => Action::Navigate(Screen::QsoEntry),
+                    _ => Action::None,
+                };
+                if extending && qso_count > 0 {
+                    self.extend_range(&order);
                 }
-                KeyCode::Home => {
-                    self.selected = 0;
-                    Action::None
+                action
+            }
+        }
+    }
+
+    /// Handles a key while the bulk-edit dialog is open.
+    fn handle_bulk_edit_key(&mut self, key: KeyEvent) -> Action {
+        let Some(dialog) = self.bulk_edit.as_mut() else {
+            return Action::None;
+        };
+        match key.code {
+            KeyCode::Tab | KeyCode::Down => dialog.form.focus_next(),
+            KeyCode::BackTab | KeyCode::Up => dialog.form.focus_prev(),
+            KeyCode::Char(ch) => dialog.form.insert_char(ch),
+            KeyCode::Backspace => dialog.form.delete_char(),
+            KeyCode::Esc => self.bulk_edit = None,
+            KeyCode::Enter => {
+                let Some(changes) = dialog.changes() else {
+                    return Action::None;
+                };
+                if changes.is_empty() {
+                    self.bulk_edit = None;
+                    return Action::None;
                 }
-                KeyCode::End => {
-                    self.selected = qso_count.saturating_sub(1);
-                    Action::None
+                let indices = std::mem::take(&mut dialog.indices);
+                self.bulk_edit = None;
+                self.clear_marks();
+                return Action::BulkUpdateQsos(indices, changes);
+            }
+            _ => {}
+        }
+        Action::None
+    }
+
+    /// Handles a key while the column picker is open.
+    ///
+    /// Enter saves the choice for the log type and returns every saved choice
+    /// for the app to persist.
+    fn handle_column_picker_key(&mut self, key: KeyEvent) -> Action {
+        let Some(picker) = self.column_picker.as_mut() else {
+            return Action::None;
+        };
+        match key.code {
+            KeyCode::Up => picker.cursor = picker.cursor.saturating_sub(1),
+            KeyCode::Down => picker.cursor = (picker.cursor + 1).min(ALL_COLUMNS.len() - 1),
+            KeyCode::Char(' ') => {
+                let column = ALL_COLUMNS[picker.cursor];
+                // Keep at least one column so the table never disappears
+                if !picker.shown.remove(&column) || picker.shown.is_empty() {
+                    picker.shown.insert(column);
                 }
-                KeyCode::Enter => {
-                    if qso_count > 0 {
-                        Action::EditQso(self.selected)
-                    } else {
-                        Action::None
-                    }
+            }
+            KeyCode::Esc => self.column_picker = None,
+            KeyCode::Enter => {
+                let shown = picker.shown.iter().copied().collect();
+                self.columns.insert(picker.log_type.to_string(), shown);
+                self.column_picker = None;
+                return Action::SaveQsoColumns(self.column_names());
+            }
+            _ => {}
+        }
+        Action::None
+    }
+
+    /// Applies a keymap [`Command`], returning an [`Action`] for the app to apply.
+    pub fn handle_command(&mut self, command: Command, log: Option<&Log>) -> Action {
+        let order = self.display_order(log);
+        let current = order.get(self.selected).copied();
+        match (command, current) {
+            (Command::EditQso, Some(index)) => Action::EditQso(index),
+            (Command::DeleteQso, Some(index)) => {
+                self.pending_delete = Some(index);
+                Action::None
+            }
+            (Command::MarkQso, Some(index)) => {
+                self.range = None;
+                if !self.marked.remove(&index) {
+                    self.marked.insert(index);
                 }
-                KeyCode::Char('d') => {
-                    if qso_count > 0 {
-                        self.pending_delete = Some(self.selected);
-                    }
-                    Action::None
+                Action::None
+            }
+            (Command::BulkEdit, Some(_)) => {
+                let indices = self.marked_indices();
+                if indices.is_empty() {
+                    self.error = Some("Mark QSOs to bulk edit with Space".into());
+                } else {
+                    self.error = None;
+                    let frequency_required =
+                        matches!(log, Some(Log::FieldDay(_) | Log::WinterFieldDay(_)));
+                    self.bulk_edit = Some(BulkEditDialog::new(indices, frequency_required));
                 }
-                KeyCode::Esc => Action::Navigate(Screen::QsoEntry),
-                _ => Action::None,
-            },
+                Action::None
+            }
+            (Command::SortQsos, _) => {
+                self.sort = self.sort.next();
+                self.descending = false;
+                self.range = None;
+                Action::None
+            }
+            (Command::ReverseSort, _) => {
+                self.descending = !self.descending;
+                self.range = None;
+                Action::None
+            }
+            (Command::ChooseColumns, _) => {
+                if let Some(log) = log {
+                    self.column_picker = Some(ColumnPicker {
+                        log_type: log.log_type_name(),
+                        cursor: 0,
+                        shown: self.columns_for(log).into_iter().collect(),
+                    });
+                }
+                Action::None
+            }
+            _ => Action::None,
+        }
+    }
+
+    /// Returns the QSO indices of `log` in display order.
+    pub fn display_order(&self, log: Option<&Log>) -> Vec<usize> {
+        let qsos = log.map_or(&[][..], Log::qsos);
+        let mut order: Vec<usize> = (0..qsos.len()).collect();
+        order.sort_by(|&a, &b| self.sort.compare(&qsos[a], &qsos[b]));
+        if self.descending {
+            order.reverse();
+        }
+        order
+    }
+
+    /// Marks every row between the range start and the cursor, on top of the
+    /// marks that existed before the range began.
+    fn extend_range(&mut self, order: &[usize]) {
+        if let Some((start, base)) = &self.range {
+            let (from, to) = (*start.min(&self.selected), *start.max(&self.selected));
+            self.marked = base.clone();
+            if let Some(rows) = order.get(from..=to) {
+                self.marked.extend(rows);
+            }
         }
     }
 
+    /// Returns the marked QSO indices in ascending order.
+    pub fn marked_indices(&self) -> Vec<usize> {
+        self.marked.iter().copied().collect()
+    }
+
+    /// Returns `true` if the QSO at `index` is marked.
+    pub fn is_marked(&self, index: usize) -> bool {
+        self.marked.contains(&index)
+    }
+
+    /// Clears all marks, cancels a pending delete and closes the bulk-edit dialog.
+    ///
+    /// Called whenever the log's rows may have shifted, since each of these
+    /// holds QSO indices.
+    pub fn clear_marks(&mut self) {
+        self.pending_delete = None;
+        self.marked.clear();
+        self.range = None;
+        self.bulk_edit = None;
+    }
+
+    /// Returns `true` while the bulk-edit dialog is open.
+    pub fn bulk_edit_open(&self) -> bool {
+        self.bulk_edit.is_some()
+    }
+
+    /// Returns `true` while the column picker is open.
+    pub fn column_picker_open(&self) -> bool {
+        self.column_picker.is_some()
+    }
+
+    /// Returns the current sort key and whether it is descending.
+    pub fn sort(&self) -> (SortKey, bool) {
+        (self.sort, self.descending)
+    }
+
+    /// Returns the columns shown for `log`.
+    pub fn columns_for(&self, log: &Log) -> Vec<QsoColumn> {
+        self.columns
+            .get(log.log_type_name())
+            .cloned()
+            .unwrap_or_else(|| QsoColumn::defaults(log))
+    }
+
+    /// Restores saved column choices, as column names keyed by log type name.
+    ///
+    /// Unknown column names are skipped, and a type left with no known
+    /// columns falls back to its defaults.
+    pub fn set_column_names(&mut self, names: &BTreeMap<String, Vec<String>>) {
+        self.columns = names
+            .iter()
+            .filter_map(|(log_type, names)| {
+                let columns: Vec<QsoColumn> = names
+                    .iter()
+                    .filter_map(|n| QsoColumn::from_name(n))
+                    .collect();
+                (!columns.is_empty()).then(|| (log_type.clone(), columns))
+            })
+            .collect();
+    }
+
+    /// Returns the saved column choices as column names keyed by log type name.
+    pub fn column_names(&self) -> BTreeMap<String, Vec<String>> {
+        self.columns
+            .iter()
+            .map(|(log_type, columns)| {
+                let names = columns.iter().map(|c| c.name().to_string()).collect();
+                (log_type.clone(), names)
+            })
+            .collect()
+    }
+
     /// Clamps `selected` to the last valid index in a list of `count` items.
     ///
     /// If `count` is 0, `selected` is set to 0.
@@ -110,27 +667,44 @@ impl QsoListState {
         self.error = Some(msg);
     }
 
-    /// Returns the currently selected row index.
+    /// Returns the currently selected display row.
     pub fn selected(&self) -> usize {
         self.selected
     }
 
-    /// Sets the selected row index.
+    /// Sets the selected display row.
     pub fn set_selected(&mut self, idx: usize) {
         self.selected = idx;
     }
 
+    /// Moves the cursor to the row showing the QSO at `index` in `log`.
+    pub fn select_qso(&mut self, index: usize, log: Option<&Log>) {
+        let order = self.display_order(log);
+        self.selected = order.iter().position(|&i| i == index).unwrap_or(0);
+    }
+
     /// Resets the cursor to the first row and clears transient state.
+    ///
+    /// The sort order and column choices are kept.
     pub fn reset(&mut self) {
         self.selected = 0;
         self.pending_delete = None;
         self.error = None;
+        self.column_picker = None;
+        self.clear_marks();
     }
 }
 
 /// Renders the QSO list screen.
 #[mutants::skip]
-pub fn draw_qso_list(state: &QsoListState, log: Option<&Log>, frame: &mut Frame, area: Rect) {
+pub fn draw_qso_list(
+    state: &QsoListState,
+    log: Option<&Log>,
+    timers: &[TimerReading],
+    theme: &Theme,
+    frame: &mut Frame,
+    area: Rect,
+) {
     let [status_area, title_area, table_area, footer_area] = Layout::vertical([
         Constraint::Length(1),
         Constraint::Length(1),
@@ -139,71 +713,70 @@ pub fn draw_qso_list(state: &QsoListState, log: Option<&Log>, frame: &mut Frame,
     ])
     .areas(area);
 
-    let ctx = log.map(StatusBarContext::from_log).unwrap_or_default();
-    draw_status_bar(&ctx, frame, status_area);
+    let ctx = log
+        .map(|log| StatusBarContext::from_log(log).with_timers(timers))
+        .unwrap_or_default();
+    draw_status_bar(&ctx, theme, frame, status_area);
 
-    // Title
+    // Title, with per-operator counts for multi-op logs and the sort order
     let qso_count = log.map_or(0, |l| l.header().qsos.len());
+    let title_text = match log.map(Log::qso_counts_by_operator) {
+        Some(counts) if counts.len() > 1 => {
+            let per_op: Vec<String> = counts
+                .iter()
+                .map(|(op, count)| format!("{op} {count}"))
+                .collect();
+            format!("QSO List ({qso_count} QSOs: {})", per_op.join(", "))
+        }
+        Some(_) => format!("QSO List ({qso_count} QSOs)"),
+        None => "QSO List (no log)".to_string(),
+    };
     let title_text = if log.is_some() {
-        format!("QSO List ({qso_count} QSOs)")
+        let arrow = if state.descending { "▼" } else { "▲" };
+        format!("{title_text} — by {} {arrow}", state.sort.label())
     } else {
-        "QSO List (no log)".to_string()
+        title_text
     };
     let title = Paragraph::new(Line::from(title_text))
         .alignment(Alignment::Center)
-        .style(
-            Style::default()
-                .fg(Color::Cyan)
-                .add_modifier(Modifier::BOLD),
-        );
+        .style(theme.header);
     frame.render_widget(title, title_area);
 
-    // Table or empty state
-    if qso_count == 0 {
+    // Dialog, table, or empty state
+    if let Some(dialog) = &state.bulk_edit {
+        draw_bulk_edit(dialog, theme, frame, table_area);
+    } else if let Some(picker) = &state.column_picker {
+        draw_column_picker(picker, theme, frame, table_area);
+    } else if qso_count == 0 {
         let empty = Paragraph::new("No QSOs logged yet").alignment(Alignment::Center);
         frame.render_widget(empty, table_area);
     } else if let Some(log) = log {
-        let qsos = &log.header().qsos;
+        let qsos = log.qsos();
+        let columns = state.columns_for(log);
 
-        let header = Row::new(vec![
-            "Time", "Date", "Call", "Band", "Mode", "RST S/R", "Park", "Comments",
-        ])
-        .style(Style::default().add_modifier(Modifier::BOLD))
-        .bottom_margin(1);
+        let mut titles = vec![""];
+        titles.extend(columns.iter().map(|c| c.title()));
+        let header = Row::new(titles).style(theme.header).bottom_margin(1);
 
-        let rows: Vec<Row> = qsos
-            .iter()
+        let rows: Vec<Row> = state
+            .display_order(Some(log))
+            .into_iter()
             .enumerate()
-            .map(|(i, qso)| {
-                let style = if i == state.selected() {
-                    Style::default().fg(Color::Black).bg(Color::Yellow)
+            .map(|(row, index)| {
+                let style = if row == state.selected() {
+                    theme.selected
                 } else {
                     Style::default()
                 };
-                Row::new(vec![
-                    qso.timestamp.format("%H:%M").to_string(),
-                    qso.timestamp.format("%Y-%m-%d").to_string(),
-                    qso.their_call.clone(),
-                    qso.band.to_string(),
-                    qso.mode.to_string(),
-                    format!("{}/{}", qso.rst_sent, qso.rst_rcvd),
-                    qso.their_park.as_deref().unwrap_or("").to_string(),
-                    qso.comments.clone(),
-                ])
-                .style(style)
+                let mark = if state.is_marked(index) { "*" } else { "" };
+                let mut cells = vec![mark.to_string()];
+                cells.extend(columns.iter().map(|c| c.cell(&qsos[index])));
+                Row::new(cells).style(style)
             })
             .collect();
 
-        let widths = [
-            Constraint::Length(6),
-            Constraint::Length(11),
-            Constraint::Length(12),
-            Constraint::Length(5),
-            Constraint::Length(5),
-            Constraint::Length(8),
-            Constraint::Length(8),
-            Constraint::Min(0),
-        ];
+        let mut widths = vec![Constraint::Length(1)];
+        widths.extend(columns.iter().map(|c| c.width()));
 
         let table = Table::new(rows, widths).header(header);
         frame.render_widget(table, table_area);
@@ -212,22 +785,77 @@ pub fn draw_qso_list(state: &QsoListState, log: Option<&Log>, frame: &mut Frame,
     // Footer
     if state.pending_delete().is_some() {
         let prompt = Paragraph::new("Delete QSO? y/n")
-            .style(Style::default().fg(Color::Yellow))
+            .style(theme.warning)
             .alignment(Alignment::Center);
         frame.render_widget(prompt, footer_area);
     } else if let Some(err) = state.error() {
         let err_line = Paragraph::new(err)
-            .style(Style::default().fg(Color::Red))
+            .style(theme.error)
             .alignment(Alignment::Center);
         frame.render_widget(err_line, footer_area);
+    } else if state.bulk_edit.is_some() {
+        let footer = Paragraph::new("Tab: next field  Enter: apply  Esc: cancel  blank: unchanged")
+            .style(theme.dim);
+        frame.render_widget(footer, footer_area);
+    } else if state.column_picker.is_some() {
+        let footer = Paragraph::new("↑↓: navigate  Space: show/hide  Enter: save  Esc: cancel")
+            .style(theme.dim);
+        frame.render_widget(footer, footer_area);
+    } else if !state.marked.is_empty() {
+        let footer = Paragraph::new(format!(
+            "{} marked  Space: mark  Shift-↑↓: range  b: bulk edit  Esc: clear marks",
+            state.marked.len()
+        ))
+        .style(theme.dim);
+        frame.render_widget(footer, footer_area);
     } else {
-        let footer =
-            Paragraph::new("↑↓: navigate  Home/End: jump  Enter: edit  d: delete  Esc: back")
-                .style(Style::default().fg(Color::DarkGray));
+        let footer = Paragraph::new(
+            "↑↓: navigate  Home/End: jump  Enter: edit  d: delete  Space: mark  Esc: back",
+        )
+        .style(theme.dim);
         frame.render_widget(footer, footer_area);
     }
 }
 
+/// Renders the bulk-edit dialog in place of the table.
+#[mutants::skip]
+fn draw_bulk_edit(dialog: &BulkEditDialog, theme: &Theme, frame: &mut Frame, area: Rect) {
+    let block = Block::default()
+        .title(format!(" Bulk edit {} QSOs ", dialog.indices.len()))
+        .borders(Borders::ALL)
+        .border_style(theme.focus);
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+    draw_form(&dialog.form, theme, frame, inner);
+}
+
+/// Renders the column picker in place of the table.
+#[mutants::skip]
+fn draw_column_picker(picker: &ColumnPicker, theme: &Theme, frame: &mut Frame, area: Rect) {
+    let block = Block::default()
+        .title(format!(" Columns for {} logs ", picker.log_type))
+        .borders(Borders::ALL)
+        .border_style(theme.focus);
+    let lines: Vec<Line> = ALL_COLUMNS
+        .iter()
+        .enumerate()
+        .map(|(i, column)| {
+            let check = if picker.shown.contains(column) {
+                "x"
+            } else {
+                " "
+            };
+            let line = Line::from(format!("[{check}] {}", column.title()));
+            if i == picker.cursor {
+                line.style(theme.selected)
+            } else {
+                line
+            }
+        })
+        .collect();
+    frame.render_widget(Paragraph::new(lines).block(block), area);
+}
+
 #[cfg(test)]
 mod tests {
     use chrono::{TimeZone, Utc};
@@ -257,6 +885,7 @@ mod tests {
             None,
             None,
             None,
+            None,
         )
         .unwrap()
     }
@@ -299,7 +928,7 @@ mod tests {
         #[test]
         fn down_increments_selected() {
             let mut state = QsoListState::new();
-            let action = state.handle_key(press(KeyCode::Down), 5);
+            let action = state.handle_key(press(KeyCode::Down), Some(&make_log_with_qsos(5)));
             assert_eq!(action, Action::None);
             assert_eq!(state.selected(), 1);
         }
@@ -308,7 +937,7 @@ mod tests {
         fn up_decrements_selected() {
             let mut state = QsoListState::new();
             state.set_selected(3);
-            let action = state.handle_key(press(KeyCode::Up), 5);
+            let action = state.handle_key(press(KeyCode::Up), Some(&make_log_with_qsos(5)));
             assert_eq!(action, Action::None);
             assert_eq!(state.selected(), 2);
         }
@@ -316,7 +945,7 @@ mod tests {
         #[test]
         fn up_at_top_saturates() {
             let mut state = QsoListState::new();
-            state.handle_key(press(KeyCode::Up), 5);
+            state.handle_key(press(KeyCode::Up), Some(&make_log_with_qsos(5)));
             assert_eq!(state.selected(), 0);
         }
 
@@ -324,14 +953,14 @@ mod tests {
         fn down_at_bottom_saturates() {
             let mut state = QsoListState::new();
             state.set_selected(4);
-            state.handle_key(press(KeyCode::Down), 5);
+            state.handle_key(press(KeyCode::Down), Some(&make_log_with_qsos(5)));
             assert_eq!(state.selected(), 4);
         }
 
         #[test]
         fn down_with_empty_list_stays_at_zero() {
             let mut state = QsoListState::new();
-            state.handle_key(press(KeyCode::Down), 0);
+            state.handle_key(press(KeyCode::Down), Some(&make_log_with_qsos(0)));
             assert_eq!(state.selected(), 0);
         }
 
@@ -339,7 +968,7 @@ mod tests {
         fn home_jumps_to_first() {
             let mut state = QsoListState::new();
             state.set_selected(4);
-            let action = state.handle_key(press(KeyCode::Home), 5);
+            let action = state.handle_key(press(KeyCode::Home), Some(&make_log_with_qsos(5)));
             assert_eq!(action, Action::None);
             assert_eq!(state.selected(), 0);
         }
@@ -347,7 +976,7 @@ mod tests {
         #[test]
         fn end_jumps_to_last() {
             let mut state = QsoListState::new();
-            let action = state.handle_key(press(KeyCode::End), 5);
+            let action = state.handle_key(press(KeyCode::End), Some(&make_log_with_qsos(5)));
             assert_eq!(action, Action::None);
             assert_eq!(state.selected(), 4);
         }
@@ -355,7 +984,7 @@ mod tests {
         #[test]
         fn end_with_empty_list_stays_at_zero() {
             let mut state = QsoListState::new();
-            state.handle_key(press(KeyCode::End), 0);
+            state.handle_key(press(KeyCode::End), Some(&make_log_with_qsos(0)));
             assert_eq!(state.selected(), 0);
         }
     }
@@ -364,25 +993,33 @@ mod tests {
         use super::*;
 
         #[test]
-        fn enter_returns_edit_qso() {
+        fn edit_qso_returns_edit_qso() {
             let mut state = QsoListState::new();
-            let action = state.handle_key(press(KeyCode::Enter), 5);
+            let action = state.handle_command(Command::EditQso, Some(&make_log_with_qsos(5)));
             assert_eq!(action, Action::EditQso(0));
         }
 
         #[test]
-        fn enter_returns_selected_index() {
+        fn edit_qso_returns_selected_index() {
             let mut state = QsoListState::new();
             state.set_selected(3);
-            let action = state.handle_key(press(KeyCode::Enter), 5);
+            let action = state.handle_command(Command::EditQso, Some(&make_log_with_qsos(5)));
             assert_eq!(action, Action::EditQso(3));
         }
 
         #[test]
-        fn enter_on_empty_list_returns_none() {
+        fn edit_qso_on_empty_list_returns_none() {
+            let mut state = QsoListState::new();
+            let action = state.handle_command(Command::EditQso, Some(&make_log_with_qsos(0)));
+            assert_eq!(action, Action::None);
+        }
+
+        #[test]
+        fn unrelated_command_returns_none() {
             let mut state = QsoListState::new();
-            let action = state.handle_key(press(KeyCode::Enter), 0);
+            let action = state.handle_command(Command::NextBand, Some(&make_log_with_qsos(5)));
             assert_eq!(action, Action::None);
+            assert_eq!(state.pending_delete(), None);
         }
     }
 
@@ -392,14 +1029,14 @@ mod tests {
         #[test]
         fn esc_navigates_to_qso_entry() {
             let mut state = QsoListState::new();
-            let action = state.handle_key(press(KeyCode::Esc), 5);
+            let action = state.handle_key(press(KeyCode::Esc), Some(&make_log_with_qsos(5)));
             assert_eq!(action, Action::Navigate(Screen::QsoEntry));
         }
 
         #[test]
         fn q_is_ignored() {
             let mut state = QsoListState::new();
-            let action = state.handle_key(press(KeyCode::Char('q')), 5);
+            let action = state.handle_key(press(KeyCode::Char('q')), Some(&make_log_with_qsos(5)));
             assert_eq!(action, Action::None);
         }
     }
@@ -410,14 +1047,14 @@ mod tests {
         #[test]
         fn unhandled_key_returns_none() {
             let mut state = QsoListState::new();
-            let action = state.handle_key(press(KeyCode::Char('x')), 5);
+            let action = state.handle_key(press(KeyCode::Char('x')), Some(&make_log_with_qsos(5)));
             assert_eq!(action, Action::None);
         }
 
         #[test]
         fn f1_returns_none() {
             let mut state = QsoListState::new();
-            let action = state.handle_key(press(KeyCode::F(1)), 5);
+            let action = state.handle_key(press(KeyCode::F(1)), Some(&make_log_with_qsos(5)));
             assert_eq!(action, Action::None);
         }
     }
@@ -426,18 +1063,18 @@ mod tests {
         use super::*;
 
         #[test]
-        fn d_on_empty_list_is_noop() {
+        fn delete_qso_on_empty_list_is_noop() {
             let mut state = QsoListState::new();
-            let action = state.handle_key(press(KeyCode::Char('d')), 0);
+            let action = state.handle_command(Command::DeleteQso, Some(&make_log_with_qsos(0)));
             assert_eq!(action, Action::None);
             assert_eq!(state.pending_delete(), None);
         }
 
         #[test]
-        fn d_on_populated_list_sets_pending() {
+        fn delete_qso_on_populated_list_sets_pending() {
             let mut state = QsoListState::new();
             state.set_selected(2);
-            let action = state.handle_key(press(KeyCode::Char('d')), 5);
+            let action = state.handle_command(Command::DeleteQso, Some(&make_log_with_qsos(5)));
             assert_eq!(action, Action::None);
             assert_eq!(state.pending_delete(), Some(2));
         }
@@ -446,8 +1083,8 @@ mod tests {
         fn y_while_pending_returns_delete_qso() {
             let mut state = QsoListState::new();
             state.set_selected(1);
-            state.handle_key(press(KeyCode::Char('d')), 3);
-            let action = state.handle_key(press(KeyCode::Char('y')), 3);
+            state.handle_command(Command::DeleteQso, Some(&make_log_with_qsos(3)));
+            let action = state.handle_key(press(KeyCode::Char('y')), Some(&make_log_with_qsos(3)));
             assert_eq!(action, Action::DeleteQso(1));
             assert_eq!(state.pending_delete(), None);
         }
@@ -455,8 +1092,8 @@ mod tests {
         #[test]
         fn n_while_pending_cancels() {
             let mut state = QsoListState::new();
-            state.handle_key(press(KeyCode::Char('d')), 3);
-            let action = state.handle_key(press(KeyCode::Char('n')), 3);
+            state.handle_command(Command::DeleteQso, Some(&make_log_with_qsos(3)));
+            let action = state.handle_key(press(KeyCode::Char('n')), Some(&make_log_with_qsos(3)));
             assert_eq!(action, Action::None);
             assert_eq!(state.pending_delete(), None);
         }
@@ -464,8 +1101,8 @@ mod tests {
         #[test]
         fn esc_while_pending_cancels() {
             let mut state = QsoListState::new();
-            state.handle_key(press(KeyCode::Char('d')), 3);
-            let action = state.handle_key(press(KeyCode::Esc), 3);
+            state.handle_command(Command::DeleteQso, Some(&make_log_with_qsos(3)));
+            let action = state.handle_key(press(KeyCode::Esc), Some(&make_log_with_qsos(3)));
             assert_eq!(action, Action::None);
             assert_eq!(state.pending_delete(), None);
         }
@@ -473,13 +1110,625 @@ mod tests {
         #[test]
         fn other_key_while_pending_restores_pending() {
             let mut state = QsoListState::new();
-            state.handle_key(press(KeyCode::Char('d')), 3);
-            let action = state.handle_key(press(KeyCode::Char('x')), 3);
+            state.handle_command(Command::DeleteQso, Some(&make_log_with_qsos(3)));
+            let action = state.handle_key(press(KeyCode::Char('x')), Some(&make_log_with_qsos(3)));
             assert_eq!(action, Action::None);
             assert_eq!(state.pending_delete(), Some(0));
         }
     }
 
+    fn shift_press(code: KeyCode) -> KeyEvent {
+        KeyEvent {
+            modifiers: KeyModifiers::SHIFT,
+            ..press(code)
+        }
+    }
+
+    fn type_text(state: &mut QsoListState, text: &str) {
+        for ch in text.chars() {
+            state.handle_key(press(KeyCode::Char(ch)), Some(&make_log_with_qsos(5)));
+        }
+    }
+
+    mod marking {
+        use super::*;
+
+        #[test]
+        fn mark_qso_toggles_selected_row() {
+            let mut state = QsoListState::new();
+            state.set_selected(2);
+            assert_eq!(
+                state.handle_command(Command::MarkQso, Some(&make_log_with_qsos(5))),
+                Action::None
+            );
+            assert!(state.is_marked(2));
+            state.handle_command(Command::MarkQso, Some(&make_log_with_qsos(5)));
+            assert!(!state.is_marked(2));
+        }
+
+        #[test]
+        fn mark_qso_on_empty_list_is_noop() {
+            let mut state = QsoListState::new();
+            state.handle_command(Command::MarkQso, Some(&make_log_with_qsos(0)));
+            assert!(state.marked_indices().is_empty());
+        }
+
+        #[test]
+        fn marks_survive_plain_navigation() {
+            let mut state = QsoListState::new();
+            state.handle_command(Command::MarkQso, Some(&make_log_with_qsos(5)));
+            state.handle_key(press(KeyCode::Down), Some(&make_log_with_qsos(5)));
+            state.handle_key(press(KeyCode::Down), Some(&make_log_with_qsos(5)));
+            state.handle_command(Command::MarkQso, Some(&make_log_with_qsos(5)));
+            assert_eq!(state.marked_indices(), vec![0, 2]);
+        }
+
+        #[test]
+        fn shift_down_extends_range_from_anchor() {
+            let mut state = QsoListState::new();
+            state.set_selected(1);
+            state.handle_key(shift_press(KeyCode::Down), Some(&make_log_with_qsos(5)));
+            state.handle_key(shift_press(KeyCode::Down), Some(&make_log_with_qsos(5)));
+            assert_eq!(state.selected(), 3);
+            assert_eq!(state.marked_indices(), vec![1, 2, 3]);
+        }
+
+        #[test]
+        fn shift_up_shrinks_range_back_toward_anchor() {
+            let mut state = QsoListState::new();
+            state.handle_key(shift_press(KeyCode::Down), Some(&make_log_with_qsos(5)));
+            state.handle_key(shift_press(KeyCode::Down), Some(&make_log_with_qsos(5)));
+            state.handle_key(shift_press(KeyCode::Up), Some(&make_log_with_qsos(5)));
+            assert_eq!(state.marked_indices(), vec![0, 1]);
+        }
+
+        #[test]
+        fn shift_up_extends_range_upwards() {
+            let mut state = QsoListState::new();
+            state.set_selected(3);
+            state.handle_key(shift_press(KeyCode::Up), Some(&make_log_with_qsos(5)));
+            assert_eq!(state.marked_indices(), vec![2, 3]);
+        }
+
+        #[test]
+        fn range_is_kept_after_plain_navigation() {
+            let mut state = QsoListState::new();
+            state.handle_key(shift_press(KeyCode::Down), Some(&make_log_with_qsos(5)));
+            state.handle_key(press(KeyCode::End), Some(&make_log_with_qsos(5)));
+            state.handle_key(shift_press(KeyCode::Up), Some(&make_log_with_qsos(5)));
+            assert_eq!(state.marked_indices(), vec![0, 1, 3, 4]);
+        }
+
+        #[test]
+        fn esc_clears_marks_before_leaving() {
+            let mut state = QsoListState::new();
+            state.handle_command(Command::MarkQso, Some(&make_log_with_qsos(5)));
+            assert_eq!(
+                state.handle_key(press(KeyCode::Esc), Some(&make_log_with_qsos(5))),
+                Action::None
+            );
+            assert!(state.marked_indices().is_empty());
+            assert_eq!(
+                state.handle_key(press(KeyCode::Esc), Some(&make_log_with_qsos(5))),
+                Action::Navigate(Screen::QsoEntry)
+            );
+        }
+
+        #[test]
+        fn reset_clears_marks() {
+            let mut state = QsoListState::new();
+            state.handle_key(shift_press(KeyCode::Down), Some(&make_log_with_qsos(5)));
+            state.reset();
+            assert!(state.marked_indices().is_empty());
+        }
+    }
+
+    mod bulk_edit {
+        use super::*;
+        use crate::model::{FdClass, FdPowerCategory, FieldDayLog};
+
+        fn open_dialog() -> QsoListState {
+            let mut state = QsoListState::new();
+            state.handle_key(shift_press(KeyCode::Down), Some(&make_log_with_qsos(5)));
+            state.handle_command(Command::BulkEdit, Some(&make_log_with_qsos(5)));
+            assert!(state.bulk_edit_open());
+            state
+        }
+
+        /// Moves focus to the bulk-edit field at `index`.
+        fn focus(state: &mut QsoListState, index: usize) {
+            for _ in 0..index {
+                state.handle_key(press(KeyCode::Tab), Some(&make_log_with_qsos(5)));
+            }
+        }
+
+        #[test]
+        fn bulk_edit_without_marks_shows_error() {
+            let mut state = QsoListState::new();
+            state.handle_command(Command::BulkEdit, Some(&make_log_with_qsos(5)));
+            assert!(!state.bulk_edit_open());
+            assert_eq!(state.error(), Some("Mark QSOs to bulk edit with Space"));
+        }
+
+        #[test]
+        fn enter_returns_changes_for_marked_rows() {
+            let mut state = open_dialog();
+            type_text(&mut state, "40m");
+            focus(&mut state, BULK_RST_SENT);
+            type_text(&mut state, "599");
+            focus(&mut state, BULK_COMMENTS - BULK_RST_SENT);
+            type_text(&mut state, "QRP");
+            let action = state.handle_key(press(KeyCode::Enter), Some(&make_log_with_qsos(5)));
+            assert_eq!(
+                action,
+                Action::BulkUpdateQsos(
+                    vec![0, 1],
+                    QsoChanges {
+                        band: Some(Band::M40),
+                        rst_sent: Some("599".to_string()),
+                        comments: Some("QRP".to_string()),
+                        ..QsoChanges::default()
+                    }
+                )
+            );
+            assert!(!state.bulk_edit_open());
+            assert!(state.marked_indices().is_empty());
+        }
+
+        #[test]
+        fn frequency_without_band_selects_band() {
+            let mut state = open_dialog();
+            focus(&mut state, BULK_FREQUENCY);
+            type_text(&mut state, "7030");
+            let Action::BulkUpdateQsos(_, changes) =
+                state.handle_key(press(KeyCode::Enter), Some(&make_log_with_qsos(5)))
+            else {
+                panic!("expected bulk update");
+            };
+            assert_eq!(changes.frequency, Some(7_030_000));
+            assert_eq!(changes.band, Some(Band::M40));
+        }
+
+        #[test]
+        fn fractional_frequency_selects_lf_band() {
+            let mut state = open_dialog();
+            focus(&mut state, BULK_FREQUENCY);
+            type_text(&mut state, "137.5");
+            let Action::BulkUpdateQsos(_, changes) =
+                state.handle_key(press(KeyCode::Enter), Some(&make_log_with_qsos(5)))
+            else {
+                panic!("expected bulk update");
+            };
+            assert_eq!(changes.frequency, Some(137_500));
+            assert_eq!(changes.band, Some(Band::M2190));
+        }
+
+        #[test]
+        fn frequency_outside_band_is_rejected() {
+            let mut state = open_dialog();
+            type_text(&mut state, "40m");
+            focus(&mut state, BULK_FREQUENCY - BULK_BAND);
+            type_text(&mut state, "14030");
+            assert_eq!(
+                state.handle_key(press(KeyCode::Enter), Some(&make_log_with_qsos(5))),
+                Action::None
+            );
+            let dialog = state.bulk_edit.as_ref().unwrap();
+            assert_eq!(
+                dialog.form.fields()[BULK_FREQUENCY].error(),
+                Some("14030 kHz is not in the 40M band")
+            );
+        }
+
+        fn make_fd_log(n: usize) -> Log {
+            let mut fd = FieldDayLog::new(
+                "W1AW".to_string(),
+                None,
+                1,
+                FdClass::B,
+                "EPA".to_string(),
+                FdPowerCategory::Low,
+                "FN31".to_string(),
+            )
+            .unwrap();
+            fd.header.qsos = make_log_with_qsos(n).qsos().to_vec();
+            Log::FieldDay(fd)
+        }
+
+        fn open_fd_dialog(log: &Log) -> QsoListState {
+            let mut state = QsoListState::new();
+            state.handle_key(shift_press(KeyCode::Down), Some(log));
+            state.handle_command(Command::BulkEdit, Some(log));
+            assert!(state.bulk_edit_open());
+            state
+        }
+
+        #[test]
+        fn contest_band_change_requires_frequency() {
+            let log = make_fd_log(3);
+            let mut state = open_fd_dialog(&log);
+            type_text(&mut state, "40m");
+            assert_eq!(
+                state.handle_key(press(KeyCode::Enter), Some(&log)),
+                Action::None
+            );
+            let dialog = state.bulk_edit.as_ref().unwrap();
+            assert_eq!(
+                dialog.form.fields()[BULK_FREQUENCY].error(),
+                Some("a band change needs a frequency in this log")
+            );
+        }
+
+        #[test]
+        fn contest_band_change_with_frequency_in_band_is_accepted() {
+            let log = make_fd_log(3);
+            let mut state = open_fd_dialog(&log);
+            type_text(&mut state, "40m");
+            focus(&mut state, BULK_FREQUENCY - BULK_BAND);
+            type_text(&mut state, "7030");
+            let Action::BulkUpdateQsos(_, changes) =
+                state.handle_key(press(KeyCode::Enter), Some(&log))
+            else {
+                panic!("expected bulk update");
+            };
+            assert_eq!(changes.band, Some(Band::M40));
+            assert_eq!(changes.frequency, Some(7_030_000));
+        }
+
+        #[test]
+        fn mode_is_parsed_case_insensitively() {
+            let mut state = open_dialog();
+            focus(&mut state, BULK_MODE);
+            type_text(&mut state, "cw");
+            let Action::BulkUpdateQsos(_, changes) =
+                state.handle_key(press(KeyCode::Enter), Some(&make_log_with_qsos(5)))
+            else {
+                panic!("expected bulk update");
+            };
+            assert_eq!(changes.mode, Some(Mode::Cw));
+        }
+
+        #[test]
+        fn invalid_values_keep_dialog_open() {
+            let mut state = open_dialog();
+            type_text(&mut state, "21m");
+            focus(&mut state, BULK_FREQUENCY - BULK_BAND);
+            type_text(&mut state, "abc");
+            assert_eq!(
+                state.handle_key(press(KeyCode::Enter), Some(&make_log_with_qsos(5))),
+                Action::None
+            );
+            assert!(state.bulk_edit_open());
+            let dialog = state.bulk_edit.as_ref().unwrap();
+            assert_eq!(
+                dialog.form.fields()[BULK_BAND].error(),
+                Some("unknown band \"21m\"")
+            );
+            assert!(dialog.form.fields()[BULK_FREQUENCY].error().is_some());
+        }
+
+        #[test]
+        fn unknown_mode_is_rejected() {
+            let mut state = open_dialog();
+            focus(&mut state, BULK_MODE);
+            type_text(&mut state, "SSTV2");
+            assert_eq!(
+                state.handle_key(press(KeyCode::Enter), Some(&make_log_with_qsos(5))),
+                Action::None
+            );
+            assert!(state.bulk_edit_open());
+        }
+
+        #[test]
+        fn all_blank_closes_without_changes() {
+            let mut state = open_dialog();
+            assert_eq!(
+                state.handle_key(press(KeyCode::Enter), Some(&make_log_with_qsos(5))),
+                Action::None
+            );
+            assert!(!state.bulk_edit_open());
+            assert_eq!(state.marked_indices(), vec![0, 1], "marks are kept");
+        }
+
+        #[test]
+        fn esc_cancels_and_keeps_marks() {
+            let mut state = open_dialog();
+            type_text(&mut state, "40m");
+            assert_eq!(
+                state.handle_key(press(KeyCode::Esc), Some(&make_log_with_qsos(5))),
+                Action::None
+            );
+            assert!(!state.bulk_edit_open());
+            assert_eq!(state.marked_indices(), vec![0, 1]);
+        }
+
+        #[test]
+        fn backspace_edits_focused_field() {
+            let mut state = open_dialog();
+            type_text(&mut state, "40mm");
+            state.handle_key(press(KeyCode::Backspace), Some(&make_log_with_qsos(5)));
+            let dialog = state.bulk_edit.as_ref().unwrap();
+            assert_eq!(dialog.form.value(BULK_BAND), "40m");
+        }
+
+        #[test]
+        fn clear_marks_closes_dialog() {
+            let mut state = open_dialog();
+            state.clear_marks();
+            assert!(!state.bulk_edit_open());
+        }
+
+        #[test]
+        fn clear_marks_cancels_pending_delete() {
+            let mut state = QsoListState::new();
+            state.handle_command(Command::DeleteQso, Some(&make_log_with_qsos(3)));
+            assert_eq!(state.pending_delete(), Some(0));
+            state.clear_marks();
+            assert_eq!(state.pending_delete(), None);
+        }
+    }
+
+    /// A log whose insertion order differs from time, call, band and park order.
+    fn make_mixed_log() -> Log {
+        let mut log = make_log_with_qsos(0);
+        for (call, band, minute, park) in [
+            ("W2BB", Band::M40, 32, Some("K-0002")),
+            ("W1AA", Band::M20, 31, None),
+            ("W3CC", Band::M80, 30, Some("K-0001")),
+        ] {
+            let mut qso = make_qso(call);
+            qso.band = band;
+            qso.timestamp = Utc.with_ymd_and_hms(2026, 2, 16, 14, minute, 0).unwrap();
+            qso.their_park = park.map(str::to_string);
+            log.add_qso(qso);
+        }
+        log
+    }
+
+    mod sorting {
+        use super::*;
+
+        fn order_after(commands: &[Command]) -> Vec<usize> {
+            let log = make_mixed_log();
+            let mut state = QsoListState::new();
+            for &command in commands {
+                state.handle_command(command, Some(&log));
+            }
+            state.display_order(Some(&log))
+        }
+
+        #[test]
+        fn default_sort_is_time_ascending() {
+            assert_eq!(order_after(&[]), vec![2, 1, 0]);
+            assert_eq!(QsoListState::new().sort(), (SortKey::Time, false));
+        }
+
+        #[test]
+        fn sort_cycles_through_keys() {
+            let mut state = QsoListState::new();
+            let keys: Vec<SortKey> = (0..5)
+                .map(|_| {
+                    state.handle_command(Command::SortQsos, None);
+                    state.sort().0
+                })
+                .collect();
+            assert_eq!(
+                keys,
+                vec![
+                    SortKey::Call,
+                    SortKey::Band,
+                    SortKey::Mode,
+                    SortKey::Park,
+                    SortKey::Time
+                ]
+            );
+        }
+
+        #[test]
+        fn sort_by_call() {
+            assert_eq!(order_after(&[Command::SortQsos]), vec![1, 0, 2]);
+        }
+
+        #[test]
+        fn sort_by_band_uses_frequency_order() {
+            let order = order_after(&[Command::SortQsos, Command::SortQsos]);
+            assert_eq!(order, vec![2, 0, 1], "80m, 40m, 20m");
+        }
+
+        #[test]
+        fn sort_by_mode_falls_back_to_time() {
+            let order = order_after(&[Command::SortQsos; 3]);
+            assert_eq!(order, vec![2, 1, 0]);
+        }
+
+        #[test]
+        fn sort_by_park_puts_missing_parks_last() {
+            let order = order_after(&[Command::SortQsos; 4]);
+            assert_eq!(order, vec![2, 0, 1]);
+        }
+
+        #[test]
+        fn reverse_sort_flips_order() {
+            assert_eq!(order_after(&[Command::ReverseSort]), vec![0, 1, 2]);
+        }
+
+        #[test]
+        fn changing_sort_key_resets_direction() {
+            let mut state = QsoListState::new();
+            state.handle_command(Command::ReverseSort, None);
+            state.handle_command(Command::SortQsos, None);
+            assert_eq!(state.sort(), (SortKey::Call, false));
+        }
+
+        #[test]
+        fn edit_maps_display_row_to_log_index() {
+            let log = make_mixed_log();
+            let mut state = QsoListState::new();
+            state.handle_command(Command::SortQsos, Some(&log));
+            // Sorted by call: W1AA (index 1) is the first row
+            assert_eq!(
+                state.handle_command(Command::EditQso, Some(&log)),
+                Action::EditQso(1)
+            );
+            state.handle_key(press(KeyCode::End), Some(&log));
+            assert_eq!(
+                state.handle_command(Command::EditQso, Some(&log)),
+                Action::EditQso(2)
+            );
+        }
+
+        #[test]
+        fn delete_maps_display_row_to_log_index() {
+            let log = make_mixed_log();
+            let mut state = QsoListState::new();
+            state.handle_command(Command::DeleteQso, Some(&log));
+            assert_eq!(state.pending_delete(), Some(2), "earliest QSO is row 0");
+            assert_eq!(
+                state.handle_key(press(KeyCode::Char('y')), Some(&log)),
+                Action::DeleteQso(2)
+            );
+        }
+
+        #[test]
+        fn marks_use_log_indices_and_survive_resorting() {
+            let log = make_mixed_log();
+            let mut state = QsoListState::new();
+            state.handle_key(shift_press(KeyCode::Down), Some(&log));
+            assert_eq!(state.marked_indices(), vec![1, 2]);
+            state.handle_command(Command::ReverseSort, Some(&log));
+            assert_eq!(state.marked_indices(), vec![1, 2]);
+        }
+    }
+
+    mod columns {
+        use super::*;
+        use crate::model::{FdClass, FdPowerCategory, FieldDayLog};
+
+        #[test]
+        fn column_names_round_trip() {
+            for &column in QsoColumn::all() {
+                assert_eq!(QsoColumn::from_name(column.name()), Some(column));
+            }
+            assert_eq!(QsoColumn::from_name("bogus"), None);
+        }
+
+        #[test]
+        fn defaults_depend_on_log_type() {
+            let pota = make_log_with_qsos(0);
+            assert!(
+                QsoListState::new()
+                    .columns_for(&pota)
+                    .contains(&QsoColumn::Park)
+            );
+            let fd = Log::FieldDay(
+                FieldDayLog::new(
+                    "W1AW".to_string(),
+                    None,
+                    1,
+                    FdClass::B,
+                    "EPA".to_string(),
+                    FdPowerCategory::Low,
+                    "FN31".to_string(),
+                )
+                .unwrap(),
+            );
+            let fd_columns = QsoColumn::defaults(&fd);
+            assert!(fd_columns.contains(&QsoColumn::Exchange));
+            assert!(!fd_columns.contains(&QsoColumn::Park));
+        }
+
+        #[test]
+        fn picker_toggles_and_saves_for_log_type() {
+            let log = make_log_with_qsos(1);
+            let mut state = QsoListState::new();
+            state.handle_command(Command::ChooseColumns, Some(&log));
+            assert!(state.column_picker_open());
+            // Hide Time (first), show Frequency (sixth)
+            state.handle_key(press(KeyCode::Char(' ')), Some(&log));
+            for _ in 0..5 {
+                state.handle_key(press(KeyCode::Down), Some(&log));
+            }
+            state.handle_key(press(KeyCode::Char(' ')), Some(&log));
+            let action = state.handle_key(press(KeyCode::Enter), Some(&log));
+
+            let expected = vec![
+                "date",
+                "call",
+                "band",
+                "mode",
+                "frequency",
+                "rst",
+                "park",
+                "comments",
+            ];
+            let Action::SaveQsoColumns(saved) = action else {
+                panic!("expected SaveQsoColumns, got {action:?}");
+            };
+            assert_eq!(saved["POTA"], expected);
+            assert!(!state.column_picker_open());
+            assert_eq!(
+                state.columns_for(&log),
+                expected
+                    .iter()
+                    .map(|n| QsoColumn::from_name(n).unwrap())
+                    .collect::<Vec<_>>()
+            );
+        }
+
+        #[test]
+        fn picker_keeps_at_least_one_column() {
+            let log = make_log_with_qsos(1);
+            let mut state = QsoListState::new();
+            state.set_column_names(&BTreeMap::from([(
+                "POTA".to_string(),
+                vec!["time".to_string()],
+            )]));
+            state.handle_command(Command::ChooseColumns, Some(&log));
+            state.handle_key(press(KeyCode::Char(' ')), Some(&log));
+            state.handle_key(press(KeyCode::Enter), Some(&log));
+            assert_eq!(state.columns_for(&log), vec![QsoColumn::Time]);
+        }
+
+        #[test]
+        fn esc_cancels_picker() {
+            let log = make_log_with_qsos(1);
+            let mut state = QsoListState::new();
+            state.handle_command(Command::ChooseColumns, Some(&log));
+            state.handle_key(press(KeyCode::Char(' ')), Some(&log));
+            assert_eq!(
+                state.handle_key(press(KeyCode::Esc), Some(&log)),
+                Action::None
+            );
+            assert!(!state.column_picker_open());
+            assert_eq!(state.columns_for(&log), QsoColumn::defaults(&log));
+        }
+
+        #[test]
+        fn picker_without_log_is_noop() {
+            let mut state = QsoListState::new();
+            state.handle_command(Command::ChooseColumns, None);
+            assert!(!state.column_picker_open());
+        }
+
+        #[test]
+        fn set_column_names_skips_unknown_names() {
+            let log = make_log_with_qsos(0);
+            let mut state = QsoListState::new();
+            state.set_column_names(&BTreeMap::from([
+                (
+                    "POTA".to_string(),
+                    vec!["call".to_string(), "future_column".to_string()],
+                ),
+                ("FD".to_string(), vec!["future_column".to_string()]),
+            ]));
+            assert_eq!(state.columns_for(&log), vec![QsoColumn::Call]);
+            assert_eq!(
+                state.column_names(),
+                BTreeMap::from([("POTA".to_string(), vec!["call".to_string()])])
+            );
+        }
+    }
+
     mod clamp_selection {
         use super::*;
 
@@ -529,7 +1778,7 @@ mod tests {
         #[test]
         fn reset_clears_pending_delete() {
             let mut state = QsoListState::new();
-            state.handle_key(press(KeyCode::Char('d')), 3);
+            state.handle_command(Command::DeleteQso, Some(&make_log_with_qsos(3)));
             assert!(state.pending_delete().is_some());
             state.reset();
             assert_eq!(state.pending_delete(), None);
@@ -547,10 +1796,12 @@ mod tests {
     mod rendering {
         use ratatui::Terminal;
         use ratatui::backend::TestBackend;
+        use ratatui::style::{Color, Modifier};
 
         use super::*;
 
         use crate::tui::test_utils::buffer_to_string;
+        use crate::tui::theme::ThemeName;
 
         fn render_qso_list(
             state: &QsoListState,
@@ -562,7 +1813,7 @@ mod tests {
             let mut terminal = Terminal::new(backend).unwrap();
             terminal
                 .draw(|frame| {
-                    draw_qso_list(state, log, frame, frame.area());
+                    draw_qso_list(state, log, &[], &Theme::default(), frame, frame.area());
                 })
                 .unwrap();
             buffer_to_string(terminal.backend().buffer())
@@ -579,6 +1830,22 @@ mod tests {
             );
         }
 
+        #[test]
+        fn renders_per_operator_counts_for_multi_op_log() {
+            let state = QsoListState::new();
+            let mut log = make_log_with_qsos(1);
+            for op in ["K1ABC", "K1ABC"] {
+                let mut qso = make_qso("N0CALL");
+                qso.operator = Some(op.to_string());
+                log.add_qso(qso);
+            }
+            let output = render_qso_list(&state, Some(&log), 80, 20);
+            assert!(
+                output.contains("QSO List (3 QSOs: K1ABC 2, W1AW 1)"),
+                "should show per-operator counts: {output}"
+            );
+        }
+
         #[test]
         fn renders_no_log_title() {
             let state = QsoListState::new();
@@ -642,7 +1909,14 @@ mod tests {
             let mut terminal = Terminal::new(backend).unwrap();
             terminal
                 .draw(|frame| {
-                    draw_qso_list(&state, Some(&log), frame, frame.area());
+                    draw_qso_list(
+                        &state,
+                        Some(&log),
+                        &[],
+                        &Theme::default(),
+                        frame,
+                        frame.area(),
+                    );
                 })
                 .unwrap();
 
@@ -664,6 +1938,24 @@ mod tests {
             );
         }
 
+        #[test]
+        fn selected_row_uses_theme() {
+            let state = QsoListState::new();
+            let log = make_log_with_qsos(3);
+            let theme = Theme::new(ThemeName::Monochrome);
+            let backend = TestBackend::new(80, 20);
+            let mut terminal = Terminal::new(backend).unwrap();
+            terminal
+                .draw(|frame| {
+                    draw_qso_list(&state, Some(&log), &[], &theme, frame, frame.area());
+                })
+                .unwrap();
+
+            let cell = &terminal.backend().buffer()[(0, 4)];
+            assert!(cell.modifier.contains(Modifier::REVERSED));
+            assert_eq!(cell.bg, Color::Reset);
+        }
+
         #[test]
         fn renders_park_and_comments() {
             let state = QsoListState::new();
@@ -679,6 +1971,7 @@ mod tests {
                 Some("K-5678".to_string()),
                 None,
                 None,
+                None,
             )
             .unwrap();
             log.add_qso(qso);
@@ -706,18 +1999,86 @@ mod tests {
         }
 
         #[test]
-        fn pending_delete_shows_confirmation_prompt() {
+        fn marked_rows_show_mark_and_count() {
             let mut state = QsoListState::new();
             let log = make_log_with_qsos(3);
-            state.handle_key(
-                KeyEvent {
-                    code: KeyCode::Char('d'),
-                    modifiers: crossterm::event::KeyModifiers::NONE,
-                    kind: crossterm::event::KeyEventKind::Press,
-                    state: crossterm::event::KeyEventState::NONE,
-                },
-                3,
+            state.handle_key(shift_press(KeyCode::Down), Some(&make_log_with_qsos(3)));
+            let output = render_qso_list(&state, Some(&log), 80, 20);
+            assert_eq!(output.matches('*').count(), 2, "{output}");
+            assert!(output.contains("2 marked"), "{output}");
+            assert!(output.contains("b: bulk edit"), "{output}");
+        }
+
+        #[test]
+        fn bulk_edit_dialog_replaces_table() {
+            let mut state = QsoListState::new();
+            let log = make_log_with_qsos(3);
+            state.handle_command(Command::MarkQso, Some(&make_log_with_qsos(3)));
+            state.handle_command(Command::BulkEdit, Some(&make_log_with_qsos(3)));
+            let output = render_qso_list(&state, Some(&log), 80, 24);
+            assert!(output.contains("Bulk edit 1 QSOs"), "{output}");
+            assert!(output.contains("Frequency (kHz)"), "{output}");
+            assert!(!output.contains("W0AW"), "table should be hidden");
+            assert!(output.contains("Enter: apply"), "{output}");
+        }
+
+        #[test]
+        fn renders_rows_in_sort_order() {
+            let mut state = QsoListState::new();
+            let log = make_mixed_log();
+            state.handle_command(Command::SortQsos, Some(&log));
+            let output = render_qso_list(&state, Some(&log), 100, 20);
+            let pos = |call| output.find(call).unwrap();
+            assert!(pos("W1AA") < pos("W2BB") && pos("W2BB") < pos("W3CC"));
+            assert!(output.contains("by call ▲"), "{output}");
+        }
+
+        #[test]
+        fn renders_chosen_columns_only() {
+            let mut state = QsoListState::new();
+            let mut log = make_log_with_qsos(0);
+            let mut qso = make_qso("W1AA");
+            qso.frequency = Some(14_074_000);
+            qso.exchange_rcvd = Some("3A CT".to_string());
+            log.add_qso(qso);
+            state.set_column_names(&BTreeMap::from([(
+                "POTA".to_string(),
+                vec![
+                    "call".to_string(),
+                    "frequency".to_string(),
+                    "exchange".to_string(),
+                ],
+            )]));
+            let output = render_qso_list(&state, Some(&log), 80, 20);
+            assert!(
+                output.contains("Freq") && output.contains("14074"),
+                "{output}"
             );
+            assert!(
+                output.contains("Exch") && output.contains("3A CT"),
+                "{output}"
+            );
+            assert!(!output.contains("Park"), "{output}");
+            assert!(!output.contains("Comments"), "{output}");
+        }
+
+        #[test]
+        fn column_picker_replaces_table() {
+            let mut state = QsoListState::new();
+            let log = make_log_with_qsos(3);
+            state.handle_command(Command::ChooseColumns, Some(&log));
+            let output = render_qso_list(&state, Some(&log), 80, 20);
+            assert!(output.contains("Columns for POTA logs"), "{output}");
+            assert!(output.contains("[x] Time"), "{output}");
+            assert!(output.contains("[ ] Freq"), "{output}");
+            assert!(output.contains("Space: show/hide"), "{output}");
+        }
+
+        #[test]
+        fn pending_delete_shows_confirmation_prompt() {
+            let mut state = QsoListState::new();
+            let log = make_log_with_qsos(3);
+            state.handle_command(Command::DeleteQso, Some(&make_log_with_qsos(3)));
             let output = render_qso_list(&state, Some(&log), 80, 20);
             assert!(
                 output.contains("Delete QSO? y/n"),
@@ -0,0 +1,575 @@
+//! Search screen — find QSOs in every log and jump to one.
+
+use std::collections::HashSet;
+
+use chrono::NaiveDate;
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::Frame;
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::Style;
+use ratatui::text::Span;
+use ratatui::widgets::{Block, Borders, Paragraph, Row, Table};
+
+use crate::model::{Band, Mode, QsoQuery};
+use crate::storage::WorkedEntry;
+use crate::tui::action::Action;
+use crate::tui::app::Screen;
+use crate::tui::theme::Theme;
+use crate::tui::widgets::form::{Form, FormField, draw_form_field};
+
+/// Search field index for the callsign.
+const SEARCH_CALLSIGN: usize = 0;
+/// Search field index for their park reference.
+const SEARCH_PARK: usize = 1;
+/// Search field index for the band.
+const SEARCH_BAND: usize = 2;
+/// Search field index for the mode.
+const SEARCH_MODE: usize = 3;
+/// Search field index for the first date.
+const SEARCH_FROM: usize = 4;
+/// Search field index for the last date.
+const SEARCH_TO: usize = 5;
+/// Search field index for the received exchange.
+const SEARCH_EXCHANGE: usize = 6;
+/// Search field index for the comment text.
+const SEARCH_COMMENTS: usize = 7;
+
+/// Number of search fields on each row of the form.
+const FIELDS_PER_ROW: usize = 4;
+
+/// State for the search screen.
+#[derive(Debug)]
+pub struct SearchState {
+    form: Form,
+    /// The query last run, re-run when the screen is opened again.
+    query: Option<QsoQuery>,
+    results: Vec<WorkedEntry>,
+    /// `true` while the cursor is in the results rather than the form.
+    in_results: bool,
+    selected: usize,
+    error: Option<String>,
+}
+
+impl Default for SearchState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SearchState {
+    /// Creates a search screen with an empty form and no results.
+    pub fn new() -> Self {
+        Self {
+            form: Form::new(vec![
+                Box::new(FormField::new("Callsign", false)),
+                Box::new(FormField::new("Park", false)),
+                Box::new(FormField::new("Band", false)),
+                Box::new(FormField::new("Mode", false)),
+                Box::new(FormField::new("From (YYYY-MM-DD)", false)),
+                Box::new(FormField::new("To (YYYY-MM-DD)", false)),
+                Box::new(FormField::new("Exchange", false)),
+                Box::new(FormField::new("Comments", false)),
+            ]),
+            query: None,
+            results: Vec::new(),
+            in_results: false,
+            selected: 0,
+            error: None,
+        }
+    }
+
+    /// Handles a key event, returning an [`Action`] for the app to apply.
+    ///
+    /// In the form, Enter runs the search and Esc returns to Log Select. In
+    /// the results, Enter opens the selected QSO and Esc returns to the form.
+    pub fn handle_key(&mut self, key: KeyEvent) -> Action {
+        if self.in_results {
+            return self.handle_results_key(key);
+        }
+        match key.code {
+            KeyCode::Tab => self.form.focus_next(),
+            KeyCode::BackTab => self.form.focus_prev(),
+            KeyCode::Char(ch) => self.form.insert_char(ch),
+            KeyCode::Backspace => self.form.delete_char(),
+            KeyCode::Down if !self.results.is_empty() => self.in_results = true,
+            KeyCode::Esc => return Action::Navigate(Screen::LogSelect),
+            KeyCode::Enter => {
+                self.error = None;
+                let Some(query) = self.parse_query() else {
+                    return Action::None;
+                };
+                if query.is_empty() {
+                    self.error = Some("Enter at least one search term".into());
+                    return Action::None;
+                }
+                return Action::SearchQsos(query);
+            }
+            _ => {}
+        }
+        Action::None
+    }
+
+    fn handle_results_key(&mut self, key: KeyEvent) -> Action {
+        match key.code {
+            KeyCode::Up if self.selected == 0 => self.in_results = false,
+            KeyCode::Up => self.selected -= 1,
+            KeyCode::Down => {
+                self.selected = (self.selected + 1).min(self.results.len().saturating_sub(1));
+            }
+            KeyCode::Home => self.selected = 0,
+            KeyCode::End => self.selected = self.results.len().saturating_sub(1),
+            KeyCode::Esc | KeyCode::Tab | KeyCode::BackTab => self.in_results = false,
+            KeyCode::Enter => {
+                if let Some(hit) = self.results.get(self.selected) {
+                    return Action::OpenSearchHit(hit.log_id.clone(), hit.qso.clone());
+                }
+            }
+            _ => {}
+        }
+        Action::None
+    }
+
+    /// Parses the form into a query, marking invalid fields.
+    fn parse_query(&mut self) -> Option<QsoQuery> {
+        self.form.clear_errors();
+        let text = |idx| Some(self.form.value(idx).trim().to_string()).filter(|v| !v.is_empty());
+        let mut query = QsoQuery {
+            callsign: text(SEARCH_CALLSIGN),
+            park: text(SEARCH_PARK),
+            exchange: text(SEARCH_EXCHANGE),
+            comments: text(SEARCH_COMMENTS),
+            ..QsoQuery::default()
+        };
+        let band = text(SEARCH_BAND);
+        let mode = text(SEARCH_MODE);
+        let from = text(SEARCH_FROM);
+        let to = text(SEARCH_TO);
+
+        if let Some(band) = band {
+            match Band::from_adif_str(&band) {
+                Some(b) => query.band = Some(b),
+                None => self
+                    .form
+                    .set_error(SEARCH_BAND, format!("unknown band {band:?}")),
+            }
+        }
+        if let Some(mode) = mode {
+            match Mode::from_name(&mode) {
+                Some(m) => query.mode = Some(m),
+                None => self
+                    .form
+                    .set_error(SEARCH_MODE, format!("unknown mode {mode:?}")),
+            }
+        }
+        for (idx, value, slot) in [
+            (SEARCH_FROM, from, &mut query.from),
+            (SEARCH_TO, to, &mut query.to),
+        ] {
+            if let Some(value) = value {
+                match parse_date(&value) {
+                    Some(date) => *slot = Some(date),
+                    None => self.form.set_error(idx, "use YYYY-MM-DD".into()),
+                }
+            }
+        }
+
+        (!self.form.has_errors()).then_some(query)
+    }
+
+    /// Shows the QSOs found for `query`.
+    ///
+    /// A new query moves the cursor to the first result; re-running the last
+    /// one keeps the cursor where it was.
+    pub fn set_results(&mut self, query: QsoQuery, results: Vec<WorkedEntry>) {
+        if self.query.as_ref() == Some(&query) {
+            self.selected = self.selected.min(results.len().saturating_sub(1));
+            self.in_results &= !results.is_empty();
+        } else {
+            self.selected = 0;
+            self.in_results = !results.is_empty();
+        }
+        self.query = Some(query);
+        self.results = results;
+        self.error = None;
+    }
+
+    /// Returns the query last run, if any.
+    pub fn query(&self) -> Option<&QsoQuery> {
+        self.query.as_ref()
+    }
+
+    /// Returns the QSOs found by the last search, newest first.
+    pub fn results(&self) -> &[WorkedEntry] {
+        &self.results
+    }
+
+    /// Returns the selected result row.
+    pub fn selected(&self) -> usize {
+        self.selected
+    }
+
+    /// Returns `true` if the cursor is in the results.
+    pub fn in_results(&self) -> bool {
+        self.in_results
+    }
+
+    /// Returns the form.
+    pub fn form(&self) -> &Form {
+        &self.form
+    }
+
+    /// Returns the current error message, if any.
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    /// Sets an error message to display.
+    pub fn set_error(&mut self, msg: String) {
+        self.error = Some(msg);
+    }
+}
+
+/// Parses a date as `YYYY-MM-DD` or `YYYYMMDD`.
+fn parse_date(s: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(s, "%Y-%m-%d")
+        .or_else(|_| NaiveDate::parse_from_str(s, "%Y%m%d"))
+        .ok()
+}
+
+/// Renders the search screen: the form, a summary or error line, and the
+/// results table.
+#[mutants::skip]
+pub fn draw_search(state: &SearchState, theme: &Theme, frame: &mut Frame, area: Rect) {
+    let block = Block::default()
+        .title(" Search All Logs ")
+        .borders(Borders::ALL)
+        .border_style(theme.accent);
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let [form_area, message_area, results_area, footer_area] = Layout::vertical([
+        Constraint::Length(6),
+        Constraint::Length(1),
+        Constraint::Min(0),
+        Constraint::Length(1),
+    ])
+    .areas(inner);
+
+    let rows = Layout::vertical([Constraint::Length(3), Constraint::Length(3)]).split(form_area);
+    for (row, row_area) in rows.iter().enumerate() {
+        let cells =
+            Layout::horizontal([Constraint::Ratio(1, FIELDS_PER_ROW as u32); FIELDS_PER_ROW])
+                .split(*row_area);
+        for (col, cell) in cells.iter().enumerate() {
+            let idx = row * FIELDS_PER_ROW + col;
+            draw_form_field(&state.form, idx, theme, frame, *cell);
+        }
+    }
+
+    let message = match (state.error(), state.query()) {
+        (Some(err), _) => Span::styled(err, theme.error),
+        (None, Some(_)) if state.results().is_empty() => {
+            Span::styled("No matching QSOs", theme.warning)
+        }
+        (None, Some(_)) => {
+            let logs: HashSet<&str> = state.results().iter().map(|h| h.log_id.as_str()).collect();
+            Span::styled(
+                format!("{} QSOs in {} logs", state.results().len(), logs.len()),
+                theme.accent,
+            )
+        }
+        (None, None) => Span::raw(""),
+    };
+    frame.render_widget(Paragraph::new(message), message_area);
+
+    draw_results(state, theme, frame, results_area);
+
+    let footer_text = if state.in_results() {
+        "↑/↓: select  Enter: open in QSO list  Esc: back to search form"
+    } else {
+        "Tab/Shift+Tab: next/prev  Enter: search  ↓: results  Esc: back"
+    };
+    frame.render_widget(Paragraph::new(footer_text).style(theme.dim), footer_area);
+}
+
+/// Renders the results table, scrolled so the selected row is visible.
+///
+/// Columns: Date | Time | Log | Call | Band | Mode | Park or exchange | Comments.
+#[mutants::skip]
+fn draw_results(state: &SearchState, theme: &Theme, frame: &mut Frame, area: Rect) {
+    let header = Row::new(vec![
+        "Date",
+        "Time",
+        "Log",
+        "Call",
+        "Band",
+        "Mode",
+        "Park/Exch",
+        "Comments",
+    ])
+    .style(theme.header);
+    let visible = (area.height as usize).saturating_sub(1);
+    let skip = (state.selected() + 1).saturating_sub(visible);
+    let rows: Vec<Row> = state
+        .results()
+        .iter()
+        .enumerate()
+        .skip(skip)
+        .take(visible)
+        .map(|(i, hit)| {
+            let qso = &hit.qso;
+            let style = if state.in_results() && i == state.selected() {
+                theme.selected
+            } else {
+                Style::default()
+            };
+            Row::new(vec![
+                qso.timestamp.format("%Y-%m-%d").to_string(),
+                qso.timestamp.format("%H:%M").to_string(),
+                hit.log_label.clone(),
+                qso.their_call.clone(),
+                qso.band.to_string(),
+                qso.mode_name().to_string(),
+                qso.their_park
+                    .clone()
+                    .or_else(|| qso.exchange_rcvd.clone())
+                    .unwrap_or_default(),
+                qso.comments.clone(),
+            ])
+            .style(style)
+        })
+        .collect();
+    let widths = [
+        Constraint::Length(10),
+        Constraint::Length(5),
+        Constraint::Length(10),
+        Constraint::Length(10),
+        Constraint::Length(5),
+        Constraint::Length(6),
+        Constraint::Length(10),
+        Constraint::Min(10),
+    ];
+    frame.render_widget(Table::new(rows, widths).header(header), area);
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use crossterm::event::{KeyEventKind, KeyEventState, KeyModifiers};
+
+    use super::*;
+    use crate::model::Qso;
+
+    fn press(code: KeyCode) -> KeyEvent {
+        KeyEvent {
+            code,
+            modifiers: KeyModifiers::NONE,
+            kind: KeyEventKind::Press,
+            state: KeyEventState::NONE,
+        }
+    }
+
+    fn type_string(state: &mut SearchState, s: &str) {
+        for ch in s.chars() {
+            state.handle_key(press(KeyCode::Char(ch)));
+        }
+    }
+
+    /// Moves the form focus to field `idx`.
+    fn focus(state: &mut SearchState, idx: usize) {
+        while state.form.focus() != idx {
+            state.handle_key(press(KeyCode::Tab));
+        }
+    }
+
+    fn make_hit(log_id: &str, call: &str) -> WorkedEntry {
+        WorkedEntry {
+            log_id: log_id.into(),
+            log_label: "K-0001".into(),
+            qso: Qso::new(
+                call.into(),
+                "59".into(),
+                "59".into(),
+                Band::M20,
+                Mode::Ssb,
+                Utc.with_ymd_and_hms(2026, 3, 14, 15, 0, 0).unwrap(),
+                "summit".into(),
+                Some("K-1234".into()),
+                None,
+                None,
+                None,
+            )
+            .unwrap(),
+        }
+    }
+
+    fn with_results(hits: Vec<WorkedEntry>) -> SearchState {
+        let mut state = SearchState::new();
+        state.set_results(QsoQuery::default(), hits);
+        state
+    }
+
+    mod form {
+        use super::*;
+
+        #[test]
+        fn enter_returns_query_from_fields() {
+            let mut state = SearchState::new();
+            type_string(&mut state, "k4abc");
+            focus(&mut state, SEARCH_BAND);
+            type_string(&mut state, "20m");
+            focus(&mut state, SEARCH_MODE);
+            type_string(&mut state, "cw");
+            focus(&mut state, SEARCH_FROM);
+            type_string(&mut state, "2026-03-01");
+            focus(&mut state, SEARCH_TO);
+            type_string(&mut state, "20260331");
+            focus(&mut state, SEARCH_COMMENTS);
+            type_string(&mut state, " summit ");
+            assert_eq!(
+                state.handle_key(press(KeyCode::Enter)),
+                Action::SearchQsos(QsoQuery {
+                    callsign: Some("k4abc".into()),
+                    band: Some(Band::M20),
+                    mode: Some(Mode::Cw),
+                    from: NaiveDate::from_ymd_opt(2026, 3, 1),
+                    to: NaiveDate::from_ymd_opt(2026, 3, 31),
+                    comments: Some("summit".into()),
+                    ..QsoQuery::default()
+                })
+            );
+        }
+
+        #[test]
+        fn empty_query_is_refused() {
+            let mut state = SearchState::new();
+            assert_eq!(state.handle_key(press(KeyCode::Enter)), Action::None);
+            assert_eq!(state.error(), Some("Enter at least one search term"));
+        }
+
+        #[test]
+        fn invalid_fields_are_marked() {
+            let mut state = SearchState::new();
+            focus(&mut state, SEARCH_BAND);
+            type_string(&mut state, "21m");
+            focus(&mut state, SEARCH_FROM);
+            type_string(&mut state, "March");
+            assert_eq!(state.handle_key(press(KeyCode::Enter)), Action::None);
+            let errors: Vec<_> = state.form().fields().iter().map(|f| f.error()).collect();
+            assert_eq!(errors[SEARCH_BAND], Some("unknown band \"21m\""));
+            assert_eq!(errors[SEARCH_FROM], Some("use YYYY-MM-DD"));
+            assert_eq!(errors[SEARCH_MODE], None);
+        }
+
+        #[test]
+        fn esc_returns_to_log_select() {
+            let mut state = SearchState::new();
+            assert_eq!(
+                state.handle_key(press(KeyCode::Esc)),
+                Action::Navigate(Screen::LogSelect)
+            );
+        }
+    }
+
+    mod results {
+        use super::*;
+
+        #[test]
+        fn results_take_the_cursor() {
+            let state = with_results(vec![make_hit("a", "K4ABC")]);
+            assert!(state.in_results());
+            assert_eq!(state.query(), Some(&QsoQuery::default()));
+            assert!(!with_results(vec![]).in_results());
+        }
+
+        #[test]
+        fn enter_opens_selected_hit() {
+            let mut state = with_results(vec![make_hit("a", "K4ABC"), make_hit("b", "W1AW")]);
+            state.handle_key(press(KeyCode::Down));
+            let hit = make_hit("b", "W1AW");
+            assert_eq!(
+                state.handle_key(press(KeyCode::Enter)),
+                Action::OpenSearchHit("b".into(), hit.qso)
+            );
+        }
+
+        #[test]
+        fn cursor_stays_within_results() {
+            let mut state = with_results(vec![make_hit("a", "K4ABC"), make_hit("b", "W1AW")]);
+            state.handle_key(press(KeyCode::Down));
+            state.handle_key(press(KeyCode::Down));
+            assert_eq!(state.selected(), 1);
+            state.handle_key(press(KeyCode::Home));
+            assert_eq!(state.selected(), 0);
+            state.handle_key(press(KeyCode::End));
+            assert_eq!(state.selected(), 1);
+        }
+
+        #[test]
+        fn rerunning_query_keeps_cursor() {
+            let hits = vec![make_hit("a", "K4ABC"), make_hit("b", "W1AW")];
+            let mut state = with_results(hits.clone());
+            state.handle_key(press(KeyCode::Down));
+            state.set_results(QsoQuery::default(), hits.clone());
+            assert_eq!(state.selected(), 1);
+            let query = QsoQuery {
+                callsign: Some("W1AW".into()),
+                ..QsoQuery::default()
+            };
+            state.set_results(query, hits);
+            assert_eq!(state.selected(), 0);
+        }
+
+        #[test]
+        fn up_from_first_row_and_esc_return_to_form() {
+            let mut state = with_results(vec![make_hit("a", "K4ABC")]);
+            state.handle_key(press(KeyCode::Up));
+            assert!(!state.in_results());
+            state.handle_key(press(KeyCode::Down));
+            assert!(state.in_results());
+            assert_eq!(state.handle_key(press(KeyCode::Esc)), Action::None);
+            assert!(!state.in_results());
+        }
+    }
+
+    mod rendering {
+        use ratatui::Terminal;
+        use ratatui::backend::TestBackend;
+
+        use super::*;
+        use crate::tui::test_utils::buffer_to_string;
+
+        fn render(state: &SearchState) -> String {
+            let backend = TestBackend::new(100, 24);
+            let mut terminal = Terminal::new(backend).unwrap();
+            terminal
+                .draw(|frame| draw_search(state, &Theme::default(), frame, frame.area()))
+                .unwrap();
+            buffer_to_string(terminal.backend().buffer())
+        }
+
+        #[test]
+        fn renders_form_fields() {
+            let output = render(&SearchState::new());
+            for label in ["Search All Logs", "Callsign", "Park", "Band", "Exchange"] {
+                assert!(output.contains(label), "should show {label}: {output}");
+            }
+        }
+
+        #[test]
+        fn renders_hits_with_summary() {
+            let state = with_results(vec![make_hit("a", "K4ABC"), make_hit("b", "W1AW")]);
+            let output = render(&state);
+            assert!(output.contains("2 QSOs in 2 logs"), "{output}");
+            for text in ["2026-03-14", "15:00", "K-0001", "K4ABC", "20M", "K-1234"] {
+                assert!(output.contains(text), "should show {text}: {output}");
+            }
+        }
+
+        #[test]
+        fn renders_no_matches() {
+            let output = render(&with_results(vec![]));
+            assert!(output.contains("No matching QSOs"), "{output}");
+        }
+    }
+}
@@ -0,0 +1,285 @@
+//! Color themes — named styles shared by every screen and widget.
+//!
+//! Draw functions never hard-code colors; they take a [`Theme`] and pick the
+//! style for the role being rendered (header, focus, error, ...).
+
+use std::fmt;
+
+use ratatui::style::{Color, Modifier, Style};
+
+use super::error::AppError;
+
+/// The built-in themes, selectable via the `theme` config setting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ThemeName {
+    /// Colors tuned for a dark terminal background.
+    #[default]
+    Dark,
+    /// Colors tuned for a light terminal background.
+    Light,
+    /// Bright, bold colors for direct sunlight.
+    HighContrast,
+    /// No colors at all; roles are distinguished by bold, underline and reverse video.
+    Monochrome,
+}
+
+static ALL_THEME_NAMES: &[ThemeName] = &[
+    ThemeName::Dark,
+    ThemeName::Light,
+    ThemeName::HighContrast,
+    ThemeName::Monochrome,
+];
+
+impl ThemeName {
+    /// Returns all built-in themes.
+    pub fn all() -> &'static [ThemeName] {
+        ALL_THEME_NAMES
+    }
+
+    /// Returns the config name of this theme (e.g. `"high-contrast"`).
+    pub fn name(self) -> &'static str {
+        match self {
+            ThemeName::Dark => "dark",
+            ThemeName::Light => "light",
+            ThemeName::HighContrast => "high-contrast",
+            ThemeName::Monochrome => "monochrome",
+        }
+    }
+
+    /// Looks up a theme by config name (case-insensitive).
+    pub fn from_name(name: &str) -> Option<Self> {
+        ALL_THEME_NAMES
+            .iter()
+            .copied()
+            .find(|theme| theme.name().eq_ignore_ascii_case(name))
+    }
+}
+
+#[mutants::skip]
+impl fmt::Display for ThemeName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Named styles used by all draw functions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Theme {
+    /// Screen borders and the status bar.
+    pub accent: Style,
+    /// Titles, section headings and table header rows.
+    pub header: Style,
+    /// Plain body text.
+    pub text: Style,
+    /// Hints, footers and unfocused fields.
+    pub dim: Style,
+    /// The focused field and key names on the help screen.
+    pub focus: Style,
+    /// The highlighted row in a table.
+    pub selected: Style,
+    /// Validation and operation errors.
+    pub error: Style,
+    /// Confirmation prompts and other warnings.
+    pub warning: Style,
+    /// Success states: POTA activation, completed export.
+    pub activated: Style,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::new(ThemeName::default())
+    }
+}
+
+impl Theme {
+    /// Builds the styles for a built-in theme.
+    pub fn new(name: ThemeName) -> Self {
+        match name {
+            ThemeName::Dark => Self::dark(),
+            ThemeName::Light => Self::light(),
+            ThemeName::HighContrast => Self::high_contrast(),
+            ThemeName::Monochrome => Self::monochrome(),
+        }
+    }
+
+    /// Resolves the theme from the `theme` config setting and the `NO_COLOR` convention.
+    ///
+    /// An explicit setting wins; otherwise `no_color` selects monochrome and the
+    /// default is dark. Fails if the setting names an unknown theme.
+    pub fn resolve(setting: Option<&str>, no_color: bool) -> Result<Self, AppError> {
+        let name = match setting {
+            Some(name) => {
+                ThemeName::from_name(name).ok_or_else(|| AppError::UnknownTheme(name.into()))?
+            }
+            None if no_color => ThemeName::Monochrome,
+            None => ThemeName::default(),
+        };
+        Ok(Self::new(name))
+    }
+
+    fn dark() -> Self {
+        let cyan = Style::default().fg(Color::Cyan);
+        Self {
+            accent: cyan,
+            header: cyan.add_modifier(Modifier::BOLD),
+            text: Style::default().fg(Color::White),
+            dim: Style::default().fg(Color::DarkGray),
+            focus: Style::default().fg(Color::Yellow),
+            selected: Style::default().fg(Color::Black).bg(Color::Yellow),
+            error: Style::default().fg(Color::Red),
+            warning: Style::default().fg(Color::Yellow),
+            activated: Style::default().fg(Color::Green),
+        }
+    }
+
+    fn light() -> Self {
+        let blue = Style::default().fg(Color::Blue);
+        Self {
+            accent: blue,
+            header: blue.add_modifier(Modifier::BOLD),
+            text: Style::default().fg(Color::Black),
+            dim: Style::default().fg(Color::DarkGray),
+            focus: Style::default().fg(Color::Magenta),
+            selected: Style::default().fg(Color::White).bg(Color::Blue),
+            error: Style::default().fg(Color::Red),
+            warning: Style::default().fg(Color::Magenta),
+            activated: Style::default().fg(Color::Green),
+        }
+    }
+
+    fn high_contrast() -> Self {
+        let bold = Style::default().add_modifier(Modifier::BOLD);
+        Self {
+            accent: Style::default().fg(Color::White),
+            header: bold.fg(Color::White),
+            text: Style::default().fg(Color::White),
+            dim: Style::default().fg(Color::Gray),
+            focus: bold.fg(Color::LightYellow),
+            selected: bold.fg(Color::Black).bg(Color::LightYellow),
+            error: bold.fg(Color::LightRed),
+            warning: bold.fg(Color::LightYellow),
+            activated: bold.fg(Color::LightGreen),
+        }
+    }
+
+    fn monochrome() -> Self {
+        let bold = Style::default().add_modifier(Modifier::BOLD);
+        Self {
+            accent: Style::default(),
+            header: bold,
+            text: Style::default(),
+            dim: Style::default(),
+            focus: bold.add_modifier(Modifier::UNDERLINED),
+            selected: Style::default().add_modifier(Modifier::REVERSED),
+            error: bold,
+            warning: bold,
+            activated: bold,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod theme_name {
+        use super::*;
+
+        #[test]
+        fn default_is_dark() {
+            assert_eq!(ThemeName::default(), ThemeName::Dark);
+        }
+
+        #[test]
+        fn from_name_round_trips_all() {
+            for &theme in ThemeName::all() {
+                assert_eq!(ThemeName::from_name(theme.name()), Some(theme));
+            }
+        }
+
+        #[test]
+        fn from_name_is_case_insensitive() {
+            assert_eq!(
+                ThemeName::from_name("High-Contrast"),
+                Some(ThemeName::HighContrast)
+            );
+        }
+
+        #[test]
+        fn from_name_rejects_unknown() {
+            assert_eq!(ThemeName::from_name("solarized"), None);
+        }
+    }
+
+    mod resolve {
+        use super::*;
+
+        #[test]
+        fn defaults_to_dark() {
+            assert_eq!(Theme::resolve(None, false).unwrap(), Theme::dark());
+        }
+
+        #[test]
+        fn no_color_selects_monochrome() {
+            assert_eq!(Theme::resolve(None, true).unwrap(), Theme::monochrome());
+        }
+
+        #[test]
+        fn explicit_setting_overrides_no_color() {
+            assert_eq!(Theme::resolve(Some("light"), true).unwrap(), Theme::light());
+        }
+
+        #[test]
+        fn unknown_setting_is_error() {
+            let err = Theme::resolve(Some("solarized"), false).unwrap_err();
+            assert!(matches!(err, AppError::UnknownTheme(ref name) if name == "solarized"));
+        }
+    }
+
+    mod styles {
+        use super::*;
+
+        fn all_styles(theme: &Theme) -> [Style; 9] {
+            [
+                theme.accent,
+                theme.header,
+                theme.text,
+                theme.dim,
+                theme.focus,
+                theme.selected,
+                theme.error,
+                theme.warning,
+                theme.activated,
+            ]
+        }
+
+        #[test]
+        fn default_is_dark() {
+            assert_eq!(Theme::default(), Theme::new(ThemeName::Dark));
+        }
+
+        #[test]
+        fn monochrome_uses_no_colors() {
+            for style in all_styles(&Theme::new(ThemeName::Monochrome)) {
+                assert_eq!(style.fg, None, "{style:?}");
+                assert_eq!(style.bg, None, "{style:?}");
+            }
+        }
+
+        #[test]
+        fn high_contrast_avoids_dark_gray() {
+            for style in all_styles(&Theme::new(ThemeName::HighContrast)) {
+                assert_ne!(style.fg, Some(Color::DarkGray), "{style:?}");
+            }
+        }
+
+        #[test]
+        fn focus_is_distinguishable_from_dim() {
+            for &name in ThemeName::all() {
+                let theme = Theme::new(name);
+                assert_ne!(theme.focus, theme.dim, "{name}");
+                assert_ne!(theme.selected, theme.text, "{name}");
+            }
+        }
+    }
+}
@@ -0,0 +1,344 @@
+//! Status bar timers: time left in a contest period, the countdown to the UTC
+//! day rollover, and the station ID reminder.
+//!
+//! [`Timers`] holds the clock the readings are computed against. The app
+//! advances it on every tick of the event loop, so the status bar counts down
+//! without keypresses.
+
+use chrono::{DateTime, Days, TimeDelta, Utc};
+
+use crate::config::TimersConfig;
+use crate::model::Log;
+
+/// How long before the UTC rollover a POTA log short of activation is flagged.
+const ROLLOVER_WARNING: TimeDelta = TimeDelta::hours(1);
+
+/// One timer shown in the status bar.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimerReading {
+    /// The text shown (e.g. `FD ends in 4h 05m`).
+    pub text: String,
+    /// When `true`, the reading needs the operator's attention and is drawn in
+    /// the warning style.
+    pub urgent: bool,
+}
+
+impl TimerReading {
+    fn new(text: String, urgent: bool) -> Self {
+        Self { text, urgent }
+    }
+}
+
+/// The current time and when the station last identified.
+#[derive(Debug, Clone)]
+pub struct Timers {
+    now: DateTime<Utc>,
+    station_id_interval: Option<TimeDelta>,
+    last_station_id: DateTime<Utc>,
+}
+
+impl Timers {
+    /// Starts the timers at `now`, with the station ID reminder counting from
+    /// `now`.
+    pub fn new(config: &TimersConfig, now: DateTime<Utc>) -> Self {
+        let minutes = i64::try_from(config.station_id_minutes).unwrap_or(i64::MAX);
+        Self {
+            now,
+            station_id_interval: (minutes > 0)
+                .then(|| TimeDelta::try_minutes(minutes).unwrap_or(TimeDelta::MAX)),
+            last_station_id: now,
+        }
+    }
+
+    /// Advances the clock to `now`.
+    pub fn tick(&mut self, now: DateTime<Utc>) {
+        self.now = now;
+    }
+
+    /// Records that the station has just identified, restarting the reminder.
+    pub fn reset_station_id(&mut self) {
+        self.last_station_id = self.now;
+    }
+
+    /// Returns the timers that apply to `log`, in display order.
+    ///
+    /// - FD / WFD: time until the contest starts, time left in it, or that it
+    ///   has ended (flagged).
+    /// - POTA / General: time until the UTC day rolls over. A POTA log that
+    ///   still needs QSOs is flagged in the final hour.
+    /// - All logs: time until the next station ID, flagged once it is due.
+    pub fn readings(&self, log: &Log) -> Vec<TimerReading> {
+        let mut readings = Vec::new();
+        match log.operating_period() {
+            Some(period) => {
+                let name = log.log_type_name();
+                readings.push(if self.now < period.start {
+                    let left = format_remaining(period.start - self.now);
+                    TimerReading::new(format!("{name} starts in {left}"), false)
+                } else if self.now < period.end {
+                    let left = format_remaining(period.end - self.now);
+                    TimerReading::new(format!("{name} ends in {left}"), false)
+                } else {
+                    TimerReading::new(format!("{name} has ended"), true)
+                });
+            }
+            None => {
+                let left = self.until_rollover();
+                let needs = log.needs_for_activation();
+                let text = format!("UTC day ends in {}", format_remaining(left));
+                readings.push(if needs > 0 && left <= ROLLOVER_WARNING {
+                    TimerReading::new(format!("{text}, {needs} more QSOs needed"), true)
+                } else {
+                    TimerReading::new(text, false)
+                });
+            }
+        }
+        if let Some(interval) = self.station_id_interval {
+            let due = self.last_station_id + interval;
+            readings.push(if self.now < due {
+                let left = format_remaining(due - self.now);
+                TimerReading::new(format!("ID in {left}"), false)
+            } else {
+                TimerReading::new("ID now".to_string(), true)
+            });
+        }
+        readings
+    }
+
+    /// Time left until 0000 UTC.
+    fn until_rollover(&self) -> TimeDelta {
+        let midnight = self
+            .now
+            .date_naive()
+            .checked_add_days(Days::new(1))
+            .and_then(|d| d.and_hms_opt(0, 0, 0))
+            .map(|dt| dt.and_utc());
+        midnight.map_or(TimeDelta::zero(), |m| m - self.now)
+    }
+}
+
+/// Formats a duration for the status bar: `2d 03h`, `4h 05m`, or `9m 30s`.
+fn format_remaining(remaining: TimeDelta) -> String {
+    let secs = remaining.num_seconds().max(0);
+    let (days, hours, minutes, seconds) =
+        (secs / 86_400, secs / 3600 % 24, secs / 60 % 60, secs % 60);
+    if days > 0 {
+        format!("{days}d {hours:02}h")
+    } else if hours > 0 {
+        format!("{hours}h {minutes:02}m")
+    } else {
+        format!("{minutes}m {seconds:02}s")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+    use crate::model::{
+        Band, FdClass, FdPowerCategory, FieldDayLog, GeneralLog, Mode, OperatingPeriod, PotaLog,
+        Qso,
+    };
+
+    fn utc(y: i32, m: u32, d: u32, h: u32, min: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(y, m, d, h, min, 0).unwrap()
+    }
+
+    fn timers_at(now: DateTime<Utc>) -> Timers {
+        Timers::new(&TimersConfig::default(), now)
+    }
+
+    fn texts(readings: &[TimerReading]) -> Vec<&str> {
+        readings.iter().map(|r| r.text.as_str()).collect()
+    }
+
+    fn field_day_2025() -> Log {
+        let mut log = FieldDayLog::new(
+            "W1AW".into(),
+            None,
+            1,
+            FdClass::B,
+            "EPA".into(),
+            FdPowerCategory::Low,
+            "FN31".into(),
+        )
+        .unwrap();
+        log.header.created_at = utc(2025, 6, 1, 0, 0);
+        Log::FieldDay(log)
+    }
+
+    fn pota_log() -> Log {
+        Log::Pota(PotaLog::new("W1AW".into(), None, "K-0001".into(), "FN31".into()).unwrap())
+    }
+
+    mod format_remaining {
+        use super::*;
+
+        #[test]
+        fn under_an_hour_shows_seconds() {
+            assert_eq!(format_remaining(TimeDelta::seconds(570)), "9m 30s");
+        }
+
+        #[test]
+        fn hours_show_minutes() {
+            assert_eq!(format_remaining(TimeDelta::minutes(245)), "4h 05m");
+        }
+
+        #[test]
+        fn days_show_hours() {
+            assert_eq!(format_remaining(TimeDelta::hours(51)), "2d 03h");
+        }
+
+        #[test]
+        fn negative_is_zero() {
+            assert_eq!(format_remaining(TimeDelta::seconds(-5)), "0m 00s");
+        }
+    }
+
+    mod contest_period {
+        use super::*;
+
+        #[test]
+        fn before_start_counts_down_to_start() {
+            let timers = timers_at(utc(2025, 6, 28, 15, 30));
+            let readings = timers.readings(&field_day_2025());
+            assert_eq!(readings[0].text, "FD starts in 2h 30m");
+            assert!(!readings[0].urgent);
+        }
+
+        #[test]
+        fn during_period_counts_down_to_end() {
+            let timers = timers_at(utc(2025, 6, 29, 16, 55));
+            let readings = timers.readings(&field_day_2025());
+            assert_eq!(readings[0].text, "FD ends in 4h 05m");
+            assert!(!readings[0].urgent);
+        }
+
+        #[test]
+        fn after_end_is_flagged() {
+            let period = OperatingPeriod::field_day(2025);
+            let timers = timers_at(period.end);
+            let readings = timers.readings(&field_day_2025());
+            assert_eq!(readings[0].text, "FD has ended");
+            assert!(readings[0].urgent);
+        }
+
+        #[test]
+        fn contest_logs_have_no_rollover_countdown() {
+            let timers = timers_at(utc(2025, 6, 28, 23, 30));
+            let readings = timers.readings(&field_day_2025());
+            assert!(!texts(&readings).iter().any(|t| t.starts_with("UTC")));
+        }
+    }
+
+    mod rollover {
+        use super::*;
+
+        #[test]
+        fn counts_down_to_midnight() {
+            let timers = timers_at(utc(2026, 3, 1, 21, 15));
+            let log = Log::General(GeneralLog::new("W1AW".into(), None, "FN31".into()).unwrap());
+            let readings = timers.readings(&log);
+            assert_eq!(readings[0].text, "UTC day ends in 2h 45m");
+            assert!(!readings[0].urgent);
+        }
+
+        #[test]
+        fn pota_short_of_activation_is_flagged_in_final_hour() {
+            let timers = timers_at(utc(2026, 3, 1, 23, 20));
+            let readings = timers.readings(&pota_log());
+            assert_eq!(
+                readings[0].text,
+                "UTC day ends in 40m 00s, 10 more QSOs needed"
+            );
+            assert!(readings[0].urgent);
+        }
+
+        #[test]
+        fn pota_short_of_activation_is_not_flagged_earlier() {
+            let timers = timers_at(utc(2026, 3, 1, 22, 59));
+            let readings = timers.readings(&pota_log());
+            assert_eq!(readings[0].text, "UTC day ends in 1h 01m");
+            assert!(!readings[0].urgent);
+        }
+
+        #[test]
+        fn activated_pota_is_not_flagged() {
+            let mut log = pota_log();
+            for i in 0..10 {
+                log.add_qso(
+                    Qso::new(
+                        format!("W{i}ABC"),
+                        "59".into(),
+                        "59".into(),
+                        Band::M20,
+                        Mode::Ssb,
+                        Utc::now(),
+                        String::new(),
+                        None,
+                        None,
+                        None,
+                        None,
+                    )
+                    .unwrap(),
+                );
+            }
+            let timers = timers_at(utc(2026, 3, 1, 23, 50));
+            assert!(!timers.readings(&log)[0].urgent);
+        }
+    }
+
+    mod station_id {
+        use super::*;
+
+        #[test]
+        fn counts_down_from_start() {
+            let start = utc(2026, 3, 1, 12, 0);
+            let mut timers = timers_at(start);
+            timers.tick(start + TimeDelta::seconds(150));
+            let readings = timers.readings(&pota_log());
+            assert_eq!(readings[1].text, "ID in 7m 30s");
+            assert!(!readings[1].urgent);
+        }
+
+        #[test]
+        fn due_after_interval() {
+            let start = utc(2026, 3, 1, 12, 0);
+            let mut timers = timers_at(start);
+            timers.tick(start + TimeDelta::minutes(10));
+            let readings = timers.readings(&pota_log());
+            assert_eq!(readings[1].text, "ID now");
+            assert!(readings[1].urgent);
+        }
+
+        #[test]
+        fn reset_restarts_countdown() {
+            let start = utc(2026, 3, 1, 12, 0);
+            let mut timers = timers_at(start);
+            timers.tick(start + TimeDelta::minutes(12));
+            timers.reset_station_id();
+            timers.tick(start + TimeDelta::minutes(13));
+            assert_eq!(timers.readings(&pota_log())[1].text, "ID in 9m 00s");
+        }
+
+        #[test]
+        fn interval_is_configurable() {
+            let start = utc(2026, 3, 1, 12, 0);
+            let config = TimersConfig {
+                station_id_minutes: 30,
+            };
+            let timers = Timers::new(&config, start);
+            assert_eq!(timers.readings(&pota_log())[1].text, "ID in 30m 00s");
+        }
+
+        #[test]
+        fn zero_minutes_turns_reminder_off() {
+            let config = TimersConfig {
+                station_id_minutes: 0,
+            };
+            let timers = Timers::new(&config, utc(2026, 3, 1, 12, 0));
+            assert_eq!(timers.readings(&pota_log()).len(), 1);
+        }
+    }
+}
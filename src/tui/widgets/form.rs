@@ -4,10 +4,12 @@ use std::fmt;
 
 use ratatui::Frame;
 use ratatui::layout::{Constraint, Layout, Rect};
-use ratatui::style::{Color, Modifier, Style};
+use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, Paragraph};
 
+use crate::tui::theme::Theme;
+
 /// A single input field within a [`Form`].
 pub trait Field: fmt::Debug {
     /// Display label shown above/beside the input.
@@ -205,6 +207,21 @@ impl Form {
         Self { fields, focus: 0 }
     }
 
+    /// Appends a field to the end of the form.
+    pub fn push_field(&mut self, field: Box<dyn Field>) {
+        self.fields.push(field);
+    }
+
+    /// Removes all fields from index `len` onward.
+    ///
+    /// Focus moves to the first field if it was on a removed field.
+    pub fn truncate(&mut self, len: usize) {
+        self.fields.truncate(len);
+        if self.focus >= self.fields.len() {
+            self.focus = 0;
+        }
+    }
+
     /// Returns the index of the currently focused field.
     pub fn focus(&self) -> usize {
         self.focus
@@ -332,19 +349,25 @@ impl Form {
 
 /// Renders a single form field at the given area.
 #[mutants::skip]
-pub fn draw_form_field(form: &Form, field_idx: usize, frame: &mut Frame, area: Rect) {
+pub fn draw_form_field(
+    form: &Form,
+    field_idx: usize,
+    theme: &Theme,
+    frame: &mut Frame,
+    area: Rect,
+) {
     let Some(field) = form.fields.get(field_idx) else {
         return;
     };
     let row_height = 3_u16;
     let is_focused = field_idx == form.focus;
 
-    let border_color = if field.error().is_some() {
-        Color::Red
+    let border_style = if field.error().is_some() {
+        theme.error
     } else if is_focused {
-        Color::Yellow
+        theme.focus
     } else {
-        Color::DarkGray
+        theme.dim
     };
 
     let label = if field.required() {
@@ -356,7 +379,7 @@ pub fn draw_form_field(form: &Form, field_idx: usize, frame: &mut Frame, area: R
     let block = Block::default()
         .title(label)
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(border_color));
+        .border_style(border_style);
 
     let mut spans = vec![Span::raw(field.value())];
     if is_focused {
@@ -371,7 +394,7 @@ pub fn draw_form_field(form: &Form, field_idx: usize, frame: &mut Frame, area: R
 
     // Draw error below the field if there's space
     if let Some(err) = field.error() {
-        let error_line = Paragraph::new(Span::styled(err, Style::default().fg(Color::Red)));
+        let error_line = Paragraph::new(Span::styled(err, theme.error));
         let err_area = Rect {
             x: area.x + 2,
             y: area.y + row_height.saturating_sub(1),
@@ -384,7 +407,7 @@ pub fn draw_form_field(form: &Form, field_idx: usize, frame: &mut Frame, area: R
 
 /// Renders a form within the given area.
 #[mutants::skip]
-pub fn draw_form(form: &Form, frame: &mut Frame, area: Rect) {
+pub fn draw_form(form: &Form, theme: &Theme, frame: &mut Frame, area: Rect) {
     let row_height = 3_u16;
     let constraints: Vec<Constraint> = form
         .fields
@@ -395,7 +418,7 @@ pub fn draw_form(form: &Form, frame: &mut Frame, area: Rect) {
     let rows = Layout::vertical(constraints).split(area);
 
     for i in 0..form.fields.len() {
-        draw_form_field(form, i, frame, rows[i]);
+        draw_form_field(form, i, theme, frame, rows[i]);
     }
 }
 
@@ -793,6 +816,42 @@ mod tests {
         }
     }
 
+    mod push_and_truncate {
+        use super::*;
+
+        #[test]
+        fn push_field_appends() {
+            let mut form = make_form();
+            form.push_field(Box::new(FormField::new("Date", true)));
+            assert_eq!(form.fields().len(), 4);
+            assert_eq!(form.fields()[3].label(), "Date");
+        }
+
+        #[test]
+        fn truncate_removes_trailing_fields() {
+            let mut form = make_form();
+            form.truncate(1);
+            assert_eq!(form.fields().len(), 1);
+            assert_eq!(form.fields()[0].label(), "Callsign");
+        }
+
+        #[test]
+        fn truncate_keeps_focus_on_remaining_field() {
+            let mut form = make_form();
+            form.set_focus(1);
+            form.truncate(2);
+            assert_eq!(form.focus(), 1);
+        }
+
+        #[test]
+        fn truncate_resets_focus_on_removed_field() {
+            let mut form = make_form();
+            form.set_focus(2);
+            form.truncate(2);
+            assert_eq!(form.focus(), 0);
+        }
+    }
+
     mod rendering {
         use ratatui::Terminal;
         use ratatui::backend::TestBackend;
@@ -806,7 +865,7 @@ mod tests {
             let mut terminal = Terminal::new(backend).unwrap();
             terminal
                 .draw(|frame| {
-                    draw_form(form, frame, frame.area());
+                    draw_form(form, &Theme::default(), frame, frame.area());
                 })
                 .unwrap();
             buffer_to_string(terminal.backend().buffer())
@@ -833,7 +892,7 @@ mod tests {
             let mut terminal = Terminal::new(backend).unwrap();
             terminal
                 .draw(|frame| {
-                    draw_form_field(form, field_idx, frame, frame.area());
+                    draw_form_field(form, field_idx, &Theme::default(), frame, frame.area());
                 })
                 .unwrap();
             buffer_to_string(terminal.backend().buffer())
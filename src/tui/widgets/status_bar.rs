@@ -2,15 +2,17 @@
 
 use ratatui::Frame;
 use ratatui::layout::Rect;
-use ratatui::style::{Color, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::Paragraph;
 
 use crate::model::Log;
+use crate::tui::theme::Theme;
+use crate::tui::timers::TimerReading;
 
 /// Data passed to the status bar widget.
 ///
-/// Construct via [`StatusBarContext::from_log`] or [`Default`] for an empty bar.
+/// Construct via [`StatusBarContext::from_log`] or [`Default`] for an empty bar,
+/// adding timers with [`StatusBarContext::with_timers`].
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub struct StatusBarContext {
     /// Label shown in brackets: park ref for POTA, sent exchange for FD/WFD, callsign for General.
@@ -21,6 +23,8 @@ pub struct StatusBarContext {
     pub pota_mode: bool,
     /// When `true`, show `ACTIVATED` instead of the QSO count (POTA only).
     pub is_activated: bool,
+    /// Timer readings shown after the count, in order.
+    pub timers: Vec<TimerReading>,
 }
 
 impl StatusBarContext {
@@ -40,40 +44,57 @@ impl StatusBarContext {
             },
             pota_mode: is_pota,
             is_activated: log.is_activated(),
+            timers: Vec::new(),
         }
     }
+
+    /// Adds the timer readings to show after the count.
+    pub fn with_timers(mut self, timers: &[TimerReading]) -> Self {
+        self.timers = timers.to_vec();
+        self
+    }
 }
 
 /// Renders a one-line status bar showing the active log context.
 ///
 /// Display format (left-aligned):
-/// - POTA activated:       `[K-0001]  ACTIVATED`  (ACTIVATED in Green)
+/// - POTA activated:       `[K-0001]  ACTIVATED`  (ACTIVATED in the theme's activated style)
 /// - POTA not activated:   `[K-0001]  7/10 QSOs`
 /// - FD / WFD:             `[1B EPA]  42 QSOs`
 /// - General:              `[W1AW]  5 QSOs`
 ///
+/// Timer readings follow the count, separated by `  |  `; urgent ones use the
+/// theme's warning style (e.g. `[K-0001]  7/10 QSOs  |  UTC day ends in 40m 00s`).
+///
 /// Renders nothing if `ctx.context_label` is empty (no active log).
 #[mutants::skip]
-pub fn draw_status_bar(ctx: &StatusBarContext, frame: &mut Frame, area: Rect) {
+pub fn draw_status_bar(ctx: &StatusBarContext, theme: &Theme, frame: &mut Frame, area: Rect) {
     if ctx.context_label.is_empty() {
         return;
     }
 
-    let cyan = Style::default().fg(Color::Cyan);
-    let green = Style::default().fg(Color::Green);
-
     let (count_str, count_style) = if ctx.is_activated {
-        ("ACTIVATED".to_string(), green)
+        ("ACTIVATED".to_string(), theme.activated)
     } else if ctx.pota_mode {
-        (format!("{}/10 QSOs", ctx.qso_count), cyan)
+        (format!("{}/10 QSOs", ctx.qso_count), theme.accent)
     } else {
-        (format!("{} QSOs", ctx.qso_count), cyan)
+        (format!("{} QSOs", ctx.qso_count), theme.accent)
     };
 
-    let line = Line::from(vec![
-        Span::styled(format!("[{}]  ", ctx.context_label), cyan),
+    let mut spans = vec![
+        Span::styled(format!("[{}]  ", ctx.context_label), theme.accent),
         Span::styled(count_str, count_style),
-    ]);
+    ];
+    for reading in &ctx.timers {
+        let style = if reading.urgent {
+            theme.warning
+        } else {
+            theme.dim
+        };
+        spans.push(Span::styled("  |  ", theme.dim));
+        spans.push(Span::styled(reading.text.as_str(), style));
+    }
+    let line = Line::from(spans);
 
     frame.render_widget(Paragraph::new(line), area);
 }
@@ -85,7 +106,8 @@ mod tests {
 
     use super::*;
     use crate::model::{
-        FdClass, FdPowerCategory, FieldDayLog, GeneralLog, LogHeader, PotaLog, WfdClass, WfdLog,
+        ExtraFields, FdClass, FdPowerCategory, FieldDayLog, GeneralLog, LogHeader, PotaLog,
+        WfdClass, WfdLog,
     };
 
     use crate::tui::test_utils::buffer_to_string;
@@ -95,7 +117,7 @@ mod tests {
         let mut terminal = Terminal::new(backend).unwrap();
         terminal
             .draw(|frame| {
-                draw_status_bar(ctx, frame, frame.area());
+                draw_status_bar(ctx, &Theme::default(), frame, frame.area());
             })
             .unwrap();
         buffer_to_string(terminal.backend().buffer())
@@ -109,6 +131,7 @@ mod tests {
             qsos: vec![],
             created_at: chrono::Utc::now(),
             log_id: "test".into(),
+            extra_fields: ExtraFields::default(),
         }
     }
 
@@ -119,6 +142,7 @@ mod tests {
             qso_count: 10,
             pota_mode: true,
             is_activated: true,
+            timers: vec![],
         };
         let output = render_status_bar(&ctx, 40, 1);
         assert!(
@@ -135,6 +159,7 @@ mod tests {
             qso_count: 7,
             pota_mode: true,
             is_activated: false,
+            timers: vec![],
         };
         let output = render_status_bar(&ctx, 40, 1);
         assert!(
@@ -151,6 +176,7 @@ mod tests {
             qso_count: 5,
             pota_mode: false,
             is_activated: false,
+            timers: vec![],
         };
         let output = render_status_bar(&ctx, 40, 1);
         assert!(
@@ -168,6 +194,7 @@ mod tests {
             qso_count: 42,
             pota_mode: false,
             is_activated: false,
+            timers: vec![],
         };
         let output = render_status_bar(&ctx, 40, 1);
         assert!(
@@ -192,6 +219,31 @@ mod tests {
         );
     }
 
+    #[test]
+    fn renders_timers_after_count() {
+        let ctx = StatusBarContext {
+            context_label: "K-0001".to_string(),
+            qso_count: 7,
+            pota_mode: true,
+            is_activated: false,
+            timers: vec![
+                TimerReading {
+                    text: "UTC day ends in 40m 00s".into(),
+                    urgent: false,
+                },
+                TimerReading {
+                    text: "ID now".into(),
+                    urgent: true,
+                },
+            ],
+        };
+        let output = render_status_bar(&ctx, 80, 1);
+        assert!(
+            output.contains("7/10 QSOs  |  UTC day ends in 40m 00s  |  ID now"),
+            "{output}"
+        );
+    }
+
     mod from_log {
         use super::*;
 
@@ -1,6 +1,8 @@
-//! Integration tests that validate generated ADIF files using adif-multitool.
+//! Integration tests that validate generated ADIF files using duklog's own
+//! validator and, independently, adif-multitool.
 //!
-//! These tests write ADIF files to temporary directories and invoke:
+//! These tests write ADIF files to temporary directories, check them with
+//! `duklog::adif::validate`, and invoke:
 //!   adif-multitool validate <file>
 //!
 //! `adif-multitool` must be installed and on PATH. Install with:
@@ -13,7 +15,7 @@ use std::process::Command;
 use chrono::{TimeZone, Utc};
 use tempfile::tempdir;
 
-use duklog::adif::format_adif;
+use duklog::adif::{format_adif, validate};
 use duklog::model::{
     Band, FdClass, FdPowerCategory, FieldDayLog, GeneralLog, Log, Mode, PotaLog, Qso, WfdClass,
     WfdLog,
@@ -48,8 +50,21 @@ fn adifmt_bin() -> String {
     "adifmt".to_string() // produces a clear error message when the test actually runs
 }
 
-/// Run `adifmt validate <path>` and panic with a diagnostic if it fails.
+/// Run the built-in validator and `adifmt validate <path>`, panicking with a
+/// diagnostic if either finds a problem.
 fn validate_adif(path: &Path) {
+    let content = fs::read_to_string(path).expect("read adif");
+    let findings = validate(&content);
+    assert!(
+        findings.is_empty(),
+        "duklog::adif::validate found problems in {path:?}:\n{}\nFile:\n{content}",
+        findings
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join("\n")
+    );
+
     let bin = adifmt_bin();
     let output = Command::new(&bin)
         .arg("validate")
@@ -101,6 +116,7 @@ fn make_qso(call: &str) -> Qso {
         None,
         None,
         None,
+        None,
     )
     .unwrap()
 }
@@ -116,7 +132,8 @@ fn make_cw_qso(call: &str) -> Qso {
         String::new(),
         None,
         None,
-        Some(7074),
+        Some(7_074_000),
+        None,
     )
     .unwrap()
 }
@@ -133,6 +150,7 @@ fn make_p2p_qso() -> Qso {
         Some("K-1234".to_string()),
         None,
         None,
+        None,
     )
     .unwrap()
 }
@@ -148,7 +166,8 @@ fn make_fd_qso() -> Qso {
         String::new(),
         None,
         Some("3A CT".to_string()),
-        Some(14225),
+        Some(14_225_000),
+        None,
     )
     .unwrap()
 }
@@ -246,6 +265,22 @@ fn pota_log_with_cw_qso_and_frequency_is_valid_adif() {
     validate_adif(&path);
 }
 
+#[test]
+fn general_log_with_submode_qsos_is_valid_adif() {
+    let mut log = make_general_log();
+    for (call, mode) in [
+        ("W3ABC", Mode::Ft4),
+        ("N0CALL", Mode::Usb),
+        ("KD9XYZ", Mode::Psk31),
+    ] {
+        let mut qso = make_qso(call);
+        qso.mode = mode;
+        log.add_qso(qso);
+    }
+    let (_dir, path) = write_adif(&log);
+    validate_adif(&path);
+}
+
 #[test]
 fn pota_log_with_p2p_qso_is_valid_adif() {
     let mut log = make_pota_log();
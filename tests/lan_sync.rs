@@ -0,0 +1,269 @@
+//! Integration tests that run several sync stations on localhost.
+//!
+//! Each station is a [`SyncNode`] with its own copy of the same log, as on
+//! separate laptops at a Field Day site. Tests poll every station until the
+//! logs converge or a timeout expires.
+
+use std::thread;
+use std::time::{Duration, Instant};
+
+use chrono::{TimeZone, Utc};
+
+use duklog::model::{Band, FdClass, FdPowerCategory, FieldDayLog, Log, Mode, Qso};
+use duklog::sync::{SyncNode, assign_id};
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+/// A station: a sync node plus its local copy of the log.
+struct Station {
+    node: SyncNode,
+    log: Log,
+}
+
+impl Station {
+    fn poll(&mut self) {
+        self.node.poll(Some(&mut self.log));
+    }
+
+    /// Logs a QSO the way the app does: assign an ID, stamp, add, publish.
+    fn add(&mut self, call: &str, band: Band, minute: u32) -> String {
+        let mut qso = make_qso(call, band, minute);
+        assign_id(&self.log, &mut qso);
+        self.node.stamp(&mut qso);
+        let id = qso.id.clone().unwrap();
+        self.log.add_qso(qso);
+        self.node.publish(&self.log, &id);
+        id
+    }
+
+    fn edit(&mut self, id: &str, comments: &str) {
+        let idx = self.log.qso_index_by_id(id).unwrap();
+        let mut qso = self.log.qsos()[idx].clone();
+        qso.comments = comments.to_string();
+        self.node.stamp(&mut qso);
+        self.log.replace_qso(idx, qso);
+        self.node.publish(&self.log, id);
+    }
+
+    fn delete(&mut self, id: &str) {
+        let idx = self.log.qso_index_by_id(id).unwrap();
+        let removed = self.log.remove_qso(idx).unwrap();
+        self.node.stamp_deletion(&mut self.log, &removed);
+        self.node.publish(&self.log, id);
+    }
+
+    fn calls(&self) -> Vec<String> {
+        let mut calls: Vec<String> = self
+            .log
+            .qsos()
+            .iter()
+            .map(|q| q.their_call.clone())
+            .collect();
+        calls.sort();
+        calls
+    }
+
+    fn qso(&self, id: &str) -> Option<&Qso> {
+        self.log
+            .qso_index_by_id(id)
+            .map(|idx| &self.log.qsos()[idx])
+    }
+}
+
+fn make_log() -> Log {
+    make_log_for("W1AW")
+}
+
+fn make_log_for(station_callsign: &str) -> Log {
+    Log::FieldDay(
+        FieldDayLog::new(
+            station_callsign.to_string(),
+            None,
+            3,
+            FdClass::A,
+            "EPA".to_string(),
+            FdPowerCategory::Low,
+            "FN31".to_string(),
+        )
+        .unwrap(),
+    )
+}
+
+fn make_qso(call: &str, band: Band, minute: u32) -> Qso {
+    Qso::new(
+        call.to_string(),
+        "59".to_string(),
+        "59".to_string(),
+        band,
+        Mode::Ssb,
+        Utc.with_ymd_and_hms(2026, 6, 27, 18, minute, 0).unwrap(),
+        String::new(),
+        None,
+        Some("2A CT".to_string()),
+        None,
+        None,
+    )
+    .unwrap()
+}
+
+/// Starts a hub station and `n - 1` stations connected to it, all sharing `log`.
+fn start_stations(n: usize, log: &Log) -> Vec<Station> {
+    let hub = SyncNode::start(Some("127.0.0.1:0"), &[]).unwrap();
+    let addr = hub.local_addr().unwrap().to_string();
+    let mut stations = vec![Station {
+        node: hub,
+        log: log.clone(),
+    }];
+    for _ in 1..n {
+        stations.push(Station {
+            node: SyncNode::start(None, std::slice::from_ref(&addr)).unwrap(),
+            log: log.clone(),
+        });
+    }
+    for station in &mut stations {
+        station.node.announce(&mut station.log);
+    }
+    poll_until(&mut stations, |s| s[0].node.peer_count() == n - 1);
+    stations
+}
+
+fn poll_until(stations: &mut [Station], done: impl Fn(&[Station]) -> bool) {
+    let deadline = Instant::now() + TIMEOUT;
+    while !done(stations) {
+        assert!(Instant::now() < deadline, "stations did not converge");
+        for station in stations.iter_mut() {
+            station.poll();
+        }
+        thread::sleep(Duration::from_millis(10));
+    }
+}
+
+fn converged(stations: &[Station]) -> bool {
+    stations
+        .windows(2)
+        .all(|pair| pair[0].calls() == pair[1].calls())
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[test]
+fn adds_from_every_station_reach_all_stations() {
+    let mut stations = start_stations(3, &make_log());
+    stations[0].add("KD9XYZ", Band::M20, 1);
+    stations[1].add("N0CALL", Band::M40, 2);
+    stations[2].add("K1ABC", Band::M15, 3);
+
+    poll_until(&mut stations, |s| {
+        s.iter().all(|st| st.log.qsos().len() == 3)
+    });
+    assert!(converged(&stations));
+}
+
+#[test]
+fn spoke_changes_are_relayed_through_hub() {
+    let mut stations = start_stations(3, &make_log());
+    let id = stations[1].add("KD9XYZ", Band::M20, 1);
+    poll_until(&mut stations, |s| s[2].qso(&id).is_some());
+}
+
+#[test]
+fn edits_and_deletes_propagate() {
+    let mut stations = start_stations(2, &make_log());
+    let id = stations[0].add("KD9XYZ", Band::M20, 1);
+    poll_until(&mut stations, |s| s[1].qso(&id).is_some());
+
+    stations[1].edit(&id, "worked on dipole");
+    poll_until(&mut stations, |s| {
+        s[0].qso(&id).unwrap().comments == "worked on dipole"
+    });
+
+    stations[0].delete(&id);
+    poll_until(&mut stations, |s| s[1].qso(&id).is_none());
+}
+
+#[test]
+fn concurrent_edits_converge_on_same_copy() {
+    let mut stations = start_stations(2, &make_log());
+    let id = stations[0].add("KD9XYZ", Band::M20, 1);
+    poll_until(&mut stations, |s| s[1].qso(&id).is_some());
+
+    // Both stations edit before either hears about the other's change
+    stations[0].edit(&id, "from station 0");
+    stations[1].edit(&id, "from station 1");
+    poll_until(&mut stations, |s| {
+        s[0].qso(&id).unwrap().comments == s[1].qso(&id).unwrap().comments
+    });
+    // Keep polling briefly so any in-flight message would surface a divergence
+    let settle = Instant::now() + Duration::from_millis(200);
+    while Instant::now() < settle {
+        for station in &mut stations {
+            station.poll();
+        }
+    }
+    assert_eq!(stations[0].qso(&id), stations[1].qso(&id));
+}
+
+#[test]
+fn late_joiner_receives_existing_qsos() {
+    let mut log = make_log();
+    log.add_qso(make_qso("KD9XYZ", Band::M20, 1));
+    let mut stations = start_stations(1, &log);
+    stations[0].add("N0CALL", Band::M40, 2);
+
+    let addr = stations[0].node.local_addr().unwrap().to_string();
+    stations.push(Station {
+        node: SyncNode::start(None, &[addr]).unwrap(),
+        log: make_log_with_id(&log),
+    });
+    poll_until(&mut stations, |s| s[1].log.qsos().len() == 2);
+    assert!(converged(&stations));
+}
+
+/// An empty log sharing `log`'s ID — a station that has not logged anything yet.
+fn make_log_with_id(log: &Log) -> Log {
+    let mut empty = log.clone();
+    while !empty.qsos().is_empty() {
+        empty.remove_qso(0);
+    }
+    empty
+}
+
+#[test]
+fn messages_for_other_logs_are_ignored() {
+    let mut stations = start_stations(2, &make_log());
+    // Station 1 switches to a different club's log
+    stations[1].log = make_log_for("K1ABC");
+    stations[0].add("KD9XYZ", Band::M20, 1);
+    let settle = Instant::now() + Duration::from_millis(300);
+    while Instant::now() < settle {
+        for station in &mut stations {
+            station.poll();
+        }
+        thread::sleep(Duration::from_millis(10));
+    }
+    assert!(stations[1].log.qsos().is_empty());
+}
+
+#[test]
+fn duplicate_check_sees_combined_log() {
+    let mut stations = start_stations(2, &make_log());
+    let id = stations[0].add("KD9XYZ", Band::M20, 1);
+    poll_until(&mut stations, |s| s[1].qso(&id).is_some());
+
+    let dupes = stations[1]
+        .log
+        .find_duplicates(&make_qso("KD9XYZ", Band::M20, 45));
+    assert_eq!(dupes.len(), 1);
+    assert!(
+        stations[1]
+            .log
+            .find_duplicates(&make_qso("KD9XYZ", Band::M40, 45))
+            .is_empty()
+    );
+}